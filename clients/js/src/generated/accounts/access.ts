@@ -17,6 +17,8 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBooleanDecoder,
   getBooleanEncoder,
   getBytesDecoder,
@@ -38,6 +40,7 @@ import {
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import { findAccessPda, type AccessSeeds } from '../pdas';
 
 export const ACCESS_DISCRIMINATOR = new Uint8Array([
   117, 154, 108, 210, 202, 83, 96, 222,
@@ -52,9 +55,21 @@ export type Access = {
   user: Address;
   isGranted: boolean;
   bump: number;
+  partner: Address;
+  version: number;
+  intentRequired: boolean;
+  reserved: Array<number>;
 };
 
-export type AccessArgs = { user: Address; isGranted: boolean; bump: number };
+export type AccessArgs = {
+  user: Address;
+  isGranted: boolean;
+  bump: number;
+  partner: Address;
+  version: number;
+  intentRequired: boolean;
+  reserved: Array<number>;
+};
 
 export function getAccessEncoder(): FixedSizeEncoder<AccessArgs> {
   return transformEncoder(
@@ -63,6 +78,10 @@ export function getAccessEncoder(): FixedSizeEncoder<AccessArgs> {
       ['user', getAddressEncoder()],
       ['isGranted', getBooleanEncoder()],
       ['bump', getU8Encoder()],
+      ['partner', getAddressEncoder()],
+      ['version', getU8Encoder()],
+      ['intentRequired', getBooleanEncoder()],
+      ['reserved', getArrayEncoder(getU8Encoder(), { size: 31 })],
     ]),
     (value) => ({ ...value, discriminator: ACCESS_DISCRIMINATOR })
   );
@@ -74,6 +93,10 @@ export function getAccessDecoder(): FixedSizeDecoder<Access> {
     ['user', getAddressDecoder()],
     ['isGranted', getBooleanDecoder()],
     ['bump', getU8Decoder()],
+    ['partner', getAddressDecoder()],
+    ['version', getU8Decoder()],
+    ['intentRequired', getBooleanDecoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 31 })],
   ]);
 }
 
@@ -135,5 +158,25 @@ export async function fetchAllMaybeAccess(
 }
 
 export function getAccessSize(): number {
-  return 42;
+  return 107;
+}
+
+export async function fetchAccessFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: AccessSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<Access>> {
+  const maybeAccount = await fetchMaybeAccessFromSeeds(rpc, seeds, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeAccessFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: AccessSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<Access>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findAccessPda(seeds, { programAddress });
+  return await fetchMaybeAccess(rpc, address, fetchConfig);
 }
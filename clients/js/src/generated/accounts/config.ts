@@ -17,6 +17,8 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBooleanDecoder,
   getBooleanEncoder,
   getBytesDecoder,
@@ -25,6 +27,10 @@ import {
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -40,6 +46,7 @@ import {
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import { findConfigPda, type ConfigSeeds } from '../pdas';
 
 export const CONFIG_DISCRIMINATOR = new Uint8Array([
   155, 12, 170, 224, 30, 250, 204, 130,
@@ -60,6 +67,14 @@ export type Config = {
   isPaused: boolean;
   cooldownDuration: bigint;
   bump: number;
+  feeBps: number;
+  feeTreasury: Address;
+  maxCommissionBps: number;
+  perUserVault: boolean;
+  namespace: number;
+  version: number;
+  tradeSequence: bigint;
+  reserved: Array<number>;
 };
 
 export type ConfigArgs = {
@@ -72,6 +87,14 @@ export type ConfigArgs = {
   isPaused: boolean;
   cooldownDuration: number | bigint;
   bump: number;
+  feeBps: number;
+  feeTreasury: Address;
+  maxCommissionBps: number;
+  perUserVault: boolean;
+  namespace: number;
+  version: number;
+  tradeSequence: number | bigint;
+  reserved: Array<number>;
 };
 
 export function getConfigEncoder(): FixedSizeEncoder<ConfigArgs> {
@@ -87,6 +110,14 @@ export function getConfigEncoder(): FixedSizeEncoder<ConfigArgs> {
       ['isPaused', getBooleanEncoder()],
       ['cooldownDuration', getI64Encoder()],
       ['bump', getU8Encoder()],
+      ['feeBps', getU16Encoder()],
+      ['feeTreasury', getAddressEncoder()],
+      ['maxCommissionBps', getU16Encoder()],
+      ['perUserVault', getBooleanEncoder()],
+      ['namespace', getU16Encoder()],
+      ['version', getU8Encoder()],
+      ['tradeSequence', getU64Encoder()],
+      ['reserved', getArrayEncoder(getU8Encoder(), { size: 56 })],
     ]),
     (value) => ({ ...value, discriminator: CONFIG_DISCRIMINATOR })
   );
@@ -104,6 +135,14 @@ export function getConfigDecoder(): FixedSizeDecoder<Config> {
     ['isPaused', getBooleanDecoder()],
     ['cooldownDuration', getI64Decoder()],
     ['bump', getU8Decoder()],
+    ['feeBps', getU16Decoder()],
+    ['feeTreasury', getAddressDecoder()],
+    ['maxCommissionBps', getU16Decoder()],
+    ['perUserVault', getBooleanDecoder()],
+    ['namespace', getU16Decoder()],
+    ['version', getU8Decoder()],
+    ['tradeSequence', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 56 })],
   ]);
 }
 
@@ -165,5 +204,25 @@ export async function fetchAllMaybeConfig(
 }
 
 export function getConfigSize(): number {
-  return 155;
+  return 259;
+}
+
+export async function fetchConfigFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: ConfigSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<Config>> {
+  const maybeAccount = await fetchMaybeConfigFromSeeds(rpc, seeds, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeConfigFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: ConfigSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<Config>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findConfigPda(seeds, { programAddress });
+  return await fetchMaybeConfig(rpc, address, fetchConfig);
 }
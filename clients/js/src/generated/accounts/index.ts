@@ -8,3 +8,12 @@
 
 export * from './access';
 export * from './config';
+export * from './dcaPlan';
+export * from './destination';
+export * from './dflowOrder';
+export * from './limitOrder';
+export * from './partner';
+export * from './tradeLog';
+export * from './tradeReceipt';
+export * from './userCredit';
+export * from './userStats';
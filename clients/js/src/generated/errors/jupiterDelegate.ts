@@ -28,104 +28,260 @@ export const JUPITER_DELEGATE_ERROR__CONFIG_ALREADY_INITIALIZED = 0x1774; // 600
 export const JUPITER_DELEGATE_ERROR__INVALID_COOLDOWN_DURATION = 0x1775; // 6005
 /** ConfigPaused: Config is paused */
 export const JUPITER_DELEGATE_ERROR__CONFIG_PAUSED = 0x1776; // 6006
+/** UnsupportedAccountVersion: Unsupported account version */
+export const JUPITER_DELEGATE_ERROR__UNSUPPORTED_ACCOUNT_VERSION = 0x1777; // 6007
+/** AccountAlreadyMigrated: Account is already migrated */
+export const JUPITER_DELEGATE_ERROR__ACCOUNT_ALREADY_MIGRATED = 0x1778; // 6008
 /** SwapTooFrequent: Swap too frequent */
-export const JUPITER_DELEGATE_ERROR__SWAP_TOO_FREQUENT = 0x1777; // 6007
+export const JUPITER_DELEGATE_ERROR__SWAP_TOO_FREQUENT = 0x1779; // 6009
 /** SwapFailed: Swap failed */
-export const JUPITER_DELEGATE_ERROR__SWAP_FAILED = 0x1778; // 6008
+export const JUPITER_DELEGATE_ERROR__SWAP_FAILED = 0x177a; // 6010
 /** InvalidOperator: Invalid operator */
-export const JUPITER_DELEGATE_ERROR__INVALID_OPERATOR = 0x1779; // 6009
+export const JUPITER_DELEGATE_ERROR__INVALID_OPERATOR = 0x177b; // 6011
 /** OnlyAdminCanProposeNewAdmin: Only admin can propose new admin */
-export const JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_PROPOSE_NEW_ADMIN = 0x177a; // 6010
+export const JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_PROPOSE_NEW_ADMIN = 0x177c; // 6012
 /** OnlyProposedAdminCanAccept: Only proposed admin can accept */
-export const JUPITER_DELEGATE_ERROR__ONLY_PROPOSED_ADMIN_CAN_ACCEPT = 0x177b; // 6011
+export const JUPITER_DELEGATE_ERROR__ONLY_PROPOSED_ADMIN_CAN_ACCEPT = 0x177d; // 6013
 /** ProposedAdminAlreadySet: Proposed admin is already set */
-export const JUPITER_DELEGATE_ERROR__PROPOSED_ADMIN_ALREADY_SET = 0x177c; // 6012
+export const JUPITER_DELEGATE_ERROR__PROPOSED_ADMIN_ALREADY_SET = 0x177e; // 6014
 /** ProposedAdminIsCurrentAdmin: Proposed admin is current admin */
-export const JUPITER_DELEGATE_ERROR__PROPOSED_ADMIN_IS_CURRENT_ADMIN = 0x177d; // 6013
+export const JUPITER_DELEGATE_ERROR__PROPOSED_ADMIN_IS_CURRENT_ADMIN = 0x177f; // 6015
 /** NoPendingAdminTransfer: No pending admin transfer */
-export const JUPITER_DELEGATE_ERROR__NO_PENDING_ADMIN_TRANSFER = 0x177e; // 6014
+export const JUPITER_DELEGATE_ERROR__NO_PENDING_ADMIN_TRANSFER = 0x1780; // 6016
 /** OnlyAdminCanModifyCooldownDuration: Only admin can modify cooldown duration */
-export const JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_COOLDOWN_DURATION = 0x177f; // 6015
+export const JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_COOLDOWN_DURATION = 0x1781; // 6017
 /** OnlyAdminCanModifyOperator: Only admin can modify operator */
-export const JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_OPERATOR = 0x1780; // 6016
+export const JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_OPERATOR = 0x1782; // 6018
 /** OnlyAdminCanPause: Only admin can pause */
-export const JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_PAUSE = 0x1781; // 6017
+export const JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_PAUSE = 0x1783; // 6019
+/** OnlyAdminCanModifyProtocolFee: Only admin can modify protocol fee */
+export const JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_PROTOCOL_FEE = 0x1784; // 6020
+/** InvalidFeeBps: Invalid fee bps */
+export const JUPITER_DELEGATE_ERROR__INVALID_FEE_BPS = 0x1785; // 6021
+/** OnlyAdminCanModifyMaxCommission: Only admin can modify max commission */
+export const JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_MAX_COMMISSION = 0x1786; // 6022
 /** SwapAmountTooSmall: Swap amount is too small */
-export const JUPITER_DELEGATE_ERROR__SWAP_AMOUNT_TOO_SMALL = 0x1782; // 6018
+export const JUPITER_DELEGATE_ERROR__SWAP_AMOUNT_TOO_SMALL = 0x1787; // 6023
 /** AdminAuthorityMismatch: Admin authority mismatch */
-export const JUPITER_DELEGATE_ERROR__ADMIN_AUTHORITY_MISMATCH = 0x1783; // 6019
+export const JUPITER_DELEGATE_ERROR__ADMIN_AUTHORITY_MISMATCH = 0x1788; // 6024
 /** NewAdminProposed: New admin proposed */
-export const JUPITER_DELEGATE_ERROR__NEW_ADMIN_PROPOSED = 0x1784; // 6020
+export const JUPITER_DELEGATE_ERROR__NEW_ADMIN_PROPOSED = 0x1789; // 6025
 /** NoNewAdminProposed: No new admin proposed */
-export const JUPITER_DELEGATE_ERROR__NO_NEW_ADMIN_PROPOSED = 0x1785; // 6021
+export const JUPITER_DELEGATE_ERROR__NO_NEW_ADMIN_PROPOSED = 0x178a; // 6026
 /** InsufficientDelegatedAmount: Delegated amount is insufficient */
-export const JUPITER_DELEGATE_ERROR__INSUFFICIENT_DELEGATED_AMOUNT = 0x1786; // 6022
+export const JUPITER_DELEGATE_ERROR__INSUFFICIENT_DELEGATED_AMOUNT = 0x178b; // 6027
 /** DelegateNotApproved: Vault has not been delegated authority */
-export const JUPITER_DELEGATE_ERROR__DELEGATE_NOT_APPROVED = 0x1787; // 6023
+export const JUPITER_DELEGATE_ERROR__DELEGATE_NOT_APPROVED = 0x178c; // 6028
 /** InvalidDelegateTokenAccount: Invalid delegate token account */
-export const JUPITER_DELEGATE_ERROR__INVALID_DELEGATE_TOKEN_ACCOUNT = 0x1788; // 6024
+export const JUPITER_DELEGATE_ERROR__INVALID_DELEGATE_TOKEN_ACCOUNT = 0x178d; // 6029
 /** ReceiverTokenAccountNotFound: Receiver token account not found in remaining accounts */
-export const JUPITER_DELEGATE_ERROR__RECEIVER_TOKEN_ACCOUNT_NOT_FOUND = 0x1789; // 6025
+export const JUPITER_DELEGATE_ERROR__RECEIVER_TOKEN_ACCOUNT_NOT_FOUND = 0x178e; // 6030
 /** OrderEngineFailed: Order engine failed */
-export const JUPITER_DELEGATE_ERROR__ORDER_ENGINE_FAILED = 0x178a; // 6026
+export const JUPITER_DELEGATE_ERROR__ORDER_ENGINE_FAILED = 0x178f; // 6031
 /** InvalidOrderEngineData: Invalid order engine data */
-export const JUPITER_DELEGATE_ERROR__INVALID_ORDER_ENGINE_DATA = 0x178b; // 6027
+export const JUPITER_DELEGATE_ERROR__INVALID_ORDER_ENGINE_DATA = 0x1790; // 6032
+/** InvalidPerpetualsData: Invalid perpetuals data */
+export const JUPITER_DELEGATE_ERROR__INVALID_PERPETUALS_DATA = 0x1791; // 6033
 /** TwoHopInsufficientInputAmount: Two hop insufficient input amount */
-export const JUPITER_DELEGATE_ERROR__TWO_HOP_INSUFFICIENT_INPUT_AMOUNT = 0x178c; // 6028
+export const JUPITER_DELEGATE_ERROR__TWO_HOP_INSUFFICIENT_INPUT_AMOUNT = 0x1792; // 6034
 /** TwoHopMaxSlippageOutputAmountExceeded: Two hop max slippage output amount exceeded */
-export const JUPITER_DELEGATE_ERROR__TWO_HOP_MAX_SLIPPAGE_OUTPUT_AMOUNT_EXCEEDED = 0x178d; // 6029
+export const JUPITER_DELEGATE_ERROR__TWO_HOP_MAX_SLIPPAGE_OUTPUT_AMOUNT_EXCEEDED = 0x1793; // 6035
 /** TwoHopInvalidIntermediateTokenAmount: Two hop invalid intermediate token amount */
-export const JUPITER_DELEGATE_ERROR__TWO_HOP_INVALID_INTERMEDIATE_TOKEN_AMOUNT = 0x178e; // 6030
+export const JUPITER_DELEGATE_ERROR__TWO_HOP_INVALID_INTERMEDIATE_TOKEN_AMOUNT = 0x1794; // 6036
 /** VaultOutputTokenAccountIsInsufficient: Vault output token account is insufficient */
-export const JUPITER_DELEGATE_ERROR__VAULT_OUTPUT_TOKEN_ACCOUNT_IS_INSUFFICIENT = 0x178f; // 6031
+export const JUPITER_DELEGATE_ERROR__VAULT_OUTPUT_TOKEN_ACCOUNT_IS_INSUFFICIENT = 0x1795; // 6037
 /** FundVaultOutputTokenAccountNotFound: Fund vault output token account not found */
-export const JUPITER_DELEGATE_ERROR__FUND_VAULT_OUTPUT_TOKEN_ACCOUNT_NOT_FOUND = 0x1790; // 6032
+export const JUPITER_DELEGATE_ERROR__FUND_VAULT_OUTPUT_TOKEN_ACCOUNT_NOT_FOUND = 0x1796; // 6038
 /** UnsupportedTokenProgram: Unsupported token program */
-export const JUPITER_DELEGATE_ERROR__UNSUPPORTED_TOKEN_PROGRAM = 0x1791; // 6033
+export const JUPITER_DELEGATE_ERROR__UNSUPPORTED_TOKEN_PROGRAM = 0x1797; // 6039
+/** UnsupportedMintExtension: Unsupported mint extension */
+export const JUPITER_DELEGATE_ERROR__UNSUPPORTED_MINT_EXTENSION = 0x1798; // 6040
+/** InvalidTransferFee: Invalid transfer fee */
+export const JUPITER_DELEGATE_ERROR__INVALID_TRANSFER_FEE = 0x1799; // 6041
 /** DelegateIsNotReceiver: Delegate is not receiver */
-export const JUPITER_DELEGATE_ERROR__DELEGATE_IS_NOT_RECEIVER = 0x1792; // 6034
+export const JUPITER_DELEGATE_ERROR__DELEGATE_IS_NOT_RECEIVER = 0x179a; // 6042
+/** NativeOutputRequiresWrappedSol: Native output requires wrapped SOL output mint */
+export const JUPITER_DELEGATE_ERROR__NATIVE_OUTPUT_REQUIRES_WRAPPED_SOL = 0x179b; // 6043
+/** UnsupportedVenueInstruction: Venue instruction is not supported */
+export const JUPITER_DELEGATE_ERROR__UNSUPPORTED_VENUE_INSTRUCTION = 0x179c; // 6044
+/** InvalidOkxCommissionData: Invalid okx commission data */
+export const JUPITER_DELEGATE_ERROR__INVALID_OKX_COMMISSION_DATA = 0x179d; // 6045
+/** InvalidCommissionAccount: Commission account is not owned by the fee treasury */
+export const JUPITER_DELEGATE_ERROR__INVALID_COMMISSION_ACCOUNT = 0x179e; // 6046
+/** CommissionRateTooHigh: Commission rate exceeds the configured maximum */
+export const JUPITER_DELEGATE_ERROR__COMMISSION_RATE_TOO_HIGH = 0x179f; // 6047
+/** OkxPlatformFeeNotSupported: OKX platform fee is not supported */
+export const JUPITER_DELEGATE_ERROR__OKX_PLATFORM_FEE_NOT_SUPPORTED = 0x17a0; // 6048
+/** OkxTrimNotSupported: OKX trim is not supported */
+export const JUPITER_DELEGATE_ERROR__OKX_TRIM_NOT_SUPPORTED = 0x17a1; // 6049
+/** InvalidDflowOrderData: Invalid dflow order data */
+export const JUPITER_DELEGATE_ERROR__INVALID_DFLOW_ORDER_DATA = 0x17a2; // 6050
+/** DflowOrderMismatch: Dflow order does not match the delegate order */
+export const JUPITER_DELEGATE_ERROR__DFLOW_ORDER_MISMATCH = 0x17a3; // 6051
+/** DflowOrderStillOpen: Dflow order is still open */
+export const JUPITER_DELEGATE_ERROR__DFLOW_ORDER_STILL_OPEN = 0x17a4; // 6052
+/** InvalidDcaPlan: Invalid dca plan */
+export const JUPITER_DELEGATE_ERROR__INVALID_DCA_PLAN = 0x17a5; // 6053
+/** DcaSliceNotDue: Dca slice is not due */
+export const JUPITER_DELEGATE_ERROR__DCA_SLICE_NOT_DUE = 0x17a6; // 6054
+/** DcaPlanCompleted: Dca plan budget is exhausted */
+export const JUPITER_DELEGATE_ERROR__DCA_PLAN_COMPLETED = 0x17a7; // 6055
+/** DcaPriceOutOfBounds: Dca slice output is out of price bounds */
+export const JUPITER_DELEGATE_ERROR__DCA_PRICE_OUT_OF_BOUNDS = 0x17a8; // 6056
+/** DcaSliceEmpty: Dca slice spent no input */
+export const JUPITER_DELEGATE_ERROR__DCA_SLICE_EMPTY = 0x17a9; // 6057
+/** InvalidLimitOrder: Invalid limit order */
+export const JUPITER_DELEGATE_ERROR__INVALID_LIMIT_ORDER = 0x17aa; // 6058
+/** LimitOrderExpired: Limit order is expired */
+export const JUPITER_DELEGATE_ERROR__LIMIT_ORDER_EXPIRED = 0x17ab; // 6059
+/** LimitOrderNotExpired: Limit order is not expired */
+export const JUPITER_DELEGATE_ERROR__LIMIT_ORDER_NOT_EXPIRED = 0x17ac; // 6060
+/** LimitOrderFillTooLarge: Limit order fill exceeds remaining size */
+export const JUPITER_DELEGATE_ERROR__LIMIT_ORDER_FILL_TOO_LARGE = 0x17ad; // 6061
+/** LimitOrderRateNotMet: Realized rate is below the limit rate */
+export const JUPITER_DELEGATE_ERROR__LIMIT_ORDER_RATE_NOT_MET = 0x17ae; // 6062
+/** InvalidBatchLegs: Invalid batch legs */
+export const JUPITER_DELEGATE_ERROR__INVALID_BATCH_LEGS = 0x17af; // 6063
+/** InvalidBatchLegAccounts: Invalid batch leg accounts */
+export const JUPITER_DELEGATE_ERROR__INVALID_BATCH_LEG_ACCOUNTS = 0x17b0; // 6064
+/** BatchRequiresSharedVault: Batch trades require the shared vault */
+export const JUPITER_DELEGATE_ERROR__BATCH_REQUIRES_SHARED_VAULT = 0x17b1; // 6065
+/** OnlyAdminCanModifyVaultMode: Only admin can modify vault mode */
+export const JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_VAULT_MODE = 0x17b2; // 6066
+/** InvalidVaultMigration: Invalid vault migration */
+export const JUPITER_DELEGATE_ERROR__INVALID_VAULT_MIGRATION = 0x17b3; // 6067
+/** TradeAlreadyExecuted: Trade with this client order id was already executed */
+export const JUPITER_DELEGATE_ERROR__TRADE_ALREADY_EXECUTED = 0x17b4; // 6068
+/** TradeReceiptRetentionNotElapsed: Trade receipt retention period has not elapsed */
+export const JUPITER_DELEGATE_ERROR__TRADE_RECEIPT_RETENTION_NOT_ELAPSED = 0x17b5; // 6069
+/** InvalidTradeReceipt: Invalid trade receipt */
+export const JUPITER_DELEGATE_ERROR__INVALID_TRADE_RECEIPT = 0x17b6; // 6070
+/** InvalidUserCredit: Invalid user credit account */
+export const JUPITER_DELEGATE_ERROR__INVALID_USER_CREDIT = 0x17b7; // 6071
+/** UserCreditRequired: User credit account is required to park output in the vault */
+export const JUPITER_DELEGATE_ERROR__USER_CREDIT_REQUIRED = 0x17b8; // 6072
+/** InsufficientUserCredit: Insufficient user credit */
+export const JUPITER_DELEGATE_ERROR__INSUFFICIENT_USER_CREDIT = 0x17b9; // 6073
+/** DestinationNotAllowed: Destination is neither registered nor credited in the ledger */
+export const JUPITER_DELEGATE_ERROR__DESTINATION_NOT_ALLOWED = 0x17ba; // 6074
+/** InvalidTradeLogCapacity: Invalid trade log capacity */
+export const JUPITER_DELEGATE_ERROR__INVALID_TRADE_LOG_CAPACITY = 0x17bb; // 6075
+/** InvalidUserStats: Invalid user stats account */
+export const JUPITER_DELEGATE_ERROR__INVALID_USER_STATS = 0x17bc; // 6076
+/** IntentRequired: User requires a signed trade intent */
+export const JUPITER_DELEGATE_ERROR__INTENT_REQUIRED = 0x17bd; // 6077
+/** InvalidIntentSignature: Invalid trade intent signature */
+export const JUPITER_DELEGATE_ERROR__INVALID_INTENT_SIGNATURE = 0x17be; // 6078
+/** IntentMismatch: Trade does not match the signed intent */
+export const JUPITER_DELEGATE_ERROR__INTENT_MISMATCH = 0x17bf; // 6079
+/** IntentExpired: Trade intent has expired */
+export const JUPITER_DELEGATE_ERROR__INTENT_EXPIRED = 0x17c0; // 6080
+/** IntentInputExceeded: Input amount exceeds the signed intent */
+export const JUPITER_DELEGATE_ERROR__INTENT_INPUT_EXCEEDED = 0x17c1; // 6081
+/** IntentOutputTooLow: Output amount is below the signed intent */
+export const JUPITER_DELEGATE_ERROR__INTENT_OUTPUT_TOO_LOW = 0x17c2; // 6082
+/** IntentNotSupported: Instruction does not support signed trade intents */
+export const JUPITER_DELEGATE_ERROR__INTENT_NOT_SUPPORTED = 0x17c3; // 6083
+/** InvalidPartner: Invalid partner */
+export const JUPITER_DELEGATE_ERROR__INVALID_PARTNER = 0x17c4; // 6084
+/** InvalidPartnerShareBps: Invalid partner share bps */
+export const JUPITER_DELEGATE_ERROR__INVALID_PARTNER_SHARE_BPS = 0x17c5; // 6085
+/** PartnerFeeTokenAccountNotFound: Partner fee token account not found */
+export const JUPITER_DELEGATE_ERROR__PARTNER_FEE_TOKEN_ACCOUNT_NOT_FOUND = 0x17c6; // 6086
 /** InsufficientFunds: Insufficient funds */
-export const JUPITER_DELEGATE_ERROR__INSUFFICIENT_FUNDS = 0x1793; // 6035
+export const JUPITER_DELEGATE_ERROR__INSUFFICIENT_FUNDS = 0x17c7; // 6087
 /** InvalidTokenAccount: Invalid token account */
-export const JUPITER_DELEGATE_ERROR__INVALID_TOKEN_ACCOUNT = 0x1794; // 6036
+export const JUPITER_DELEGATE_ERROR__INVALID_TOKEN_ACCOUNT = 0x17c8; // 6088
 
 export type JupiterDelegateError =
   | typeof JUPITER_DELEGATE_ERROR__ACCESS_ALREADY_GRANTED
   | typeof JUPITER_DELEGATE_ERROR__ACCESS_NOT_GRANTED
+  | typeof JUPITER_DELEGATE_ERROR__ACCOUNT_ALREADY_MIGRATED
   | typeof JUPITER_DELEGATE_ERROR__ADMIN_AUTHORITY_MISMATCH
+  | typeof JUPITER_DELEGATE_ERROR__BATCH_REQUIRES_SHARED_VAULT
+  | typeof JUPITER_DELEGATE_ERROR__COMMISSION_RATE_TOO_HIGH
   | typeof JUPITER_DELEGATE_ERROR__CONFIG_ALREADY_INITIALIZED
   | typeof JUPITER_DELEGATE_ERROR__CONFIG_NOT_INITIALIZED
   | typeof JUPITER_DELEGATE_ERROR__CONFIG_PAUSED
+  | typeof JUPITER_DELEGATE_ERROR__DCA_PLAN_COMPLETED
+  | typeof JUPITER_DELEGATE_ERROR__DCA_PRICE_OUT_OF_BOUNDS
+  | typeof JUPITER_DELEGATE_ERROR__DCA_SLICE_EMPTY
+  | typeof JUPITER_DELEGATE_ERROR__DCA_SLICE_NOT_DUE
   | typeof JUPITER_DELEGATE_ERROR__DELEGATE_IS_NOT_RECEIVER
   | typeof JUPITER_DELEGATE_ERROR__DELEGATE_NOT_APPROVED
+  | typeof JUPITER_DELEGATE_ERROR__DESTINATION_NOT_ALLOWED
+  | typeof JUPITER_DELEGATE_ERROR__DFLOW_ORDER_MISMATCH
+  | typeof JUPITER_DELEGATE_ERROR__DFLOW_ORDER_STILL_OPEN
   | typeof JUPITER_DELEGATE_ERROR__FUND_VAULT_OUTPUT_TOKEN_ACCOUNT_NOT_FOUND
   | typeof JUPITER_DELEGATE_ERROR__INSUFFICIENT_DELEGATED_AMOUNT
   | typeof JUPITER_DELEGATE_ERROR__INSUFFICIENT_FUNDS
+  | typeof JUPITER_DELEGATE_ERROR__INSUFFICIENT_USER_CREDIT
+  | typeof JUPITER_DELEGATE_ERROR__INTENT_EXPIRED
+  | typeof JUPITER_DELEGATE_ERROR__INTENT_INPUT_EXCEEDED
+  | typeof JUPITER_DELEGATE_ERROR__INTENT_MISMATCH
+  | typeof JUPITER_DELEGATE_ERROR__INTENT_NOT_SUPPORTED
+  | typeof JUPITER_DELEGATE_ERROR__INTENT_OUTPUT_TOO_LOW
+  | typeof JUPITER_DELEGATE_ERROR__INTENT_REQUIRED
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_BATCH_LEGS
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_BATCH_LEG_ACCOUNTS
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_COMMISSION_ACCOUNT
   | typeof JUPITER_DELEGATE_ERROR__INVALID_COOLDOWN_DURATION
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_DCA_PLAN
   | typeof JUPITER_DELEGATE_ERROR__INVALID_DELEGATE_TOKEN_ACCOUNT
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_DFLOW_ORDER_DATA
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_FEE_BPS
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_INTENT_SIGNATURE
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_LIMIT_ORDER
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_OKX_COMMISSION_DATA
   | typeof JUPITER_DELEGATE_ERROR__INVALID_OPERATOR
   | typeof JUPITER_DELEGATE_ERROR__INVALID_ORDER_ENGINE_DATA
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_PARTNER
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_PARTNER_SHARE_BPS
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_PERPETUALS_DATA
   | typeof JUPITER_DELEGATE_ERROR__INVALID_TOKEN_ACCOUNT
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_TRADE_LOG_CAPACITY
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_TRADE_RECEIPT
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_TRANSFER_FEE
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_USER_CREDIT
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_USER_STATS
+  | typeof JUPITER_DELEGATE_ERROR__INVALID_VAULT_MIGRATION
+  | typeof JUPITER_DELEGATE_ERROR__LIMIT_ORDER_EXPIRED
+  | typeof JUPITER_DELEGATE_ERROR__LIMIT_ORDER_FILL_TOO_LARGE
+  | typeof JUPITER_DELEGATE_ERROR__LIMIT_ORDER_NOT_EXPIRED
+  | typeof JUPITER_DELEGATE_ERROR__LIMIT_ORDER_RATE_NOT_MET
+  | typeof JUPITER_DELEGATE_ERROR__NATIVE_OUTPUT_REQUIRES_WRAPPED_SOL
   | typeof JUPITER_DELEGATE_ERROR__NEW_ADMIN_PROPOSED
   | typeof JUPITER_DELEGATE_ERROR__NO_NEW_ADMIN_PROPOSED
   | typeof JUPITER_DELEGATE_ERROR__NO_PENDING_ADMIN_TRANSFER
+  | typeof JUPITER_DELEGATE_ERROR__OKX_PLATFORM_FEE_NOT_SUPPORTED
+  | typeof JUPITER_DELEGATE_ERROR__OKX_TRIM_NOT_SUPPORTED
   | typeof JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_COOLDOWN_DURATION
+  | typeof JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_MAX_COMMISSION
   | typeof JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_OPERATOR
+  | typeof JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_PROTOCOL_FEE
+  | typeof JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_VAULT_MODE
   | typeof JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_PAUSE
   | typeof JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_PROPOSE_NEW_ADMIN
   | typeof JUPITER_DELEGATE_ERROR__ONLY_PROPOSED_ADMIN_CAN_ACCEPT
   | typeof JUPITER_DELEGATE_ERROR__ORDER_ENGINE_FAILED
+  | typeof JUPITER_DELEGATE_ERROR__PARTNER_FEE_TOKEN_ACCOUNT_NOT_FOUND
   | typeof JUPITER_DELEGATE_ERROR__PROPOSED_ADMIN_ALREADY_SET
   | typeof JUPITER_DELEGATE_ERROR__PROPOSED_ADMIN_IS_CURRENT_ADMIN
   | typeof JUPITER_DELEGATE_ERROR__RECEIVER_TOKEN_ACCOUNT_NOT_FOUND
   | typeof JUPITER_DELEGATE_ERROR__SWAP_AMOUNT_TOO_SMALL
   | typeof JUPITER_DELEGATE_ERROR__SWAP_FAILED
   | typeof JUPITER_DELEGATE_ERROR__SWAP_TOO_FREQUENT
+  | typeof JUPITER_DELEGATE_ERROR__TRADE_ALREADY_EXECUTED
+  | typeof JUPITER_DELEGATE_ERROR__TRADE_RECEIPT_RETENTION_NOT_ELAPSED
   | typeof JUPITER_DELEGATE_ERROR__TWO_HOP_INSUFFICIENT_INPUT_AMOUNT
   | typeof JUPITER_DELEGATE_ERROR__TWO_HOP_INVALID_INTERMEDIATE_TOKEN_AMOUNT
   | typeof JUPITER_DELEGATE_ERROR__TWO_HOP_MAX_SLIPPAGE_OUTPUT_AMOUNT_EXCEEDED
   | typeof JUPITER_DELEGATE_ERROR__UNAUTHORIZED
+  | typeof JUPITER_DELEGATE_ERROR__UNSUPPORTED_ACCOUNT_VERSION
+  | typeof JUPITER_DELEGATE_ERROR__UNSUPPORTED_MINT_EXTENSION
   | typeof JUPITER_DELEGATE_ERROR__UNSUPPORTED_TOKEN_PROGRAM
+  | typeof JUPITER_DELEGATE_ERROR__UNSUPPORTED_VENUE_INSTRUCTION
+  | typeof JUPITER_DELEGATE_ERROR__USER_CREDIT_REQUIRED
   | typeof JUPITER_DELEGATE_ERROR__VAULT_OUTPUT_TOKEN_ACCOUNT_IS_INSUFFICIENT;
 
 let jupiterDelegateErrorMessages:
@@ -135,40 +291,92 @@ if (process.env.NODE_ENV !== 'production') {
   jupiterDelegateErrorMessages = {
     [JUPITER_DELEGATE_ERROR__ACCESS_ALREADY_GRANTED]: `Access is already granted`,
     [JUPITER_DELEGATE_ERROR__ACCESS_NOT_GRANTED]: `Access is not granted`,
+    [JUPITER_DELEGATE_ERROR__ACCOUNT_ALREADY_MIGRATED]: `Account is already migrated`,
     [JUPITER_DELEGATE_ERROR__ADMIN_AUTHORITY_MISMATCH]: `Admin authority mismatch`,
+    [JUPITER_DELEGATE_ERROR__BATCH_REQUIRES_SHARED_VAULT]: `Batch trades require the shared vault`,
+    [JUPITER_DELEGATE_ERROR__COMMISSION_RATE_TOO_HIGH]: `Commission rate exceeds the configured maximum`,
     [JUPITER_DELEGATE_ERROR__CONFIG_ALREADY_INITIALIZED]: `Config is already initialized`,
     [JUPITER_DELEGATE_ERROR__CONFIG_NOT_INITIALIZED]: `Config is not initialized`,
     [JUPITER_DELEGATE_ERROR__CONFIG_PAUSED]: `Config is paused`,
+    [JUPITER_DELEGATE_ERROR__DCA_PLAN_COMPLETED]: `Dca plan budget is exhausted`,
+    [JUPITER_DELEGATE_ERROR__DCA_PRICE_OUT_OF_BOUNDS]: `Dca slice output is out of price bounds`,
+    [JUPITER_DELEGATE_ERROR__DCA_SLICE_EMPTY]: `Dca slice spent no input`,
+    [JUPITER_DELEGATE_ERROR__DCA_SLICE_NOT_DUE]: `Dca slice is not due`,
     [JUPITER_DELEGATE_ERROR__DELEGATE_IS_NOT_RECEIVER]: `Delegate is not receiver`,
     [JUPITER_DELEGATE_ERROR__DELEGATE_NOT_APPROVED]: `Vault has not been delegated authority`,
+    [JUPITER_DELEGATE_ERROR__DESTINATION_NOT_ALLOWED]: `Destination is neither registered nor credited in the ledger`,
+    [JUPITER_DELEGATE_ERROR__DFLOW_ORDER_MISMATCH]: `Dflow order does not match the delegate order`,
+    [JUPITER_DELEGATE_ERROR__DFLOW_ORDER_STILL_OPEN]: `Dflow order is still open`,
     [JUPITER_DELEGATE_ERROR__FUND_VAULT_OUTPUT_TOKEN_ACCOUNT_NOT_FOUND]: `Fund vault output token account not found`,
     [JUPITER_DELEGATE_ERROR__INSUFFICIENT_DELEGATED_AMOUNT]: `Delegated amount is insufficient`,
     [JUPITER_DELEGATE_ERROR__INSUFFICIENT_FUNDS]: `Insufficient funds`,
+    [JUPITER_DELEGATE_ERROR__INSUFFICIENT_USER_CREDIT]: `Insufficient user credit`,
+    [JUPITER_DELEGATE_ERROR__INTENT_EXPIRED]: `Trade intent has expired`,
+    [JUPITER_DELEGATE_ERROR__INTENT_INPUT_EXCEEDED]: `Input amount exceeds the signed intent`,
+    [JUPITER_DELEGATE_ERROR__INTENT_MISMATCH]: `Trade does not match the signed intent`,
+    [JUPITER_DELEGATE_ERROR__INTENT_NOT_SUPPORTED]: `Instruction does not support signed trade intents`,
+    [JUPITER_DELEGATE_ERROR__INTENT_OUTPUT_TOO_LOW]: `Output amount is below the signed intent`,
+    [JUPITER_DELEGATE_ERROR__INTENT_REQUIRED]: `User requires a signed trade intent`,
+    [JUPITER_DELEGATE_ERROR__INVALID_BATCH_LEGS]: `Invalid batch legs`,
+    [JUPITER_DELEGATE_ERROR__INVALID_BATCH_LEG_ACCOUNTS]: `Invalid batch leg accounts`,
+    [JUPITER_DELEGATE_ERROR__INVALID_COMMISSION_ACCOUNT]: `Commission account is not owned by the fee treasury`,
     [JUPITER_DELEGATE_ERROR__INVALID_COOLDOWN_DURATION]: `Invalid cooldown duration`,
+    [JUPITER_DELEGATE_ERROR__INVALID_DCA_PLAN]: `Invalid dca plan`,
     [JUPITER_DELEGATE_ERROR__INVALID_DELEGATE_TOKEN_ACCOUNT]: `Invalid delegate token account`,
+    [JUPITER_DELEGATE_ERROR__INVALID_DFLOW_ORDER_DATA]: `Invalid dflow order data`,
+    [JUPITER_DELEGATE_ERROR__INVALID_FEE_BPS]: `Invalid fee bps`,
+    [JUPITER_DELEGATE_ERROR__INVALID_INTENT_SIGNATURE]: `Invalid trade intent signature`,
+    [JUPITER_DELEGATE_ERROR__INVALID_LIMIT_ORDER]: `Invalid limit order`,
+    [JUPITER_DELEGATE_ERROR__INVALID_OKX_COMMISSION_DATA]: `Invalid okx commission data`,
     [JUPITER_DELEGATE_ERROR__INVALID_OPERATOR]: `Invalid operator`,
     [JUPITER_DELEGATE_ERROR__INVALID_ORDER_ENGINE_DATA]: `Invalid order engine data`,
+    [JUPITER_DELEGATE_ERROR__INVALID_PARTNER]: `Invalid partner`,
+    [JUPITER_DELEGATE_ERROR__INVALID_PARTNER_SHARE_BPS]: `Invalid partner share bps`,
+    [JUPITER_DELEGATE_ERROR__INVALID_PERPETUALS_DATA]: `Invalid perpetuals data`,
     [JUPITER_DELEGATE_ERROR__INVALID_TOKEN_ACCOUNT]: `Invalid token account`,
+    [JUPITER_DELEGATE_ERROR__INVALID_TRADE_LOG_CAPACITY]: `Invalid trade log capacity`,
+    [JUPITER_DELEGATE_ERROR__INVALID_TRADE_RECEIPT]: `Invalid trade receipt`,
+    [JUPITER_DELEGATE_ERROR__INVALID_TRANSFER_FEE]: `Invalid transfer fee`,
+    [JUPITER_DELEGATE_ERROR__INVALID_USER_CREDIT]: `Invalid user credit account`,
+    [JUPITER_DELEGATE_ERROR__INVALID_USER_STATS]: `Invalid user stats account`,
+    [JUPITER_DELEGATE_ERROR__INVALID_VAULT_MIGRATION]: `Invalid vault migration`,
+    [JUPITER_DELEGATE_ERROR__LIMIT_ORDER_EXPIRED]: `Limit order is expired`,
+    [JUPITER_DELEGATE_ERROR__LIMIT_ORDER_FILL_TOO_LARGE]: `Limit order fill exceeds remaining size`,
+    [JUPITER_DELEGATE_ERROR__LIMIT_ORDER_NOT_EXPIRED]: `Limit order is not expired`,
+    [JUPITER_DELEGATE_ERROR__LIMIT_ORDER_RATE_NOT_MET]: `Realized rate is below the limit rate`,
+    [JUPITER_DELEGATE_ERROR__NATIVE_OUTPUT_REQUIRES_WRAPPED_SOL]: `Native output requires wrapped SOL output mint`,
     [JUPITER_DELEGATE_ERROR__NEW_ADMIN_PROPOSED]: `New admin proposed`,
     [JUPITER_DELEGATE_ERROR__NO_NEW_ADMIN_PROPOSED]: `No new admin proposed`,
     [JUPITER_DELEGATE_ERROR__NO_PENDING_ADMIN_TRANSFER]: `No pending admin transfer`,
+    [JUPITER_DELEGATE_ERROR__OKX_PLATFORM_FEE_NOT_SUPPORTED]: `OKX platform fee is not supported`,
+    [JUPITER_DELEGATE_ERROR__OKX_TRIM_NOT_SUPPORTED]: `OKX trim is not supported`,
     [JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_COOLDOWN_DURATION]: `Only admin can modify cooldown duration`,
+    [JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_MAX_COMMISSION]: `Only admin can modify max commission`,
     [JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_OPERATOR]: `Only admin can modify operator`,
+    [JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_PROTOCOL_FEE]: `Only admin can modify protocol fee`,
+    [JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_MODIFY_VAULT_MODE]: `Only admin can modify vault mode`,
     [JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_PAUSE]: `Only admin can pause`,
     [JUPITER_DELEGATE_ERROR__ONLY_ADMIN_CAN_PROPOSE_NEW_ADMIN]: `Only admin can propose new admin`,
     [JUPITER_DELEGATE_ERROR__ONLY_PROPOSED_ADMIN_CAN_ACCEPT]: `Only proposed admin can accept`,
     [JUPITER_DELEGATE_ERROR__ORDER_ENGINE_FAILED]: `Order engine failed`,
+    [JUPITER_DELEGATE_ERROR__PARTNER_FEE_TOKEN_ACCOUNT_NOT_FOUND]: `Partner fee token account not found`,
     [JUPITER_DELEGATE_ERROR__PROPOSED_ADMIN_ALREADY_SET]: `Proposed admin is already set`,
     [JUPITER_DELEGATE_ERROR__PROPOSED_ADMIN_IS_CURRENT_ADMIN]: `Proposed admin is current admin`,
     [JUPITER_DELEGATE_ERROR__RECEIVER_TOKEN_ACCOUNT_NOT_FOUND]: `Receiver token account not found in remaining accounts`,
     [JUPITER_DELEGATE_ERROR__SWAP_AMOUNT_TOO_SMALL]: `Swap amount is too small`,
     [JUPITER_DELEGATE_ERROR__SWAP_FAILED]: `Swap failed`,
     [JUPITER_DELEGATE_ERROR__SWAP_TOO_FREQUENT]: `Swap too frequent`,
+    [JUPITER_DELEGATE_ERROR__TRADE_ALREADY_EXECUTED]: `Trade with this client order id was already executed`,
+    [JUPITER_DELEGATE_ERROR__TRADE_RECEIPT_RETENTION_NOT_ELAPSED]: `Trade receipt retention period has not elapsed`,
     [JUPITER_DELEGATE_ERROR__TWO_HOP_INSUFFICIENT_INPUT_AMOUNT]: `Two hop insufficient input amount`,
     [JUPITER_DELEGATE_ERROR__TWO_HOP_INVALID_INTERMEDIATE_TOKEN_AMOUNT]: `Two hop invalid intermediate token amount`,
     [JUPITER_DELEGATE_ERROR__TWO_HOP_MAX_SLIPPAGE_OUTPUT_AMOUNT_EXCEEDED]: `Two hop max slippage output amount exceeded`,
     [JUPITER_DELEGATE_ERROR__UNAUTHORIZED]: `Unauthorized`,
+    [JUPITER_DELEGATE_ERROR__UNSUPPORTED_ACCOUNT_VERSION]: `Unsupported account version`,
+    [JUPITER_DELEGATE_ERROR__UNSUPPORTED_MINT_EXTENSION]: `Unsupported mint extension`,
     [JUPITER_DELEGATE_ERROR__UNSUPPORTED_TOKEN_PROGRAM]: `Unsupported token program`,
+    [JUPITER_DELEGATE_ERROR__UNSUPPORTED_VENUE_INSTRUCTION]: `Venue instruction is not supported`,
+    [JUPITER_DELEGATE_ERROR__USER_CREDIT_REQUIRED]: `User credit account is required to park output in the vault`,
     [JUPITER_DELEGATE_ERROR__VAULT_OUTPUT_TOKEN_ACCOUNT_IS_INSUFFICIENT]: `Vault output token account is insufficient`,
   };
 }
@@ -51,6 +51,8 @@ export type AcceptAdminTransferInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -66,6 +68,12 @@ export type AcceptAdminTransferInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -106,22 +114,30 @@ export type AcceptAdminTransferAsyncInput<
   TAccountNewAdmin extends string = string,
   TAccountConfig extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   newAdmin: TransactionSigner<TAccountNewAdmin>;
-  config?: Address<TAccountConfig>;
+  config: Address<TAccountConfig>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
 };
 
 export async function getAcceptAdminTransferInstructionAsync<
   TAccountNewAdmin extends string,
   TAccountConfig extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: AcceptAdminTransferAsyncInput<
     TAccountNewAdmin,
     TAccountConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -129,7 +145,9 @@ export async function getAcceptAdminTransferInstructionAsync<
     TProgramAddress,
     TAccountNewAdmin,
     TAccountConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -141,6 +159,8 @@ export async function getAcceptAdminTransferInstructionAsync<
     newAdmin: { value: input.newAdmin ?? null, isWritable: true },
     config: { value: input.config ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -148,23 +168,23 @@ export async function getAcceptAdminTransferInstructionAsync<
   >;
 
   // Resolve default values.
-  if (!accounts.config.value) {
-    accounts.config.value = await getProgramDerivedAddress({
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
           new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 99, 111, 110, 102, 105, 103,
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
           ])
         ),
       ],
     });
   }
-  if (!accounts.systemProgram.value) {
-    accounts.systemProgram.value =
-      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
-  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -172,6 +192,8 @@ export async function getAcceptAdminTransferInstructionAsync<
       getAccountMeta(accounts.newAdmin),
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getAcceptAdminTransferInstructionDataEncoder().encode({}),
     programAddress,
@@ -179,7 +201,9 @@ export async function getAcceptAdminTransferInstructionAsync<
     TProgramAddress,
     TAccountNewAdmin,
     TAccountConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -187,29 +211,39 @@ export type AcceptAdminTransferInput<
   TAccountNewAdmin extends string = string,
   TAccountConfig extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   newAdmin: TransactionSigner<TAccountNewAdmin>;
   config: Address<TAccountConfig>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
 };
 
 export function getAcceptAdminTransferInstruction<
   TAccountNewAdmin extends string,
   TAccountConfig extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: AcceptAdminTransferInput<
     TAccountNewAdmin,
     TAccountConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): AcceptAdminTransferInstruction<
   TProgramAddress,
   TAccountNewAdmin,
   TAccountConfig,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -220,6 +254,8 @@ export function getAcceptAdminTransferInstruction<
     newAdmin: { value: input.newAdmin ?? null, isWritable: true },
     config: { value: input.config ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -238,6 +274,8 @@ export function getAcceptAdminTransferInstruction<
       getAccountMeta(accounts.newAdmin),
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getAcceptAdminTransferInstructionDataEncoder().encode({}),
     programAddress,
@@ -245,7 +283,9 @@ export function getAcceptAdminTransferInstruction<
     TProgramAddress,
     TAccountNewAdmin,
     TAccountConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -258,6 +298,8 @@ export type ParsedAcceptAdminTransferInstruction<
     newAdmin: TAccountMetas[0];
     config: TAccountMetas[1];
     systemProgram: TAccountMetas[2];
+    eventAuthority: TAccountMetas[3];
+    program: TAccountMetas[4];
   };
   data: AcceptAdminTransferInstructionData;
 };
@@ -270,7 +312,7 @@ export function parseAcceptAdminTransferInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedAcceptAdminTransferInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -286,6 +328,8 @@ export function parseAcceptAdminTransferInstruction<
       newAdmin: getNextAccount(),
       config: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getAcceptAdminTransferInstructionDataDecoder().decode(
       instruction.data
@@ -16,6 +16,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -33,6 +35,8 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -45,6 +49,12 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
+import {
+  getTradeIntentDecoder,
+  getTradeIntentEncoder,
+  type TradeIntent,
+  type TradeIntentArgs,
+} from '../types';
 
 export const FILL_ORDER_ENGINE_DISCRIMINATOR = new Uint8Array([
   182, 236, 4, 68, 64, 15, 189, 85,
@@ -75,9 +85,24 @@ export type FillOrderEngineInstruction<
   TAccountReceiverOutputTokenAccount extends
     | string
     | AccountMeta<string> = string,
+  TAccountFeeTokenAccount extends string | AccountMeta<string> = string,
+  TAccountFeeVaultAuthority extends string | AccountMeta<string> = string,
+  TAccountPartner extends string | AccountMeta<string> = string,
+  TAccountPartnerFeeTokenAccount extends string | AccountMeta<string> = string,
   TAccountJupiterOrderEngineProgram extends
     | string
     | AccountMeta<string> = '61DFfeTKM7trxYcPQCM78bJ794ddZprZpAwAnLiwTpYH',
+  TAccountInstructionsSysvar extends
+    | string
+    | AccountMeta<string> = 'Sysvar1nstructions1111111111111111111111111',
+  TAccountTradeReceipt extends string | AccountMeta<string> = string,
+  TAccountUserStats extends string | AccountMeta<string> = string,
+  TAccountTradeLog extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -123,9 +148,42 @@ export type FillOrderEngineInstruction<
       TAccountReceiverOutputTokenAccount extends string
         ? WritableAccount<TAccountReceiverOutputTokenAccount>
         : TAccountReceiverOutputTokenAccount,
+      TAccountFeeTokenAccount extends string
+        ? WritableAccount<TAccountFeeTokenAccount>
+        : TAccountFeeTokenAccount,
+      TAccountFeeVaultAuthority extends string
+        ? ReadonlyAccount<TAccountFeeVaultAuthority>
+        : TAccountFeeVaultAuthority,
+      TAccountPartner extends string
+        ? ReadonlyAccount<TAccountPartner>
+        : TAccountPartner,
+      TAccountPartnerFeeTokenAccount extends string
+        ? WritableAccount<TAccountPartnerFeeTokenAccount>
+        : TAccountPartnerFeeTokenAccount,
       TAccountJupiterOrderEngineProgram extends string
         ? ReadonlyAccount<TAccountJupiterOrderEngineProgram>
         : TAccountJupiterOrderEngineProgram,
+      TAccountInstructionsSysvar extends string
+        ? ReadonlyAccount<TAccountInstructionsSysvar>
+        : TAccountInstructionsSysvar,
+      TAccountTradeReceipt extends string
+        ? WritableAccount<TAccountTradeReceipt>
+        : TAccountTradeReceipt,
+      TAccountUserStats extends string
+        ? WritableAccount<TAccountUserStats>
+        : TAccountUserStats,
+      TAccountTradeLog extends string
+        ? WritableAccount<TAccountTradeLog>
+        : TAccountTradeLog,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -135,12 +193,16 @@ export type FillOrderEngineInstructionData = {
   data: ReadonlyUint8Array;
   inAmount: bigint;
   delegate: Address;
+  clientOrderId: bigint;
+  intent: Option<TradeIntent>;
 };
 
 export type FillOrderEngineInstructionDataArgs = {
   data: ReadonlyUint8Array;
   inAmount: number | bigint;
   delegate: Address;
+  clientOrderId: number | bigint;
+  intent: OptionOrNullable<TradeIntentArgs>;
 };
 
 export function getFillOrderEngineInstructionDataEncoder(): Encoder<FillOrderEngineInstructionDataArgs> {
@@ -150,6 +212,8 @@ export function getFillOrderEngineInstructionDataEncoder(): Encoder<FillOrderEng
       ['data', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
       ['inAmount', getU64Encoder()],
       ['delegate', getAddressEncoder()],
+      ['clientOrderId', getU64Encoder()],
+      ['intent', getOptionEncoder(getTradeIntentEncoder())],
     ]),
     (value) => ({ ...value, discriminator: FILL_ORDER_ENGINE_DISCRIMINATOR })
   );
@@ -161,6 +225,8 @@ export function getFillOrderEngineInstructionDataDecoder(): Decoder<FillOrderEng
     ['data', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
     ['inAmount', getU64Decoder()],
     ['delegate', getAddressDecoder()],
+    ['clientOrderId', getU64Decoder()],
+    ['intent', getOptionDecoder(getTradeIntentDecoder())],
   ]);
 }
 
@@ -188,25 +254,49 @@ export type FillOrderEngineAsyncInput<
   TAccountAccess extends string = string,
   TAccountUser extends string = string,
   TAccountReceiverOutputTokenAccount extends string = string,
+  TAccountFeeTokenAccount extends string = string,
+  TAccountFeeVaultAuthority extends string = string,
+  TAccountPartner extends string = string,
+  TAccountPartnerFeeTokenAccount extends string = string,
   TAccountJupiterOrderEngineProgram extends string = string,
+  TAccountInstructionsSysvar extends string = string,
+  TAccountTradeReceipt extends string = string,
+  TAccountUserStats extends string = string,
+  TAccountTradeLog extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   inputMint: Address<TAccountInputMint>;
   inputMintProgram: Address<TAccountInputMintProgram>;
   outputMint: Address<TAccountOutputMint>;
   outputMintProgram: Address<TAccountOutputMintProgram>;
   operator: TransactionSigner<TAccountOperator>;
-  vault?: Address<TAccountVault>;
+  vault: Address<TAccountVault>;
   vaultInputTokenAccount?: Address<TAccountVaultInputTokenAccount>;
   vaultOutputTokenAccount?: Address<TAccountVaultOutputTokenAccount>;
   config: Address<TAccountConfig>;
   delegateInputTokenAccount: Address<TAccountDelegateInputTokenAccount>;
-  access?: Address<TAccountAccess>;
+  access: Address<TAccountAccess>;
   user: Address<TAccountUser>;
   receiverOutputTokenAccount?: Address<TAccountReceiverOutputTokenAccount>;
+  feeTokenAccount: Address<TAccountFeeTokenAccount>;
+  feeVaultAuthority: Address<TAccountFeeVaultAuthority>;
+  partner?: Address<TAccountPartner>;
+  partnerFeeTokenAccount?: Address<TAccountPartnerFeeTokenAccount>;
   jupiterOrderEngineProgram?: Address<TAccountJupiterOrderEngineProgram>;
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  tradeReceipt: Address<TAccountTradeReceipt>;
+  userStats: Address<TAccountUserStats>;
+  tradeLog: Address<TAccountTradeLog>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   data: FillOrderEngineInstructionDataArgs['data'];
   inAmount: FillOrderEngineInstructionDataArgs['inAmount'];
   delegate: FillOrderEngineInstructionDataArgs['delegate'];
+  clientOrderId: FillOrderEngineInstructionDataArgs['clientOrderId'];
+  intent: FillOrderEngineInstructionDataArgs['intent'];
 };
 
 export async function getFillOrderEngineInstructionAsync<
@@ -223,7 +313,18 @@ export async function getFillOrderEngineInstructionAsync<
   TAccountAccess extends string,
   TAccountUser extends string,
   TAccountReceiverOutputTokenAccount extends string,
+  TAccountFeeTokenAccount extends string,
+  TAccountFeeVaultAuthority extends string,
+  TAccountPartner extends string,
+  TAccountPartnerFeeTokenAccount extends string,
   TAccountJupiterOrderEngineProgram extends string,
+  TAccountInstructionsSysvar extends string,
+  TAccountTradeReceipt extends string,
+  TAccountUserStats extends string,
+  TAccountTradeLog extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: FillOrderEngineAsyncInput<
@@ -240,7 +341,18 @@ export async function getFillOrderEngineInstructionAsync<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountJupiterOrderEngineProgram
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountJupiterOrderEngineProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -259,7 +371,18 @@ export async function getFillOrderEngineInstructionAsync<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountJupiterOrderEngineProgram
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountJupiterOrderEngineProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -299,10 +422,30 @@ export async function getFillOrderEngineInstructionAsync<
       value: input.receiverOutputTokenAccount ?? null,
       isWritable: true,
     },
+    feeTokenAccount: { value: input.feeTokenAccount ?? null, isWritable: true },
+    feeVaultAuthority: {
+      value: input.feeVaultAuthority ?? null,
+      isWritable: false,
+    },
+    partner: { value: input.partner ?? null, isWritable: false },
+    partnerFeeTokenAccount: {
+      value: input.partnerFeeTokenAccount ?? null,
+      isWritable: true,
+    },
     jupiterOrderEngineProgram: {
       value: input.jupiterOrderEngineProgram ?? null,
       isWritable: false,
     },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
+    tradeReceipt: { value: input.tradeReceipt ?? null, isWritable: true },
+    userStats: { value: input.userStats ?? null, isWritable: true },
+    tradeLog: { value: input.tradeLog ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -313,19 +456,6 @@ export async function getFillOrderEngineInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.vault.value) {
-    accounts.vault.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 118, 97, 117, 108, 116,
-          ])
-        ),
-      ],
-    });
-  }
   if (!accounts.vaultInputTokenAccount.value) {
     accounts.vaultInputTokenAccount.value = await getProgramDerivedAddress({
       programAddress:
@@ -352,20 +482,6 @@ export async function getFillOrderEngineInstructionAsync<
       ],
     });
   }
-  if (!accounts.access.value) {
-    accounts.access.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 97, 99, 99, 101, 115, 115,
-          ])
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.user.value)),
-      ],
-    });
-  }
   if (!accounts.receiverOutputTokenAccount.value) {
     accounts.receiverOutputTokenAccount.value = await getProgramDerivedAddress({
       programAddress:
@@ -383,6 +499,27 @@ export async function getFillOrderEngineInstructionAsync<
     accounts.jupiterOrderEngineProgram.value =
       '61DFfeTKM7trxYcPQCM78bJ794ddZprZpAwAnLiwTpYH' as Address<'61DFfeTKM7trxYcPQCM78bJ794ddZprZpAwAnLiwTpYH'>;
   }
+  if (!accounts.instructionsSysvar.value) {
+    accounts.instructionsSysvar.value =
+      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -400,7 +537,18 @@ export async function getFillOrderEngineInstructionAsync<
       getAccountMeta(accounts.access),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.receiverOutputTokenAccount),
+      getAccountMeta(accounts.feeTokenAccount),
+      getAccountMeta(accounts.feeVaultAuthority),
+      getAccountMeta(accounts.partner),
+      getAccountMeta(accounts.partnerFeeTokenAccount),
       getAccountMeta(accounts.jupiterOrderEngineProgram),
+      getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.tradeReceipt),
+      getAccountMeta(accounts.userStats),
+      getAccountMeta(accounts.tradeLog),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getFillOrderEngineInstructionDataEncoder().encode(
       args as FillOrderEngineInstructionDataArgs
@@ -421,7 +569,18 @@ export async function getFillOrderEngineInstructionAsync<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountJupiterOrderEngineProgram
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountJupiterOrderEngineProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -439,7 +598,18 @@ export type FillOrderEngineInput<
   TAccountAccess extends string = string,
   TAccountUser extends string = string,
   TAccountReceiverOutputTokenAccount extends string = string,
+  TAccountFeeTokenAccount extends string = string,
+  TAccountFeeVaultAuthority extends string = string,
+  TAccountPartner extends string = string,
+  TAccountPartnerFeeTokenAccount extends string = string,
   TAccountJupiterOrderEngineProgram extends string = string,
+  TAccountInstructionsSysvar extends string = string,
+  TAccountTradeReceipt extends string = string,
+  TAccountUserStats extends string = string,
+  TAccountTradeLog extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   inputMint: Address<TAccountInputMint>;
   inputMintProgram: Address<TAccountInputMintProgram>;
@@ -454,10 +624,23 @@ export type FillOrderEngineInput<
   access: Address<TAccountAccess>;
   user: Address<TAccountUser>;
   receiverOutputTokenAccount: Address<TAccountReceiverOutputTokenAccount>;
+  feeTokenAccount: Address<TAccountFeeTokenAccount>;
+  feeVaultAuthority: Address<TAccountFeeVaultAuthority>;
+  partner?: Address<TAccountPartner>;
+  partnerFeeTokenAccount?: Address<TAccountPartnerFeeTokenAccount>;
   jupiterOrderEngineProgram?: Address<TAccountJupiterOrderEngineProgram>;
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  tradeReceipt: Address<TAccountTradeReceipt>;
+  userStats: Address<TAccountUserStats>;
+  tradeLog: Address<TAccountTradeLog>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   data: FillOrderEngineInstructionDataArgs['data'];
   inAmount: FillOrderEngineInstructionDataArgs['inAmount'];
   delegate: FillOrderEngineInstructionDataArgs['delegate'];
+  clientOrderId: FillOrderEngineInstructionDataArgs['clientOrderId'];
+  intent: FillOrderEngineInstructionDataArgs['intent'];
 };
 
 export function getFillOrderEngineInstruction<
@@ -474,7 +657,18 @@ export function getFillOrderEngineInstruction<
   TAccountAccess extends string,
   TAccountUser extends string,
   TAccountReceiverOutputTokenAccount extends string,
+  TAccountFeeTokenAccount extends string,
+  TAccountFeeVaultAuthority extends string,
+  TAccountPartner extends string,
+  TAccountPartnerFeeTokenAccount extends string,
   TAccountJupiterOrderEngineProgram extends string,
+  TAccountInstructionsSysvar extends string,
+  TAccountTradeReceipt extends string,
+  TAccountUserStats extends string,
+  TAccountTradeLog extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: FillOrderEngineInput<
@@ -491,7 +685,18 @@ export function getFillOrderEngineInstruction<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountJupiterOrderEngineProgram
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountJupiterOrderEngineProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): FillOrderEngineInstruction<
@@ -509,7 +714,18 @@ export function getFillOrderEngineInstruction<
   TAccountAccess,
   TAccountUser,
   TAccountReceiverOutputTokenAccount,
-  TAccountJupiterOrderEngineProgram
+  TAccountFeeTokenAccount,
+  TAccountFeeVaultAuthority,
+  TAccountPartner,
+  TAccountPartnerFeeTokenAccount,
+  TAccountJupiterOrderEngineProgram,
+  TAccountInstructionsSysvar,
+  TAccountTradeReceipt,
+  TAccountUserStats,
+  TAccountTradeLog,
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -548,10 +764,30 @@ export function getFillOrderEngineInstruction<
       value: input.receiverOutputTokenAccount ?? null,
       isWritable: true,
     },
+    feeTokenAccount: { value: input.feeTokenAccount ?? null, isWritable: true },
+    feeVaultAuthority: {
+      value: input.feeVaultAuthority ?? null,
+      isWritable: false,
+    },
+    partner: { value: input.partner ?? null, isWritable: false },
+    partnerFeeTokenAccount: {
+      value: input.partnerFeeTokenAccount ?? null,
+      isWritable: true,
+    },
     jupiterOrderEngineProgram: {
       value: input.jupiterOrderEngineProgram ?? null,
       isWritable: false,
     },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
+    tradeReceipt: { value: input.tradeReceipt ?? null, isWritable: true },
+    userStats: { value: input.userStats ?? null, isWritable: true },
+    tradeLog: { value: input.tradeLog ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -566,6 +802,14 @@ export function getFillOrderEngineInstruction<
     accounts.jupiterOrderEngineProgram.value =
       '61DFfeTKM7trxYcPQCM78bJ794ddZprZpAwAnLiwTpYH' as Address<'61DFfeTKM7trxYcPQCM78bJ794ddZprZpAwAnLiwTpYH'>;
   }
+  if (!accounts.instructionsSysvar.value) {
+    accounts.instructionsSysvar.value =
+      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -583,7 +827,18 @@ export function getFillOrderEngineInstruction<
       getAccountMeta(accounts.access),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.receiverOutputTokenAccount),
+      getAccountMeta(accounts.feeTokenAccount),
+      getAccountMeta(accounts.feeVaultAuthority),
+      getAccountMeta(accounts.partner),
+      getAccountMeta(accounts.partnerFeeTokenAccount),
       getAccountMeta(accounts.jupiterOrderEngineProgram),
+      getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.tradeReceipt),
+      getAccountMeta(accounts.userStats),
+      getAccountMeta(accounts.tradeLog),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getFillOrderEngineInstructionDataEncoder().encode(
       args as FillOrderEngineInstructionDataArgs
@@ -604,7 +859,18 @@ export function getFillOrderEngineInstruction<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountJupiterOrderEngineProgram
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountJupiterOrderEngineProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -627,7 +893,18 @@ export type ParsedFillOrderEngineInstruction<
     access: TAccountMetas[10];
     user: TAccountMetas[11];
     receiverOutputTokenAccount: TAccountMetas[12];
-    jupiterOrderEngineProgram: TAccountMetas[13];
+    feeTokenAccount: TAccountMetas[13];
+    feeVaultAuthority: TAccountMetas[14];
+    partner?: TAccountMetas[15] | undefined;
+    partnerFeeTokenAccount?: TAccountMetas[16] | undefined;
+    jupiterOrderEngineProgram: TAccountMetas[17];
+    instructionsSysvar?: TAccountMetas[18] | undefined;
+    tradeReceipt: TAccountMetas[19];
+    userStats: TAccountMetas[20];
+    tradeLog: TAccountMetas[21];
+    systemProgram: TAccountMetas[22];
+    eventAuthority: TAccountMetas[23];
+    program: TAccountMetas[24];
   };
   data: FillOrderEngineInstructionData;
 };
@@ -640,7 +917,7 @@ export function parseFillOrderEngineInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedFillOrderEngineInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 25) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -650,6 +927,12 @@ export function parseFillOrderEngineInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JUPITER_DELEGATE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -666,7 +949,18 @@ export function parseFillOrderEngineInstruction<
       access: getNextAccount(),
       user: getNextAccount(),
       receiverOutputTokenAccount: getNextAccount(),
+      feeTokenAccount: getNextAccount(),
+      feeVaultAuthority: getNextAccount(),
+      partner: getNextOptionalAccount(),
+      partnerFeeTokenAccount: getNextOptionalAccount(),
       jupiterOrderEngineProgram: getNextAccount(),
+      instructionsSysvar: getNextOptionalAccount(),
+      tradeReceipt: getNextAccount(),
+      userStats: getNextAccount(),
+      tradeLog: getNextAccount(),
+      systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getFillOrderEngineInstructionDataDecoder().decode(instruction.data),
   };
@@ -34,11 +34,7 @@ import {
   type WritableSignerAccount,
 } from '@solana/kit';
 import { JUPITER_DELEGATE_PROGRAM_ADDRESS } from '../programs';
-import {
-  expectSome,
-  getAccountMetaFactory,
-  type ResolvedAccount,
-} from '../shared';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
 export const GRANT_ACCESS_DISCRIMINATOR = new Uint8Array([
   66, 88, 87, 113, 39, 22, 27, 165,
@@ -55,12 +51,15 @@ export type GrantAccessInstruction<
   TAccountAdmin extends string | AccountMeta<string> = string,
   TAccountConfig extends string | AccountMeta<string> = string,
   TAccountAccess extends string | AccountMeta<string> = string,
+  TAccountPartner extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
   TAccountRent extends
     | string
     | AccountMeta<string> = 'SysvarRent111111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -76,12 +75,21 @@ export type GrantAccessInstruction<
       TAccountAccess extends string
         ? WritableAccount<TAccountAccess>
         : TAccountAccess,
+      TAccountPartner extends string
+        ? ReadonlyAccount<TAccountPartner>
+        : TAccountPartner,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
       TAccountRent extends string
         ? ReadonlyAccount<TAccountRent>
         : TAccountRent,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -124,14 +132,20 @@ export type GrantAccessAsyncInput<
   TAccountAdmin extends string = string,
   TAccountConfig extends string = string,
   TAccountAccess extends string = string,
+  TAccountPartner extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountRent extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   admin: TransactionSigner<TAccountAdmin>;
   config: Address<TAccountConfig>;
-  access?: Address<TAccountAccess>;
+  access: Address<TAccountAccess>;
+  partner?: Address<TAccountPartner>;
   systemProgram?: Address<TAccountSystemProgram>;
   rent?: Address<TAccountRent>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   user: GrantAccessInstructionDataArgs['user'];
 };
 
@@ -139,16 +153,22 @@ export async function getGrantAccessInstructionAsync<
   TAccountAdmin extends string,
   TAccountConfig extends string,
   TAccountAccess extends string,
+  TAccountPartner extends string,
   TAccountSystemProgram extends string,
   TAccountRent extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: GrantAccessAsyncInput<
     TAccountAdmin,
     TAccountConfig,
     TAccountAccess,
+    TAccountPartner,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -157,8 +177,11 @@ export async function getGrantAccessInstructionAsync<
     TAccountAdmin,
     TAccountConfig,
     TAccountAccess,
+    TAccountPartner,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -170,8 +193,11 @@ export async function getGrantAccessInstructionAsync<
     admin: { value: input.admin ?? null, isWritable: true },
     config: { value: input.config ?? null, isWritable: false },
     access: { value: input.access ?? null, isWritable: true },
+    partner: { value: input.partner ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     rent: { value: input.rent ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -182,28 +208,27 @@ export async function getGrantAccessInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.access.value) {
-    accounts.access.value = await getProgramDerivedAddress({
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.rent.value) {
+    accounts.rent.value =
+      'SysvarRent111111111111111111111111111111111' as Address<'SysvarRent111111111111111111111111111111111'>;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
           new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 97, 99, 99, 101, 115, 115,
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
           ])
         ),
-        getAddressEncoder().encode(expectSome(args.user)),
       ],
     });
   }
-  if (!accounts.systemProgram.value) {
-    accounts.systemProgram.value =
-      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
-  }
-  if (!accounts.rent.value) {
-    accounts.rent.value =
-      'SysvarRent111111111111111111111111111111111' as Address<'SysvarRent111111111111111111111111111111111'>;
-  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -211,8 +236,11 @@ export async function getGrantAccessInstructionAsync<
       getAccountMeta(accounts.admin),
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.access),
+      getAccountMeta(accounts.partner),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.rent),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getGrantAccessInstructionDataEncoder().encode(
       args as GrantAccessInstructionDataArgs
@@ -223,8 +251,11 @@ export async function getGrantAccessInstructionAsync<
     TAccountAdmin,
     TAccountConfig,
     TAccountAccess,
+    TAccountPartner,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -232,14 +263,20 @@ export type GrantAccessInput<
   TAccountAdmin extends string = string,
   TAccountConfig extends string = string,
   TAccountAccess extends string = string,
+  TAccountPartner extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountRent extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   admin: TransactionSigner<TAccountAdmin>;
   config: Address<TAccountConfig>;
   access: Address<TAccountAccess>;
+  partner?: Address<TAccountPartner>;
   systemProgram?: Address<TAccountSystemProgram>;
   rent?: Address<TAccountRent>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   user: GrantAccessInstructionDataArgs['user'];
 };
 
@@ -247,16 +284,22 @@ export function getGrantAccessInstruction<
   TAccountAdmin extends string,
   TAccountConfig extends string,
   TAccountAccess extends string,
+  TAccountPartner extends string,
   TAccountSystemProgram extends string,
   TAccountRent extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: GrantAccessInput<
     TAccountAdmin,
     TAccountConfig,
     TAccountAccess,
+    TAccountPartner,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): GrantAccessInstruction<
@@ -264,8 +307,11 @@ export function getGrantAccessInstruction<
   TAccountAdmin,
   TAccountConfig,
   TAccountAccess,
+  TAccountPartner,
   TAccountSystemProgram,
-  TAccountRent
+  TAccountRent,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -276,8 +322,11 @@ export function getGrantAccessInstruction<
     admin: { value: input.admin ?? null, isWritable: true },
     config: { value: input.config ?? null, isWritable: false },
     access: { value: input.access ?? null, isWritable: true },
+    partner: { value: input.partner ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     rent: { value: input.rent ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -303,8 +352,11 @@ export function getGrantAccessInstruction<
       getAccountMeta(accounts.admin),
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.access),
+      getAccountMeta(accounts.partner),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.rent),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getGrantAccessInstructionDataEncoder().encode(
       args as GrantAccessInstructionDataArgs
@@ -315,8 +367,11 @@ export function getGrantAccessInstruction<
     TAccountAdmin,
     TAccountConfig,
     TAccountAccess,
+    TAccountPartner,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -329,8 +384,11 @@ export type ParsedGrantAccessInstruction<
     admin: TAccountMetas[0];
     config: TAccountMetas[1];
     access: TAccountMetas[2];
-    systemProgram: TAccountMetas[3];
-    rent: TAccountMetas[4];
+    partner?: TAccountMetas[3] | undefined;
+    systemProgram: TAccountMetas[4];
+    rent: TAccountMetas[5];
+    eventAuthority: TAccountMetas[6];
+    program: TAccountMetas[7];
   };
   data: GrantAccessInstructionData;
 };
@@ -343,7 +401,7 @@ export function parseGrantAccessInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedGrantAccessInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -353,14 +411,23 @@ export function parseGrantAccessInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JUPITER_DELEGATE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       admin: getNextAccount(),
       config: getNextAccount(),
       access: getNextAccount(),
+      partner: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
       rent: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getGrantAccessInstructionDataDecoder().decode(instruction.data),
   };
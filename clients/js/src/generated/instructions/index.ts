@@ -7,19 +7,48 @@
  */
 
 export * from './acceptAdminTransfer';
+export * from './addDestination';
+export * from './batchTrade';
+export * from './cancelDcaPlan';
+export * from './cancelLimitOrder';
+export * from './claimPartnerFee';
+export * from './closeTradeReceipt';
+export * from './closeUserStats';
+export * from './createDcaPlan';
+export * from './createLimitOrder';
+export * from './createPartner';
 export * from './dflowAggregator';
+export * from './dflowCloseOrder';
+export * from './dflowOpenOrder';
+export * from './dflowSettleOrder';
+export * from './executeDcaSlice';
+export * from './expireLimitOrder';
+export * from './fillLimitOrder';
 export * from './fillOrderEngine';
 export * from './grantAccess';
 export * from './initConfig';
+export * from './initPartnerFeeAccount';
+export * from './initTradeLog';
 export * from './jupiterAggregator';
 export * from './jupiterPerpetuals';
+export * from './migrateAccess';
+export * from './migrateConfig';
+export * from './migrateUserVault';
+export * from './modifyAccessIntent';
 export * from './modifyCooldownDuration';
+export * from './modifyMaxCommission';
+export * from './modifyPartnerShare';
+export * from './modifyProtocolFee';
+export * from './modifyVaultMode';
 export * from './okxAggregator';
 export * from './pause';
 export * from './proposeNewAdmin';
+export * from './removeDestination';
+export * from './resizeTradeLog';
 export * from './revokeAccess';
 export * from './swap';
 export * from './tokenReceive';
 export * from './transferIn';
 export * from './transferOut';
 export * from './twoHop';
+export * from './withdrawProtocolFee';
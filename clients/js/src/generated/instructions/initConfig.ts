@@ -19,6 +19,8 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -57,6 +59,8 @@ export type InitConfigInstruction<
   TAccountRent extends
     | string
     | AccountMeta<string> = 'SysvarRent111111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -78,17 +82,25 @@ export type InitConfigInstruction<
       TAccountRent extends string
         ? ReadonlyAccount<TAccountRent>
         : TAccountRent,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
 
 export type InitConfigInstructionData = {
   discriminator: ReadonlyUint8Array;
+  namespace: number;
   operator: Address;
   cooldownDuration: bigint;
 };
 
 export type InitConfigInstructionDataArgs = {
+  namespace: number;
   operator: Address;
   cooldownDuration: number | bigint;
 };
@@ -97,6 +109,7 @@ export function getInitConfigInstructionDataEncoder(): FixedSizeEncoder<InitConf
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['namespace', getU16Encoder()],
       ['operator', getAddressEncoder()],
       ['cooldownDuration', getI64Encoder()],
     ]),
@@ -107,6 +120,7 @@ export function getInitConfigInstructionDataEncoder(): FixedSizeEncoder<InitConf
 export function getInitConfigInstructionDataDecoder(): FixedSizeDecoder<InitConfigInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['namespace', getU16Decoder()],
     ['operator', getAddressDecoder()],
     ['cooldownDuration', getI64Decoder()],
   ]);
@@ -128,12 +142,17 @@ export type InitConfigAsyncInput<
   TAccountVault extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountRent extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   admin: TransactionSigner<TAccountAdmin>;
-  config?: Address<TAccountConfig>;
-  vault?: Address<TAccountVault>;
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
   systemProgram?: Address<TAccountSystemProgram>;
   rent?: Address<TAccountRent>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  namespace: InitConfigInstructionDataArgs['namespace'];
   operator: InitConfigInstructionDataArgs['operator'];
   cooldownDuration: InitConfigInstructionDataArgs['cooldownDuration'];
 };
@@ -144,6 +163,8 @@ export async function getInitConfigInstructionAsync<
   TAccountVault extends string,
   TAccountSystemProgram extends string,
   TAccountRent extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: InitConfigAsyncInput<
@@ -151,7 +172,9 @@ export async function getInitConfigInstructionAsync<
     TAccountConfig,
     TAccountVault,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -161,7 +184,9 @@ export async function getInitConfigInstructionAsync<
     TAccountConfig,
     TAccountVault,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -175,6 +200,8 @@ export async function getInitConfigInstructionAsync<
     vault: { value: input.vault ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     rent: { value: input.rent ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -185,40 +212,27 @@ export async function getInitConfigInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.config.value) {
-    accounts.config.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 99, 111, 110, 102, 105, 103,
-          ])
-        ),
-      ],
-    });
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
-  if (!accounts.vault.value) {
-    accounts.vault.value = await getProgramDerivedAddress({
+  if (!accounts.rent.value) {
+    accounts.rent.value =
+      'SysvarRent111111111111111111111111111111111' as Address<'SysvarRent111111111111111111111111111111111'>;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
           new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 118, 97, 117, 108, 116,
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
           ])
         ),
       ],
     });
   }
-  if (!accounts.systemProgram.value) {
-    accounts.systemProgram.value =
-      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
-  }
-  if (!accounts.rent.value) {
-    accounts.rent.value =
-      'SysvarRent111111111111111111111111111111111' as Address<'SysvarRent111111111111111111111111111111111'>;
-  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -228,6 +242,8 @@ export async function getInitConfigInstructionAsync<
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.rent),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getInitConfigInstructionDataEncoder().encode(
       args as InitConfigInstructionDataArgs
@@ -239,7 +255,9 @@ export async function getInitConfigInstructionAsync<
     TAccountConfig,
     TAccountVault,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -249,12 +267,17 @@ export type InitConfigInput<
   TAccountVault extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountRent extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   admin: TransactionSigner<TAccountAdmin>;
   config: Address<TAccountConfig>;
   vault: Address<TAccountVault>;
   systemProgram?: Address<TAccountSystemProgram>;
   rent?: Address<TAccountRent>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  namespace: InitConfigInstructionDataArgs['namespace'];
   operator: InitConfigInstructionDataArgs['operator'];
   cooldownDuration: InitConfigInstructionDataArgs['cooldownDuration'];
 };
@@ -265,6 +288,8 @@ export function getInitConfigInstruction<
   TAccountVault extends string,
   TAccountSystemProgram extends string,
   TAccountRent extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: InitConfigInput<
@@ -272,7 +297,9 @@ export function getInitConfigInstruction<
     TAccountConfig,
     TAccountVault,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): InitConfigInstruction<
@@ -281,7 +308,9 @@ export function getInitConfigInstruction<
   TAccountConfig,
   TAccountVault,
   TAccountSystemProgram,
-  TAccountRent
+  TAccountRent,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -294,6 +323,8 @@ export function getInitConfigInstruction<
     vault: { value: input.vault ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     rent: { value: input.rent ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -321,6 +352,8 @@ export function getInitConfigInstruction<
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.rent),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getInitConfigInstructionDataEncoder().encode(
       args as InitConfigInstructionDataArgs
@@ -332,7 +365,9 @@ export function getInitConfigInstruction<
     TAccountConfig,
     TAccountVault,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -347,6 +382,8 @@ export type ParsedInitConfigInstruction<
     vault: TAccountMetas[2];
     systemProgram: TAccountMetas[3];
     rent: TAccountMetas[4];
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: InitConfigInstructionData;
 };
@@ -359,7 +396,7 @@ export function parseInitConfigInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitConfigInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -377,6 +414,8 @@ export function parseInitConfigInstruction<
       vault: getNextAccount(),
       systemProgram: getNextAccount(),
       rent: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getInitConfigInstructionDataDecoder().decode(instruction.data),
   };
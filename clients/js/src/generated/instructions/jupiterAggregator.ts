@@ -16,6 +16,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -23,8 +25,6 @@ import {
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
-  getUtf8Decoder,
-  getUtf8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -35,6 +35,8 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -47,6 +49,12 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
+import {
+  getTradeIntentDecoder,
+  getTradeIntentEncoder,
+  type TradeIntent,
+  type TradeIntentArgs,
+} from '../types';
 
 export const JUPITER_AGGREGATOR_DISCRIMINATOR = new Uint8Array([
   62, 105, 65, 121, 81, 228, 149, 1,
@@ -77,9 +85,28 @@ export type JupiterAggregatorInstruction<
   TAccountReceiverOutputTokenAccount extends
     | string
     | AccountMeta<string> = string,
+  TAccountNativeOutputTokenAccount extends
+    | string
+    | AccountMeta<string> = string,
+  TAccountFeeTokenAccount extends string | AccountMeta<string> = string,
+  TAccountFeeVaultAuthority extends string | AccountMeta<string> = string,
+  TAccountPartner extends string | AccountMeta<string> = string,
+  TAccountPartnerFeeTokenAccount extends string | AccountMeta<string> = string,
   TAccountJupiterProgram extends
     | string
     | AccountMeta<string> = 'JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4',
+  TAccountInstructionsSysvar extends
+    | string
+    | AccountMeta<string> = 'Sysvar1nstructions1111111111111111111111111',
+  TAccountTradeReceipt extends string | AccountMeta<string> = string,
+  TAccountUserStats extends string | AccountMeta<string> = string,
+  TAccountTradeLog extends string | AccountMeta<string> = string,
+  TAccountUserCredit extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -120,14 +147,53 @@ export type JupiterAggregatorInstruction<
         ? ReadonlyAccount<TAccountAccess>
         : TAccountAccess,
       TAccountUser extends string
-        ? ReadonlyAccount<TAccountUser>
+        ? WritableAccount<TAccountUser>
         : TAccountUser,
       TAccountReceiverOutputTokenAccount extends string
         ? WritableAccount<TAccountReceiverOutputTokenAccount>
         : TAccountReceiverOutputTokenAccount,
+      TAccountNativeOutputTokenAccount extends string
+        ? WritableAccount<TAccountNativeOutputTokenAccount>
+        : TAccountNativeOutputTokenAccount,
+      TAccountFeeTokenAccount extends string
+        ? WritableAccount<TAccountFeeTokenAccount>
+        : TAccountFeeTokenAccount,
+      TAccountFeeVaultAuthority extends string
+        ? ReadonlyAccount<TAccountFeeVaultAuthority>
+        : TAccountFeeVaultAuthority,
+      TAccountPartner extends string
+        ? ReadonlyAccount<TAccountPartner>
+        : TAccountPartner,
+      TAccountPartnerFeeTokenAccount extends string
+        ? WritableAccount<TAccountPartnerFeeTokenAccount>
+        : TAccountPartnerFeeTokenAccount,
       TAccountJupiterProgram extends string
         ? ReadonlyAccount<TAccountJupiterProgram>
         : TAccountJupiterProgram,
+      TAccountInstructionsSysvar extends string
+        ? ReadonlyAccount<TAccountInstructionsSysvar>
+        : TAccountInstructionsSysvar,
+      TAccountTradeReceipt extends string
+        ? WritableAccount<TAccountTradeReceipt>
+        : TAccountTradeReceipt,
+      TAccountUserStats extends string
+        ? WritableAccount<TAccountUserStats>
+        : TAccountUserStats,
+      TAccountTradeLog extends string
+        ? WritableAccount<TAccountTradeLog>
+        : TAccountTradeLog,
+      TAccountUserCredit extends string
+        ? WritableAccount<TAccountUserCredit>
+        : TAccountUserCredit,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -136,15 +202,17 @@ export type JupiterAggregatorInstructionData = {
   discriminator: ReadonlyUint8Array;
   data: ReadonlyUint8Array;
   inAmount: bigint;
-  instructionName: string;
   delegate: Address;
+  clientOrderId: bigint;
+  intent: Option<TradeIntent>;
 };
 
 export type JupiterAggregatorInstructionDataArgs = {
   data: ReadonlyUint8Array;
   inAmount: number | bigint;
-  instructionName: string;
   delegate: Address;
+  clientOrderId: number | bigint;
+  intent: OptionOrNullable<TradeIntentArgs>;
 };
 
 export function getJupiterAggregatorInstructionDataEncoder(): Encoder<JupiterAggregatorInstructionDataArgs> {
@@ -153,11 +221,9 @@ export function getJupiterAggregatorInstructionDataEncoder(): Encoder<JupiterAgg
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['data', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
       ['inAmount', getU64Encoder()],
-      [
-        'instructionName',
-        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()),
-      ],
       ['delegate', getAddressEncoder()],
+      ['clientOrderId', getU64Encoder()],
+      ['intent', getOptionEncoder(getTradeIntentEncoder())],
     ]),
     (value) => ({ ...value, discriminator: JUPITER_AGGREGATOR_DISCRIMINATOR })
   );
@@ -168,11 +234,9 @@ export function getJupiterAggregatorInstructionDataDecoder(): Decoder<JupiterAgg
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['data', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
     ['inAmount', getU64Decoder()],
-    [
-      'instructionName',
-      addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder()),
-    ],
     ['delegate', getAddressDecoder()],
+    ['clientOrderId', getU64Decoder()],
+    ['intent', getOptionDecoder(getTradeIntentDecoder())],
   ]);
 }
 
@@ -200,26 +264,57 @@ export type JupiterAggregatorAsyncInput<
   TAccountAccess extends string = string,
   TAccountUser extends string = string,
   TAccountReceiverOutputTokenAccount extends string = string,
+  TAccountNativeOutputTokenAccount extends string = string,
+  TAccountFeeTokenAccount extends string = string,
+  TAccountFeeVaultAuthority extends string = string,
+  TAccountPartner extends string = string,
+  TAccountPartnerFeeTokenAccount extends string = string,
   TAccountJupiterProgram extends string = string,
+  TAccountInstructionsSysvar extends string = string,
+  TAccountTradeReceipt extends string = string,
+  TAccountUserStats extends string = string,
+  TAccountTradeLog extends string = string,
+  TAccountUserCredit extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   inputMint: Address<TAccountInputMint>;
   inputMintProgram: Address<TAccountInputMintProgram>;
   outputMint: Address<TAccountOutputMint>;
   outputMintProgram: Address<TAccountOutputMintProgram>;
   operator: TransactionSigner<TAccountOperator>;
-  vault?: Address<TAccountVault>;
+  vault: Address<TAccountVault>;
   delegateInputTokenAccount: Address<TAccountDelegateInputTokenAccount>;
   vaultInputTokenAccount?: Address<TAccountVaultInputTokenAccount>;
   vaultOutputTokenAccount?: Address<TAccountVaultOutputTokenAccount>;
   config: Address<TAccountConfig>;
-  access?: Address<TAccountAccess>;
+  access: Address<TAccountAccess>;
   user: Address<TAccountUser>;
   receiverOutputTokenAccount?: Address<TAccountReceiverOutputTokenAccount>;
+  nativeOutputTokenAccount?: Address<TAccountNativeOutputTokenAccount>;
+  feeTokenAccount: Address<TAccountFeeTokenAccount>;
+  feeVaultAuthority: Address<TAccountFeeVaultAuthority>;
+  partner?: Address<TAccountPartner>;
+  partnerFeeTokenAccount?: Address<TAccountPartnerFeeTokenAccount>;
   jupiterProgram?: Address<TAccountJupiterProgram>;
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  tradeReceipt: Address<TAccountTradeReceipt>;
+  userStats: Address<TAccountUserStats>;
+  tradeLog: Address<TAccountTradeLog>;
+  /**
+   * Ledger entry of the user for the output mint, required when the output
+   * is parked in the shared vault
+   */
+  userCredit?: Address<TAccountUserCredit>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   data: JupiterAggregatorInstructionDataArgs['data'];
   inAmount: JupiterAggregatorInstructionDataArgs['inAmount'];
-  instructionName: JupiterAggregatorInstructionDataArgs['instructionName'];
   delegate: JupiterAggregatorInstructionDataArgs['delegate'];
+  clientOrderId: JupiterAggregatorInstructionDataArgs['clientOrderId'];
+  intent: JupiterAggregatorInstructionDataArgs['intent'];
 };
 
 export async function getJupiterAggregatorInstructionAsync<
@@ -236,7 +331,20 @@ export async function getJupiterAggregatorInstructionAsync<
   TAccountAccess extends string,
   TAccountUser extends string,
   TAccountReceiverOutputTokenAccount extends string,
+  TAccountNativeOutputTokenAccount extends string,
+  TAccountFeeTokenAccount extends string,
+  TAccountFeeVaultAuthority extends string,
+  TAccountPartner extends string,
+  TAccountPartnerFeeTokenAccount extends string,
   TAccountJupiterProgram extends string,
+  TAccountInstructionsSysvar extends string,
+  TAccountTradeReceipt extends string,
+  TAccountUserStats extends string,
+  TAccountTradeLog extends string,
+  TAccountUserCredit extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: JupiterAggregatorAsyncInput<
@@ -253,7 +361,20 @@ export async function getJupiterAggregatorInstructionAsync<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountJupiterProgram
+    TAccountNativeOutputTokenAccount,
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountJupiterProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountUserCredit,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -272,7 +393,20 @@ export async function getJupiterAggregatorInstructionAsync<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountJupiterProgram
+    TAccountNativeOutputTokenAccount,
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountJupiterProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountUserCredit,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -307,12 +441,37 @@ export async function getJupiterAggregatorInstructionAsync<
     },
     config: { value: input.config ?? null, isWritable: true },
     access: { value: input.access ?? null, isWritable: false },
-    user: { value: input.user ?? null, isWritable: false },
+    user: { value: input.user ?? null, isWritable: true },
     receiverOutputTokenAccount: {
       value: input.receiverOutputTokenAccount ?? null,
       isWritable: true,
     },
+    nativeOutputTokenAccount: {
+      value: input.nativeOutputTokenAccount ?? null,
+      isWritable: true,
+    },
+    feeTokenAccount: { value: input.feeTokenAccount ?? null, isWritable: true },
+    feeVaultAuthority: {
+      value: input.feeVaultAuthority ?? null,
+      isWritable: false,
+    },
+    partner: { value: input.partner ?? null, isWritable: false },
+    partnerFeeTokenAccount: {
+      value: input.partnerFeeTokenAccount ?? null,
+      isWritable: true,
+    },
     jupiterProgram: { value: input.jupiterProgram ?? null, isWritable: false },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
+    tradeReceipt: { value: input.tradeReceipt ?? null, isWritable: true },
+    userStats: { value: input.userStats ?? null, isWritable: true },
+    tradeLog: { value: input.tradeLog ?? null, isWritable: true },
+    userCredit: { value: input.userCredit ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -323,19 +482,6 @@ export async function getJupiterAggregatorInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.vault.value) {
-    accounts.vault.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 118, 97, 117, 108, 116,
-          ])
-        ),
-      ],
-    });
-  }
   if (!accounts.vaultInputTokenAccount.value) {
     accounts.vaultInputTokenAccount.value = await getProgramDerivedAddress({
       programAddress:
@@ -362,20 +508,6 @@ export async function getJupiterAggregatorInstructionAsync<
       ],
     });
   }
-  if (!accounts.access.value) {
-    accounts.access.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 97, 99, 99, 101, 115, 115,
-          ])
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.user.value)),
-      ],
-    });
-  }
   if (!accounts.receiverOutputTokenAccount.value) {
     accounts.receiverOutputTokenAccount.value = await getProgramDerivedAddress({
       programAddress:
@@ -389,10 +521,46 @@ export async function getJupiterAggregatorInstructionAsync<
       ],
     });
   }
+  if (!accounts.nativeOutputTokenAccount.value) {
+    accounts.nativeOutputTokenAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
+            116, 101, 45, 110, 97, 116, 105, 118, 101, 45, 111, 117, 116, 112,
+            117, 116,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.vault.value)),
+      ],
+    });
+  }
   if (!accounts.jupiterProgram.value) {
     accounts.jupiterProgram.value =
       'JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4' as Address<'JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4'>;
   }
+  if (!accounts.instructionsSysvar.value) {
+    accounts.instructionsSysvar.value =
+      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -410,7 +578,20 @@ export async function getJupiterAggregatorInstructionAsync<
       getAccountMeta(accounts.access),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.receiverOutputTokenAccount),
+      getAccountMeta(accounts.nativeOutputTokenAccount),
+      getAccountMeta(accounts.feeTokenAccount),
+      getAccountMeta(accounts.feeVaultAuthority),
+      getAccountMeta(accounts.partner),
+      getAccountMeta(accounts.partnerFeeTokenAccount),
       getAccountMeta(accounts.jupiterProgram),
+      getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.tradeReceipt),
+      getAccountMeta(accounts.userStats),
+      getAccountMeta(accounts.tradeLog),
+      getAccountMeta(accounts.userCredit),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getJupiterAggregatorInstructionDataEncoder().encode(
       args as JupiterAggregatorInstructionDataArgs
@@ -431,7 +612,20 @@ export async function getJupiterAggregatorInstructionAsync<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountJupiterProgram
+    TAccountNativeOutputTokenAccount,
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountJupiterProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountUserCredit,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -449,7 +643,20 @@ export type JupiterAggregatorInput<
   TAccountAccess extends string = string,
   TAccountUser extends string = string,
   TAccountReceiverOutputTokenAccount extends string = string,
+  TAccountNativeOutputTokenAccount extends string = string,
+  TAccountFeeTokenAccount extends string = string,
+  TAccountFeeVaultAuthority extends string = string,
+  TAccountPartner extends string = string,
+  TAccountPartnerFeeTokenAccount extends string = string,
   TAccountJupiterProgram extends string = string,
+  TAccountInstructionsSysvar extends string = string,
+  TAccountTradeReceipt extends string = string,
+  TAccountUserStats extends string = string,
+  TAccountTradeLog extends string = string,
+  TAccountUserCredit extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   inputMint: Address<TAccountInputMint>;
   inputMintProgram: Address<TAccountInputMintProgram>;
@@ -463,12 +670,30 @@ export type JupiterAggregatorInput<
   config: Address<TAccountConfig>;
   access: Address<TAccountAccess>;
   user: Address<TAccountUser>;
-  receiverOutputTokenAccount: Address<TAccountReceiverOutputTokenAccount>;
+  receiverOutputTokenAccount?: Address<TAccountReceiverOutputTokenAccount>;
+  nativeOutputTokenAccount?: Address<TAccountNativeOutputTokenAccount>;
+  feeTokenAccount: Address<TAccountFeeTokenAccount>;
+  feeVaultAuthority: Address<TAccountFeeVaultAuthority>;
+  partner?: Address<TAccountPartner>;
+  partnerFeeTokenAccount?: Address<TAccountPartnerFeeTokenAccount>;
   jupiterProgram?: Address<TAccountJupiterProgram>;
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  tradeReceipt: Address<TAccountTradeReceipt>;
+  userStats: Address<TAccountUserStats>;
+  tradeLog: Address<TAccountTradeLog>;
+  /**
+   * Ledger entry of the user for the output mint, required when the output
+   * is parked in the shared vault
+   */
+  userCredit?: Address<TAccountUserCredit>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   data: JupiterAggregatorInstructionDataArgs['data'];
   inAmount: JupiterAggregatorInstructionDataArgs['inAmount'];
-  instructionName: JupiterAggregatorInstructionDataArgs['instructionName'];
   delegate: JupiterAggregatorInstructionDataArgs['delegate'];
+  clientOrderId: JupiterAggregatorInstructionDataArgs['clientOrderId'];
+  intent: JupiterAggregatorInstructionDataArgs['intent'];
 };
 
 export function getJupiterAggregatorInstruction<
@@ -485,7 +710,20 @@ export function getJupiterAggregatorInstruction<
   TAccountAccess extends string,
   TAccountUser extends string,
   TAccountReceiverOutputTokenAccount extends string,
+  TAccountNativeOutputTokenAccount extends string,
+  TAccountFeeTokenAccount extends string,
+  TAccountFeeVaultAuthority extends string,
+  TAccountPartner extends string,
+  TAccountPartnerFeeTokenAccount extends string,
   TAccountJupiterProgram extends string,
+  TAccountInstructionsSysvar extends string,
+  TAccountTradeReceipt extends string,
+  TAccountUserStats extends string,
+  TAccountTradeLog extends string,
+  TAccountUserCredit extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: JupiterAggregatorInput<
@@ -502,7 +740,20 @@ export function getJupiterAggregatorInstruction<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountJupiterProgram
+    TAccountNativeOutputTokenAccount,
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountJupiterProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountUserCredit,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): JupiterAggregatorInstruction<
@@ -520,7 +771,20 @@ export function getJupiterAggregatorInstruction<
   TAccountAccess,
   TAccountUser,
   TAccountReceiverOutputTokenAccount,
-  TAccountJupiterProgram
+  TAccountNativeOutputTokenAccount,
+  TAccountFeeTokenAccount,
+  TAccountFeeVaultAuthority,
+  TAccountPartner,
+  TAccountPartnerFeeTokenAccount,
+  TAccountJupiterProgram,
+  TAccountInstructionsSysvar,
+  TAccountTradeReceipt,
+  TAccountUserStats,
+  TAccountTradeLog,
+  TAccountUserCredit,
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -554,12 +818,37 @@ export function getJupiterAggregatorInstruction<
     },
     config: { value: input.config ?? null, isWritable: true },
     access: { value: input.access ?? null, isWritable: false },
-    user: { value: input.user ?? null, isWritable: false },
+    user: { value: input.user ?? null, isWritable: true },
     receiverOutputTokenAccount: {
       value: input.receiverOutputTokenAccount ?? null,
       isWritable: true,
     },
+    nativeOutputTokenAccount: {
+      value: input.nativeOutputTokenAccount ?? null,
+      isWritable: true,
+    },
+    feeTokenAccount: { value: input.feeTokenAccount ?? null, isWritable: true },
+    feeVaultAuthority: {
+      value: input.feeVaultAuthority ?? null,
+      isWritable: false,
+    },
+    partner: { value: input.partner ?? null, isWritable: false },
+    partnerFeeTokenAccount: {
+      value: input.partnerFeeTokenAccount ?? null,
+      isWritable: true,
+    },
     jupiterProgram: { value: input.jupiterProgram ?? null, isWritable: false },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
+    tradeReceipt: { value: input.tradeReceipt ?? null, isWritable: true },
+    userStats: { value: input.userStats ?? null, isWritable: true },
+    tradeLog: { value: input.tradeLog ?? null, isWritable: true },
+    userCredit: { value: input.userCredit ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -574,6 +863,14 @@ export function getJupiterAggregatorInstruction<
     accounts.jupiterProgram.value =
       'JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4' as Address<'JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4'>;
   }
+  if (!accounts.instructionsSysvar.value) {
+    accounts.instructionsSysvar.value =
+      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -591,7 +888,20 @@ export function getJupiterAggregatorInstruction<
       getAccountMeta(accounts.access),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.receiverOutputTokenAccount),
+      getAccountMeta(accounts.nativeOutputTokenAccount),
+      getAccountMeta(accounts.feeTokenAccount),
+      getAccountMeta(accounts.feeVaultAuthority),
+      getAccountMeta(accounts.partner),
+      getAccountMeta(accounts.partnerFeeTokenAccount),
       getAccountMeta(accounts.jupiterProgram),
+      getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.tradeReceipt),
+      getAccountMeta(accounts.userStats),
+      getAccountMeta(accounts.tradeLog),
+      getAccountMeta(accounts.userCredit),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getJupiterAggregatorInstructionDataEncoder().encode(
       args as JupiterAggregatorInstructionDataArgs
@@ -612,7 +922,20 @@ export function getJupiterAggregatorInstruction<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountJupiterProgram
+    TAccountNativeOutputTokenAccount,
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountJupiterProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountUserCredit,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -634,8 +957,25 @@ export type ParsedJupiterAggregatorInstruction<
     config: TAccountMetas[9];
     access: TAccountMetas[10];
     user: TAccountMetas[11];
-    receiverOutputTokenAccount: TAccountMetas[12];
-    jupiterProgram: TAccountMetas[13];
+    receiverOutputTokenAccount?: TAccountMetas[12] | undefined;
+    nativeOutputTokenAccount?: TAccountMetas[13] | undefined;
+    feeTokenAccount: TAccountMetas[14];
+    feeVaultAuthority: TAccountMetas[15];
+    partner?: TAccountMetas[16] | undefined;
+    partnerFeeTokenAccount?: TAccountMetas[17] | undefined;
+    jupiterProgram: TAccountMetas[18];
+    instructionsSysvar?: TAccountMetas[19] | undefined;
+    tradeReceipt: TAccountMetas[20];
+    userStats: TAccountMetas[21];
+    tradeLog: TAccountMetas[22];
+    /**
+     * Ledger entry of the user for the output mint, required when the output
+     * is parked in the shared vault
+     */
+    userCredit?: TAccountMetas[23] | undefined;
+    systemProgram: TAccountMetas[24];
+    eventAuthority: TAccountMetas[25];
+    program: TAccountMetas[26];
   };
   data: JupiterAggregatorInstructionData;
 };
@@ -648,7 +988,7 @@ export function parseJupiterAggregatorInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedJupiterAggregatorInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 27) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -658,6 +998,12 @@ export function parseJupiterAggregatorInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JUPITER_DELEGATE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -673,8 +1019,21 @@ export function parseJupiterAggregatorInstruction<
       config: getNextAccount(),
       access: getNextAccount(),
       user: getNextAccount(),
-      receiverOutputTokenAccount: getNextAccount(),
+      receiverOutputTokenAccount: getNextOptionalAccount(),
+      nativeOutputTokenAccount: getNextOptionalAccount(),
+      feeTokenAccount: getNextAccount(),
+      feeVaultAuthority: getNextAccount(),
+      partner: getNextOptionalAccount(),
+      partnerFeeTokenAccount: getNextOptionalAccount(),
       jupiterProgram: getNextAccount(),
+      instructionsSysvar: getNextOptionalAccount(),
+      tradeReceipt: getNextAccount(),
+      userStats: getNextAccount(),
+      tradeLog: getNextAccount(),
+      userCredit: getNextOptionalAccount(),
+      systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getJupiterAggregatorInstructionDataDecoder().decode(instruction.data),
   };
@@ -16,11 +16,15 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -31,6 +35,8 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -43,6 +49,12 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
+import {
+  getTradeIntentDecoder,
+  getTradeIntentEncoder,
+  type TradeIntent,
+  type TradeIntentArgs,
+} from '../types';
 
 export const JUPITER_PERPETUALS_DISCRIMINATOR = new Uint8Array([
   44, 20, 13, 72, 65, 168, 15, 194,
@@ -73,9 +85,24 @@ export type JupiterPerpetualsInstruction<
   TAccountReceiverOutputTokenAccount extends
     | string
     | AccountMeta<string> = string,
+  TAccountFeeTokenAccount extends string | AccountMeta<string> = string,
+  TAccountFeeVaultAuthority extends string | AccountMeta<string> = string,
+  TAccountPartner extends string | AccountMeta<string> = string,
+  TAccountPartnerFeeTokenAccount extends string | AccountMeta<string> = string,
   TAccountPerpetualsProgram extends
     | string
     | AccountMeta<string> = 'PERPHjGBqRHArX4DySjwM6UJHiR3sWAatqfdBS2qQJu',
+  TAccountInstructionsSysvar extends
+    | string
+    | AccountMeta<string> = 'Sysvar1nstructions1111111111111111111111111',
+  TAccountTradeReceipt extends string | AccountMeta<string> = string,
+  TAccountUserStats extends string | AccountMeta<string> = string,
+  TAccountTradeLog extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -121,9 +148,42 @@ export type JupiterPerpetualsInstruction<
       TAccountReceiverOutputTokenAccount extends string
         ? WritableAccount<TAccountReceiverOutputTokenAccount>
         : TAccountReceiverOutputTokenAccount,
+      TAccountFeeTokenAccount extends string
+        ? WritableAccount<TAccountFeeTokenAccount>
+        : TAccountFeeTokenAccount,
+      TAccountFeeVaultAuthority extends string
+        ? ReadonlyAccount<TAccountFeeVaultAuthority>
+        : TAccountFeeVaultAuthority,
+      TAccountPartner extends string
+        ? ReadonlyAccount<TAccountPartner>
+        : TAccountPartner,
+      TAccountPartnerFeeTokenAccount extends string
+        ? WritableAccount<TAccountPartnerFeeTokenAccount>
+        : TAccountPartnerFeeTokenAccount,
       TAccountPerpetualsProgram extends string
         ? ReadonlyAccount<TAccountPerpetualsProgram>
         : TAccountPerpetualsProgram,
+      TAccountInstructionsSysvar extends string
+        ? ReadonlyAccount<TAccountInstructionsSysvar>
+        : TAccountInstructionsSysvar,
+      TAccountTradeReceipt extends string
+        ? WritableAccount<TAccountTradeReceipt>
+        : TAccountTradeReceipt,
+      TAccountUserStats extends string
+        ? WritableAccount<TAccountUserStats>
+        : TAccountUserStats,
+      TAccountTradeLog extends string
+        ? WritableAccount<TAccountTradeLog>
+        : TAccountTradeLog,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -132,11 +192,15 @@ export type JupiterPerpetualsInstructionData = {
   discriminator: ReadonlyUint8Array;
   delegate: Address;
   data: ReadonlyUint8Array;
+  clientOrderId: bigint;
+  intent: Option<TradeIntent>;
 };
 
 export type JupiterPerpetualsInstructionDataArgs = {
   delegate: Address;
   data: ReadonlyUint8Array;
+  clientOrderId: number | bigint;
+  intent: OptionOrNullable<TradeIntentArgs>;
 };
 
 export function getJupiterPerpetualsInstructionDataEncoder(): Encoder<JupiterPerpetualsInstructionDataArgs> {
@@ -145,6 +209,8 @@ export function getJupiterPerpetualsInstructionDataEncoder(): Encoder<JupiterPer
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['delegate', getAddressEncoder()],
       ['data', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+      ['clientOrderId', getU64Encoder()],
+      ['intent', getOptionEncoder(getTradeIntentEncoder())],
     ]),
     (value) => ({ ...value, discriminator: JUPITER_PERPETUALS_DISCRIMINATOR })
   );
@@ -155,6 +221,8 @@ export function getJupiterPerpetualsInstructionDataDecoder(): Decoder<JupiterPer
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['delegate', getAddressDecoder()],
     ['data', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    ['clientOrderId', getU64Decoder()],
+    ['intent', getOptionDecoder(getTradeIntentDecoder())],
   ]);
 }
 
@@ -182,24 +250,48 @@ export type JupiterPerpetualsAsyncInput<
   TAccountAccess extends string = string,
   TAccountUser extends string = string,
   TAccountReceiverOutputTokenAccount extends string = string,
+  TAccountFeeTokenAccount extends string = string,
+  TAccountFeeVaultAuthority extends string = string,
+  TAccountPartner extends string = string,
+  TAccountPartnerFeeTokenAccount extends string = string,
   TAccountPerpetualsProgram extends string = string,
+  TAccountInstructionsSysvar extends string = string,
+  TAccountTradeReceipt extends string = string,
+  TAccountUserStats extends string = string,
+  TAccountTradeLog extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   inputMint: Address<TAccountInputMint>;
   inputMintProgram: Address<TAccountInputMintProgram>;
   outputMint: Address<TAccountOutputMint>;
   outputMintProgram: Address<TAccountOutputMintProgram>;
   operator: TransactionSigner<TAccountOperator>;
-  vault?: Address<TAccountVault>;
+  vault: Address<TAccountVault>;
   delegateInputTokenAccount: Address<TAccountDelegateInputTokenAccount>;
   vaultInputTokenAccount?: Address<TAccountVaultInputTokenAccount>;
   vaultOutputTokenAccount?: Address<TAccountVaultOutputTokenAccount>;
   config: Address<TAccountConfig>;
-  access?: Address<TAccountAccess>;
+  access: Address<TAccountAccess>;
   user: Address<TAccountUser>;
   receiverOutputTokenAccount?: Address<TAccountReceiverOutputTokenAccount>;
+  feeTokenAccount: Address<TAccountFeeTokenAccount>;
+  feeVaultAuthority: Address<TAccountFeeVaultAuthority>;
+  partner?: Address<TAccountPartner>;
+  partnerFeeTokenAccount?: Address<TAccountPartnerFeeTokenAccount>;
   perpetualsProgram?: Address<TAccountPerpetualsProgram>;
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  tradeReceipt: Address<TAccountTradeReceipt>;
+  userStats: Address<TAccountUserStats>;
+  tradeLog: Address<TAccountTradeLog>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   delegate: JupiterPerpetualsInstructionDataArgs['delegate'];
   data: JupiterPerpetualsInstructionDataArgs['data'];
+  clientOrderId: JupiterPerpetualsInstructionDataArgs['clientOrderId'];
+  intent: JupiterPerpetualsInstructionDataArgs['intent'];
 };
 
 export async function getJupiterPerpetualsInstructionAsync<
@@ -216,7 +308,18 @@ export async function getJupiterPerpetualsInstructionAsync<
   TAccountAccess extends string,
   TAccountUser extends string,
   TAccountReceiverOutputTokenAccount extends string,
+  TAccountFeeTokenAccount extends string,
+  TAccountFeeVaultAuthority extends string,
+  TAccountPartner extends string,
+  TAccountPartnerFeeTokenAccount extends string,
   TAccountPerpetualsProgram extends string,
+  TAccountInstructionsSysvar extends string,
+  TAccountTradeReceipt extends string,
+  TAccountUserStats extends string,
+  TAccountTradeLog extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: JupiterPerpetualsAsyncInput<
@@ -233,7 +336,18 @@ export async function getJupiterPerpetualsInstructionAsync<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountPerpetualsProgram
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountPerpetualsProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -252,7 +366,18 @@ export async function getJupiterPerpetualsInstructionAsync<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountPerpetualsProgram
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountPerpetualsProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -292,10 +417,30 @@ export async function getJupiterPerpetualsInstructionAsync<
       value: input.receiverOutputTokenAccount ?? null,
       isWritable: true,
     },
+    feeTokenAccount: { value: input.feeTokenAccount ?? null, isWritable: true },
+    feeVaultAuthority: {
+      value: input.feeVaultAuthority ?? null,
+      isWritable: false,
+    },
+    partner: { value: input.partner ?? null, isWritable: false },
+    partnerFeeTokenAccount: {
+      value: input.partnerFeeTokenAccount ?? null,
+      isWritable: true,
+    },
     perpetualsProgram: {
       value: input.perpetualsProgram ?? null,
       isWritable: false,
     },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
+    tradeReceipt: { value: input.tradeReceipt ?? null, isWritable: true },
+    userStats: { value: input.userStats ?? null, isWritable: true },
+    tradeLog: { value: input.tradeLog ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -306,19 +451,6 @@ export async function getJupiterPerpetualsInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.vault.value) {
-    accounts.vault.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 118, 97, 117, 108, 116,
-          ])
-        ),
-      ],
-    });
-  }
   if (!accounts.vaultInputTokenAccount.value) {
     accounts.vaultInputTokenAccount.value = await getProgramDerivedAddress({
       programAddress:
@@ -345,20 +477,6 @@ export async function getJupiterPerpetualsInstructionAsync<
       ],
     });
   }
-  if (!accounts.access.value) {
-    accounts.access.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 97, 99, 99, 101, 115, 115,
-          ])
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.user.value)),
-      ],
-    });
-  }
   if (!accounts.receiverOutputTokenAccount.value) {
     accounts.receiverOutputTokenAccount.value = await getProgramDerivedAddress({
       programAddress:
@@ -376,6 +494,27 @@ export async function getJupiterPerpetualsInstructionAsync<
     accounts.perpetualsProgram.value =
       'PERPHjGBqRHArX4DySjwM6UJHiR3sWAatqfdBS2qQJu' as Address<'PERPHjGBqRHArX4DySjwM6UJHiR3sWAatqfdBS2qQJu'>;
   }
+  if (!accounts.instructionsSysvar.value) {
+    accounts.instructionsSysvar.value =
+      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -393,7 +532,18 @@ export async function getJupiterPerpetualsInstructionAsync<
       getAccountMeta(accounts.access),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.receiverOutputTokenAccount),
+      getAccountMeta(accounts.feeTokenAccount),
+      getAccountMeta(accounts.feeVaultAuthority),
+      getAccountMeta(accounts.partner),
+      getAccountMeta(accounts.partnerFeeTokenAccount),
       getAccountMeta(accounts.perpetualsProgram),
+      getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.tradeReceipt),
+      getAccountMeta(accounts.userStats),
+      getAccountMeta(accounts.tradeLog),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getJupiterPerpetualsInstructionDataEncoder().encode(
       args as JupiterPerpetualsInstructionDataArgs
@@ -414,7 +564,18 @@ export async function getJupiterPerpetualsInstructionAsync<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountPerpetualsProgram
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountPerpetualsProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -432,7 +593,18 @@ export type JupiterPerpetualsInput<
   TAccountAccess extends string = string,
   TAccountUser extends string = string,
   TAccountReceiverOutputTokenAccount extends string = string,
+  TAccountFeeTokenAccount extends string = string,
+  TAccountFeeVaultAuthority extends string = string,
+  TAccountPartner extends string = string,
+  TAccountPartnerFeeTokenAccount extends string = string,
   TAccountPerpetualsProgram extends string = string,
+  TAccountInstructionsSysvar extends string = string,
+  TAccountTradeReceipt extends string = string,
+  TAccountUserStats extends string = string,
+  TAccountTradeLog extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   inputMint: Address<TAccountInputMint>;
   inputMintProgram: Address<TAccountInputMintProgram>;
@@ -447,9 +619,22 @@ export type JupiterPerpetualsInput<
   access: Address<TAccountAccess>;
   user: Address<TAccountUser>;
   receiverOutputTokenAccount: Address<TAccountReceiverOutputTokenAccount>;
+  feeTokenAccount: Address<TAccountFeeTokenAccount>;
+  feeVaultAuthority: Address<TAccountFeeVaultAuthority>;
+  partner?: Address<TAccountPartner>;
+  partnerFeeTokenAccount?: Address<TAccountPartnerFeeTokenAccount>;
   perpetualsProgram?: Address<TAccountPerpetualsProgram>;
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  tradeReceipt: Address<TAccountTradeReceipt>;
+  userStats: Address<TAccountUserStats>;
+  tradeLog: Address<TAccountTradeLog>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   delegate: JupiterPerpetualsInstructionDataArgs['delegate'];
   data: JupiterPerpetualsInstructionDataArgs['data'];
+  clientOrderId: JupiterPerpetualsInstructionDataArgs['clientOrderId'];
+  intent: JupiterPerpetualsInstructionDataArgs['intent'];
 };
 
 export function getJupiterPerpetualsInstruction<
@@ -466,7 +651,18 @@ export function getJupiterPerpetualsInstruction<
   TAccountAccess extends string,
   TAccountUser extends string,
   TAccountReceiverOutputTokenAccount extends string,
+  TAccountFeeTokenAccount extends string,
+  TAccountFeeVaultAuthority extends string,
+  TAccountPartner extends string,
+  TAccountPartnerFeeTokenAccount extends string,
   TAccountPerpetualsProgram extends string,
+  TAccountInstructionsSysvar extends string,
+  TAccountTradeReceipt extends string,
+  TAccountUserStats extends string,
+  TAccountTradeLog extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: JupiterPerpetualsInput<
@@ -483,7 +679,18 @@ export function getJupiterPerpetualsInstruction<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountPerpetualsProgram
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountPerpetualsProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): JupiterPerpetualsInstruction<
@@ -501,7 +708,18 @@ export function getJupiterPerpetualsInstruction<
   TAccountAccess,
   TAccountUser,
   TAccountReceiverOutputTokenAccount,
-  TAccountPerpetualsProgram
+  TAccountFeeTokenAccount,
+  TAccountFeeVaultAuthority,
+  TAccountPartner,
+  TAccountPartnerFeeTokenAccount,
+  TAccountPerpetualsProgram,
+  TAccountInstructionsSysvar,
+  TAccountTradeReceipt,
+  TAccountUserStats,
+  TAccountTradeLog,
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -540,10 +758,30 @@ export function getJupiterPerpetualsInstruction<
       value: input.receiverOutputTokenAccount ?? null,
       isWritable: true,
     },
+    feeTokenAccount: { value: input.feeTokenAccount ?? null, isWritable: true },
+    feeVaultAuthority: {
+      value: input.feeVaultAuthority ?? null,
+      isWritable: false,
+    },
+    partner: { value: input.partner ?? null, isWritable: false },
+    partnerFeeTokenAccount: {
+      value: input.partnerFeeTokenAccount ?? null,
+      isWritable: true,
+    },
     perpetualsProgram: {
       value: input.perpetualsProgram ?? null,
       isWritable: false,
     },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
+    tradeReceipt: { value: input.tradeReceipt ?? null, isWritable: true },
+    userStats: { value: input.userStats ?? null, isWritable: true },
+    tradeLog: { value: input.tradeLog ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -558,6 +796,14 @@ export function getJupiterPerpetualsInstruction<
     accounts.perpetualsProgram.value =
       'PERPHjGBqRHArX4DySjwM6UJHiR3sWAatqfdBS2qQJu' as Address<'PERPHjGBqRHArX4DySjwM6UJHiR3sWAatqfdBS2qQJu'>;
   }
+  if (!accounts.instructionsSysvar.value) {
+    accounts.instructionsSysvar.value =
+      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -575,7 +821,18 @@ export function getJupiterPerpetualsInstruction<
       getAccountMeta(accounts.access),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.receiverOutputTokenAccount),
+      getAccountMeta(accounts.feeTokenAccount),
+      getAccountMeta(accounts.feeVaultAuthority),
+      getAccountMeta(accounts.partner),
+      getAccountMeta(accounts.partnerFeeTokenAccount),
       getAccountMeta(accounts.perpetualsProgram),
+      getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.tradeReceipt),
+      getAccountMeta(accounts.userStats),
+      getAccountMeta(accounts.tradeLog),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getJupiterPerpetualsInstructionDataEncoder().encode(
       args as JupiterPerpetualsInstructionDataArgs
@@ -596,7 +853,18 @@ export function getJupiterPerpetualsInstruction<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccount,
-    TAccountPerpetualsProgram
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountPerpetualsProgram,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -619,7 +887,18 @@ export type ParsedJupiterPerpetualsInstruction<
     access: TAccountMetas[10];
     user: TAccountMetas[11];
     receiverOutputTokenAccount: TAccountMetas[12];
-    perpetualsProgram: TAccountMetas[13];
+    feeTokenAccount: TAccountMetas[13];
+    feeVaultAuthority: TAccountMetas[14];
+    partner?: TAccountMetas[15] | undefined;
+    partnerFeeTokenAccount?: TAccountMetas[16] | undefined;
+    perpetualsProgram: TAccountMetas[17];
+    instructionsSysvar?: TAccountMetas[18] | undefined;
+    tradeReceipt: TAccountMetas[19];
+    userStats: TAccountMetas[20];
+    tradeLog: TAccountMetas[21];
+    systemProgram: TAccountMetas[22];
+    eventAuthority: TAccountMetas[23];
+    program: TAccountMetas[24];
   };
   data: JupiterPerpetualsInstructionData;
 };
@@ -632,7 +911,7 @@ export function parseJupiterPerpetualsInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedJupiterPerpetualsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 25) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -642,6 +921,12 @@ export function parseJupiterPerpetualsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JUPITER_DELEGATE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -658,7 +943,18 @@ export function parseJupiterPerpetualsInstruction<
       access: getNextAccount(),
       user: getNextAccount(),
       receiverOutputTokenAccount: getNextAccount(),
+      feeTokenAccount: getNextAccount(),
+      feeVaultAuthority: getNextAccount(),
+      partner: getNextOptionalAccount(),
+      partnerFeeTokenAccount: getNextOptionalAccount(),
       perpetualsProgram: getNextAccount(),
+      instructionsSysvar: getNextOptionalAccount(),
+      tradeReceipt: getNextAccount(),
+      userStats: getNextAccount(),
+      tradeLog: getNextAccount(),
+      systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getJupiterPerpetualsInstructionDataDecoder().decode(instruction.data),
   };
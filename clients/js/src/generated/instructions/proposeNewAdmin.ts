@@ -52,6 +52,8 @@ export type ProposeNewAdminInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -70,6 +72,12 @@ export type ProposeNewAdminInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -108,11 +116,15 @@ export type ProposeNewAdminAsyncInput<
   TAccountProposedAdmin extends string = string,
   TAccountConfig extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   currentAdmin: TransactionSigner<TAccountCurrentAdmin>;
   proposedAdmin: Address<TAccountProposedAdmin>;
-  config?: Address<TAccountConfig>;
+  config: Address<TAccountConfig>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
 };
 
 export async function getProposeNewAdminInstructionAsync<
@@ -120,13 +132,17 @@ export async function getProposeNewAdminInstructionAsync<
   TAccountProposedAdmin extends string,
   TAccountConfig extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: ProposeNewAdminAsyncInput<
     TAccountCurrentAdmin,
     TAccountProposedAdmin,
     TAccountConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -135,7 +151,9 @@ export async function getProposeNewAdminInstructionAsync<
     TAccountCurrentAdmin,
     TAccountProposedAdmin,
     TAccountConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -148,6 +166,8 @@ export async function getProposeNewAdminInstructionAsync<
     proposedAdmin: { value: input.proposedAdmin ?? null, isWritable: false },
     config: { value: input.config ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -155,23 +175,23 @@ export async function getProposeNewAdminInstructionAsync<
   >;
 
   // Resolve default values.
-  if (!accounts.config.value) {
-    accounts.config.value = await getProgramDerivedAddress({
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
           new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 99, 111, 110, 102, 105, 103,
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
           ])
         ),
       ],
     });
   }
-  if (!accounts.systemProgram.value) {
-    accounts.systemProgram.value =
-      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
-  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -180,6 +200,8 @@ export async function getProposeNewAdminInstructionAsync<
       getAccountMeta(accounts.proposedAdmin),
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getProposeNewAdminInstructionDataEncoder().encode({}),
     programAddress,
@@ -188,7 +210,9 @@ export async function getProposeNewAdminInstructionAsync<
     TAccountCurrentAdmin,
     TAccountProposedAdmin,
     TAccountConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -197,11 +221,15 @@ export type ProposeNewAdminInput<
   TAccountProposedAdmin extends string = string,
   TAccountConfig extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   currentAdmin: TransactionSigner<TAccountCurrentAdmin>;
   proposedAdmin: Address<TAccountProposedAdmin>;
   config: Address<TAccountConfig>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
 };
 
 export function getProposeNewAdminInstruction<
@@ -209,13 +237,17 @@ export function getProposeNewAdminInstruction<
   TAccountProposedAdmin extends string,
   TAccountConfig extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: ProposeNewAdminInput<
     TAccountCurrentAdmin,
     TAccountProposedAdmin,
     TAccountConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): ProposeNewAdminInstruction<
@@ -223,7 +255,9 @@ export function getProposeNewAdminInstruction<
   TAccountCurrentAdmin,
   TAccountProposedAdmin,
   TAccountConfig,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -235,6 +269,8 @@ export function getProposeNewAdminInstruction<
     proposedAdmin: { value: input.proposedAdmin ?? null, isWritable: false },
     config: { value: input.config ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -254,6 +290,8 @@ export function getProposeNewAdminInstruction<
       getAccountMeta(accounts.proposedAdmin),
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getProposeNewAdminInstructionDataEncoder().encode({}),
     programAddress,
@@ -262,7 +300,9 @@ export function getProposeNewAdminInstruction<
     TAccountCurrentAdmin,
     TAccountProposedAdmin,
     TAccountConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -276,6 +316,8 @@ export type ParsedProposeNewAdminInstruction<
     proposedAdmin: TAccountMetas[1];
     config: TAccountMetas[2];
     systemProgram: TAccountMetas[3];
+    eventAuthority: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: ProposeNewAdminInstructionData;
 };
@@ -288,7 +330,7 @@ export function parseProposeNewAdminInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedProposeNewAdminInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -305,6 +347,8 @@ export function parseProposeNewAdminInstruction<
       proposedAdmin: getNextAccount(),
       config: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getProposeNewAdminInstructionDataDecoder().decode(instruction.data),
   };
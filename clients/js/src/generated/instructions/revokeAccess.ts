@@ -34,11 +34,7 @@ import {
   type WritableSignerAccount,
 } from '@solana/kit';
 import { JUPITER_DELEGATE_PROGRAM_ADDRESS } from '../programs';
-import {
-  expectSome,
-  getAccountMetaFactory,
-  type ResolvedAccount,
-} from '../shared';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
 export const REVOKE_ACCESS_DISCRIMINATOR = new Uint8Array([
   106, 128, 38, 169, 103, 238, 102, 147,
@@ -61,6 +57,8 @@ export type RevokeAccessInstruction<
   TAccountRent extends
     | string
     | AccountMeta<string> = 'SysvarRent111111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -82,6 +80,12 @@ export type RevokeAccessInstruction<
       TAccountRent extends string
         ? ReadonlyAccount<TAccountRent>
         : TAccountRent,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -126,12 +130,16 @@ export type RevokeAccessAsyncInput<
   TAccountAccess extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountRent extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   admin: TransactionSigner<TAccountAdmin>;
   config: Address<TAccountConfig>;
-  access?: Address<TAccountAccess>;
+  access: Address<TAccountAccess>;
   systemProgram?: Address<TAccountSystemProgram>;
   rent?: Address<TAccountRent>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   user: RevokeAccessInstructionDataArgs['user'];
 };
 
@@ -141,6 +149,8 @@ export async function getRevokeAccessInstructionAsync<
   TAccountAccess extends string,
   TAccountSystemProgram extends string,
   TAccountRent extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: RevokeAccessAsyncInput<
@@ -148,7 +158,9 @@ export async function getRevokeAccessInstructionAsync<
     TAccountConfig,
     TAccountAccess,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -158,7 +170,9 @@ export async function getRevokeAccessInstructionAsync<
     TAccountConfig,
     TAccountAccess,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -172,6 +186,8 @@ export async function getRevokeAccessInstructionAsync<
     access: { value: input.access ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     rent: { value: input.rent ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -182,28 +198,27 @@ export async function getRevokeAccessInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.access.value) {
-    accounts.access.value = await getProgramDerivedAddress({
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.rent.value) {
+    accounts.rent.value =
+      'SysvarRent111111111111111111111111111111111' as Address<'SysvarRent111111111111111111111111111111111'>;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
           new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 97, 99, 99, 101, 115, 115,
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
           ])
         ),
-        getAddressEncoder().encode(expectSome(args.user)),
       ],
     });
   }
-  if (!accounts.systemProgram.value) {
-    accounts.systemProgram.value =
-      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
-  }
-  if (!accounts.rent.value) {
-    accounts.rent.value =
-      'SysvarRent111111111111111111111111111111111' as Address<'SysvarRent111111111111111111111111111111111'>;
-  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -213,6 +228,8 @@ export async function getRevokeAccessInstructionAsync<
       getAccountMeta(accounts.access),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.rent),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getRevokeAccessInstructionDataEncoder().encode(
       args as RevokeAccessInstructionDataArgs
@@ -224,7 +241,9 @@ export async function getRevokeAccessInstructionAsync<
     TAccountConfig,
     TAccountAccess,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -234,12 +253,16 @@ export type RevokeAccessInput<
   TAccountAccess extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountRent extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   admin: TransactionSigner<TAccountAdmin>;
   config: Address<TAccountConfig>;
   access: Address<TAccountAccess>;
   systemProgram?: Address<TAccountSystemProgram>;
   rent?: Address<TAccountRent>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   user: RevokeAccessInstructionDataArgs['user'];
 };
 
@@ -249,6 +272,8 @@ export function getRevokeAccessInstruction<
   TAccountAccess extends string,
   TAccountSystemProgram extends string,
   TAccountRent extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: RevokeAccessInput<
@@ -256,7 +281,9 @@ export function getRevokeAccessInstruction<
     TAccountConfig,
     TAccountAccess,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): RevokeAccessInstruction<
@@ -265,7 +292,9 @@ export function getRevokeAccessInstruction<
   TAccountConfig,
   TAccountAccess,
   TAccountSystemProgram,
-  TAccountRent
+  TAccountRent,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -278,6 +307,8 @@ export function getRevokeAccessInstruction<
     access: { value: input.access ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     rent: { value: input.rent ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -305,6 +336,8 @@ export function getRevokeAccessInstruction<
       getAccountMeta(accounts.access),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.rent),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getRevokeAccessInstructionDataEncoder().encode(
       args as RevokeAccessInstructionDataArgs
@@ -316,7 +349,9 @@ export function getRevokeAccessInstruction<
     TAccountConfig,
     TAccountAccess,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -331,6 +366,8 @@ export type ParsedRevokeAccessInstruction<
     access: TAccountMetas[2];
     systemProgram: TAccountMetas[3];
     rent: TAccountMetas[4];
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: RevokeAccessInstructionData;
 };
@@ -343,7 +380,7 @@ export function parseRevokeAccessInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedRevokeAccessInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -361,6 +398,8 @@ export function parseRevokeAccessInstruction<
       access: getNextAccount(),
       systemProgram: getNextAccount(),
       rent: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getRevokeAccessInstructionDataDecoder().decode(instruction.data),
   };
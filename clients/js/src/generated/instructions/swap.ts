@@ -72,6 +72,8 @@ export type SwapInstruction<
   TAccountJupiterProgram extends
     | string
     | AccountMeta<string> = 'JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -114,6 +116,12 @@ export type SwapInstruction<
       TAccountJupiterProgram extends string
         ? ReadonlyAccount<TAccountJupiterProgram>
         : TAccountJupiterProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -175,19 +183,23 @@ export type SwapAsyncInput<
   TAccountAccess extends string = string,
   TAccountUser extends string = string,
   TAccountJupiterProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   inputMint: Address<TAccountInputMint>;
   inputMintProgram: Address<TAccountInputMintProgram>;
   outputMint: Address<TAccountOutputMint>;
   outputMintProgram: Address<TAccountOutputMintProgram>;
   operator: TransactionSigner<TAccountOperator>;
-  vault?: Address<TAccountVault>;
+  vault: Address<TAccountVault>;
   vaultInputTokenAccount?: Address<TAccountVaultInputTokenAccount>;
   config: Address<TAccountConfig>;
   delegateInputTokenAccount: Address<TAccountDelegateInputTokenAccount>;
-  access?: Address<TAccountAccess>;
+  access: Address<TAccountAccess>;
   user: Address<TAccountUser>;
   jupiterProgram?: Address<TAccountJupiterProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   data: SwapInstructionDataArgs['data'];
   inAmount: SwapInstructionDataArgs['inAmount'];
   delegate: SwapInstructionDataArgs['delegate'];
@@ -206,6 +218,8 @@ export async function getSwapInstructionAsync<
   TAccountAccess extends string,
   TAccountUser extends string,
   TAccountJupiterProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: SwapAsyncInput<
@@ -220,7 +234,9 @@ export async function getSwapInstructionAsync<
     TAccountDelegateInputTokenAccount,
     TAccountAccess,
     TAccountUser,
-    TAccountJupiterProgram
+    TAccountJupiterProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -237,7 +253,9 @@ export async function getSwapInstructionAsync<
     TAccountDelegateInputTokenAccount,
     TAccountAccess,
     TAccountUser,
-    TAccountJupiterProgram
+    TAccountJupiterProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -270,6 +288,8 @@ export async function getSwapInstructionAsync<
     access: { value: input.access ?? null, isWritable: false },
     user: { value: input.user ?? null, isWritable: false },
     jupiterProgram: { value: input.jupiterProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -280,19 +300,6 @@ export async function getSwapInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.vault.value) {
-    accounts.vault.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 118, 97, 117, 108, 116,
-          ])
-        ),
-      ],
-    });
-  }
   if (!accounts.vaultInputTokenAccount.value) {
     accounts.vaultInputTokenAccount.value = await getProgramDerivedAddress({
       programAddress:
@@ -306,24 +313,23 @@ export async function getSwapInstructionAsync<
       ],
     });
   }
-  if (!accounts.access.value) {
-    accounts.access.value = await getProgramDerivedAddress({
+  if (!accounts.jupiterProgram.value) {
+    accounts.jupiterProgram.value =
+      'JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4' as Address<'JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4'>;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
           new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 97, 99, 99, 101, 115, 115,
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
           ])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.user.value)),
       ],
     });
   }
-  if (!accounts.jupiterProgram.value) {
-    accounts.jupiterProgram.value =
-      'JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4' as Address<'JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4'>;
-  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -340,6 +346,8 @@ export async function getSwapInstructionAsync<
       getAccountMeta(accounts.access),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.jupiterProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getSwapInstructionDataEncoder().encode(
       args as SwapInstructionDataArgs
@@ -358,7 +366,9 @@ export async function getSwapInstructionAsync<
     TAccountDelegateInputTokenAccount,
     TAccountAccess,
     TAccountUser,
-    TAccountJupiterProgram
+    TAccountJupiterProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -375,6 +385,8 @@ export type SwapInput<
   TAccountAccess extends string = string,
   TAccountUser extends string = string,
   TAccountJupiterProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   inputMint: Address<TAccountInputMint>;
   inputMintProgram: Address<TAccountInputMintProgram>;
@@ -388,6 +400,8 @@ export type SwapInput<
   access: Address<TAccountAccess>;
   user: Address<TAccountUser>;
   jupiterProgram?: Address<TAccountJupiterProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   data: SwapInstructionDataArgs['data'];
   inAmount: SwapInstructionDataArgs['inAmount'];
   delegate: SwapInstructionDataArgs['delegate'];
@@ -406,6 +420,8 @@ export function getSwapInstruction<
   TAccountAccess extends string,
   TAccountUser extends string,
   TAccountJupiterProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: SwapInput<
@@ -420,7 +436,9 @@ export function getSwapInstruction<
     TAccountDelegateInputTokenAccount,
     TAccountAccess,
     TAccountUser,
-    TAccountJupiterProgram
+    TAccountJupiterProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): SwapInstruction<
@@ -436,7 +454,9 @@ export function getSwapInstruction<
   TAccountDelegateInputTokenAccount,
   TAccountAccess,
   TAccountUser,
-  TAccountJupiterProgram
+  TAccountJupiterProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -468,6 +488,8 @@ export function getSwapInstruction<
     access: { value: input.access ?? null, isWritable: false },
     user: { value: input.user ?? null, isWritable: false },
     jupiterProgram: { value: input.jupiterProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -498,6 +520,8 @@ export function getSwapInstruction<
       getAccountMeta(accounts.access),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.jupiterProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getSwapInstructionDataEncoder().encode(
       args as SwapInstructionDataArgs
@@ -516,7 +540,9 @@ export function getSwapInstruction<
     TAccountDelegateInputTokenAccount,
     TAccountAccess,
     TAccountUser,
-    TAccountJupiterProgram
+    TAccountJupiterProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -538,6 +564,8 @@ export type ParsedSwapInstruction<
     access: TAccountMetas[9];
     user: TAccountMetas[10];
     jupiterProgram: TAccountMetas[11];
+    eventAuthority: TAccountMetas[12];
+    program: TAccountMetas[13];
   };
   data: SwapInstructionData;
 };
@@ -550,7 +578,7 @@ export function parseSwapInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSwapInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -575,6 +603,8 @@ export function parseSwapInstruction<
       access: getNextAccount(),
       user: getNextAccount(),
       jupiterProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getSwapInstructionDataDecoder().decode(instruction.data),
   };
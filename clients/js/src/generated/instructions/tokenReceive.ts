@@ -63,6 +63,9 @@ export type TokenReceiveInstruction<
   TAccountDelegate extends string | AccountMeta<string> = string,
   TAccountReceiver extends string | AccountMeta<string> = string,
   TAccountAccess extends string | AccountMeta<string> = string,
+  TAccountUserCredit extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -99,6 +102,15 @@ export type TokenReceiveInstruction<
       TAccountAccess extends string
         ? ReadonlyAccount<TAccountAccess>
         : TAccountAccess,
+      TAccountUserCredit extends string
+        ? WritableAccount<TAccountUserCredit>
+        : TAccountUserCredit,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -141,17 +153,23 @@ export type TokenReceiveAsyncInput<
   TAccountDelegate extends string = string,
   TAccountReceiver extends string = string,
   TAccountAccess extends string = string,
+  TAccountUserCredit extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   config: Address<TAccountConfig>;
   outputMint: Address<TAccountOutputMint>;
   outputMintProgram: Address<TAccountOutputMintProgram>;
   executor: TransactionSigner<TAccountExecutor>;
   receiverOutputTokenAccount?: Address<TAccountReceiverOutputTokenAccount>;
-  vault?: Address<TAccountVault>;
+  vault: Address<TAccountVault>;
   vaultOutputTokenAccount?: Address<TAccountVaultOutputTokenAccount>;
   delegate: Address<TAccountDelegate>;
   receiver: Address<TAccountReceiver>;
-  access?: Address<TAccountAccess>;
+  access: Address<TAccountAccess>;
+  userCredit: Address<TAccountUserCredit>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
 };
 
 export async function getTokenReceiveInstructionAsync<
@@ -165,6 +183,9 @@ export async function getTokenReceiveInstructionAsync<
   TAccountDelegate extends string,
   TAccountReceiver extends string,
   TAccountAccess extends string,
+  TAccountUserCredit extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: TokenReceiveAsyncInput<
@@ -177,7 +198,10 @@ export async function getTokenReceiveInstructionAsync<
     TAccountVaultOutputTokenAccount,
     TAccountDelegate,
     TAccountReceiver,
-    TAccountAccess
+    TAccountAccess,
+    TAccountUserCredit,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -192,7 +216,10 @@ export async function getTokenReceiveInstructionAsync<
     TAccountVaultOutputTokenAccount,
     TAccountDelegate,
     TAccountReceiver,
-    TAccountAccess
+    TAccountAccess,
+    TAccountUserCredit,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -220,6 +247,9 @@ export async function getTokenReceiveInstructionAsync<
     delegate: { value: input.delegate ?? null, isWritable: true },
     receiver: { value: input.receiver ?? null, isWritable: true },
     access: { value: input.access ?? null, isWritable: false },
+    userCredit: { value: input.userCredit ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -240,19 +270,6 @@ export async function getTokenReceiveInstructionAsync<
       ],
     });
   }
-  if (!accounts.vault.value) {
-    accounts.vault.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 118, 97, 117, 108, 116,
-          ])
-        ),
-      ],
-    });
-  }
   if (!accounts.vaultOutputTokenAccount.value) {
     accounts.vaultOutputTokenAccount.value = await getProgramDerivedAddress({
       programAddress:
@@ -266,17 +283,16 @@ export async function getTokenReceiveInstructionAsync<
       ],
     });
   }
-  if (!accounts.access.value) {
-    accounts.access.value = await getProgramDerivedAddress({
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
           new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 97, 99, 99, 101, 115, 115,
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
           ])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.receiver.value)),
       ],
     });
   }
@@ -294,6 +310,9 @@ export async function getTokenReceiveInstructionAsync<
       getAccountMeta(accounts.delegate),
       getAccountMeta(accounts.receiver),
       getAccountMeta(accounts.access),
+      getAccountMeta(accounts.userCredit),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getTokenReceiveInstructionDataEncoder().encode({}),
     programAddress,
@@ -308,7 +327,10 @@ export async function getTokenReceiveInstructionAsync<
     TAccountVaultOutputTokenAccount,
     TAccountDelegate,
     TAccountReceiver,
-    TAccountAccess
+    TAccountAccess,
+    TAccountUserCredit,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -323,6 +345,9 @@ export type TokenReceiveInput<
   TAccountDelegate extends string = string,
   TAccountReceiver extends string = string,
   TAccountAccess extends string = string,
+  TAccountUserCredit extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   config: Address<TAccountConfig>;
   outputMint: Address<TAccountOutputMint>;
@@ -334,6 +359,9 @@ export type TokenReceiveInput<
   delegate: Address<TAccountDelegate>;
   receiver: Address<TAccountReceiver>;
   access: Address<TAccountAccess>;
+  userCredit: Address<TAccountUserCredit>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
 };
 
 export function getTokenReceiveInstruction<
@@ -347,6 +375,9 @@ export function getTokenReceiveInstruction<
   TAccountDelegate extends string,
   TAccountReceiver extends string,
   TAccountAccess extends string,
+  TAccountUserCredit extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: TokenReceiveInput<
@@ -359,7 +390,10 @@ export function getTokenReceiveInstruction<
     TAccountVaultOutputTokenAccount,
     TAccountDelegate,
     TAccountReceiver,
-    TAccountAccess
+    TAccountAccess,
+    TAccountUserCredit,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): TokenReceiveInstruction<
@@ -373,7 +407,10 @@ export function getTokenReceiveInstruction<
   TAccountVaultOutputTokenAccount,
   TAccountDelegate,
   TAccountReceiver,
-  TAccountAccess
+  TAccountAccess,
+  TAccountUserCredit,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -400,6 +437,9 @@ export function getTokenReceiveInstruction<
     delegate: { value: input.delegate ?? null, isWritable: true },
     receiver: { value: input.receiver ?? null, isWritable: true },
     access: { value: input.access ?? null, isWritable: false },
+    userCredit: { value: input.userCredit ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -419,6 +459,9 @@ export function getTokenReceiveInstruction<
       getAccountMeta(accounts.delegate),
       getAccountMeta(accounts.receiver),
       getAccountMeta(accounts.access),
+      getAccountMeta(accounts.userCredit),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getTokenReceiveInstructionDataEncoder().encode({}),
     programAddress,
@@ -433,7 +476,10 @@ export function getTokenReceiveInstruction<
     TAccountVaultOutputTokenAccount,
     TAccountDelegate,
     TAccountReceiver,
-    TAccountAccess
+    TAccountAccess,
+    TAccountUserCredit,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -453,6 +499,9 @@ export type ParsedTokenReceiveInstruction<
     delegate: TAccountMetas[7];
     receiver: TAccountMetas[8];
     access: TAccountMetas[9];
+    userCredit: TAccountMetas[10];
+    eventAuthority: TAccountMetas[11];
+    program: TAccountMetas[12];
   };
   data: TokenReceiveInstructionData;
 };
@@ -465,7 +514,7 @@ export function parseTokenReceiveInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedTokenReceiveInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 13) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -488,6 +537,9 @@ export function parseTokenReceiveInstruction<
       delegate: getNextAccount(),
       receiver: getNextAccount(),
       access: getNextAccount(),
+      userCredit: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getTokenReceiveInstructionDataDecoder().decode(instruction.data),
   };
@@ -58,6 +58,8 @@ export type TransferInInstruction<
   TAccountConfig extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
   TAccountToTokenAccount extends string | AccountMeta<string> = string,
+  TAccountAccess extends string | AccountMeta<string> = string,
+  TAccountUserCredit extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
@@ -67,6 +69,8 @@ export type TransferInInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -94,6 +98,12 @@ export type TransferInInstruction<
       TAccountToTokenAccount extends string
         ? WritableAccount<TAccountToTokenAccount>
         : TAccountToTokenAccount,
+      TAccountAccess extends string
+        ? ReadonlyAccount<TAccountAccess>
+        : TAccountAccess,
+      TAccountUserCredit extends string
+        ? WritableAccount<TAccountUserCredit>
+        : TAccountUserCredit,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -103,6 +113,12 @@ export type TransferInInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -149,20 +165,29 @@ export type TransferInAsyncInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
   TAccountToTokenAccount extends string = string,
+  TAccountAccess extends string = string,
+  TAccountUserCredit extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   operator: TransactionSigner<TAccountOperator>;
   authority: Address<TAccountAuthority>;
   tokenMint: Address<TAccountTokenMint>;
   fromTokenAccount: Address<TAccountFromTokenAccount>;
-  config?: Address<TAccountConfig>;
-  vault?: Address<TAccountVault>;
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
   toTokenAccount?: Address<TAccountToTokenAccount>;
+  access: Address<TAccountAccess>;
+  /** Ledger entry of the source owner for the deposited mint */
+  userCredit: Address<TAccountUserCredit>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   amounts: TransferInInstructionDataArgs['amounts'];
 };
 
@@ -174,9 +199,13 @@ export async function getTransferInInstructionAsync<
   TAccountConfig extends string,
   TAccountVault extends string,
   TAccountToTokenAccount extends string,
+  TAccountAccess extends string,
+  TAccountUserCredit extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: TransferInAsyncInput<
@@ -187,9 +216,13 @@ export async function getTransferInInstructionAsync<
     TAccountConfig,
     TAccountVault,
     TAccountToTokenAccount,
+    TAccountAccess,
+    TAccountUserCredit,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -202,9 +235,13 @@ export async function getTransferInInstructionAsync<
     TAccountConfig,
     TAccountVault,
     TAccountToTokenAccount,
+    TAccountAccess,
+    TAccountUserCredit,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -223,12 +260,16 @@ export async function getTransferInInstructionAsync<
     config: { value: input.config ?? null, isWritable: false },
     vault: { value: input.vault ?? null, isWritable: true },
     toTokenAccount: { value: input.toTokenAccount ?? null, isWritable: true },
+    access: { value: input.access ?? null, isWritable: false },
+    userCredit: { value: input.userCredit ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -239,32 +280,6 @@ export async function getTransferInInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.config.value) {
-    accounts.config.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 99, 111, 110, 102, 105, 103,
-          ])
-        ),
-      ],
-    });
-  }
-  if (!accounts.vault.value) {
-    accounts.vault.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 118, 97, 117, 108, 116,
-          ])
-        ),
-      ],
-    });
-  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
@@ -288,6 +303,19 @@ export async function getTransferInInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -299,9 +327,13 @@ export async function getTransferInInstructionAsync<
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.toTokenAccount),
+      getAccountMeta(accounts.access),
+      getAccountMeta(accounts.userCredit),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getTransferInInstructionDataEncoder().encode(
       args as TransferInInstructionDataArgs
@@ -316,9 +348,13 @@ export async function getTransferInInstructionAsync<
     TAccountConfig,
     TAccountVault,
     TAccountToTokenAccount,
+    TAccountAccess,
+    TAccountUserCredit,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -330,9 +366,13 @@ export type TransferInInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
   TAccountToTokenAccount extends string = string,
+  TAccountAccess extends string = string,
+  TAccountUserCredit extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   operator: TransactionSigner<TAccountOperator>;
   authority: Address<TAccountAuthority>;
@@ -341,9 +381,14 @@ export type TransferInInput<
   config: Address<TAccountConfig>;
   vault: Address<TAccountVault>;
   toTokenAccount: Address<TAccountToTokenAccount>;
+  access: Address<TAccountAccess>;
+  /** Ledger entry of the source owner for the deposited mint */
+  userCredit: Address<TAccountUserCredit>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   amounts: TransferInInstructionDataArgs['amounts'];
 };
 
@@ -355,9 +400,13 @@ export function getTransferInInstruction<
   TAccountConfig extends string,
   TAccountVault extends string,
   TAccountToTokenAccount extends string,
+  TAccountAccess extends string,
+  TAccountUserCredit extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: TransferInInput<
@@ -368,9 +417,13 @@ export function getTransferInInstruction<
     TAccountConfig,
     TAccountVault,
     TAccountToTokenAccount,
+    TAccountAccess,
+    TAccountUserCredit,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): TransferInInstruction<
@@ -382,9 +435,13 @@ export function getTransferInInstruction<
   TAccountConfig,
   TAccountVault,
   TAccountToTokenAccount,
+  TAccountAccess,
+  TAccountUserCredit,
   TAccountTokenProgram,
   TAccountAssociatedTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -402,12 +459,16 @@ export function getTransferInInstruction<
     config: { value: input.config ?? null, isWritable: false },
     vault: { value: input.vault ?? null, isWritable: true },
     toTokenAccount: { value: input.toTokenAccount ?? null, isWritable: true },
+    access: { value: input.access ?? null, isWritable: false },
+    userCredit: { value: input.userCredit ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -441,9 +502,13 @@ export function getTransferInInstruction<
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.toTokenAccount),
+      getAccountMeta(accounts.access),
+      getAccountMeta(accounts.userCredit),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getTransferInInstructionDataEncoder().encode(
       args as TransferInInstructionDataArgs
@@ -458,9 +523,13 @@ export function getTransferInInstruction<
     TAccountConfig,
     TAccountVault,
     TAccountToTokenAccount,
+    TAccountAccess,
+    TAccountUserCredit,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -477,9 +546,14 @@ export type ParsedTransferInInstruction<
     config: TAccountMetas[4];
     vault: TAccountMetas[5];
     toTokenAccount: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
-    associatedTokenProgram: TAccountMetas[8];
-    systemProgram: TAccountMetas[9];
+    access: TAccountMetas[7];
+    /** Ledger entry of the source owner for the deposited mint */
+    userCredit: TAccountMetas[8];
+    tokenProgram: TAccountMetas[9];
+    associatedTokenProgram: TAccountMetas[10];
+    systemProgram: TAccountMetas[11];
+    eventAuthority: TAccountMetas[12];
+    program: TAccountMetas[13];
   };
   data: TransferInInstructionData;
 };
@@ -492,7 +566,7 @@ export function parseTransferInInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedTransferInInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -512,9 +586,13 @@ export function parseTransferInInstruction<
       config: getNextAccount(),
       vault: getNextAccount(),
       toTokenAccount: getNextAccount(),
+      access: getNextAccount(),
+      userCredit: getNextAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getTransferInInstructionDataDecoder().decode(instruction.data),
   };
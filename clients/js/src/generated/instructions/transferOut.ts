@@ -59,12 +59,16 @@ export type TransferOutInstruction<
   TAccountTokenMint extends string | AccountMeta<string> = string,
   TAccountFromTokenAccount extends string | AccountMeta<string> = string,
   TAccountToTokenAccount extends string | AccountMeta<string> = string,
+  TAccountDestination extends string | AccountMeta<string> = string,
+  TAccountUserCredit extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
   TAccountAssociatedTokenProgram extends
     | string
     | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -84,17 +88,29 @@ export type TransferOutInstruction<
         ? ReadonlyAccount<TAccountTokenMint>
         : TAccountTokenMint,
       TAccountFromTokenAccount extends string
-        ? ReadonlyAccount<TAccountFromTokenAccount>
+        ? WritableAccount<TAccountFromTokenAccount>
         : TAccountFromTokenAccount,
       TAccountToTokenAccount extends string
         ? WritableAccount<TAccountToTokenAccount>
         : TAccountToTokenAccount,
+      TAccountDestination extends string
+        ? ReadonlyAccount<TAccountDestination>
+        : TAccountDestination,
+      TAccountUserCredit extends string
+        ? WritableAccount<TAccountUserCredit>
+        : TAccountUserCredit,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
       TAccountAssociatedTokenProgram extends string
         ? ReadonlyAccount<TAccountAssociatedTokenProgram>
         : TAccountAssociatedTokenProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -140,17 +156,25 @@ export type TransferOutAsyncInput<
   TAccountTokenMint extends string = string,
   TAccountFromTokenAccount extends string = string,
   TAccountToTokenAccount extends string = string,
+  TAccountDestination extends string = string,
+  TAccountUserCredit extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   operator: TransactionSigner<TAccountOperator>;
   config: Address<TAccountConfig>;
-  vault?: Address<TAccountVault>;
+  vault: Address<TAccountVault>;
   tokenMint: Address<TAccountTokenMint>;
   fromTokenAccount?: Address<TAccountFromTokenAccount>;
   toTokenAccount: Address<TAccountToTokenAccount>;
+  destination?: Address<TAccountDestination>;
+  userCredit?: Address<TAccountUserCredit>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   amounts: TransferOutInstructionDataArgs['amounts'];
 };
 
@@ -161,8 +185,12 @@ export async function getTransferOutInstructionAsync<
   TAccountTokenMint extends string,
   TAccountFromTokenAccount extends string,
   TAccountToTokenAccount extends string,
+  TAccountDestination extends string,
+  TAccountUserCredit extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: TransferOutAsyncInput<
@@ -172,8 +200,12 @@ export async function getTransferOutInstructionAsync<
     TAccountTokenMint,
     TAccountFromTokenAccount,
     TAccountToTokenAccount,
+    TAccountDestination,
+    TAccountUserCredit,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -185,8 +217,12 @@ export async function getTransferOutInstructionAsync<
     TAccountTokenMint,
     TAccountFromTokenAccount,
     TAccountToTokenAccount,
+    TAccountDestination,
+    TAccountUserCredit,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -201,14 +237,18 @@ export async function getTransferOutInstructionAsync<
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     fromTokenAccount: {
       value: input.fromTokenAccount ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     toTokenAccount: { value: input.toTokenAccount ?? null, isWritable: true },
+    destination: { value: input.destination ?? null, isWritable: false },
+    userCredit: { value: input.userCredit ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -219,19 +259,6 @@ export async function getTransferOutInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.vault.value) {
-    accounts.vault.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 118, 97, 117, 108, 116,
-          ])
-        ),
-      ],
-    });
-  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
@@ -251,6 +278,19 @@ export async function getTransferOutInstructionAsync<
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -261,8 +301,12 @@ export async function getTransferOutInstructionAsync<
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.fromTokenAccount),
       getAccountMeta(accounts.toTokenAccount),
+      getAccountMeta(accounts.destination),
+      getAccountMeta(accounts.userCredit),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getTransferOutInstructionDataEncoder().encode(
       args as TransferOutInstructionDataArgs
@@ -276,8 +320,12 @@ export async function getTransferOutInstructionAsync<
     TAccountTokenMint,
     TAccountFromTokenAccount,
     TAccountToTokenAccount,
+    TAccountDestination,
+    TAccountUserCredit,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -288,8 +336,12 @@ export type TransferOutInput<
   TAccountTokenMint extends string = string,
   TAccountFromTokenAccount extends string = string,
   TAccountToTokenAccount extends string = string,
+  TAccountDestination extends string = string,
+  TAccountUserCredit extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   operator: TransactionSigner<TAccountOperator>;
   config: Address<TAccountConfig>;
@@ -297,8 +349,12 @@ export type TransferOutInput<
   tokenMint: Address<TAccountTokenMint>;
   fromTokenAccount: Address<TAccountFromTokenAccount>;
   toTokenAccount: Address<TAccountToTokenAccount>;
+  destination?: Address<TAccountDestination>;
+  userCredit?: Address<TAccountUserCredit>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   amounts: TransferOutInstructionDataArgs['amounts'];
 };
 
@@ -309,8 +365,12 @@ export function getTransferOutInstruction<
   TAccountTokenMint extends string,
   TAccountFromTokenAccount extends string,
   TAccountToTokenAccount extends string,
+  TAccountDestination extends string,
+  TAccountUserCredit extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: TransferOutInput<
@@ -320,8 +380,12 @@ export function getTransferOutInstruction<
     TAccountTokenMint,
     TAccountFromTokenAccount,
     TAccountToTokenAccount,
+    TAccountDestination,
+    TAccountUserCredit,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): TransferOutInstruction<
@@ -332,8 +396,12 @@ export function getTransferOutInstruction<
   TAccountTokenMint,
   TAccountFromTokenAccount,
   TAccountToTokenAccount,
+  TAccountDestination,
+  TAccountUserCredit,
   TAccountTokenProgram,
-  TAccountAssociatedTokenProgram
+  TAccountAssociatedTokenProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -347,14 +415,18 @@ export function getTransferOutInstruction<
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     fromTokenAccount: {
       value: input.fromTokenAccount ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     toTokenAccount: { value: input.toTokenAccount ?? null, isWritable: true },
+    destination: { value: input.destination ?? null, isWritable: false },
+    userCredit: { value: input.userCredit ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -383,8 +455,12 @@ export function getTransferOutInstruction<
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.fromTokenAccount),
       getAccountMeta(accounts.toTokenAccount),
+      getAccountMeta(accounts.destination),
+      getAccountMeta(accounts.userCredit),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getTransferOutInstructionDataEncoder().encode(
       args as TransferOutInstructionDataArgs
@@ -398,8 +474,12 @@ export function getTransferOutInstruction<
     TAccountTokenMint,
     TAccountFromTokenAccount,
     TAccountToTokenAccount,
+    TAccountDestination,
+    TAccountUserCredit,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -415,8 +495,12 @@ export type ParsedTransferOutInstruction<
     tokenMint: TAccountMetas[3];
     fromTokenAccount: TAccountMetas[4];
     toTokenAccount: TAccountMetas[5];
-    tokenProgram: TAccountMetas[6];
-    associatedTokenProgram: TAccountMetas[7];
+    destination?: TAccountMetas[6] | undefined;
+    userCredit?: TAccountMetas[7] | undefined;
+    tokenProgram: TAccountMetas[8];
+    associatedTokenProgram: TAccountMetas[9];
+    eventAuthority: TAccountMetas[10];
+    program: TAccountMetas[11];
   };
   data: TransferOutInstructionData;
 };
@@ -429,7 +513,7 @@ export function parseTransferOutInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedTransferOutInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -439,6 +523,12 @@ export function parseTransferOutInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JUPITER_DELEGATE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -448,8 +538,12 @@ export function parseTransferOutInstruction<
       tokenMint: getNextAccount(),
       fromTokenAccount: getNextAccount(),
       toTokenAccount: getNextAccount(),
+      destination: getNextOptionalAccount(),
+      userCredit: getNextOptionalAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getTransferOutInstructionDataDecoder().decode(instruction.data),
   };
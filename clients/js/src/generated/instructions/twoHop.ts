@@ -14,9 +14,13 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -27,6 +31,8 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -42,8 +48,12 @@ import {
 import {
   getStepParamsDecoder,
   getStepParamsEncoder,
+  getTradeIntentDecoder,
+  getTradeIntentEncoder,
   type StepParams,
   type StepParamsArgs,
+  type TradeIntent,
+  type TradeIntentArgs,
 } from '../types';
 
 export const TWO_HOP_DISCRIMINATOR = new Uint8Array([
@@ -90,6 +100,21 @@ export type TwoHopInstruction<
   TAccountReceiverOutputTokenAccountTwo extends
     | string
     | AccountMeta<string> = string,
+  TAccountFeeTokenAccount extends string | AccountMeta<string> = string,
+  TAccountFeeVaultAuthority extends string | AccountMeta<string> = string,
+  TAccountPartner extends string | AccountMeta<string> = string,
+  TAccountPartnerFeeTokenAccount extends string | AccountMeta<string> = string,
+  TAccountInstructionsSysvar extends
+    | string
+    | AccountMeta<string> = 'Sysvar1nstructions1111111111111111111111111',
+  TAccountTradeReceipt extends string | AccountMeta<string> = string,
+  TAccountUserStats extends string | AccountMeta<string> = string,
+  TAccountTradeLog extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -156,6 +181,39 @@ export type TwoHopInstruction<
       TAccountReceiverOutputTokenAccountTwo extends string
         ? WritableAccount<TAccountReceiverOutputTokenAccountTwo>
         : TAccountReceiverOutputTokenAccountTwo,
+      TAccountFeeTokenAccount extends string
+        ? WritableAccount<TAccountFeeTokenAccount>
+        : TAccountFeeTokenAccount,
+      TAccountFeeVaultAuthority extends string
+        ? ReadonlyAccount<TAccountFeeVaultAuthority>
+        : TAccountFeeVaultAuthority,
+      TAccountPartner extends string
+        ? ReadonlyAccount<TAccountPartner>
+        : TAccountPartner,
+      TAccountPartnerFeeTokenAccount extends string
+        ? WritableAccount<TAccountPartnerFeeTokenAccount>
+        : TAccountPartnerFeeTokenAccount,
+      TAccountInstructionsSysvar extends string
+        ? ReadonlyAccount<TAccountInstructionsSysvar>
+        : TAccountInstructionsSysvar,
+      TAccountTradeReceipt extends string
+        ? WritableAccount<TAccountTradeReceipt>
+        : TAccountTradeReceipt,
+      TAccountUserStats extends string
+        ? WritableAccount<TAccountUserStats>
+        : TAccountUserStats,
+      TAccountTradeLog extends string
+        ? WritableAccount<TAccountTradeLog>
+        : TAccountTradeLog,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -165,12 +223,16 @@ export type TwoHopInstructionData = {
   delegate: Address;
   step1: StepParams;
   step2: StepParams;
+  clientOrderId: bigint;
+  intent: Option<TradeIntent>;
 };
 
 export type TwoHopInstructionDataArgs = {
   delegate: Address;
   step1: StepParamsArgs;
   step2: StepParamsArgs;
+  clientOrderId: number | bigint;
+  intent: OptionOrNullable<TradeIntentArgs>;
 };
 
 export function getTwoHopInstructionDataEncoder(): Encoder<TwoHopInstructionDataArgs> {
@@ -180,6 +242,8 @@ export function getTwoHopInstructionDataEncoder(): Encoder<TwoHopInstructionData
       ['delegate', getAddressEncoder()],
       ['step1', getStepParamsEncoder()],
       ['step2', getStepParamsEncoder()],
+      ['clientOrderId', getU64Encoder()],
+      ['intent', getOptionEncoder(getTradeIntentEncoder())],
     ]),
     (value) => ({ ...value, discriminator: TWO_HOP_DISCRIMINATOR })
   );
@@ -191,6 +255,8 @@ export function getTwoHopInstructionDataDecoder(): Decoder<TwoHopInstructionData
     ['delegate', getAddressDecoder()],
     ['step1', getStepParamsDecoder()],
     ['step2', getStepParamsDecoder()],
+    ['clientOrderId', getU64Decoder()],
+    ['intent', getOptionDecoder(getTradeIntentDecoder())],
   ]);
 }
 
@@ -225,6 +291,17 @@ export type TwoHopAsyncInput<
   TAccountUser extends string = string,
   TAccountReceiverOutputTokenAccountOne extends string = string,
   TAccountReceiverOutputTokenAccountTwo extends string = string,
+  TAccountFeeTokenAccount extends string = string,
+  TAccountFeeVaultAuthority extends string = string,
+  TAccountPartner extends string = string,
+  TAccountPartnerFeeTokenAccount extends string = string,
+  TAccountInstructionsSysvar extends string = string,
+  TAccountTradeReceipt extends string = string,
+  TAccountUserStats extends string = string,
+  TAccountTradeLog extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   inputMintOne: Address<TAccountInputMintOne>;
   inputMintProgramOne: Address<TAccountInputMintProgramOne>;
@@ -235,20 +312,33 @@ export type TwoHopAsyncInput<
   outputMintTwo: Address<TAccountOutputMintTwo>;
   outputMintProgramTwo: Address<TAccountOutputMintProgramTwo>;
   operator: TransactionSigner<TAccountOperator>;
-  vault?: Address<TAccountVault>;
+  vault: Address<TAccountVault>;
   delegateInputTokenAccount: Address<TAccountDelegateInputTokenAccount>;
   vaultInputTokenAccountOne?: Address<TAccountVaultInputTokenAccountOne>;
   vaultOutputTokenAccountOne?: Address<TAccountVaultOutputTokenAccountOne>;
   vaultInputTokenAccountTwo?: Address<TAccountVaultInputTokenAccountTwo>;
   vaultOutputTokenAccountTwo?: Address<TAccountVaultOutputTokenAccountTwo>;
   config: Address<TAccountConfig>;
-  access?: Address<TAccountAccess>;
+  access: Address<TAccountAccess>;
   user: Address<TAccountUser>;
   receiverOutputTokenAccountOne?: Address<TAccountReceiverOutputTokenAccountOne>;
   receiverOutputTokenAccountTwo?: Address<TAccountReceiverOutputTokenAccountTwo>;
+  feeTokenAccount: Address<TAccountFeeTokenAccount>;
+  feeVaultAuthority: Address<TAccountFeeVaultAuthority>;
+  partner?: Address<TAccountPartner>;
+  partnerFeeTokenAccount?: Address<TAccountPartnerFeeTokenAccount>;
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  tradeReceipt: Address<TAccountTradeReceipt>;
+  userStats: Address<TAccountUserStats>;
+  tradeLog: Address<TAccountTradeLog>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   delegate: TwoHopInstructionDataArgs['delegate'];
   step1: TwoHopInstructionDataArgs['step1'];
   step2: TwoHopInstructionDataArgs['step2'];
+  clientOrderId: TwoHopInstructionDataArgs['clientOrderId'];
+  intent: TwoHopInstructionDataArgs['intent'];
 };
 
 export async function getTwoHopInstructionAsync<
@@ -272,6 +362,17 @@ export async function getTwoHopInstructionAsync<
   TAccountUser extends string,
   TAccountReceiverOutputTokenAccountOne extends string,
   TAccountReceiverOutputTokenAccountTwo extends string,
+  TAccountFeeTokenAccount extends string,
+  TAccountFeeVaultAuthority extends string,
+  TAccountPartner extends string,
+  TAccountPartnerFeeTokenAccount extends string,
+  TAccountInstructionsSysvar extends string,
+  TAccountTradeReceipt extends string,
+  TAccountUserStats extends string,
+  TAccountTradeLog extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: TwoHopAsyncInput<
@@ -294,7 +395,18 @@ export async function getTwoHopInstructionAsync<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccountOne,
-    TAccountReceiverOutputTokenAccountTwo
+    TAccountReceiverOutputTokenAccountTwo,
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -319,7 +431,18 @@ export async function getTwoHopInstructionAsync<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccountOne,
-    TAccountReceiverOutputTokenAccountTwo
+    TAccountReceiverOutputTokenAccountTwo,
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -381,6 +504,26 @@ export async function getTwoHopInstructionAsync<
       value: input.receiverOutputTokenAccountTwo ?? null,
       isWritable: true,
     },
+    feeTokenAccount: { value: input.feeTokenAccount ?? null, isWritable: true },
+    feeVaultAuthority: {
+      value: input.feeVaultAuthority ?? null,
+      isWritable: false,
+    },
+    partner: { value: input.partner ?? null, isWritable: false },
+    partnerFeeTokenAccount: {
+      value: input.partnerFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
+    tradeReceipt: { value: input.tradeReceipt ?? null, isWritable: true },
+    userStats: { value: input.userStats ?? null, isWritable: true },
+    tradeLog: { value: input.tradeLog ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -391,19 +534,6 @@ export async function getTwoHopInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.vault.value) {
-    accounts.vault.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 118, 97, 117, 108, 116,
-          ])
-        ),
-      ],
-    });
-  }
   if (!accounts.vaultInputTokenAccountOne.value) {
     accounts.vaultInputTokenAccountOne.value = await getProgramDerivedAddress({
       programAddress:
@@ -456,20 +586,6 @@ export async function getTwoHopInstructionAsync<
       ],
     });
   }
-  if (!accounts.access.value) {
-    accounts.access.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            106, 117, 112, 105, 116, 101, 114, 45, 100, 101, 108, 101, 103, 97,
-            116, 101, 45, 97, 99, 99, 101, 115, 115,
-          ])
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.user.value)),
-      ],
-    });
-  }
   if (!accounts.receiverOutputTokenAccountOne.value) {
     accounts.receiverOutputTokenAccountOne.value =
       await getProgramDerivedAddress({
@@ -502,6 +618,27 @@ export async function getTwoHopInstructionAsync<
         ],
       });
   }
+  if (!accounts.instructionsSysvar.value) {
+    accounts.instructionsSysvar.value =
+      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -526,6 +663,17 @@ export async function getTwoHopInstructionAsync<
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.receiverOutputTokenAccountOne),
       getAccountMeta(accounts.receiverOutputTokenAccountTwo),
+      getAccountMeta(accounts.feeTokenAccount),
+      getAccountMeta(accounts.feeVaultAuthority),
+      getAccountMeta(accounts.partner),
+      getAccountMeta(accounts.partnerFeeTokenAccount),
+      getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.tradeReceipt),
+      getAccountMeta(accounts.userStats),
+      getAccountMeta(accounts.tradeLog),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getTwoHopInstructionDataEncoder().encode(
       args as TwoHopInstructionDataArgs
@@ -552,7 +700,18 @@ export async function getTwoHopInstructionAsync<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccountOne,
-    TAccountReceiverOutputTokenAccountTwo
+    TAccountReceiverOutputTokenAccountTwo,
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -577,6 +736,17 @@ export type TwoHopInput<
   TAccountUser extends string = string,
   TAccountReceiverOutputTokenAccountOne extends string = string,
   TAccountReceiverOutputTokenAccountTwo extends string = string,
+  TAccountFeeTokenAccount extends string = string,
+  TAccountFeeVaultAuthority extends string = string,
+  TAccountPartner extends string = string,
+  TAccountPartnerFeeTokenAccount extends string = string,
+  TAccountInstructionsSysvar extends string = string,
+  TAccountTradeReceipt extends string = string,
+  TAccountUserStats extends string = string,
+  TAccountTradeLog extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   inputMintOne: Address<TAccountInputMintOne>;
   inputMintProgramOne: Address<TAccountInputMintProgramOne>;
@@ -598,9 +768,22 @@ export type TwoHopInput<
   user: Address<TAccountUser>;
   receiverOutputTokenAccountOne: Address<TAccountReceiverOutputTokenAccountOne>;
   receiverOutputTokenAccountTwo: Address<TAccountReceiverOutputTokenAccountTwo>;
+  feeTokenAccount: Address<TAccountFeeTokenAccount>;
+  feeVaultAuthority: Address<TAccountFeeVaultAuthority>;
+  partner?: Address<TAccountPartner>;
+  partnerFeeTokenAccount?: Address<TAccountPartnerFeeTokenAccount>;
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  tradeReceipt: Address<TAccountTradeReceipt>;
+  userStats: Address<TAccountUserStats>;
+  tradeLog: Address<TAccountTradeLog>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   delegate: TwoHopInstructionDataArgs['delegate'];
   step1: TwoHopInstructionDataArgs['step1'];
   step2: TwoHopInstructionDataArgs['step2'];
+  clientOrderId: TwoHopInstructionDataArgs['clientOrderId'];
+  intent: TwoHopInstructionDataArgs['intent'];
 };
 
 export function getTwoHopInstruction<
@@ -624,6 +807,17 @@ export function getTwoHopInstruction<
   TAccountUser extends string,
   TAccountReceiverOutputTokenAccountOne extends string,
   TAccountReceiverOutputTokenAccountTwo extends string,
+  TAccountFeeTokenAccount extends string,
+  TAccountFeeVaultAuthority extends string,
+  TAccountPartner extends string,
+  TAccountPartnerFeeTokenAccount extends string,
+  TAccountInstructionsSysvar extends string,
+  TAccountTradeReceipt extends string,
+  TAccountUserStats extends string,
+  TAccountTradeLog extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof JUPITER_DELEGATE_PROGRAM_ADDRESS,
 >(
   input: TwoHopInput<
@@ -646,7 +840,18 @@ export function getTwoHopInstruction<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccountOne,
-    TAccountReceiverOutputTokenAccountTwo
+    TAccountReceiverOutputTokenAccountTwo,
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): TwoHopInstruction<
@@ -670,7 +875,18 @@ export function getTwoHopInstruction<
   TAccountAccess,
   TAccountUser,
   TAccountReceiverOutputTokenAccountOne,
-  TAccountReceiverOutputTokenAccountTwo
+  TAccountReceiverOutputTokenAccountTwo,
+  TAccountFeeTokenAccount,
+  TAccountFeeVaultAuthority,
+  TAccountPartner,
+  TAccountPartnerFeeTokenAccount,
+  TAccountInstructionsSysvar,
+  TAccountTradeReceipt,
+  TAccountUserStats,
+  TAccountTradeLog,
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -731,6 +947,26 @@ export function getTwoHopInstruction<
       value: input.receiverOutputTokenAccountTwo ?? null,
       isWritable: true,
     },
+    feeTokenAccount: { value: input.feeTokenAccount ?? null, isWritable: true },
+    feeVaultAuthority: {
+      value: input.feeVaultAuthority ?? null,
+      isWritable: false,
+    },
+    partner: { value: input.partner ?? null, isWritable: false },
+    partnerFeeTokenAccount: {
+      value: input.partnerFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
+    tradeReceipt: { value: input.tradeReceipt ?? null, isWritable: true },
+    userStats: { value: input.userStats ?? null, isWritable: true },
+    tradeLog: { value: input.tradeLog ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -740,6 +976,16 @@ export function getTwoHopInstruction<
   // Original args.
   const args = { ...input };
 
+  // Resolve default values.
+  if (!accounts.instructionsSysvar.value) {
+    accounts.instructionsSysvar.value =
+      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
@@ -763,6 +1009,17 @@ export function getTwoHopInstruction<
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.receiverOutputTokenAccountOne),
       getAccountMeta(accounts.receiverOutputTokenAccountTwo),
+      getAccountMeta(accounts.feeTokenAccount),
+      getAccountMeta(accounts.feeVaultAuthority),
+      getAccountMeta(accounts.partner),
+      getAccountMeta(accounts.partnerFeeTokenAccount),
+      getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.tradeReceipt),
+      getAccountMeta(accounts.userStats),
+      getAccountMeta(accounts.tradeLog),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getTwoHopInstructionDataEncoder().encode(
       args as TwoHopInstructionDataArgs
@@ -789,7 +1046,18 @@ export function getTwoHopInstruction<
     TAccountAccess,
     TAccountUser,
     TAccountReceiverOutputTokenAccountOne,
-    TAccountReceiverOutputTokenAccountTwo
+    TAccountReceiverOutputTokenAccountTwo,
+    TAccountFeeTokenAccount,
+    TAccountFeeVaultAuthority,
+    TAccountPartner,
+    TAccountPartnerFeeTokenAccount,
+    TAccountInstructionsSysvar,
+    TAccountTradeReceipt,
+    TAccountUserStats,
+    TAccountTradeLog,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -819,6 +1087,17 @@ export type ParsedTwoHopInstruction<
     user: TAccountMetas[17];
     receiverOutputTokenAccountOne: TAccountMetas[18];
     receiverOutputTokenAccountTwo: TAccountMetas[19];
+    feeTokenAccount: TAccountMetas[20];
+    feeVaultAuthority: TAccountMetas[21];
+    partner?: TAccountMetas[22] | undefined;
+    partnerFeeTokenAccount?: TAccountMetas[23] | undefined;
+    instructionsSysvar?: TAccountMetas[24] | undefined;
+    tradeReceipt: TAccountMetas[25];
+    userStats: TAccountMetas[26];
+    tradeLog: TAccountMetas[27];
+    systemProgram: TAccountMetas[28];
+    eventAuthority: TAccountMetas[29];
+    program: TAccountMetas[30];
   };
   data: TwoHopInstructionData;
 };
@@ -831,7 +1110,7 @@ export function parseTwoHopInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedTwoHopInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 20) {
+  if (instruction.accounts.length < 31) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -841,6 +1120,12 @@ export function parseTwoHopInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JUPITER_DELEGATE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -864,6 +1149,17 @@ export function parseTwoHopInstruction<
       user: getNextAccount(),
       receiverOutputTokenAccountOne: getNextAccount(),
       receiverOutputTokenAccountTwo: getNextAccount(),
+      feeTokenAccount: getNextAccount(),
+      feeVaultAuthority: getNextAccount(),
+      partner: getNextOptionalAccount(),
+      partnerFeeTokenAccount: getNextOptionalAccount(),
+      instructionsSysvar: getNextOptionalAccount(),
+      tradeReceipt: getNextAccount(),
+      userStats: getNextAccount(),
+      tradeLog: getNextAccount(),
+      systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getTwoHopInstructionDataDecoder().decode(instruction.data),
   };
@@ -15,22 +15,51 @@ import {
 } from '@solana/kit';
 import {
   type ParsedAcceptAdminTransferInstruction,
+  type ParsedAddDestinationInstruction,
+  type ParsedBatchTradeInstruction,
+  type ParsedCancelDcaPlanInstruction,
+  type ParsedCancelLimitOrderInstruction,
+  type ParsedClaimPartnerFeeInstruction,
+  type ParsedCloseTradeReceiptInstruction,
+  type ParsedCloseUserStatsInstruction,
+  type ParsedCreateDcaPlanInstruction,
+  type ParsedCreateLimitOrderInstruction,
+  type ParsedCreatePartnerInstruction,
   type ParsedDflowAggregatorInstruction,
+  type ParsedDflowCloseOrderInstruction,
+  type ParsedDflowOpenOrderInstruction,
+  type ParsedDflowSettleOrderInstruction,
+  type ParsedExecuteDcaSliceInstruction,
+  type ParsedExpireLimitOrderInstruction,
+  type ParsedFillLimitOrderInstruction,
   type ParsedFillOrderEngineInstruction,
   type ParsedGrantAccessInstruction,
   type ParsedInitConfigInstruction,
+  type ParsedInitPartnerFeeAccountInstruction,
+  type ParsedInitTradeLogInstruction,
   type ParsedJupiterAggregatorInstruction,
   type ParsedJupiterPerpetualsInstruction,
+  type ParsedMigrateAccessInstruction,
+  type ParsedMigrateConfigInstruction,
+  type ParsedMigrateUserVaultInstruction,
+  type ParsedModifyAccessIntentInstruction,
   type ParsedModifyCooldownDurationInstruction,
+  type ParsedModifyMaxCommissionInstruction,
+  type ParsedModifyPartnerShareInstruction,
+  type ParsedModifyProtocolFeeInstruction,
+  type ParsedModifyVaultModeInstruction,
   type ParsedOkxAggregatorInstruction,
   type ParsedPauseInstruction,
   type ParsedProposeNewAdminInstruction,
+  type ParsedRemoveDestinationInstruction,
+  type ParsedResizeTradeLogInstruction,
   type ParsedRevokeAccessInstruction,
   type ParsedSwapInstruction,
   type ParsedTokenReceiveInstruction,
   type ParsedTransferInInstruction,
   type ParsedTransferOutInstruction,
   type ParsedTwoHopInstruction,
+  type ParsedWithdrawProtocolFeeInstruction,
 } from '../instructions';
 
 export const JUPITER_DELEGATE_PROGRAM_ADDRESS =
@@ -39,6 +68,15 @@ export const JUPITER_DELEGATE_PROGRAM_ADDRESS =
 export enum JupiterDelegateAccount {
   Access,
   Config,
+  DcaPlan,
+  Destination,
+  DflowOrder,
+  LimitOrder,
+  Partner,
+  TradeLog,
+  TradeReceipt,
+  UserCredit,
+  UserStats,
 }
 
 export function identifyJupiterDelegateAccount(
@@ -67,6 +105,105 @@ export function identifyJupiterDelegateAccount(
   ) {
     return JupiterDelegateAccount.Config;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([231, 97, 112, 227, 171, 241, 52, 84])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateAccount.DcaPlan;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([72, 240, 126, 108, 12, 157, 206, 229])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateAccount.Destination;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([235, 75, 185, 67, 89, 79, 238, 208])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateAccount.DflowOrder;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([137, 183, 212, 91, 115, 29, 141, 227])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateAccount.LimitOrder;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([122, 43, 246, 239, 141, 56, 243, 182])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateAccount.Partner;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([32, 113, 191, 71, 0, 74, 68, 182])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateAccount.TradeLog;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([81, 155, 106, 207, 56, 213, 102, 174])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateAccount.TradeReceipt;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([41, 47, 169, 170, 241, 28, 215, 103])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateAccount.UserCredit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([176, 223, 136, 27, 122, 79, 32, 227])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateAccount.UserStats;
+  }
   throw new Error(
     'The provided account could not be identified as a jupiterDelegate account.'
   );
@@ -74,22 +211,51 @@ export function identifyJupiterDelegateAccount(
 
 export enum JupiterDelegateInstruction {
   AcceptAdminTransfer,
+  AddDestination,
+  BatchTrade,
+  CancelDcaPlan,
+  CancelLimitOrder,
+  ClaimPartnerFee,
+  CloseTradeReceipt,
+  CloseUserStats,
+  CreateDcaPlan,
+  CreateLimitOrder,
+  CreatePartner,
   DflowAggregator,
+  DflowCloseOrder,
+  DflowOpenOrder,
+  DflowSettleOrder,
+  ExecuteDcaSlice,
+  ExpireLimitOrder,
+  FillLimitOrder,
   FillOrderEngine,
   GrantAccess,
   InitConfig,
+  InitPartnerFeeAccount,
+  InitTradeLog,
   JupiterAggregator,
   JupiterPerpetuals,
+  MigrateAccess,
+  MigrateConfig,
+  MigrateUserVault,
+  ModifyAccessIntent,
   ModifyCooldownDuration,
+  ModifyMaxCommission,
+  ModifyPartnerShare,
+  ModifyProtocolFee,
+  ModifyVaultMode,
   OkxAggregator,
   Pause,
   ProposeNewAdmin,
+  RemoveDestination,
+  ResizeTradeLog,
   RevokeAccess,
   Swap,
   TokenReceive,
   TransferIn,
   TransferOut,
   TwoHop,
+  WithdrawProtocolFee,
 }
 
 export function identifyJupiterDelegateInstruction(
@@ -107,6 +273,116 @@ export function identifyJupiterDelegateInstruction(
   ) {
     return JupiterDelegateInstruction.AcceptAdminTransfer;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([183, 152, 13, 93, 44, 178, 140, 243])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.AddDestination;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([30, 238, 204, 192, 168, 254, 69, 238])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.BatchTrade;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([238, 106, 180, 217, 78, 107, 103, 215])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.CancelDcaPlan;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([132, 156, 132, 31, 67, 40, 232, 97])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.CancelLimitOrder;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([97, 206, 39, 105, 94, 94, 126, 148])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.ClaimPartnerFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([57, 173, 10, 176, 83, 149, 150, 68])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.CloseTradeReceipt;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([179, 188, 173, 6, 6, 208, 32, 211])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.CloseUserStats;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([161, 215, 52, 81, 136, 239, 199, 249])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.CreateDcaPlan;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([76, 161, 70, 122, 82, 20, 142, 75])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.CreateLimitOrder;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([220, 20, 67, 171, 205, 106, 128, 56])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.CreatePartner;
+  }
   if (
     containsBytes(
       data,
@@ -118,6 +394,72 @@ export function identifyJupiterDelegateInstruction(
   ) {
     return JupiterDelegateInstruction.DflowAggregator;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([115, 178, 65, 179, 2, 225, 52, 191])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.DflowCloseOrder;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([172, 104, 213, 171, 183, 98, 222, 88])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.DflowOpenOrder;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([241, 85, 240, 130, 15, 243, 175, 78])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.DflowSettleOrder;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([158, 145, 101, 115, 113, 247, 46, 24])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.ExecuteDcaSlice;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([199, 38, 82, 158, 192, 143, 29, 60])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.ExpireLimitOrder;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([83, 74, 211, 114, 227, 230, 105, 177])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.FillLimitOrder;
+  }
   if (
     containsBytes(
       data,
@@ -151,6 +493,28 @@ export function identifyJupiterDelegateInstruction(
   ) {
     return JupiterDelegateInstruction.InitConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([72, 44, 251, 159, 115, 24, 191, 116])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.InitPartnerFeeAccount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([87, 229, 117, 240, 91, 134, 140, 170])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.InitTradeLog;
+  }
   if (
     containsBytes(
       data,
@@ -173,6 +537,50 @@ export function identifyJupiterDelegateInstruction(
   ) {
     return JupiterDelegateInstruction.JupiterPerpetuals;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([207, 190, 148, 92, 12, 104, 32, 4])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.MigrateAccess;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([92, 131, 58, 105, 210, 154, 224, 193])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.MigrateConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([115, 167, 36, 161, 183, 71, 165, 62])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.MigrateUserVault;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([83, 240, 126, 254, 168, 73, 182, 163])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.ModifyAccessIntent;
+  }
   if (
     containsBytes(
       data,
@@ -184,6 +592,50 @@ export function identifyJupiterDelegateInstruction(
   ) {
     return JupiterDelegateInstruction.ModifyCooldownDuration;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([101, 244, 74, 142, 235, 19, 56, 190])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.ModifyMaxCommission;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([75, 159, 187, 214, 165, 53, 252, 71])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.ModifyPartnerShare;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([245, 89, 92, 233, 21, 83, 215, 123])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.ModifyProtocolFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([15, 171, 169, 205, 133, 235, 14, 159])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.ModifyVaultMode;
+  }
   if (
     containsBytes(
       data,
@@ -217,6 +669,28 @@ export function identifyJupiterDelegateInstruction(
   ) {
     return JupiterDelegateInstruction.ProposeNewAdmin;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([57, 157, 206, 225, 233, 131, 241, 108])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.RemoveDestination;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([230, 1, 186, 20, 5, 178, 40, 213])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.ResizeTradeLog;
+  }
   if (
     containsBytes(
       data,
@@ -283,6 +757,17 @@ export function identifyJupiterDelegateInstruction(
   ) {
     return JupiterDelegateInstruction.TwoHop;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([158, 201, 158, 189, 33, 93, 162, 103])
+      ),
+      0
+    )
+  ) {
+    return JupiterDelegateInstruction.WithdrawProtocolFee;
+  }
   throw new Error(
     'The provided instruction could not be identified as a jupiterDelegate instruction.'
   );
@@ -294,9 +779,57 @@ export type ParsedJupiterDelegateInstruction<
   | ({
       instructionType: JupiterDelegateInstruction.AcceptAdminTransfer;
     } & ParsedAcceptAdminTransferInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.AddDestination;
+    } & ParsedAddDestinationInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.BatchTrade;
+    } & ParsedBatchTradeInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.CancelDcaPlan;
+    } & ParsedCancelDcaPlanInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.CancelLimitOrder;
+    } & ParsedCancelLimitOrderInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.ClaimPartnerFee;
+    } & ParsedClaimPartnerFeeInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.CloseTradeReceipt;
+    } & ParsedCloseTradeReceiptInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.CloseUserStats;
+    } & ParsedCloseUserStatsInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.CreateDcaPlan;
+    } & ParsedCreateDcaPlanInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.CreateLimitOrder;
+    } & ParsedCreateLimitOrderInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.CreatePartner;
+    } & ParsedCreatePartnerInstruction<TProgram>)
   | ({
       instructionType: JupiterDelegateInstruction.DflowAggregator;
     } & ParsedDflowAggregatorInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.DflowCloseOrder;
+    } & ParsedDflowCloseOrderInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.DflowOpenOrder;
+    } & ParsedDflowOpenOrderInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.DflowSettleOrder;
+    } & ParsedDflowSettleOrderInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.ExecuteDcaSlice;
+    } & ParsedExecuteDcaSliceInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.ExpireLimitOrder;
+    } & ParsedExpireLimitOrderInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.FillLimitOrder;
+    } & ParsedFillLimitOrderInstruction<TProgram>)
   | ({
       instructionType: JupiterDelegateInstruction.FillOrderEngine;
     } & ParsedFillOrderEngineInstruction<TProgram>)
@@ -306,15 +839,45 @@ export type ParsedJupiterDelegateInstruction<
   | ({
       instructionType: JupiterDelegateInstruction.InitConfig;
     } & ParsedInitConfigInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.InitPartnerFeeAccount;
+    } & ParsedInitPartnerFeeAccountInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.InitTradeLog;
+    } & ParsedInitTradeLogInstruction<TProgram>)
   | ({
       instructionType: JupiterDelegateInstruction.JupiterAggregator;
     } & ParsedJupiterAggregatorInstruction<TProgram>)
   | ({
       instructionType: JupiterDelegateInstruction.JupiterPerpetuals;
     } & ParsedJupiterPerpetualsInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.MigrateAccess;
+    } & ParsedMigrateAccessInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.MigrateConfig;
+    } & ParsedMigrateConfigInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.MigrateUserVault;
+    } & ParsedMigrateUserVaultInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.ModifyAccessIntent;
+    } & ParsedModifyAccessIntentInstruction<TProgram>)
   | ({
       instructionType: JupiterDelegateInstruction.ModifyCooldownDuration;
     } & ParsedModifyCooldownDurationInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.ModifyMaxCommission;
+    } & ParsedModifyMaxCommissionInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.ModifyPartnerShare;
+    } & ParsedModifyPartnerShareInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.ModifyProtocolFee;
+    } & ParsedModifyProtocolFeeInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.ModifyVaultMode;
+    } & ParsedModifyVaultModeInstruction<TProgram>)
   | ({
       instructionType: JupiterDelegateInstruction.OkxAggregator;
     } & ParsedOkxAggregatorInstruction<TProgram>)
@@ -324,6 +887,12 @@ export type ParsedJupiterDelegateInstruction<
   | ({
       instructionType: JupiterDelegateInstruction.ProposeNewAdmin;
     } & ParsedProposeNewAdminInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.RemoveDestination;
+    } & ParsedRemoveDestinationInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.ResizeTradeLog;
+    } & ParsedResizeTradeLogInstruction<TProgram>)
   | ({
       instructionType: JupiterDelegateInstruction.RevokeAccess;
     } & ParsedRevokeAccessInstruction<TProgram>)
@@ -341,4 +910,7 @@ export type ParsedJupiterDelegateInstruction<
     } & ParsedTransferOutInstruction<TProgram>)
   | ({
       instructionType: JupiterDelegateInstruction.TwoHop;
-    } & ParsedTwoHopInstruction<TProgram>);
+    } & ParsedTwoHopInstruction<TProgram>)
+  | ({
+      instructionType: JupiterDelegateInstruction.WithdrawProtocolFee;
+    } & ParsedWithdrawProtocolFeeInstruction<TProgram>);
@@ -7,31 +7,34 @@
  */
 
 import {
-  addDecoderSizePrefix,
-  addEncoderSizePrefix,
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getStructDecoder,
   getStructEncoder,
-  getU32Decoder,
-  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
-  getUtf8Decoder,
-  getUtf8Encoder,
   type Address,
-  type Codec,
-  type Decoder,
-  type Encoder,
+  type FixedSizeCodec,
+  type FixedSizeDecoder,
+  type FixedSizeEncoder,
 } from '@solana/kit';
+import {
+  getVenueInstructionDecoder,
+  getVenueInstructionEncoder,
+  type VenueInstruction,
+  type VenueInstructionArgs,
+} from '.';
 
 export type DflowAggregatorEvent = {
   user: Address;
   inputMint: Address;
   outputMint: Address;
   inputAmount: bigint;
-  instructionName: string;
+  instruction: VenueInstruction;
+  nativeOutput: boolean;
   operator: Address;
 };
 
@@ -40,39 +43,36 @@ export type DflowAggregatorEventArgs = {
   inputMint: Address;
   outputMint: Address;
   inputAmount: number | bigint;
-  instructionName: string;
+  instruction: VenueInstructionArgs;
+  nativeOutput: boolean;
   operator: Address;
 };
 
-export function getDflowAggregatorEventEncoder(): Encoder<DflowAggregatorEventArgs> {
+export function getDflowAggregatorEventEncoder(): FixedSizeEncoder<DflowAggregatorEventArgs> {
   return getStructEncoder([
     ['user', getAddressEncoder()],
     ['inputMint', getAddressEncoder()],
     ['outputMint', getAddressEncoder()],
     ['inputAmount', getU64Encoder()],
-    [
-      'instructionName',
-      addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()),
-    ],
+    ['instruction', getVenueInstructionEncoder()],
+    ['nativeOutput', getBooleanEncoder()],
     ['operator', getAddressEncoder()],
   ]);
 }
 
-export function getDflowAggregatorEventDecoder(): Decoder<DflowAggregatorEvent> {
+export function getDflowAggregatorEventDecoder(): FixedSizeDecoder<DflowAggregatorEvent> {
   return getStructDecoder([
     ['user', getAddressDecoder()],
     ['inputMint', getAddressDecoder()],
     ['outputMint', getAddressDecoder()],
     ['inputAmount', getU64Decoder()],
-    [
-      'instructionName',
-      addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder()),
-    ],
+    ['instruction', getVenueInstructionDecoder()],
+    ['nativeOutput', getBooleanDecoder()],
     ['operator', getAddressDecoder()],
   ]);
 }
 
-export function getDflowAggregatorEventCodec(): Codec<
+export function getDflowAggregatorEventCodec(): FixedSizeCodec<
   DflowAggregatorEventArgs,
   DflowAggregatorEvent
 > {
@@ -18,7 +18,11 @@ import {
   type FixedSizeEncoder,
 } from '@solana/kit';
 
-export type GrantAccessEvent = { user: Address; access: Address };
+export type GrantAccessEvent = {
+  user: Address;
+  access: Address;
+  partner: Address;
+};
 
 export type GrantAccessEventArgs = GrantAccessEvent;
 
@@ -26,6 +30,7 @@ export function getGrantAccessEventEncoder(): FixedSizeEncoder<GrantAccessEventA
   return getStructEncoder([
     ['user', getAddressEncoder()],
     ['access', getAddressEncoder()],
+    ['partner', getAddressEncoder()],
   ]);
 }
 
@@ -33,6 +38,7 @@ export function getGrantAccessEventDecoder(): FixedSizeDecoder<GrantAccessEvent>
   return getStructDecoder([
     ['user', getAddressDecoder()],
     ['access', getAddressDecoder()],
+    ['partner', getAddressDecoder()],
   ]);
 }
 
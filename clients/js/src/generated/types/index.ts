@@ -6,20 +6,61 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addDestinationEvent';
 export * from './adminTransferCompletedEvent';
 export * from './adminTransferProposedEvent';
+export * from './batchLeg';
+export * from './batchTradeEvent';
+export * from './batchTradeLegEvent';
+export * from './creditUserEvent';
+export * from './dcaPlanCancelledEvent';
+export * from './dcaPlanCreatedEvent';
+export * from './dcaSliceExecutedEvent';
+export * from './debitUserEvent';
+export * from './depositEvent';
 export * from './dflowAggregatorEvent';
+export * from './dflowOrderOpenedEvent';
+export * from './dflowOrderSettledEvent';
 export * from './fillOrderEngineEvent';
 export * from './grantAccessEvent';
 export * from './initConfigEvent';
+export * from './initTradeLogEvent';
 export * from './jupiterAggregatorEvent';
 export * from './jupiterPerpetualsEvent';
 export * from './jupiterSwapEvent';
+export * from './limitOrderCancelledEvent';
+export * from './limitOrderCreatedEvent';
+export * from './limitOrderExpiredEvent';
+export * from './limitOrderFilledEvent';
+export * from './migrateAccountEvent';
+export * from './migrateUserVaultEvent';
+export * from './mintStats';
+export * from './modifyAccessIntentEvent';
 export * from './modifyCooldownDurationEvent';
+export * from './modifyMaxCommissionEvent';
 export * from './modifyOperatorEvent';
+export * from './modifyProtocolFeeEvent';
+export * from './modifyVaultModeEvent';
+export * from './nativeOutputSettledEvent';
 export * from './okxAggregatorEvent';
+export * from './partnerEvent';
+export * from './partnerFeeAccruedEvent';
+export * from './partnerFeeClaimedEvent';
 export * from './pauseEvent';
+export * from './protocolFeeEvent';
+export * from './removeDestinationEvent';
+export * from './resizeTradeLogEvent';
 export * from './revokeAccessEvent';
 export * from './stepAction';
 export * from './stepParams';
+export * from './tokenReceiveEvent';
+export * from './tradeExecuted';
+export * from './tradeIntent';
+export * from './tradeReceiptClosedEvent';
+export * from './tradeVenue';
+export * from './transferFeeEvent';
 export * from './twoHopEvent';
+export * from './userStatsClosedEvent';
+export * from './venueInstruction';
+export * from './withdrawEvent';
+export * from './withdrawProtocolFeeEvent';
@@ -14,6 +14,8 @@ import {
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -24,12 +26,14 @@ export type InitConfigEvent = {
   admin: Address;
   vault: Address;
   cooldownDuration: bigint;
+  namespace: number;
 };
 
 export type InitConfigEventArgs = {
   admin: Address;
   vault: Address;
   cooldownDuration: number | bigint;
+  namespace: number;
 };
 
 export function getInitConfigEventEncoder(): FixedSizeEncoder<InitConfigEventArgs> {
@@ -37,6 +41,7 @@ export function getInitConfigEventEncoder(): FixedSizeEncoder<InitConfigEventArg
     ['admin', getAddressEncoder()],
     ['vault', getAddressEncoder()],
     ['cooldownDuration', getI64Encoder()],
+    ['namespace', getU16Encoder()],
   ]);
 }
 
@@ -45,6 +50,7 @@ export function getInitConfigEventDecoder(): FixedSizeDecoder<InitConfigEvent> {
     ['admin', getAddressDecoder()],
     ['vault', getAddressDecoder()],
     ['cooldownDuration', getI64Decoder()],
+    ['namespace', getU16Decoder()],
   ]);
 }
 
@@ -7,31 +7,35 @@
  */
 
 import {
-  addDecoderSizePrefix,
-  addEncoderSizePrefix,
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getStructDecoder,
   getStructEncoder,
-  getU32Decoder,
-  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
-  getUtf8Decoder,
-  getUtf8Encoder,
   type Address,
-  type Codec,
-  type Decoder,
-  type Encoder,
+  type FixedSizeCodec,
+  type FixedSizeDecoder,
+  type FixedSizeEncoder,
 } from '@solana/kit';
+import {
+  getVenueInstructionDecoder,
+  getVenueInstructionEncoder,
+  type VenueInstruction,
+  type VenueInstructionArgs,
+} from '.';
 
 export type OkxAggregatorEvent = {
   user: Address;
   inputMint: Address;
   outputMint: Address;
   inputAmount: bigint;
-  instructionName: string;
+  instruction: VenueInstruction;
+  commissionAmount: bigint;
+  nativeOutput: boolean;
   operator: Address;
 };
 
@@ -40,39 +44,39 @@ export type OkxAggregatorEventArgs = {
   inputMint: Address;
   outputMint: Address;
   inputAmount: number | bigint;
-  instructionName: string;
+  instruction: VenueInstructionArgs;
+  commissionAmount: number | bigint;
+  nativeOutput: boolean;
   operator: Address;
 };
 
-export function getOkxAggregatorEventEncoder(): Encoder<OkxAggregatorEventArgs> {
+export function getOkxAggregatorEventEncoder(): FixedSizeEncoder<OkxAggregatorEventArgs> {
   return getStructEncoder([
     ['user', getAddressEncoder()],
     ['inputMint', getAddressEncoder()],
     ['outputMint', getAddressEncoder()],
     ['inputAmount', getU64Encoder()],
-    [
-      'instructionName',
-      addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()),
-    ],
+    ['instruction', getVenueInstructionEncoder()],
+    ['commissionAmount', getU64Encoder()],
+    ['nativeOutput', getBooleanEncoder()],
     ['operator', getAddressEncoder()],
   ]);
 }
 
-export function getOkxAggregatorEventDecoder(): Decoder<OkxAggregatorEvent> {
+export function getOkxAggregatorEventDecoder(): FixedSizeDecoder<OkxAggregatorEvent> {
   return getStructDecoder([
     ['user', getAddressDecoder()],
     ['inputMint', getAddressDecoder()],
     ['outputMint', getAddressDecoder()],
     ['inputAmount', getU64Decoder()],
-    [
-      'instructionName',
-      addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder()),
-    ],
+    ['instruction', getVenueInstructionDecoder()],
+    ['commissionAmount', getU64Decoder()],
+    ['nativeOutput', getBooleanDecoder()],
     ['operator', getAddressDecoder()],
   ]);
 }
 
-export function getOkxAggregatorEventCodec(): Codec<
+export function getOkxAggregatorEventCodec(): FixedSizeCodec<
   OkxAggregatorEventArgs,
   OkxAggregatorEvent
 > {
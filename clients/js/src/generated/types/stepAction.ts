@@ -29,6 +29,7 @@ export enum StepAction {
   DFlowSwap2,
   JlpPerpetualsAddLiquidity2,
   JlpPerpetualsRemoveLiquidity2,
+  JlpPerpetualsSwap2,
 }
 
 export type StepActionArgs = StepAction;
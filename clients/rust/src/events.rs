@@ -0,0 +1,341 @@
+//! Decoding of the events the program emits through event CPI.
+//!
+//! Every event is a self-invocation signed by the event authority PDA, so it
+//! shows up as an inner instruction of the transaction instead of a log line.
+
+use borsh::io::{Error, ErrorKind, Result};
+use borsh::BorshDeserialize;
+use solana_pubkey::Pubkey;
+
+use crate::generated::types::*;
+use crate::JUPITER_DELEGATE_ID;
+
+/// Anchor's `EVENT_IX_TAG`, little endian, leading every event CPI.
+pub const EVENT_IX_TAG_LE: [u8; 8] = [0xe4, 0xa5, 0x45, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+/// Seed of the event authority PDA, the only signer of an event CPI.
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+pub const INIT_CONFIG_EVENT_DISCRIMINATOR: [u8; 8] = [125, 127, 160, 86, 247, 110, 50, 238];
+pub const GRANT_ACCESS_EVENT_DISCRIMINATOR: [u8; 8] = [250, 219, 19, 50, 163, 154, 234, 165];
+pub const REVOKE_ACCESS_EVENT_DISCRIMINATOR: [u8; 8] = [138, 72, 178, 105, 42, 85, 99, 192];
+pub const MODIFY_ACCESS_INTENT_EVENT_DISCRIMINATOR: [u8; 8] = [52, 206, 163, 230, 200, 8, 23, 54];
+pub const ADMIN_TRANSFER_PROPOSED_EVENT_DISCRIMINATOR: [u8; 8] =
+    [71, 85, 100, 58, 178, 123, 116, 206];
+pub const ADMIN_TRANSFER_COMPLETED_EVENT_DISCRIMINATOR: [u8; 8] =
+    [45, 61, 146, 62, 46, 238, 84, 243];
+pub const MODIFY_COOLDOWN_DURATION_EVENT_DISCRIMINATOR: [u8; 8] =
+    [188, 246, 159, 121, 42, 146, 244, 27];
+pub const MODIFY_OPERATOR_EVENT_DISCRIMINATOR: [u8; 8] = [160, 31, 250, 224, 144, 170, 205, 75];
+pub const PAUSE_EVENT_DISCRIMINATOR: [u8; 8] = [32, 51, 61, 169, 156, 104, 130, 43];
+pub const MODIFY_MAX_COMMISSION_EVENT_DISCRIMINATOR: [u8; 8] = [253, 62, 193, 159, 23, 231, 74, 28];
+pub const MODIFY_VAULT_MODE_EVENT_DISCRIMINATOR: [u8; 8] = [115, 253, 32, 96, 133, 181, 14, 155];
+pub const MIGRATE_USER_VAULT_EVENT_DISCRIMINATOR: [u8; 8] = [167, 113, 34, 104, 241, 125, 180, 12];
+pub const MIGRATE_ACCOUNT_EVENT_DISCRIMINATOR: [u8; 8] = [160, 251, 43, 32, 205, 79, 254, 66];
+pub const MODIFY_PROTOCOL_FEE_EVENT_DISCRIMINATOR: [u8; 8] = [189, 9, 28, 60, 54, 162, 83, 199];
+pub const WITHDRAW_PROTOCOL_FEE_EVENT_DISCRIMINATOR: [u8; 8] =
+    [130, 7, 235, 242, 149, 158, 165, 69];
+pub const PROTOCOL_FEE_EVENT_DISCRIMINATOR: [u8; 8] = [240, 185, 167, 217, 167, 89, 134, 112];
+pub const PARTNER_EVENT_DISCRIMINATOR: [u8; 8] = [160, 68, 248, 72, 92, 188, 51, 145];
+pub const PARTNER_FEE_ACCRUED_EVENT_DISCRIMINATOR: [u8; 8] = [129, 202, 248, 138, 174, 179, 3, 61];
+pub const PARTNER_FEE_CLAIMED_EVENT_DISCRIMINATOR: [u8; 8] = [100, 178, 48, 203, 56, 122, 83, 67];
+pub const JUPITER_SWAP_EVENT_DISCRIMINATOR: [u8; 8] = [71, 37, 34, 221, 98, 167, 26, 156];
+pub const JUPITER_AGGREGATOR_EVENT_DISCRIMINATOR: [u8; 8] = [85, 106, 185, 142, 206, 54, 189, 12];
+pub const FILL_ORDER_ENGINE_EVENT_DISCRIMINATOR: [u8; 8] = [186, 216, 183, 194, 59, 208, 10, 204];
+pub const DFLOW_AGGREGATOR_EVENT_DISCRIMINATOR: [u8; 8] = [81, 217, 136, 237, 188, 64, 139, 77];
+pub const TRANSFER_FEE_EVENT_DISCRIMINATOR: [u8; 8] = [244, 122, 211, 12, 61, 34, 81, 162];
+pub const NATIVE_OUTPUT_SETTLED_EVENT_DISCRIMINATOR: [u8; 8] =
+    [145, 149, 149, 58, 158, 231, 174, 219];
+pub const DFLOW_ORDER_OPENED_EVENT_DISCRIMINATOR: [u8; 8] = [26, 128, 125, 142, 115, 125, 131, 10];
+pub const DFLOW_ORDER_SETTLED_EVENT_DISCRIMINATOR: [u8; 8] = [216, 122, 231, 35, 250, 76, 189, 220];
+pub const OKX_AGGREGATOR_EVENT_DISCRIMINATOR: [u8; 8] = [186, 61, 57, 147, 190, 113, 217, 159];
+pub const JUPITER_PERPETUALS_EVENT_DISCRIMINATOR: [u8; 8] = [189, 235, 252, 68, 85, 14, 246, 92];
+pub const TWO_HOP_EVENT_DISCRIMINATOR: [u8; 8] = [168, 146, 7, 20, 33, 59, 23, 124];
+pub const DCA_PLAN_CREATED_EVENT_DISCRIMINATOR: [u8; 8] = [140, 172, 42, 169, 36, 30, 136, 212];
+pub const DCA_PLAN_CANCELLED_EVENT_DISCRIMINATOR: [u8; 8] = [254, 184, 250, 89, 160, 239, 34, 59];
+pub const DCA_SLICE_EXECUTED_EVENT_DISCRIMINATOR: [u8; 8] = [9, 34, 36, 175, 12, 114, 97, 194];
+pub const LIMIT_ORDER_CREATED_EVENT_DISCRIMINATOR: [u8; 8] = [186, 1, 59, 92, 113, 231, 231, 146];
+pub const LIMIT_ORDER_FILLED_EVENT_DISCRIMINATOR: [u8; 8] = [130, 187, 109, 222, 241, 249, 61, 130];
+pub const LIMIT_ORDER_EXPIRED_EVENT_DISCRIMINATOR: [u8; 8] =
+    [104, 185, 100, 165, 109, 210, 230, 26];
+pub const LIMIT_ORDER_CANCELLED_EVENT_DISCRIMINATOR: [u8; 8] =
+    [195, 70, 253, 130, 46, 197, 104, 110];
+pub const BATCH_TRADE_EVENT_DISCRIMINATOR: [u8; 8] = [90, 106, 79, 77, 83, 221, 124, 185];
+pub const BATCH_TRADE_LEG_EVENT_DISCRIMINATOR: [u8; 8] = [196, 226, 30, 250, 223, 31, 77, 47];
+pub const TRADE_RECEIPT_CLOSED_EVENT_DISCRIMINATOR: [u8; 8] = [20, 254, 224, 65, 44, 217, 182, 41];
+pub const USER_STATS_CLOSED_EVENT_DISCRIMINATOR: [u8; 8] = [38, 227, 156, 92, 91, 30, 168, 250];
+pub const INIT_TRADE_LOG_EVENT_DISCRIMINATOR: [u8; 8] = [78, 37, 87, 27, 99, 29, 225, 217];
+pub const RESIZE_TRADE_LOG_EVENT_DISCRIMINATOR: [u8; 8] = [37, 250, 70, 89, 45, 74, 23, 36];
+pub const CREDIT_USER_EVENT_DISCRIMINATOR: [u8; 8] = [117, 63, 4, 50, 16, 206, 68, 186];
+pub const DEBIT_USER_EVENT_DISCRIMINATOR: [u8; 8] = [244, 45, 22, 236, 163, 202, 244, 41];
+pub const DEPOSIT_EVENT_DISCRIMINATOR: [u8; 8] = [120, 248, 61, 83, 31, 142, 107, 144];
+pub const ADD_DESTINATION_EVENT_DISCRIMINATOR: [u8; 8] = [67, 153, 245, 71, 115, 175, 242, 101];
+pub const REMOVE_DESTINATION_EVENT_DISCRIMINATOR: [u8; 8] = [36, 60, 198, 132, 149, 176, 153, 167];
+pub const WITHDRAW_EVENT_DISCRIMINATOR: [u8; 8] = [22, 9, 133, 26, 160, 44, 71, 192];
+pub const TOKEN_RECEIVE_EVENT_DISCRIMINATOR: [u8; 8] = [66, 196, 89, 115, 239, 106, 179, 229];
+pub const TRADE_EXECUTED_DISCRIMINATOR: [u8; 8] = [41, 110, 64, 129, 60, 79, 179, 80];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum JupiterDelegateEvent {
+    InitConfigEvent(InitConfigEvent),
+    GrantAccessEvent(GrantAccessEvent),
+    RevokeAccessEvent(RevokeAccessEvent),
+    ModifyAccessIntentEvent(ModifyAccessIntentEvent),
+    AdminTransferProposedEvent(AdminTransferProposedEvent),
+    AdminTransferCompletedEvent(AdminTransferCompletedEvent),
+    ModifyCooldownDurationEvent(ModifyCooldownDurationEvent),
+    ModifyOperatorEvent(ModifyOperatorEvent),
+    PauseEvent(PauseEvent),
+    ModifyMaxCommissionEvent(ModifyMaxCommissionEvent),
+    ModifyVaultModeEvent(ModifyVaultModeEvent),
+    MigrateUserVaultEvent(MigrateUserVaultEvent),
+    MigrateAccountEvent(MigrateAccountEvent),
+    ModifyProtocolFeeEvent(ModifyProtocolFeeEvent),
+    WithdrawProtocolFeeEvent(WithdrawProtocolFeeEvent),
+    ProtocolFeeEvent(ProtocolFeeEvent),
+    PartnerEvent(PartnerEvent),
+    PartnerFeeAccruedEvent(PartnerFeeAccruedEvent),
+    PartnerFeeClaimedEvent(PartnerFeeClaimedEvent),
+    JupiterSwapEvent(JupiterSwapEvent),
+    JupiterAggregatorEvent(JupiterAggregatorEvent),
+    FillOrderEngineEvent(FillOrderEngineEvent),
+    DflowAggregatorEvent(DflowAggregatorEvent),
+    TransferFeeEvent(TransferFeeEvent),
+    NativeOutputSettledEvent(NativeOutputSettledEvent),
+    DflowOrderOpenedEvent(DflowOrderOpenedEvent),
+    DflowOrderSettledEvent(DflowOrderSettledEvent),
+    OkxAggregatorEvent(OkxAggregatorEvent),
+    JupiterPerpetualsEvent(JupiterPerpetualsEvent),
+    TwoHopEvent(TwoHopEvent),
+    DcaPlanCreatedEvent(DcaPlanCreatedEvent),
+    DcaPlanCancelledEvent(DcaPlanCancelledEvent),
+    DcaSliceExecutedEvent(DcaSliceExecutedEvent),
+    LimitOrderCreatedEvent(LimitOrderCreatedEvent),
+    LimitOrderFilledEvent(LimitOrderFilledEvent),
+    LimitOrderExpiredEvent(LimitOrderExpiredEvent),
+    LimitOrderCancelledEvent(LimitOrderCancelledEvent),
+    BatchTradeEvent(BatchTradeEvent),
+    BatchTradeLegEvent(BatchTradeLegEvent),
+    TradeReceiptClosedEvent(TradeReceiptClosedEvent),
+    UserStatsClosedEvent(UserStatsClosedEvent),
+    InitTradeLogEvent(InitTradeLogEvent),
+    ResizeTradeLogEvent(ResizeTradeLogEvent),
+    CreditUserEvent(CreditUserEvent),
+    DebitUserEvent(DebitUserEvent),
+    DepositEvent(DepositEvent),
+    AddDestinationEvent(AddDestinationEvent),
+    RemoveDestinationEvent(RemoveDestinationEvent),
+    WithdrawEvent(WithdrawEvent),
+    TokenReceiveEvent(TokenReceiveEvent),
+    TradeExecuted(TradeExecuted),
+}
+
+impl JupiterDelegateEvent {
+    /// Decodes an inner instruction. Returns `Ok(None)` when it is not an
+    /// event CPI of this program.
+    pub fn from_inner_instruction(program_id: &Pubkey, data: &[u8]) -> Result<Option<Self>> {
+        if *program_id != JUPITER_DELEGATE_ID {
+            return Ok(None);
+        }
+        match data.strip_prefix(&EVENT_IX_TAG_LE) {
+            Some(event) => Self::decode(event).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Decodes an event from its discriminator followed by its borsh body.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        if data.len() < 8 {
+            return Err(Error::new(ErrorKind::InvalidData, "event data too short"));
+        }
+        let (discriminator, body) = data.split_at(8);
+        let discriminator: [u8; 8] = discriminator.try_into().unwrap();
+        Ok(match discriminator {
+            INIT_CONFIG_EVENT_DISCRIMINATOR => {
+                Self::InitConfigEvent(InitConfigEvent::try_from_slice(body)?)
+            }
+            GRANT_ACCESS_EVENT_DISCRIMINATOR => {
+                Self::GrantAccessEvent(GrantAccessEvent::try_from_slice(body)?)
+            }
+            REVOKE_ACCESS_EVENT_DISCRIMINATOR => {
+                Self::RevokeAccessEvent(RevokeAccessEvent::try_from_slice(body)?)
+            }
+            MODIFY_ACCESS_INTENT_EVENT_DISCRIMINATOR => {
+                Self::ModifyAccessIntentEvent(ModifyAccessIntentEvent::try_from_slice(body)?)
+            }
+            ADMIN_TRANSFER_PROPOSED_EVENT_DISCRIMINATOR => {
+                Self::AdminTransferProposedEvent(AdminTransferProposedEvent::try_from_slice(body)?)
+            }
+            ADMIN_TRANSFER_COMPLETED_EVENT_DISCRIMINATOR => Self::AdminTransferCompletedEvent(
+                AdminTransferCompletedEvent::try_from_slice(body)?,
+            ),
+            MODIFY_COOLDOWN_DURATION_EVENT_DISCRIMINATOR => Self::ModifyCooldownDurationEvent(
+                ModifyCooldownDurationEvent::try_from_slice(body)?,
+            ),
+            MODIFY_OPERATOR_EVENT_DISCRIMINATOR => {
+                Self::ModifyOperatorEvent(ModifyOperatorEvent::try_from_slice(body)?)
+            }
+            PAUSE_EVENT_DISCRIMINATOR => Self::PauseEvent(PauseEvent::try_from_slice(body)?),
+            MODIFY_MAX_COMMISSION_EVENT_DISCRIMINATOR => {
+                Self::ModifyMaxCommissionEvent(ModifyMaxCommissionEvent::try_from_slice(body)?)
+            }
+            MODIFY_VAULT_MODE_EVENT_DISCRIMINATOR => {
+                Self::ModifyVaultModeEvent(ModifyVaultModeEvent::try_from_slice(body)?)
+            }
+            MIGRATE_USER_VAULT_EVENT_DISCRIMINATOR => {
+                Self::MigrateUserVaultEvent(MigrateUserVaultEvent::try_from_slice(body)?)
+            }
+            MIGRATE_ACCOUNT_EVENT_DISCRIMINATOR => {
+                Self::MigrateAccountEvent(MigrateAccountEvent::try_from_slice(body)?)
+            }
+            MODIFY_PROTOCOL_FEE_EVENT_DISCRIMINATOR => {
+                Self::ModifyProtocolFeeEvent(ModifyProtocolFeeEvent::try_from_slice(body)?)
+            }
+            WITHDRAW_PROTOCOL_FEE_EVENT_DISCRIMINATOR => {
+                Self::WithdrawProtocolFeeEvent(WithdrawProtocolFeeEvent::try_from_slice(body)?)
+            }
+            PROTOCOL_FEE_EVENT_DISCRIMINATOR => {
+                Self::ProtocolFeeEvent(ProtocolFeeEvent::try_from_slice(body)?)
+            }
+            PARTNER_EVENT_DISCRIMINATOR => Self::PartnerEvent(PartnerEvent::try_from_slice(body)?),
+            PARTNER_FEE_ACCRUED_EVENT_DISCRIMINATOR => {
+                Self::PartnerFeeAccruedEvent(PartnerFeeAccruedEvent::try_from_slice(body)?)
+            }
+            PARTNER_FEE_CLAIMED_EVENT_DISCRIMINATOR => {
+                Self::PartnerFeeClaimedEvent(PartnerFeeClaimedEvent::try_from_slice(body)?)
+            }
+            JUPITER_SWAP_EVENT_DISCRIMINATOR => {
+                Self::JupiterSwapEvent(JupiterSwapEvent::try_from_slice(body)?)
+            }
+            JUPITER_AGGREGATOR_EVENT_DISCRIMINATOR => {
+                Self::JupiterAggregatorEvent(JupiterAggregatorEvent::try_from_slice(body)?)
+            }
+            FILL_ORDER_ENGINE_EVENT_DISCRIMINATOR => {
+                Self::FillOrderEngineEvent(FillOrderEngineEvent::try_from_slice(body)?)
+            }
+            DFLOW_AGGREGATOR_EVENT_DISCRIMINATOR => {
+                Self::DflowAggregatorEvent(DflowAggregatorEvent::try_from_slice(body)?)
+            }
+            TRANSFER_FEE_EVENT_DISCRIMINATOR => {
+                Self::TransferFeeEvent(TransferFeeEvent::try_from_slice(body)?)
+            }
+            NATIVE_OUTPUT_SETTLED_EVENT_DISCRIMINATOR => {
+                Self::NativeOutputSettledEvent(NativeOutputSettledEvent::try_from_slice(body)?)
+            }
+            DFLOW_ORDER_OPENED_EVENT_DISCRIMINATOR => {
+                Self::DflowOrderOpenedEvent(DflowOrderOpenedEvent::try_from_slice(body)?)
+            }
+            DFLOW_ORDER_SETTLED_EVENT_DISCRIMINATOR => {
+                Self::DflowOrderSettledEvent(DflowOrderSettledEvent::try_from_slice(body)?)
+            }
+            OKX_AGGREGATOR_EVENT_DISCRIMINATOR => {
+                Self::OkxAggregatorEvent(OkxAggregatorEvent::try_from_slice(body)?)
+            }
+            JUPITER_PERPETUALS_EVENT_DISCRIMINATOR => {
+                Self::JupiterPerpetualsEvent(JupiterPerpetualsEvent::try_from_slice(body)?)
+            }
+            TWO_HOP_EVENT_DISCRIMINATOR => Self::TwoHopEvent(TwoHopEvent::try_from_slice(body)?),
+            DCA_PLAN_CREATED_EVENT_DISCRIMINATOR => {
+                Self::DcaPlanCreatedEvent(DcaPlanCreatedEvent::try_from_slice(body)?)
+            }
+            DCA_PLAN_CANCELLED_EVENT_DISCRIMINATOR => {
+                Self::DcaPlanCancelledEvent(DcaPlanCancelledEvent::try_from_slice(body)?)
+            }
+            DCA_SLICE_EXECUTED_EVENT_DISCRIMINATOR => {
+                Self::DcaSliceExecutedEvent(DcaSliceExecutedEvent::try_from_slice(body)?)
+            }
+            LIMIT_ORDER_CREATED_EVENT_DISCRIMINATOR => {
+                Self::LimitOrderCreatedEvent(LimitOrderCreatedEvent::try_from_slice(body)?)
+            }
+            LIMIT_ORDER_FILLED_EVENT_DISCRIMINATOR => {
+                Self::LimitOrderFilledEvent(LimitOrderFilledEvent::try_from_slice(body)?)
+            }
+            LIMIT_ORDER_EXPIRED_EVENT_DISCRIMINATOR => {
+                Self::LimitOrderExpiredEvent(LimitOrderExpiredEvent::try_from_slice(body)?)
+            }
+            LIMIT_ORDER_CANCELLED_EVENT_DISCRIMINATOR => {
+                Self::LimitOrderCancelledEvent(LimitOrderCancelledEvent::try_from_slice(body)?)
+            }
+            BATCH_TRADE_EVENT_DISCRIMINATOR => {
+                Self::BatchTradeEvent(BatchTradeEvent::try_from_slice(body)?)
+            }
+            BATCH_TRADE_LEG_EVENT_DISCRIMINATOR => {
+                Self::BatchTradeLegEvent(BatchTradeLegEvent::try_from_slice(body)?)
+            }
+            TRADE_RECEIPT_CLOSED_EVENT_DISCRIMINATOR => {
+                Self::TradeReceiptClosedEvent(TradeReceiptClosedEvent::try_from_slice(body)?)
+            }
+            USER_STATS_CLOSED_EVENT_DISCRIMINATOR => {
+                Self::UserStatsClosedEvent(UserStatsClosedEvent::try_from_slice(body)?)
+            }
+            INIT_TRADE_LOG_EVENT_DISCRIMINATOR => {
+                Self::InitTradeLogEvent(InitTradeLogEvent::try_from_slice(body)?)
+            }
+            RESIZE_TRADE_LOG_EVENT_DISCRIMINATOR => {
+                Self::ResizeTradeLogEvent(ResizeTradeLogEvent::try_from_slice(body)?)
+            }
+            CREDIT_USER_EVENT_DISCRIMINATOR => {
+                Self::CreditUserEvent(CreditUserEvent::try_from_slice(body)?)
+            }
+            DEBIT_USER_EVENT_DISCRIMINATOR => {
+                Self::DebitUserEvent(DebitUserEvent::try_from_slice(body)?)
+            }
+            DEPOSIT_EVENT_DISCRIMINATOR => Self::DepositEvent(DepositEvent::try_from_slice(body)?),
+            ADD_DESTINATION_EVENT_DISCRIMINATOR => {
+                Self::AddDestinationEvent(AddDestinationEvent::try_from_slice(body)?)
+            }
+            REMOVE_DESTINATION_EVENT_DISCRIMINATOR => {
+                Self::RemoveDestinationEvent(RemoveDestinationEvent::try_from_slice(body)?)
+            }
+            WITHDRAW_EVENT_DISCRIMINATOR => {
+                Self::WithdrawEvent(WithdrawEvent::try_from_slice(body)?)
+            }
+            TOKEN_RECEIVE_EVENT_DISCRIMINATOR => {
+                Self::TokenReceiveEvent(TokenReceiveEvent::try_from_slice(body)?)
+            }
+            TRADE_EXECUTED_DISCRIMINATOR => {
+                Self::TradeExecuted(TradeExecuted::try_from_slice(body)?)
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "unknown event discriminator",
+                ))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use borsh::BorshSerialize;
+
+    #[test]
+    fn test_from_inner_instruction() {
+        let event = TokenReceiveEvent {
+            user: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 42,
+            source: Pubkey::new_unique(),
+            destination: Pubkey::new_unique(),
+            operator: Pubkey::new_unique(),
+        };
+        let mut data = EVENT_IX_TAG_LE.to_vec();
+        data.extend_from_slice(&TOKEN_RECEIVE_EVENT_DISCRIMINATOR);
+        event.serialize(&mut data).unwrap();
+
+        assert_eq!(
+            JupiterDelegateEvent::from_inner_instruction(&JUPITER_DELEGATE_ID, &data).unwrap(),
+            Some(JupiterDelegateEvent::TokenReceiveEvent(event))
+        );
+        assert_eq!(
+            JupiterDelegateEvent::from_inner_instruction(&Pubkey::new_unique(), &data).unwrap(),
+            None
+        );
+        assert!(
+            JupiterDelegateEvent::from_inner_instruction(&JUPITER_DELEGATE_ID, &data[..20])
+                .is_err()
+        );
+    }
+}
@@ -20,12 +20,56 @@ pub struct Access {
     pub user: Pubkey,
     pub is_granted: bool,
     pub bump: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub partner: Pubkey,
+    pub version: u8,
+    pub intent_required: bool,
+    pub reserved: [u8; 31],
 }
 
 pub const ACCESS_DISCRIMINATOR: [u8; 8] = [117, 154, 108, 210, 202, 83, 96, 222];
 
 impl Access {
-    pub const LEN: usize = 42;
+    pub const LEN: usize = 107;
+
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `Access::PREFIX`
+    ///   1. namespace (`Vec<u8>`)
+    ///   2. user (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "jupiter-delegate-access".as_bytes();
+
+    pub fn create_pda(
+        namespace: Vec<u8>,
+        user: Pubkey,
+        bump: u8,
+    ) -> Result<solana_pubkey::Pubkey, solana_pubkey::PubkeyError> {
+        solana_pubkey::Pubkey::create_program_address(
+            &[
+                "jupiter-delegate-access".as_bytes(),
+                &namespace,
+                user.as_ref(),
+                &[bump],
+            ],
+            &crate::JUPITER_DELEGATE_ID,
+        )
+    }
+
+    pub fn find_pda(namespace: Vec<u8>, user: &Pubkey) -> (solana_pubkey::Pubkey, u8) {
+        solana_pubkey::Pubkey::find_program_address(
+            &[
+                "jupiter-delegate-access".as_bytes(),
+                &namespace,
+                user.as_ref(),
+            ],
+            &crate::JUPITER_DELEGATE_ID,
+        )
+    }
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
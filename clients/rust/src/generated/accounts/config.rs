@@ -38,12 +38,50 @@ pub struct Config {
     pub is_paused: bool,
     pub cooldown_duration: i64,
     pub bump: u8,
+    pub fee_bps: u16,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub fee_treasury: Pubkey,
+    pub max_commission_bps: u16,
+    pub per_user_vault: bool,
+    pub namespace: u16,
+    pub version: u8,
+    pub trade_sequence: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    pub reserved: [u8; 56],
 }
 
 pub const CONFIG_DISCRIMINATOR: [u8; 8] = [155, 12, 170, 224, 30, 250, 204, 130];
 
 impl Config {
-    pub const LEN: usize = 155;
+    pub const LEN: usize = 259;
+
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `Config::PREFIX`
+    ///   1. namespace (`Vec<u8>`)
+    pub const PREFIX: &'static [u8] = "jupiter-delegate-config".as_bytes();
+
+    pub fn create_pda(
+        namespace: Vec<u8>,
+        bump: u8,
+    ) -> Result<solana_pubkey::Pubkey, solana_pubkey::PubkeyError> {
+        solana_pubkey::Pubkey::create_program_address(
+            &["jupiter-delegate-config".as_bytes(), &namespace, &[bump]],
+            &crate::JUPITER_DELEGATE_ID,
+        )
+    }
+
+    pub fn find_pda(namespace: Vec<u8>) -> (solana_pubkey::Pubkey, u8) {
+        solana_pubkey::Pubkey::find_program_address(
+            &["jupiter-delegate-config".as_bytes(), &namespace],
+            &crate::JUPITER_DELEGATE_ID,
+        )
+    }
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -7,6 +7,24 @@
 
 pub(crate) mod r#access;
 pub(crate) mod r#config;
+pub(crate) mod r#dca_plan;
+pub(crate) mod r#destination;
+pub(crate) mod r#dflow_order;
+pub(crate) mod r#limit_order;
+pub(crate) mod r#partner;
+pub(crate) mod r#trade_log;
+pub(crate) mod r#trade_receipt;
+pub(crate) mod r#user_credit;
+pub(crate) mod r#user_stats;
 
 pub use self::r#access::*;
 pub use self::r#config::*;
+pub use self::r#dca_plan::*;
+pub use self::r#destination::*;
+pub use self::r#dflow_order::*;
+pub use self::r#limit_order::*;
+pub use self::r#partner::*;
+pub use self::r#trade_log::*;
+pub use self::r#trade_receipt::*;
+pub use self::r#user_credit::*;
+pub use self::r#user_stats::*;
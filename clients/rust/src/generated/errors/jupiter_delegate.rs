@@ -31,96 +31,252 @@ pub enum JupiterDelegateError {
     /// 6006 - Config is paused
     #[error("Config is paused")]
     ConfigPaused = 0x1776,
-    /// 6007 - Swap too frequent
+    /// 6007 - Unsupported account version
+    #[error("Unsupported account version")]
+    UnsupportedAccountVersion = 0x1777,
+    /// 6008 - Account is already migrated
+    #[error("Account is already migrated")]
+    AccountAlreadyMigrated = 0x1778,
+    /// 6009 - Swap too frequent
     #[error("Swap too frequent")]
-    SwapTooFrequent = 0x1777,
-    /// 6008 - Swap failed
+    SwapTooFrequent = 0x1779,
+    /// 6010 - Swap failed
     #[error("Swap failed")]
-    SwapFailed = 0x1778,
-    /// 6009 - Invalid operator
+    SwapFailed = 0x177A,
+    /// 6011 - Invalid operator
     #[error("Invalid operator")]
-    InvalidOperator = 0x1779,
-    /// 6010 - Only admin can propose new admin
+    InvalidOperator = 0x177B,
+    /// 6012 - Only admin can propose new admin
     #[error("Only admin can propose new admin")]
-    OnlyAdminCanProposeNewAdmin = 0x177A,
-    /// 6011 - Only proposed admin can accept
+    OnlyAdminCanProposeNewAdmin = 0x177C,
+    /// 6013 - Only proposed admin can accept
     #[error("Only proposed admin can accept")]
-    OnlyProposedAdminCanAccept = 0x177B,
-    /// 6012 - Proposed admin is already set
+    OnlyProposedAdminCanAccept = 0x177D,
+    /// 6014 - Proposed admin is already set
     #[error("Proposed admin is already set")]
-    ProposedAdminAlreadySet = 0x177C,
-    /// 6013 - Proposed admin is current admin
+    ProposedAdminAlreadySet = 0x177E,
+    /// 6015 - Proposed admin is current admin
     #[error("Proposed admin is current admin")]
-    ProposedAdminIsCurrentAdmin = 0x177D,
-    /// 6014 - No pending admin transfer
+    ProposedAdminIsCurrentAdmin = 0x177F,
+    /// 6016 - No pending admin transfer
     #[error("No pending admin transfer")]
-    NoPendingAdminTransfer = 0x177E,
-    /// 6015 - Only admin can modify cooldown duration
+    NoPendingAdminTransfer = 0x1780,
+    /// 6017 - Only admin can modify cooldown duration
     #[error("Only admin can modify cooldown duration")]
-    OnlyAdminCanModifyCooldownDuration = 0x177F,
-    /// 6016 - Only admin can modify operator
+    OnlyAdminCanModifyCooldownDuration = 0x1781,
+    /// 6018 - Only admin can modify operator
     #[error("Only admin can modify operator")]
-    OnlyAdminCanModifyOperator = 0x1780,
-    /// 6017 - Only admin can pause
+    OnlyAdminCanModifyOperator = 0x1782,
+    /// 6019 - Only admin can pause
     #[error("Only admin can pause")]
-    OnlyAdminCanPause = 0x1781,
-    /// 6018 - Swap amount is too small
+    OnlyAdminCanPause = 0x1783,
+    /// 6020 - Only admin can modify protocol fee
+    #[error("Only admin can modify protocol fee")]
+    OnlyAdminCanModifyProtocolFee = 0x1784,
+    /// 6021 - Invalid fee bps
+    #[error("Invalid fee bps")]
+    InvalidFeeBps = 0x1785,
+    /// 6022 - Only admin can modify max commission
+    #[error("Only admin can modify max commission")]
+    OnlyAdminCanModifyMaxCommission = 0x1786,
+    /// 6023 - Swap amount is too small
     #[error("Swap amount is too small")]
-    SwapAmountTooSmall = 0x1782,
-    /// 6019 - Admin authority mismatch
+    SwapAmountTooSmall = 0x1787,
+    /// 6024 - Admin authority mismatch
     #[error("Admin authority mismatch")]
-    AdminAuthorityMismatch = 0x1783,
-    /// 6020 - New admin proposed
+    AdminAuthorityMismatch = 0x1788,
+    /// 6025 - New admin proposed
     #[error("New admin proposed")]
-    NewAdminProposed = 0x1784,
-    /// 6021 - No new admin proposed
+    NewAdminProposed = 0x1789,
+    /// 6026 - No new admin proposed
     #[error("No new admin proposed")]
-    NoNewAdminProposed = 0x1785,
-    /// 6022 - Delegated amount is insufficient
+    NoNewAdminProposed = 0x178A,
+    /// 6027 - Delegated amount is insufficient
     #[error("Delegated amount is insufficient")]
-    InsufficientDelegatedAmount = 0x1786,
-    /// 6023 - Vault has not been delegated authority
+    InsufficientDelegatedAmount = 0x178B,
+    /// 6028 - Vault has not been delegated authority
     #[error("Vault has not been delegated authority")]
-    DelegateNotApproved = 0x1787,
-    /// 6024 - Invalid delegate token account
+    DelegateNotApproved = 0x178C,
+    /// 6029 - Invalid delegate token account
     #[error("Invalid delegate token account")]
-    InvalidDelegateTokenAccount = 0x1788,
-    /// 6025 - Receiver token account not found in remaining accounts
+    InvalidDelegateTokenAccount = 0x178D,
+    /// 6030 - Receiver token account not found in remaining accounts
     #[error("Receiver token account not found in remaining accounts")]
-    ReceiverTokenAccountNotFound = 0x1789,
-    /// 6026 - Order engine failed
+    ReceiverTokenAccountNotFound = 0x178E,
+    /// 6031 - Order engine failed
     #[error("Order engine failed")]
-    OrderEngineFailed = 0x178A,
-    /// 6027 - Invalid order engine data
+    OrderEngineFailed = 0x178F,
+    /// 6032 - Invalid order engine data
     #[error("Invalid order engine data")]
-    InvalidOrderEngineData = 0x178B,
-    /// 6028 - Two hop insufficient input amount
+    InvalidOrderEngineData = 0x1790,
+    /// 6033 - Invalid perpetuals data
+    #[error("Invalid perpetuals data")]
+    InvalidPerpetualsData = 0x1791,
+    /// 6034 - Two hop insufficient input amount
     #[error("Two hop insufficient input amount")]
-    TwoHopInsufficientInputAmount = 0x178C,
-    /// 6029 - Two hop max slippage output amount exceeded
+    TwoHopInsufficientInputAmount = 0x1792,
+    /// 6035 - Two hop max slippage output amount exceeded
     #[error("Two hop max slippage output amount exceeded")]
-    TwoHopMaxSlippageOutputAmountExceeded = 0x178D,
-    /// 6030 - Two hop invalid intermediate token amount
+    TwoHopMaxSlippageOutputAmountExceeded = 0x1793,
+    /// 6036 - Two hop invalid intermediate token amount
     #[error("Two hop invalid intermediate token amount")]
-    TwoHopInvalidIntermediateTokenAmount = 0x178E,
-    /// 6031 - Vault output token account is insufficient
+    TwoHopInvalidIntermediateTokenAmount = 0x1794,
+    /// 6037 - Vault output token account is insufficient
     #[error("Vault output token account is insufficient")]
-    VaultOutputTokenAccountIsInsufficient = 0x178F,
-    /// 6032 - Fund vault output token account not found
+    VaultOutputTokenAccountIsInsufficient = 0x1795,
+    /// 6038 - Fund vault output token account not found
     #[error("Fund vault output token account not found")]
-    FundVaultOutputTokenAccountNotFound = 0x1790,
-    /// 6033 - Unsupported token program
+    FundVaultOutputTokenAccountNotFound = 0x1796,
+    /// 6039 - Unsupported token program
     #[error("Unsupported token program")]
-    UnsupportedTokenProgram = 0x1791,
-    /// 6034 - Delegate is not receiver
+    UnsupportedTokenProgram = 0x1797,
+    /// 6040 - Unsupported mint extension
+    #[error("Unsupported mint extension")]
+    UnsupportedMintExtension = 0x1798,
+    /// 6041 - Invalid transfer fee
+    #[error("Invalid transfer fee")]
+    InvalidTransferFee = 0x1799,
+    /// 6042 - Delegate is not receiver
     #[error("Delegate is not receiver")]
-    DelegateIsNotReceiver = 0x1792,
-    /// 6035 - Insufficient funds
+    DelegateIsNotReceiver = 0x179A,
+    /// 6043 - Native output requires wrapped SOL output mint
+    #[error("Native output requires wrapped SOL output mint")]
+    NativeOutputRequiresWrappedSol = 0x179B,
+    /// 6044 - Venue instruction is not supported
+    #[error("Venue instruction is not supported")]
+    UnsupportedVenueInstruction = 0x179C,
+    /// 6045 - Invalid okx commission data
+    #[error("Invalid okx commission data")]
+    InvalidOkxCommissionData = 0x179D,
+    /// 6046 - Commission account is not owned by the fee treasury
+    #[error("Commission account is not owned by the fee treasury")]
+    InvalidCommissionAccount = 0x179E,
+    /// 6047 - Commission rate exceeds the configured maximum
+    #[error("Commission rate exceeds the configured maximum")]
+    CommissionRateTooHigh = 0x179F,
+    /// 6048 - OKX platform fee is not supported
+    #[error("OKX platform fee is not supported")]
+    OkxPlatformFeeNotSupported = 0x17A0,
+    /// 6049 - OKX trim is not supported
+    #[error("OKX trim is not supported")]
+    OkxTrimNotSupported = 0x17A1,
+    /// 6050 - Invalid dflow order data
+    #[error("Invalid dflow order data")]
+    InvalidDflowOrderData = 0x17A2,
+    /// 6051 - Dflow order does not match the delegate order
+    #[error("Dflow order does not match the delegate order")]
+    DflowOrderMismatch = 0x17A3,
+    /// 6052 - Dflow order is still open
+    #[error("Dflow order is still open")]
+    DflowOrderStillOpen = 0x17A4,
+    /// 6053 - Invalid dca plan
+    #[error("Invalid dca plan")]
+    InvalidDcaPlan = 0x17A5,
+    /// 6054 - Dca slice is not due
+    #[error("Dca slice is not due")]
+    DcaSliceNotDue = 0x17A6,
+    /// 6055 - Dca plan budget is exhausted
+    #[error("Dca plan budget is exhausted")]
+    DcaPlanCompleted = 0x17A7,
+    /// 6056 - Dca slice output is out of price bounds
+    #[error("Dca slice output is out of price bounds")]
+    DcaPriceOutOfBounds = 0x17A8,
+    /// 6057 - Dca slice spent no input
+    #[error("Dca slice spent no input")]
+    DcaSliceEmpty = 0x17A9,
+    /// 6058 - Invalid limit order
+    #[error("Invalid limit order")]
+    InvalidLimitOrder = 0x17AA,
+    /// 6059 - Limit order is expired
+    #[error("Limit order is expired")]
+    LimitOrderExpired = 0x17AB,
+    /// 6060 - Limit order is not expired
+    #[error("Limit order is not expired")]
+    LimitOrderNotExpired = 0x17AC,
+    /// 6061 - Limit order fill exceeds remaining size
+    #[error("Limit order fill exceeds remaining size")]
+    LimitOrderFillTooLarge = 0x17AD,
+    /// 6062 - Realized rate is below the limit rate
+    #[error("Realized rate is below the limit rate")]
+    LimitOrderRateNotMet = 0x17AE,
+    /// 6063 - Invalid batch legs
+    #[error("Invalid batch legs")]
+    InvalidBatchLegs = 0x17AF,
+    /// 6064 - Invalid batch leg accounts
+    #[error("Invalid batch leg accounts")]
+    InvalidBatchLegAccounts = 0x17B0,
+    /// 6065 - Batch trades require the shared vault
+    #[error("Batch trades require the shared vault")]
+    BatchRequiresSharedVault = 0x17B1,
+    /// 6066 - Only admin can modify vault mode
+    #[error("Only admin can modify vault mode")]
+    OnlyAdminCanModifyVaultMode = 0x17B2,
+    /// 6067 - Invalid vault migration
+    #[error("Invalid vault migration")]
+    InvalidVaultMigration = 0x17B3,
+    /// 6068 - Trade with this client order id was already executed
+    #[error("Trade with this client order id was already executed")]
+    TradeAlreadyExecuted = 0x17B4,
+    /// 6069 - Trade receipt retention period has not elapsed
+    #[error("Trade receipt retention period has not elapsed")]
+    TradeReceiptRetentionNotElapsed = 0x17B5,
+    /// 6070 - Invalid trade receipt
+    #[error("Invalid trade receipt")]
+    InvalidTradeReceipt = 0x17B6,
+    /// 6071 - Invalid user credit account
+    #[error("Invalid user credit account")]
+    InvalidUserCredit = 0x17B7,
+    /// 6072 - User credit account is required to park output in the vault
+    #[error("User credit account is required to park output in the vault")]
+    UserCreditRequired = 0x17B8,
+    /// 6073 - Insufficient user credit
+    #[error("Insufficient user credit")]
+    InsufficientUserCredit = 0x17B9,
+    /// 6074 - Destination is neither registered nor credited in the ledger
+    #[error("Destination is neither registered nor credited in the ledger")]
+    DestinationNotAllowed = 0x17BA,
+    /// 6075 - Invalid trade log capacity
+    #[error("Invalid trade log capacity")]
+    InvalidTradeLogCapacity = 0x17BB,
+    /// 6076 - Invalid user stats account
+    #[error("Invalid user stats account")]
+    InvalidUserStats = 0x17BC,
+    /// 6077 - User requires a signed trade intent
+    #[error("User requires a signed trade intent")]
+    IntentRequired = 0x17BD,
+    /// 6078 - Invalid trade intent signature
+    #[error("Invalid trade intent signature")]
+    InvalidIntentSignature = 0x17BE,
+    /// 6079 - Trade does not match the signed intent
+    #[error("Trade does not match the signed intent")]
+    IntentMismatch = 0x17BF,
+    /// 6080 - Trade intent has expired
+    #[error("Trade intent has expired")]
+    IntentExpired = 0x17C0,
+    /// 6081 - Input amount exceeds the signed intent
+    #[error("Input amount exceeds the signed intent")]
+    IntentInputExceeded = 0x17C1,
+    /// 6082 - Output amount is below the signed intent
+    #[error("Output amount is below the signed intent")]
+    IntentOutputTooLow = 0x17C2,
+    /// 6083 - Instruction does not support signed trade intents
+    #[error("Instruction does not support signed trade intents")]
+    IntentNotSupported = 0x17C3,
+    /// 6084 - Invalid partner
+    #[error("Invalid partner")]
+    InvalidPartner = 0x17C4,
+    /// 6085 - Invalid partner share bps
+    #[error("Invalid partner share bps")]
+    InvalidPartnerShareBps = 0x17C5,
+    /// 6086 - Partner fee token account not found
+    #[error("Partner fee token account not found")]
+    PartnerFeeTokenAccountNotFound = 0x17C6,
+    /// 6087 - Insufficient funds
     #[error("Insufficient funds")]
-    InsufficientFunds = 0x1793,
-    /// 6036 - Invalid token account
+    InsufficientFunds = 0x17C7,
+    /// 6088 - Invalid token account
     #[error("Invalid token account")]
-    InvalidTokenAccount = 0x1794,
+    InvalidTokenAccount = 0x17C8,
 }
 
 impl From<JupiterDelegateError> for solana_program_error::ProgramError {
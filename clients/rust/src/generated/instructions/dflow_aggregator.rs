@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::TradeIntent;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -38,9 +39,37 @@ pub struct DflowAggregator {
 
     pub user: solana_pubkey::Pubkey,
 
-    pub receiver_output_token_account: solana_pubkey::Pubkey,
+    pub receiver_output_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub native_output_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub fee_token_account: solana_pubkey::Pubkey,
+
+    pub fee_vault_authority: solana_pubkey::Pubkey,
+
+    pub partner: Option<solana_pubkey::Pubkey>,
+
+    pub partner_fee_token_account: Option<solana_pubkey::Pubkey>,
 
     pub dflow_program: solana_pubkey::Pubkey,
+
+    pub instructions_sysvar: Option<solana_pubkey::Pubkey>,
+
+    pub trade_receipt: solana_pubkey::Pubkey,
+
+    pub user_stats: solana_pubkey::Pubkey,
+
+    pub trade_log: solana_pubkey::Pubkey,
+
+    /// Ledger entry of the user for the output mint, required when the output
+    /// is parked in the shared vault
+    pub user_credit: Option<solana_pubkey::Pubkey>,
+
+    pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl DflowAggregator {
@@ -57,7 +86,7 @@ impl DflowAggregator {
         args: DflowAggregatorInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(27 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.input_mint,
             false,
@@ -93,15 +122,97 @@ impl DflowAggregator {
             self.access,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(self.user, false));
+        if let Some(receiver_output_token_account) = self.receiver_output_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                receiver_output_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        if let Some(native_output_token_account) = self.native_output_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                native_output_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.fee_token_account,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.user, false,
+            self.fee_vault_authority,
+            false,
         ));
+        if let Some(partner) = self.partner {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                partner, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        if let Some(partner_fee_token_account) = self.partner_fee_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                partner_fee_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.dflow_program,
+            false,
+        ));
+        if let Some(instructions_sysvar) = self.instructions_sysvar {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                instructions_sysvar,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new(
-            self.receiver_output_token_account,
+            self.trade_receipt,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(self.user_stats, false));
+        accounts.push(solana_instruction::AccountMeta::new(self.trade_log, false));
+        if let Some(user_credit) = self.user_credit {
+            accounts.push(solana_instruction::AccountMeta::new(user_credit, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.dflow_program,
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
             false,
         ));
         accounts.extend_from_slice(remaining_accounts);
@@ -146,8 +257,9 @@ impl Default for DflowAggregatorInstructionData {
 pub struct DflowAggregatorInstructionArgs {
     pub data: Vec<u8>,
     pub in_amount: u64,
-    pub instruction_name: String,
     pub delegate: Pubkey,
+    pub client_order_id: u64,
+    pub intent: Option<TradeIntent>,
 }
 
 impl DflowAggregatorInstructionArgs {
@@ -171,9 +283,22 @@ impl DflowAggregatorInstructionArgs {
 ///   8. `[writable]` vault_output_token_account
 ///   9. `[writable]` config
 ///   10. `[]` access
-///   11. `[]` user
-///   12. `[writable]` receiver_output_token_account
-///   13. `[optional]` dflow_program (default to `DF1ow4tspfHX9JwWJsAb9epbkA8hmpSEAtxXy1V27QBH`)
+///   11. `[writable]` user
+///   12. `[writable, optional]` receiver_output_token_account
+///   13. `[writable, optional]` native_output_token_account
+///   14. `[writable]` fee_token_account
+///   15. `[]` fee_vault_authority
+///   16. `[optional]` partner
+///   17. `[writable, optional]` partner_fee_token_account
+///   18. `[optional]` dflow_program (default to `DF1ow4tspfHX9JwWJsAb9epbkA8hmpSEAtxXy1V27QBH`)
+///   19. `[optional]` instructions_sysvar (default to `Sysvar1nstructions1111111111111111111111111`)
+///   20. `[writable]` trade_receipt
+///   21. `[writable]` user_stats
+///   22. `[writable]` trade_log
+///   23. `[writable, optional]` user_credit
+///   24. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   25. `[]` event_authority
+///   26. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct DflowAggregatorBuilder {
     input_mint: Option<solana_pubkey::Pubkey>,
@@ -189,11 +314,25 @@ pub struct DflowAggregatorBuilder {
     access: Option<solana_pubkey::Pubkey>,
     user: Option<solana_pubkey::Pubkey>,
     receiver_output_token_account: Option<solana_pubkey::Pubkey>,
+    native_output_token_account: Option<solana_pubkey::Pubkey>,
+    fee_token_account: Option<solana_pubkey::Pubkey>,
+    fee_vault_authority: Option<solana_pubkey::Pubkey>,
+    partner: Option<solana_pubkey::Pubkey>,
+    partner_fee_token_account: Option<solana_pubkey::Pubkey>,
     dflow_program: Option<solana_pubkey::Pubkey>,
+    instructions_sysvar: Option<solana_pubkey::Pubkey>,
+    trade_receipt: Option<solana_pubkey::Pubkey>,
+    user_stats: Option<solana_pubkey::Pubkey>,
+    trade_log: Option<solana_pubkey::Pubkey>,
+    user_credit: Option<solana_pubkey::Pubkey>,
+    system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     data: Option<Vec<u8>>,
     in_amount: Option<u64>,
-    instruction_name: Option<String>,
     delegate: Option<Pubkey>,
+    client_order_id: Option<u64>,
+    intent: Option<TradeIntent>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -270,12 +409,47 @@ impl DflowAggregatorBuilder {
         self.user = Some(user);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn receiver_output_token_account(
         &mut self,
-        receiver_output_token_account: solana_pubkey::Pubkey,
+        receiver_output_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.receiver_output_token_account = receiver_output_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn native_output_token_account(
+        &mut self,
+        native_output_token_account: Option<solana_pubkey::Pubkey>,
     ) -> &mut Self {
-        self.receiver_output_token_account = Some(receiver_output_token_account);
+        self.native_output_token_account = native_output_token_account;
+        self
+    }
+    #[inline(always)]
+    pub fn fee_token_account(&mut self, fee_token_account: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_token_account = Some(fee_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_vault_authority(&mut self, fee_vault_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_vault_authority = Some(fee_vault_authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner(&mut self, partner: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.partner = partner;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner_fee_token_account(
+        &mut self,
+        partner_fee_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.partner_fee_token_account = partner_fee_token_account;
         self
     }
     /// `[optional account, default to 'DF1ow4tspfHX9JwWJsAb9epbkA8hmpSEAtxXy1V27QBH']`
@@ -284,6 +458,54 @@ impl DflowAggregatorBuilder {
         self.dflow_program = Some(dflow_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn instructions_sysvar(
+        &mut self,
+        instructions_sysvar: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.instructions_sysvar = instructions_sysvar;
+        self
+    }
+    #[inline(always)]
+    pub fn trade_receipt(&mut self, trade_receipt: solana_pubkey::Pubkey) -> &mut Self {
+        self.trade_receipt = Some(trade_receipt);
+        self
+    }
+    #[inline(always)]
+    pub fn user_stats(&mut self, user_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn trade_log(&mut self, trade_log: solana_pubkey::Pubkey) -> &mut Self {
+        self.trade_log = Some(trade_log);
+        self
+    }
+    /// `[optional account]`
+    /// Ledger entry of the user for the output mint, required when the output
+    /// is parked in the shared vault
+    #[inline(always)]
+    pub fn user_credit(&mut self, user_credit: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.user_credit = user_credit;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn data(&mut self, data: Vec<u8>) -> &mut Self {
         self.data = Some(data);
@@ -295,13 +517,19 @@ impl DflowAggregatorBuilder {
         self
     }
     #[inline(always)]
-    pub fn instruction_name(&mut self, instruction_name: String) -> &mut Self {
-        self.instruction_name = Some(instruction_name);
+    pub fn delegate(&mut self, delegate: Pubkey) -> &mut Self {
+        self.delegate = Some(delegate);
         self
     }
     #[inline(always)]
-    pub fn delegate(&mut self, delegate: Pubkey) -> &mut Self {
-        self.delegate = Some(delegate);
+    pub fn client_order_id(&mut self, client_order_id: u64) -> &mut Self {
+        self.client_order_id = Some(client_order_id);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn intent(&mut self, intent: TradeIntent) -> &mut Self {
+        self.intent = Some(intent);
         self
     }
     /// Add an additional account to the instruction.
@@ -344,21 +572,39 @@ impl DflowAggregatorBuilder {
             config: self.config.expect("config is not set"),
             access: self.access.expect("access is not set"),
             user: self.user.expect("user is not set"),
-            receiver_output_token_account: self
-                .receiver_output_token_account
-                .expect("receiver_output_token_account is not set"),
+            receiver_output_token_account: self.receiver_output_token_account,
+            native_output_token_account: self.native_output_token_account,
+            fee_token_account: self
+                .fee_token_account
+                .expect("fee_token_account is not set"),
+            fee_vault_authority: self
+                .fee_vault_authority
+                .expect("fee_vault_authority is not set"),
+            partner: self.partner,
+            partner_fee_token_account: self.partner_fee_token_account,
             dflow_program: self.dflow_program.unwrap_or(solana_pubkey::pubkey!(
                 "DF1ow4tspfHX9JwWJsAb9epbkA8hmpSEAtxXy1V27QBH"
             )),
+            instructions_sysvar: self.instructions_sysvar,
+            trade_receipt: self.trade_receipt.expect("trade_receipt is not set"),
+            user_stats: self.user_stats.expect("user_stats is not set"),
+            trade_log: self.trade_log.expect("trade_log is not set"),
+            user_credit: self.user_credit,
+            system_program: self
+                .system_program
+                .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
         let args = DflowAggregatorInstructionArgs {
             data: self.data.clone().expect("data is not set"),
             in_amount: self.in_amount.clone().expect("in_amount is not set"),
-            instruction_name: self
-                .instruction_name
-                .clone()
-                .expect("instruction_name is not set"),
             delegate: self.delegate.clone().expect("delegate is not set"),
+            client_order_id: self
+                .client_order_id
+                .clone()
+                .expect("client_order_id is not set"),
+            intent: self.intent.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -391,9 +637,37 @@ pub struct DflowAggregatorCpiAccounts<'a, 'b> {
 
     pub user: &'b solana_account_info::AccountInfo<'a>,
 
-    pub receiver_output_token_account: &'b solana_account_info::AccountInfo<'a>,
+    pub receiver_output_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub native_output_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_token_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_vault_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub partner_fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
 
     pub dflow_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub instructions_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub trade_receipt: &'b solana_account_info::AccountInfo<'a>,
+
+    pub user_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub trade_log: &'b solana_account_info::AccountInfo<'a>,
+
+    /// Ledger entry of the user for the output mint, required when the output
+    /// is parked in the shared vault
+    pub user_credit: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `dflow_aggregator` CPI instruction.
@@ -425,9 +699,37 @@ pub struct DflowAggregatorCpi<'a, 'b> {
 
     pub user: &'b solana_account_info::AccountInfo<'a>,
 
-    pub receiver_output_token_account: &'b solana_account_info::AccountInfo<'a>,
+    pub receiver_output_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub native_output_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_token_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_vault_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub partner_fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
 
     pub dflow_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub instructions_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub trade_receipt: &'b solana_account_info::AccountInfo<'a>,
+
+    pub user_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub trade_log: &'b solana_account_info::AccountInfo<'a>,
+
+    /// Ledger entry of the user for the output mint, required when the output
+    /// is parked in the shared vault
+    pub user_credit: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: DflowAggregatorInstructionArgs,
 }
@@ -453,7 +755,20 @@ impl<'a, 'b> DflowAggregatorCpi<'a, 'b> {
             access: accounts.access,
             user: accounts.user,
             receiver_output_token_account: accounts.receiver_output_token_account,
+            native_output_token_account: accounts.native_output_token_account,
+            fee_token_account: accounts.fee_token_account,
+            fee_vault_authority: accounts.fee_vault_authority,
+            partner: accounts.partner,
+            partner_fee_token_account: accounts.partner_fee_token_account,
             dflow_program: accounts.dflow_program,
+            instructions_sysvar: accounts.instructions_sysvar,
+            trade_receipt: accounts.trade_receipt,
+            user_stats: accounts.user_stats,
+            trade_log: accounts.trade_log,
+            user_credit: accounts.user_credit,
+            system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -480,7 +795,7 @@ impl<'a, 'b> DflowAggregatorCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(27 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.input_mint.key,
             false,
@@ -522,16 +837,107 @@ impl<'a, 'b> DflowAggregatorCpi<'a, 'b> {
             *self.access.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(*self.user.key, false));
+        if let Some(receiver_output_token_account) = self.receiver_output_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *receiver_output_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        if let Some(native_output_token_account) = self.native_output_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *native_output_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.fee_token_account.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.fee_vault_authority.key,
+            false,
+        ));
+        if let Some(partner) = self.partner {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *partner.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        if let Some(partner_fee_token_account) = self.partner_fee_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *partner_fee_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.user.key,
+            *self.dflow_program.key,
+            false,
+        ));
+        if let Some(instructions_sysvar) = self.instructions_sysvar {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *instructions_sysvar.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.trade_receipt.key,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(
-            *self.receiver_output_token_account.key,
+            *self.user_stats.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.trade_log.key,
+            false,
+        ));
+        if let Some(user_credit) = self.user_credit {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *user_credit.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.dflow_program.key,
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
             false,
         ));
         remaining_accounts.iter().for_each(|remaining_account| {
@@ -550,7 +956,7 @@ impl<'a, 'b> DflowAggregatorCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(15 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(28 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.input_mint.clone());
         account_infos.push(self.input_mint_program.clone());
@@ -564,8 +970,33 @@ impl<'a, 'b> DflowAggregatorCpi<'a, 'b> {
         account_infos.push(self.config.clone());
         account_infos.push(self.access.clone());
         account_infos.push(self.user.clone());
-        account_infos.push(self.receiver_output_token_account.clone());
+        if let Some(receiver_output_token_account) = self.receiver_output_token_account {
+            account_infos.push(receiver_output_token_account.clone());
+        }
+        if let Some(native_output_token_account) = self.native_output_token_account {
+            account_infos.push(native_output_token_account.clone());
+        }
+        account_infos.push(self.fee_token_account.clone());
+        account_infos.push(self.fee_vault_authority.clone());
+        if let Some(partner) = self.partner {
+            account_infos.push(partner.clone());
+        }
+        if let Some(partner_fee_token_account) = self.partner_fee_token_account {
+            account_infos.push(partner_fee_token_account.clone());
+        }
         account_infos.push(self.dflow_program.clone());
+        if let Some(instructions_sysvar) = self.instructions_sysvar {
+            account_infos.push(instructions_sysvar.clone());
+        }
+        account_infos.push(self.trade_receipt.clone());
+        account_infos.push(self.user_stats.clone());
+        account_infos.push(self.trade_log.clone());
+        if let Some(user_credit) = self.user_credit {
+            account_infos.push(user_credit.clone());
+        }
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -593,9 +1024,22 @@ impl<'a, 'b> DflowAggregatorCpi<'a, 'b> {
 ///   8. `[writable]` vault_output_token_account
 ///   9. `[writable]` config
 ///   10. `[]` access
-///   11. `[]` user
-///   12. `[writable]` receiver_output_token_account
-///   13. `[]` dflow_program
+///   11. `[writable]` user
+///   12. `[writable, optional]` receiver_output_token_account
+///   13. `[writable, optional]` native_output_token_account
+///   14. `[writable]` fee_token_account
+///   15. `[]` fee_vault_authority
+///   16. `[optional]` partner
+///   17. `[writable, optional]` partner_fee_token_account
+///   18. `[]` dflow_program
+///   19. `[optional]` instructions_sysvar
+///   20. `[writable]` trade_receipt
+///   21. `[writable]` user_stats
+///   22. `[writable]` trade_log
+///   23. `[writable, optional]` user_credit
+///   24. `[]` system_program
+///   25. `[]` event_authority
+///   26. `[]` program
 #[derive(Clone, Debug)]
 pub struct DflowAggregatorCpiBuilder<'a, 'b> {
     instruction: Box<DflowAggregatorCpiBuilderInstruction<'a, 'b>>,
@@ -618,11 +1062,25 @@ impl<'a, 'b> DflowAggregatorCpiBuilder<'a, 'b> {
             access: None,
             user: None,
             receiver_output_token_account: None,
+            native_output_token_account: None,
+            fee_token_account: None,
+            fee_vault_authority: None,
+            partner: None,
+            partner_fee_token_account: None,
             dflow_program: None,
+            instructions_sysvar: None,
+            trade_receipt: None,
+            user_stats: None,
+            trade_log: None,
+            user_credit: None,
+            system_program: None,
+            event_authority: None,
+            program: None,
             data: None,
             in_amount: None,
-            instruction_name: None,
             delegate: None,
+            client_order_id: None,
+            intent: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -708,12 +1166,56 @@ impl<'a, 'b> DflowAggregatorCpiBuilder<'a, 'b> {
         self.instruction.user = Some(user);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn receiver_output_token_account(
         &mut self,
-        receiver_output_token_account: &'b solana_account_info::AccountInfo<'a>,
+        receiver_output_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.receiver_output_token_account = receiver_output_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn native_output_token_account(
+        &mut self,
+        native_output_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.receiver_output_token_account = Some(receiver_output_token_account);
+        self.instruction.native_output_token_account = native_output_token_account;
+        self
+    }
+    #[inline(always)]
+    pub fn fee_token_account(
+        &mut self,
+        fee_token_account: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_token_account = Some(fee_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_vault_authority(
+        &mut self,
+        fee_vault_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_vault_authority = Some(fee_vault_authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner(
+        &mut self,
+        partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.partner = partner;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner_fee_token_account(
+        &mut self,
+        partner_fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.partner_fee_token_account = partner_fee_token_account;
         self
     }
     #[inline(always)]
@@ -724,6 +1226,68 @@ impl<'a, 'b> DflowAggregatorCpiBuilder<'a, 'b> {
         self.instruction.dflow_program = Some(dflow_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn instructions_sysvar(
+        &mut self,
+        instructions_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.instructions_sysvar = instructions_sysvar;
+        self
+    }
+    #[inline(always)]
+    pub fn trade_receipt(
+        &mut self,
+        trade_receipt: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.trade_receipt = Some(trade_receipt);
+        self
+    }
+    #[inline(always)]
+    pub fn user_stats(
+        &mut self,
+        user_stats: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.user_stats = Some(user_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn trade_log(&mut self, trade_log: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.trade_log = Some(trade_log);
+        self
+    }
+    /// `[optional account]`
+    /// Ledger entry of the user for the output mint, required when the output
+    /// is parked in the shared vault
+    #[inline(always)]
+    pub fn user_credit(
+        &mut self,
+        user_credit: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.user_credit = user_credit;
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn data(&mut self, data: Vec<u8>) -> &mut Self {
         self.instruction.data = Some(data);
@@ -735,13 +1299,19 @@ impl<'a, 'b> DflowAggregatorCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn instruction_name(&mut self, instruction_name: String) -> &mut Self {
-        self.instruction.instruction_name = Some(instruction_name);
+    pub fn delegate(&mut self, delegate: Pubkey) -> &mut Self {
+        self.instruction.delegate = Some(delegate);
         self
     }
     #[inline(always)]
-    pub fn delegate(&mut self, delegate: Pubkey) -> &mut Self {
-        self.instruction.delegate = Some(delegate);
+    pub fn client_order_id(&mut self, client_order_id: u64) -> &mut Self {
+        self.instruction.client_order_id = Some(client_order_id);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn intent(&mut self, intent: TradeIntent) -> &mut Self {
+        self.instruction.intent = Some(intent);
         self
     }
     /// Add an additional account to the instruction.
@@ -785,16 +1355,17 @@ impl<'a, 'b> DflowAggregatorCpiBuilder<'a, 'b> {
                 .in_amount
                 .clone()
                 .expect("in_amount is not set"),
-            instruction_name: self
-                .instruction
-                .instruction_name
-                .clone()
-                .expect("instruction_name is not set"),
             delegate: self
                 .instruction
                 .delegate
                 .clone()
                 .expect("delegate is not set"),
+            client_order_id: self
+                .instruction
+                .client_order_id
+                .clone()
+                .expect("client_order_id is not set"),
+            intent: self.instruction.intent.clone(),
         };
         let instruction = DflowAggregatorCpi {
             __program: self.instruction.__program,
@@ -841,15 +1412,53 @@ impl<'a, 'b> DflowAggregatorCpiBuilder<'a, 'b> {
 
             user: self.instruction.user.expect("user is not set"),
 
-            receiver_output_token_account: self
+            receiver_output_token_account: self.instruction.receiver_output_token_account,
+
+            native_output_token_account: self.instruction.native_output_token_account,
+
+            fee_token_account: self
                 .instruction
-                .receiver_output_token_account
-                .expect("receiver_output_token_account is not set"),
+                .fee_token_account
+                .expect("fee_token_account is not set"),
+
+            fee_vault_authority: self
+                .instruction
+                .fee_vault_authority
+                .expect("fee_vault_authority is not set"),
+
+            partner: self.instruction.partner,
+
+            partner_fee_token_account: self.instruction.partner_fee_token_account,
 
             dflow_program: self
                 .instruction
                 .dflow_program
                 .expect("dflow_program is not set"),
+
+            instructions_sysvar: self.instruction.instructions_sysvar,
+
+            trade_receipt: self
+                .instruction
+                .trade_receipt
+                .expect("trade_receipt is not set"),
+
+            user_stats: self.instruction.user_stats.expect("user_stats is not set"),
+
+            trade_log: self.instruction.trade_log.expect("trade_log is not set"),
+
+            user_credit: self.instruction.user_credit,
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -875,11 +1484,25 @@ struct DflowAggregatorCpiBuilderInstruction<'a, 'b> {
     access: Option<&'b solana_account_info::AccountInfo<'a>>,
     user: Option<&'b solana_account_info::AccountInfo<'a>>,
     receiver_output_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    native_output_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_vault_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+    partner_fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     dflow_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    instructions_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+    trade_receipt: Option<&'b solana_account_info::AccountInfo<'a>>,
+    user_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    trade_log: Option<&'b solana_account_info::AccountInfo<'a>>,
+    user_credit: Option<&'b solana_account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     data: Option<Vec<u8>>,
     in_amount: Option<u64>,
-    instruction_name: Option<String>,
     delegate: Option<Pubkey>,
+    client_order_id: Option<u64>,
+    intent: Option<TradeIntent>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
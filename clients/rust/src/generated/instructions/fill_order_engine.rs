@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::TradeIntent;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -40,7 +41,29 @@ pub struct FillOrderEngine {
 
     pub receiver_output_token_account: solana_pubkey::Pubkey,
 
+    pub fee_token_account: solana_pubkey::Pubkey,
+
+    pub fee_vault_authority: solana_pubkey::Pubkey,
+
+    pub partner: Option<solana_pubkey::Pubkey>,
+
+    pub partner_fee_token_account: Option<solana_pubkey::Pubkey>,
+
     pub jupiter_order_engine_program: solana_pubkey::Pubkey,
+
+    pub instructions_sysvar: Option<solana_pubkey::Pubkey>,
+
+    pub trade_receipt: solana_pubkey::Pubkey,
+
+    pub user_stats: solana_pubkey::Pubkey,
+
+    pub trade_log: solana_pubkey::Pubkey,
+
+    pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl FillOrderEngine {
@@ -57,7 +80,7 @@ impl FillOrderEngine {
         args: FillOrderEngineInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(25 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.input_mint,
             false,
@@ -100,10 +123,68 @@ impl FillOrderEngine {
             self.receiver_output_token_account,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.fee_token_account,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.fee_vault_authority,
+            false,
+        ));
+        if let Some(partner) = self.partner {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                partner, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        if let Some(partner_fee_token_account) = self.partner_fee_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                partner_fee_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.jupiter_order_engine_program,
             false,
         ));
+        if let Some(instructions_sysvar) = self.instructions_sysvar {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                instructions_sysvar,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.trade_receipt,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(self.user_stats, false));
+        accounts.push(solana_instruction::AccountMeta::new(self.trade_log, false));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = FillOrderEngineInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -147,6 +228,8 @@ pub struct FillOrderEngineInstructionArgs {
     pub data: Vec<u8>,
     pub in_amount: u64,
     pub delegate: Pubkey,
+    pub client_order_id: u64,
+    pub intent: Option<TradeIntent>,
 }
 
 impl FillOrderEngineInstructionArgs {
@@ -172,7 +255,18 @@ impl FillOrderEngineInstructionArgs {
 ///   10. `[]` access
 ///   11. `[]` user
 ///   12. `[writable]` receiver_output_token_account
-///   13. `[optional]` jupiter_order_engine_program (default to `61DFfeTKM7trxYcPQCM78bJ794ddZprZpAwAnLiwTpYH`)
+///   13. `[writable]` fee_token_account
+///   14. `[]` fee_vault_authority
+///   15. `[optional]` partner
+///   16. `[writable, optional]` partner_fee_token_account
+///   17. `[optional]` jupiter_order_engine_program (default to `61DFfeTKM7trxYcPQCM78bJ794ddZprZpAwAnLiwTpYH`)
+///   18. `[optional]` instructions_sysvar (default to `Sysvar1nstructions1111111111111111111111111`)
+///   19. `[writable]` trade_receipt
+///   20. `[writable]` user_stats
+///   21. `[writable]` trade_log
+///   22. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   23. `[]` event_authority
+///   24. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct FillOrderEngineBuilder {
     input_mint: Option<solana_pubkey::Pubkey>,
@@ -188,10 +282,23 @@ pub struct FillOrderEngineBuilder {
     access: Option<solana_pubkey::Pubkey>,
     user: Option<solana_pubkey::Pubkey>,
     receiver_output_token_account: Option<solana_pubkey::Pubkey>,
+    fee_token_account: Option<solana_pubkey::Pubkey>,
+    fee_vault_authority: Option<solana_pubkey::Pubkey>,
+    partner: Option<solana_pubkey::Pubkey>,
+    partner_fee_token_account: Option<solana_pubkey::Pubkey>,
     jupiter_order_engine_program: Option<solana_pubkey::Pubkey>,
+    instructions_sysvar: Option<solana_pubkey::Pubkey>,
+    trade_receipt: Option<solana_pubkey::Pubkey>,
+    user_stats: Option<solana_pubkey::Pubkey>,
+    trade_log: Option<solana_pubkey::Pubkey>,
+    system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     data: Option<Vec<u8>>,
     in_amount: Option<u64>,
     delegate: Option<Pubkey>,
+    client_order_id: Option<u64>,
+    intent: Option<TradeIntent>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -276,6 +383,31 @@ impl FillOrderEngineBuilder {
         self.receiver_output_token_account = Some(receiver_output_token_account);
         self
     }
+    #[inline(always)]
+    pub fn fee_token_account(&mut self, fee_token_account: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_token_account = Some(fee_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_vault_authority(&mut self, fee_vault_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_vault_authority = Some(fee_vault_authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner(&mut self, partner: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.partner = partner;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner_fee_token_account(
+        &mut self,
+        partner_fee_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.partner_fee_token_account = partner_fee_token_account;
+        self
+    }
     /// `[optional account, default to '61DFfeTKM7trxYcPQCM78bJ794ddZprZpAwAnLiwTpYH']`
     #[inline(always)]
     pub fn jupiter_order_engine_program(
@@ -285,6 +417,46 @@ impl FillOrderEngineBuilder {
         self.jupiter_order_engine_program = Some(jupiter_order_engine_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn instructions_sysvar(
+        &mut self,
+        instructions_sysvar: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.instructions_sysvar = instructions_sysvar;
+        self
+    }
+    #[inline(always)]
+    pub fn trade_receipt(&mut self, trade_receipt: solana_pubkey::Pubkey) -> &mut Self {
+        self.trade_receipt = Some(trade_receipt);
+        self
+    }
+    #[inline(always)]
+    pub fn user_stats(&mut self, user_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn trade_log(&mut self, trade_log: solana_pubkey::Pubkey) -> &mut Self {
+        self.trade_log = Some(trade_log);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn data(&mut self, data: Vec<u8>) -> &mut Self {
         self.data = Some(data);
@@ -300,6 +472,17 @@ impl FillOrderEngineBuilder {
         self.delegate = Some(delegate);
         self
     }
+    #[inline(always)]
+    pub fn client_order_id(&mut self, client_order_id: u64) -> &mut Self {
+        self.client_order_id = Some(client_order_id);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn intent(&mut self, intent: TradeIntent) -> &mut Self {
+        self.intent = Some(intent);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -343,14 +526,36 @@ impl FillOrderEngineBuilder {
             receiver_output_token_account: self
                 .receiver_output_token_account
                 .expect("receiver_output_token_account is not set"),
+            fee_token_account: self
+                .fee_token_account
+                .expect("fee_token_account is not set"),
+            fee_vault_authority: self
+                .fee_vault_authority
+                .expect("fee_vault_authority is not set"),
+            partner: self.partner,
+            partner_fee_token_account: self.partner_fee_token_account,
             jupiter_order_engine_program: self.jupiter_order_engine_program.unwrap_or(
                 solana_pubkey::pubkey!("61DFfeTKM7trxYcPQCM78bJ794ddZprZpAwAnLiwTpYH"),
             ),
+            instructions_sysvar: self.instructions_sysvar,
+            trade_receipt: self.trade_receipt.expect("trade_receipt is not set"),
+            user_stats: self.user_stats.expect("user_stats is not set"),
+            trade_log: self.trade_log.expect("trade_log is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
         let args = FillOrderEngineInstructionArgs {
             data: self.data.clone().expect("data is not set"),
             in_amount: self.in_amount.clone().expect("in_amount is not set"),
             delegate: self.delegate.clone().expect("delegate is not set"),
+            client_order_id: self
+                .client_order_id
+                .clone()
+                .expect("client_order_id is not set"),
+            intent: self.intent.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -385,7 +590,29 @@ pub struct FillOrderEngineCpiAccounts<'a, 'b> {
 
     pub receiver_output_token_account: &'b solana_account_info::AccountInfo<'a>,
 
+    pub fee_token_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_vault_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub partner_fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub jupiter_order_engine_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub instructions_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub trade_receipt: &'b solana_account_info::AccountInfo<'a>,
+
+    pub user_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub trade_log: &'b solana_account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `fill_order_engine` CPI instruction.
@@ -419,7 +646,29 @@ pub struct FillOrderEngineCpi<'a, 'b> {
 
     pub receiver_output_token_account: &'b solana_account_info::AccountInfo<'a>,
 
+    pub fee_token_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_vault_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub partner_fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub jupiter_order_engine_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub instructions_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub trade_receipt: &'b solana_account_info::AccountInfo<'a>,
+
+    pub user_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub trade_log: &'b solana_account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: FillOrderEngineInstructionArgs,
 }
@@ -445,7 +694,18 @@ impl<'a, 'b> FillOrderEngineCpi<'a, 'b> {
             access: accounts.access,
             user: accounts.user,
             receiver_output_token_account: accounts.receiver_output_token_account,
+            fee_token_account: accounts.fee_token_account,
+            fee_vault_authority: accounts.fee_vault_authority,
+            partner: accounts.partner,
+            partner_fee_token_account: accounts.partner_fee_token_account,
             jupiter_order_engine_program: accounts.jupiter_order_engine_program,
+            instructions_sysvar: accounts.instructions_sysvar,
+            trade_receipt: accounts.trade_receipt,
+            user_stats: accounts.user_stats,
+            trade_log: accounts.trade_log,
+            system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -472,7 +732,7 @@ impl<'a, 'b> FillOrderEngineCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(25 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.input_mint.key,
             false,
@@ -522,10 +782,75 @@ impl<'a, 'b> FillOrderEngineCpi<'a, 'b> {
             *self.receiver_output_token_account.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.fee_token_account.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.fee_vault_authority.key,
+            false,
+        ));
+        if let Some(partner) = self.partner {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *partner.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        if let Some(partner_fee_token_account) = self.partner_fee_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *partner_fee_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.jupiter_order_engine_program.key,
             false,
         ));
+        if let Some(instructions_sysvar) = self.instructions_sysvar {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *instructions_sysvar.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.trade_receipt.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.user_stats.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.trade_log.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -542,7 +867,7 @@ impl<'a, 'b> FillOrderEngineCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(15 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(26 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.input_mint.clone());
         account_infos.push(self.input_mint_program.clone());
@@ -557,7 +882,24 @@ impl<'a, 'b> FillOrderEngineCpi<'a, 'b> {
         account_infos.push(self.access.clone());
         account_infos.push(self.user.clone());
         account_infos.push(self.receiver_output_token_account.clone());
+        account_infos.push(self.fee_token_account.clone());
+        account_infos.push(self.fee_vault_authority.clone());
+        if let Some(partner) = self.partner {
+            account_infos.push(partner.clone());
+        }
+        if let Some(partner_fee_token_account) = self.partner_fee_token_account {
+            account_infos.push(partner_fee_token_account.clone());
+        }
         account_infos.push(self.jupiter_order_engine_program.clone());
+        if let Some(instructions_sysvar) = self.instructions_sysvar {
+            account_infos.push(instructions_sysvar.clone());
+        }
+        account_infos.push(self.trade_receipt.clone());
+        account_infos.push(self.user_stats.clone());
+        account_infos.push(self.trade_log.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -587,7 +929,18 @@ impl<'a, 'b> FillOrderEngineCpi<'a, 'b> {
 ///   10. `[]` access
 ///   11. `[]` user
 ///   12. `[writable]` receiver_output_token_account
-///   13. `[]` jupiter_order_engine_program
+///   13. `[writable]` fee_token_account
+///   14. `[]` fee_vault_authority
+///   15. `[optional]` partner
+///   16. `[writable, optional]` partner_fee_token_account
+///   17. `[]` jupiter_order_engine_program
+///   18. `[optional]` instructions_sysvar
+///   19. `[writable]` trade_receipt
+///   20. `[writable]` user_stats
+///   21. `[writable]` trade_log
+///   22. `[]` system_program
+///   23. `[]` event_authority
+///   24. `[]` program
 #[derive(Clone, Debug)]
 pub struct FillOrderEngineCpiBuilder<'a, 'b> {
     instruction: Box<FillOrderEngineCpiBuilderInstruction<'a, 'b>>,
@@ -610,10 +963,23 @@ impl<'a, 'b> FillOrderEngineCpiBuilder<'a, 'b> {
             access: None,
             user: None,
             receiver_output_token_account: None,
+            fee_token_account: None,
+            fee_vault_authority: None,
+            partner: None,
+            partner_fee_token_account: None,
             jupiter_order_engine_program: None,
+            instructions_sysvar: None,
+            trade_receipt: None,
+            user_stats: None,
+            trade_log: None,
+            system_program: None,
+            event_authority: None,
+            program: None,
             data: None,
             in_amount: None,
             delegate: None,
+            client_order_id: None,
+            intent: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -708,6 +1074,40 @@ impl<'a, 'b> FillOrderEngineCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn fee_token_account(
+        &mut self,
+        fee_token_account: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_token_account = Some(fee_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_vault_authority(
+        &mut self,
+        fee_vault_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_vault_authority = Some(fee_vault_authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner(
+        &mut self,
+        partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.partner = partner;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner_fee_token_account(
+        &mut self,
+        partner_fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.partner_fee_token_account = partner_fee_token_account;
+        self
+    }
+    #[inline(always)]
     pub fn jupiter_order_engine_program(
         &mut self,
         jupiter_order_engine_program: &'b solana_account_info::AccountInfo<'a>,
@@ -715,6 +1115,57 @@ impl<'a, 'b> FillOrderEngineCpiBuilder<'a, 'b> {
         self.instruction.jupiter_order_engine_program = Some(jupiter_order_engine_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn instructions_sysvar(
+        &mut self,
+        instructions_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.instructions_sysvar = instructions_sysvar;
+        self
+    }
+    #[inline(always)]
+    pub fn trade_receipt(
+        &mut self,
+        trade_receipt: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.trade_receipt = Some(trade_receipt);
+        self
+    }
+    #[inline(always)]
+    pub fn user_stats(
+        &mut self,
+        user_stats: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.user_stats = Some(user_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn trade_log(&mut self, trade_log: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.trade_log = Some(trade_log);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn data(&mut self, data: Vec<u8>) -> &mut Self {
         self.instruction.data = Some(data);
@@ -730,6 +1181,17 @@ impl<'a, 'b> FillOrderEngineCpiBuilder<'a, 'b> {
         self.instruction.delegate = Some(delegate);
         self
     }
+    #[inline(always)]
+    pub fn client_order_id(&mut self, client_order_id: u64) -> &mut Self {
+        self.instruction.client_order_id = Some(client_order_id);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn intent(&mut self, intent: TradeIntent) -> &mut Self {
+        self.instruction.intent = Some(intent);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -776,6 +1238,12 @@ impl<'a, 'b> FillOrderEngineCpiBuilder<'a, 'b> {
                 .delegate
                 .clone()
                 .expect("delegate is not set"),
+            client_order_id: self
+                .instruction
+                .client_order_id
+                .clone()
+                .expect("client_order_id is not set"),
+            intent: self.instruction.intent.clone(),
         };
         let instruction = FillOrderEngineCpi {
             __program: self.instruction.__program,
@@ -827,10 +1295,47 @@ impl<'a, 'b> FillOrderEngineCpiBuilder<'a, 'b> {
                 .receiver_output_token_account
                 .expect("receiver_output_token_account is not set"),
 
+            fee_token_account: self
+                .instruction
+                .fee_token_account
+                .expect("fee_token_account is not set"),
+
+            fee_vault_authority: self
+                .instruction
+                .fee_vault_authority
+                .expect("fee_vault_authority is not set"),
+
+            partner: self.instruction.partner,
+
+            partner_fee_token_account: self.instruction.partner_fee_token_account,
+
             jupiter_order_engine_program: self
                 .instruction
                 .jupiter_order_engine_program
                 .expect("jupiter_order_engine_program is not set"),
+
+            instructions_sysvar: self.instruction.instructions_sysvar,
+
+            trade_receipt: self
+                .instruction
+                .trade_receipt
+                .expect("trade_receipt is not set"),
+
+            user_stats: self.instruction.user_stats.expect("user_stats is not set"),
+
+            trade_log: self.instruction.trade_log.expect("trade_log is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -856,10 +1361,23 @@ struct FillOrderEngineCpiBuilderInstruction<'a, 'b> {
     access: Option<&'b solana_account_info::AccountInfo<'a>>,
     user: Option<&'b solana_account_info::AccountInfo<'a>>,
     receiver_output_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_vault_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+    partner_fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     jupiter_order_engine_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    instructions_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+    trade_receipt: Option<&'b solana_account_info::AccountInfo<'a>>,
+    user_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    trade_log: Option<&'b solana_account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     data: Option<Vec<u8>>,
     in_amount: Option<u64>,
     delegate: Option<Pubkey>,
+    client_order_id: Option<u64>,
+    intent: Option<TradeIntent>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
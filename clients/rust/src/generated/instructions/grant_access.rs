@@ -20,9 +20,15 @@ pub struct GrantAccess {
 
     pub access: solana_pubkey::Pubkey,
 
+    pub partner: Option<solana_pubkey::Pubkey>,
+
     pub system_program: solana_pubkey::Pubkey,
 
     pub rent: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl GrantAccess {
@@ -36,13 +42,23 @@ impl GrantAccess {
         args: GrantAccessInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.admin, true));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.config,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(self.access, false));
+        if let Some(partner) = self.partner {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                partner, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -50,6 +66,14 @@ impl GrantAccess {
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.rent, false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = GrantAccessInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -106,15 +130,21 @@ impl GrantAccessInstructionArgs {
 ///   0. `[writable, signer]` admin
 ///   1. `[]` config
 ///   2. `[writable]` access
-///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   4. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+///   3. `[optional]` partner
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+///   6. `[]` event_authority
+///   7. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct GrantAccessBuilder {
     admin: Option<solana_pubkey::Pubkey>,
     config: Option<solana_pubkey::Pubkey>,
     access: Option<solana_pubkey::Pubkey>,
+    partner: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     rent: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     user: Option<Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -138,6 +168,12 @@ impl GrantAccessBuilder {
         self.access = Some(access);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner(&mut self, partner: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.partner = partner;
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -151,6 +187,16 @@ impl GrantAccessBuilder {
         self
     }
     #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn user(&mut self, user: Pubkey) -> &mut Self {
         self.user = Some(user);
         self
@@ -176,12 +222,15 @@ impl GrantAccessBuilder {
             admin: self.admin.expect("admin is not set"),
             config: self.config.expect("config is not set"),
             access: self.access.expect("access is not set"),
+            partner: self.partner,
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
             rent: self.rent.unwrap_or(solana_pubkey::pubkey!(
                 "SysvarRent111111111111111111111111111111111"
             )),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
         let args = GrantAccessInstructionArgs {
             user: self.user.clone().expect("user is not set"),
@@ -199,9 +248,15 @@ pub struct GrantAccessCpiAccounts<'a, 'b> {
 
     pub access: &'b solana_account_info::AccountInfo<'a>,
 
+    pub partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub rent: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `grant_access` CPI instruction.
@@ -215,9 +270,15 @@ pub struct GrantAccessCpi<'a, 'b> {
 
     pub access: &'b solana_account_info::AccountInfo<'a>,
 
+    pub partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub rent: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: GrantAccessInstructionArgs,
 }
@@ -233,8 +294,11 @@ impl<'a, 'b> GrantAccessCpi<'a, 'b> {
             admin: accounts.admin,
             config: accounts.config,
             access: accounts.access,
+            partner: accounts.partner,
             system_program: accounts.system_program,
             rent: accounts.rent,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -261,7 +325,7 @@ impl<'a, 'b> GrantAccessCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.admin.key, true));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.config.key,
@@ -271,6 +335,17 @@ impl<'a, 'b> GrantAccessCpi<'a, 'b> {
             *self.access.key,
             false,
         ));
+        if let Some(partner) = self.partner {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *partner.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -279,6 +354,14 @@ impl<'a, 'b> GrantAccessCpi<'a, 'b> {
             *self.rent.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -295,13 +378,18 @@ impl<'a, 'b> GrantAccessCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.admin.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.access.clone());
+        if let Some(partner) = self.partner {
+            account_infos.push(partner.clone());
+        }
         account_infos.push(self.system_program.clone());
         account_infos.push(self.rent.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -321,8 +409,11 @@ impl<'a, 'b> GrantAccessCpi<'a, 'b> {
 ///   0. `[writable, signer]` admin
 ///   1. `[]` config
 ///   2. `[writable]` access
-///   3. `[]` system_program
-///   4. `[]` rent
+///   3. `[optional]` partner
+///   4. `[]` system_program
+///   5. `[]` rent
+///   6. `[]` event_authority
+///   7. `[]` program
 #[derive(Clone, Debug)]
 pub struct GrantAccessCpiBuilder<'a, 'b> {
     instruction: Box<GrantAccessCpiBuilderInstruction<'a, 'b>>,
@@ -335,8 +426,11 @@ impl<'a, 'b> GrantAccessCpiBuilder<'a, 'b> {
             admin: None,
             config: None,
             access: None,
+            partner: None,
             system_program: None,
             rent: None,
+            event_authority: None,
+            program: None,
             user: None,
             __remaining_accounts: Vec::new(),
         });
@@ -357,6 +451,15 @@ impl<'a, 'b> GrantAccessCpiBuilder<'a, 'b> {
         self.instruction.access = Some(access);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner(
+        &mut self,
+        partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.partner = partner;
+        self
+    }
     #[inline(always)]
     pub fn system_program(
         &mut self,
@@ -371,6 +474,19 @@ impl<'a, 'b> GrantAccessCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn user(&mut self, user: Pubkey) -> &mut Self {
         self.instruction.user = Some(user);
         self
@@ -421,12 +537,21 @@ impl<'a, 'b> GrantAccessCpiBuilder<'a, 'b> {
 
             access: self.instruction.access.expect("access is not set"),
 
+            partner: self.instruction.partner,
+
             system_program: self
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
 
             rent: self.instruction.rent.expect("rent is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -442,8 +567,11 @@ struct GrantAccessCpiBuilderInstruction<'a, 'b> {
     admin: Option<&'b solana_account_info::AccountInfo<'a>>,
     config: Option<&'b solana_account_info::AccountInfo<'a>>,
     access: Option<&'b solana_account_info::AccountInfo<'a>>,
+    partner: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     rent: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     user: Option<Pubkey>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
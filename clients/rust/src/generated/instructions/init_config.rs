@@ -23,6 +23,10 @@ pub struct InitConfig {
     pub system_program: solana_pubkey::Pubkey,
 
     pub rent: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl InitConfig {
@@ -36,7 +40,7 @@ impl InitConfig {
         args: InitConfigInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.admin, true));
         accounts.push(solana_instruction::AccountMeta::new(self.config, false));
         accounts.push(solana_instruction::AccountMeta::new(self.vault, false));
@@ -47,6 +51,14 @@ impl InitConfig {
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.rent, false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = InitConfigInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -87,6 +99,7 @@ impl Default for InitConfigInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitConfigInstructionArgs {
+    pub namespace: u16,
     pub operator: Pubkey,
     pub cooldown_duration: i64,
 }
@@ -106,6 +119,8 @@ impl InitConfigInstructionArgs {
 ///   2. `[writable]` vault
 ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   4. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+///   5. `[]` event_authority
+///   6. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct InitConfigBuilder {
     admin: Option<solana_pubkey::Pubkey>,
@@ -113,6 +128,9 @@ pub struct InitConfigBuilder {
     vault: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     rent: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
+    namespace: Option<u16>,
     operator: Option<Pubkey>,
     cooldown_duration: Option<i64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -150,6 +168,21 @@ impl InitConfigBuilder {
         self
     }
     #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
+    pub fn namespace(&mut self, namespace: u16) -> &mut Self {
+        self.namespace = Some(namespace);
+        self
+    }
+    #[inline(always)]
     pub fn operator(&mut self, operator: Pubkey) -> &mut Self {
         self.operator = Some(operator);
         self
@@ -186,8 +219,11 @@ impl InitConfigBuilder {
             rent: self.rent.unwrap_or(solana_pubkey::pubkey!(
                 "SysvarRent111111111111111111111111111111111"
             )),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
         let args = InitConfigInstructionArgs {
+            namespace: self.namespace.clone().expect("namespace is not set"),
             operator: self.operator.clone().expect("operator is not set"),
             cooldown_duration: self
                 .cooldown_duration
@@ -210,6 +246,10 @@ pub struct InitConfigCpiAccounts<'a, 'b> {
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub rent: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `init_config` CPI instruction.
@@ -226,6 +266,10 @@ pub struct InitConfigCpi<'a, 'b> {
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub rent: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: InitConfigInstructionArgs,
 }
@@ -243,6 +287,8 @@ impl<'a, 'b> InitConfigCpi<'a, 'b> {
             vault: accounts.vault,
             system_program: accounts.system_program,
             rent: accounts.rent,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -269,7 +315,7 @@ impl<'a, 'b> InitConfigCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.admin.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.config.key,
@@ -284,6 +330,14 @@ impl<'a, 'b> InitConfigCpi<'a, 'b> {
             *self.rent.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -300,13 +354,15 @@ impl<'a, 'b> InitConfigCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.admin.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.rent.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -328,6 +384,8 @@ impl<'a, 'b> InitConfigCpi<'a, 'b> {
 ///   2. `[writable]` vault
 ///   3. `[]` system_program
 ///   4. `[]` rent
+///   5. `[]` event_authority
+///   6. `[]` program
 #[derive(Clone, Debug)]
 pub struct InitConfigCpiBuilder<'a, 'b> {
     instruction: Box<InitConfigCpiBuilderInstruction<'a, 'b>>,
@@ -342,6 +400,9 @@ impl<'a, 'b> InitConfigCpiBuilder<'a, 'b> {
             vault: None,
             system_program: None,
             rent: None,
+            event_authority: None,
+            program: None,
+            namespace: None,
             operator: None,
             cooldown_duration: None,
             __remaining_accounts: Vec::new(),
@@ -377,6 +438,24 @@ impl<'a, 'b> InitConfigCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
+    pub fn namespace(&mut self, namespace: u16) -> &mut Self {
+        self.instruction.namespace = Some(namespace);
+        self
+    }
+    #[inline(always)]
     pub fn operator(&mut self, operator: Pubkey) -> &mut Self {
         self.instruction.operator = Some(operator);
         self
@@ -421,6 +500,11 @@ impl<'a, 'b> InitConfigCpiBuilder<'a, 'b> {
     #[allow(clippy::vec_init_then_push)]
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
         let args = InitConfigInstructionArgs {
+            namespace: self
+                .instruction
+                .namespace
+                .clone()
+                .expect("namespace is not set"),
             operator: self
                 .instruction
                 .operator
@@ -447,6 +531,13 @@ impl<'a, 'b> InitConfigCpiBuilder<'a, 'b> {
                 .expect("system_program is not set"),
 
             rent: self.instruction.rent.expect("rent is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -464,6 +555,9 @@ struct InitConfigCpiBuilderInstruction<'a, 'b> {
     vault: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     rent: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    namespace: Option<u16>,
     operator: Option<Pubkey>,
     cooldown_duration: Option<i64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::TradeIntent;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -40,7 +41,29 @@ pub struct JupiterPerpetuals {
 
     pub receiver_output_token_account: solana_pubkey::Pubkey,
 
+    pub fee_token_account: solana_pubkey::Pubkey,
+
+    pub fee_vault_authority: solana_pubkey::Pubkey,
+
+    pub partner: Option<solana_pubkey::Pubkey>,
+
+    pub partner_fee_token_account: Option<solana_pubkey::Pubkey>,
+
     pub perpetuals_program: solana_pubkey::Pubkey,
+
+    pub instructions_sysvar: Option<solana_pubkey::Pubkey>,
+
+    pub trade_receipt: solana_pubkey::Pubkey,
+
+    pub user_stats: solana_pubkey::Pubkey,
+
+    pub trade_log: solana_pubkey::Pubkey,
+
+    pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl JupiterPerpetuals {
@@ -57,7 +80,7 @@ impl JupiterPerpetuals {
         args: JupiterPerpetualsInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(25 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.input_mint,
             false,
@@ -100,10 +123,68 @@ impl JupiterPerpetuals {
             self.receiver_output_token_account,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.fee_token_account,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.fee_vault_authority,
+            false,
+        ));
+        if let Some(partner) = self.partner {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                partner, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        if let Some(partner_fee_token_account) = self.partner_fee_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                partner_fee_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.perpetuals_program,
             false,
         ));
+        if let Some(instructions_sysvar) = self.instructions_sysvar {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                instructions_sysvar,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.trade_receipt,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(self.user_stats, false));
+        accounts.push(solana_instruction::AccountMeta::new(self.trade_log, false));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = JupiterPerpetualsInstructionData::new()
             .try_to_vec()
@@ -148,6 +229,8 @@ impl Default for JupiterPerpetualsInstructionData {
 pub struct JupiterPerpetualsInstructionArgs {
     pub delegate: Pubkey,
     pub data: Vec<u8>,
+    pub client_order_id: u64,
+    pub intent: Option<TradeIntent>,
 }
 
 impl JupiterPerpetualsInstructionArgs {
@@ -173,7 +256,18 @@ impl JupiterPerpetualsInstructionArgs {
 ///   10. `[]` access
 ///   11. `[]` user
 ///   12. `[writable]` receiver_output_token_account
-///   13. `[optional]` perpetuals_program (default to `PERPHjGBqRHArX4DySjwM6UJHiR3sWAatqfdBS2qQJu`)
+///   13. `[writable]` fee_token_account
+///   14. `[]` fee_vault_authority
+///   15. `[optional]` partner
+///   16. `[writable, optional]` partner_fee_token_account
+///   17. `[optional]` perpetuals_program (default to `PERPHjGBqRHArX4DySjwM6UJHiR3sWAatqfdBS2qQJu`)
+///   18. `[optional]` instructions_sysvar (default to `Sysvar1nstructions1111111111111111111111111`)
+///   19. `[writable]` trade_receipt
+///   20. `[writable]` user_stats
+///   21. `[writable]` trade_log
+///   22. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   23. `[]` event_authority
+///   24. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct JupiterPerpetualsBuilder {
     input_mint: Option<solana_pubkey::Pubkey>,
@@ -189,9 +283,22 @@ pub struct JupiterPerpetualsBuilder {
     access: Option<solana_pubkey::Pubkey>,
     user: Option<solana_pubkey::Pubkey>,
     receiver_output_token_account: Option<solana_pubkey::Pubkey>,
+    fee_token_account: Option<solana_pubkey::Pubkey>,
+    fee_vault_authority: Option<solana_pubkey::Pubkey>,
+    partner: Option<solana_pubkey::Pubkey>,
+    partner_fee_token_account: Option<solana_pubkey::Pubkey>,
     perpetuals_program: Option<solana_pubkey::Pubkey>,
+    instructions_sysvar: Option<solana_pubkey::Pubkey>,
+    trade_receipt: Option<solana_pubkey::Pubkey>,
+    user_stats: Option<solana_pubkey::Pubkey>,
+    trade_log: Option<solana_pubkey::Pubkey>,
+    system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     delegate: Option<Pubkey>,
     data: Option<Vec<u8>>,
+    client_order_id: Option<u64>,
+    intent: Option<TradeIntent>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -276,12 +383,77 @@ impl JupiterPerpetualsBuilder {
         self.receiver_output_token_account = Some(receiver_output_token_account);
         self
     }
+    #[inline(always)]
+    pub fn fee_token_account(&mut self, fee_token_account: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_token_account = Some(fee_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_vault_authority(&mut self, fee_vault_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_vault_authority = Some(fee_vault_authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner(&mut self, partner: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.partner = partner;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner_fee_token_account(
+        &mut self,
+        partner_fee_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.partner_fee_token_account = partner_fee_token_account;
+        self
+    }
     /// `[optional account, default to 'PERPHjGBqRHArX4DySjwM6UJHiR3sWAatqfdBS2qQJu']`
     #[inline(always)]
     pub fn perpetuals_program(&mut self, perpetuals_program: solana_pubkey::Pubkey) -> &mut Self {
         self.perpetuals_program = Some(perpetuals_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn instructions_sysvar(
+        &mut self,
+        instructions_sysvar: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.instructions_sysvar = instructions_sysvar;
+        self
+    }
+    #[inline(always)]
+    pub fn trade_receipt(&mut self, trade_receipt: solana_pubkey::Pubkey) -> &mut Self {
+        self.trade_receipt = Some(trade_receipt);
+        self
+    }
+    #[inline(always)]
+    pub fn user_stats(&mut self, user_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn trade_log(&mut self, trade_log: solana_pubkey::Pubkey) -> &mut Self {
+        self.trade_log = Some(trade_log);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn delegate(&mut self, delegate: Pubkey) -> &mut Self {
         self.delegate = Some(delegate);
@@ -292,6 +464,17 @@ impl JupiterPerpetualsBuilder {
         self.data = Some(data);
         self
     }
+    #[inline(always)]
+    pub fn client_order_id(&mut self, client_order_id: u64) -> &mut Self {
+        self.client_order_id = Some(client_order_id);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn intent(&mut self, intent: TradeIntent) -> &mut Self {
+        self.intent = Some(intent);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -335,13 +518,35 @@ impl JupiterPerpetualsBuilder {
             receiver_output_token_account: self
                 .receiver_output_token_account
                 .expect("receiver_output_token_account is not set"),
+            fee_token_account: self
+                .fee_token_account
+                .expect("fee_token_account is not set"),
+            fee_vault_authority: self
+                .fee_vault_authority
+                .expect("fee_vault_authority is not set"),
+            partner: self.partner,
+            partner_fee_token_account: self.partner_fee_token_account,
             perpetuals_program: self.perpetuals_program.unwrap_or(solana_pubkey::pubkey!(
                 "PERPHjGBqRHArX4DySjwM6UJHiR3sWAatqfdBS2qQJu"
             )),
+            instructions_sysvar: self.instructions_sysvar,
+            trade_receipt: self.trade_receipt.expect("trade_receipt is not set"),
+            user_stats: self.user_stats.expect("user_stats is not set"),
+            trade_log: self.trade_log.expect("trade_log is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
         let args = JupiterPerpetualsInstructionArgs {
             delegate: self.delegate.clone().expect("delegate is not set"),
             data: self.data.clone().expect("data is not set"),
+            client_order_id: self
+                .client_order_id
+                .clone()
+                .expect("client_order_id is not set"),
+            intent: self.intent.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -376,7 +581,29 @@ pub struct JupiterPerpetualsCpiAccounts<'a, 'b> {
 
     pub receiver_output_token_account: &'b solana_account_info::AccountInfo<'a>,
 
+    pub fee_token_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_vault_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub partner_fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub perpetuals_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub instructions_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub trade_receipt: &'b solana_account_info::AccountInfo<'a>,
+
+    pub user_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub trade_log: &'b solana_account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `jupiter_perpetuals` CPI instruction.
@@ -410,7 +637,29 @@ pub struct JupiterPerpetualsCpi<'a, 'b> {
 
     pub receiver_output_token_account: &'b solana_account_info::AccountInfo<'a>,
 
+    pub fee_token_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_vault_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub partner_fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub perpetuals_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub instructions_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub trade_receipt: &'b solana_account_info::AccountInfo<'a>,
+
+    pub user_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub trade_log: &'b solana_account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: JupiterPerpetualsInstructionArgs,
 }
@@ -436,7 +685,18 @@ impl<'a, 'b> JupiterPerpetualsCpi<'a, 'b> {
             access: accounts.access,
             user: accounts.user,
             receiver_output_token_account: accounts.receiver_output_token_account,
+            fee_token_account: accounts.fee_token_account,
+            fee_vault_authority: accounts.fee_vault_authority,
+            partner: accounts.partner,
+            partner_fee_token_account: accounts.partner_fee_token_account,
             perpetuals_program: accounts.perpetuals_program,
+            instructions_sysvar: accounts.instructions_sysvar,
+            trade_receipt: accounts.trade_receipt,
+            user_stats: accounts.user_stats,
+            trade_log: accounts.trade_log,
+            system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -463,7 +723,7 @@ impl<'a, 'b> JupiterPerpetualsCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(25 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.input_mint.key,
             false,
@@ -513,10 +773,75 @@ impl<'a, 'b> JupiterPerpetualsCpi<'a, 'b> {
             *self.receiver_output_token_account.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.fee_token_account.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.fee_vault_authority.key,
+            false,
+        ));
+        if let Some(partner) = self.partner {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *partner.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        if let Some(partner_fee_token_account) = self.partner_fee_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *partner_fee_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.perpetuals_program.key,
             false,
         ));
+        if let Some(instructions_sysvar) = self.instructions_sysvar {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *instructions_sysvar.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.trade_receipt.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.user_stats.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.trade_log.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -535,7 +860,7 @@ impl<'a, 'b> JupiterPerpetualsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(15 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(26 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.input_mint.clone());
         account_infos.push(self.input_mint_program.clone());
@@ -550,7 +875,24 @@ impl<'a, 'b> JupiterPerpetualsCpi<'a, 'b> {
         account_infos.push(self.access.clone());
         account_infos.push(self.user.clone());
         account_infos.push(self.receiver_output_token_account.clone());
+        account_infos.push(self.fee_token_account.clone());
+        account_infos.push(self.fee_vault_authority.clone());
+        if let Some(partner) = self.partner {
+            account_infos.push(partner.clone());
+        }
+        if let Some(partner_fee_token_account) = self.partner_fee_token_account {
+            account_infos.push(partner_fee_token_account.clone());
+        }
         account_infos.push(self.perpetuals_program.clone());
+        if let Some(instructions_sysvar) = self.instructions_sysvar {
+            account_infos.push(instructions_sysvar.clone());
+        }
+        account_infos.push(self.trade_receipt.clone());
+        account_infos.push(self.user_stats.clone());
+        account_infos.push(self.trade_log.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -580,7 +922,18 @@ impl<'a, 'b> JupiterPerpetualsCpi<'a, 'b> {
 ///   10. `[]` access
 ///   11. `[]` user
 ///   12. `[writable]` receiver_output_token_account
-///   13. `[]` perpetuals_program
+///   13. `[writable]` fee_token_account
+///   14. `[]` fee_vault_authority
+///   15. `[optional]` partner
+///   16. `[writable, optional]` partner_fee_token_account
+///   17. `[]` perpetuals_program
+///   18. `[optional]` instructions_sysvar
+///   19. `[writable]` trade_receipt
+///   20. `[writable]` user_stats
+///   21. `[writable]` trade_log
+///   22. `[]` system_program
+///   23. `[]` event_authority
+///   24. `[]` program
 #[derive(Clone, Debug)]
 pub struct JupiterPerpetualsCpiBuilder<'a, 'b> {
     instruction: Box<JupiterPerpetualsCpiBuilderInstruction<'a, 'b>>,
@@ -603,9 +956,22 @@ impl<'a, 'b> JupiterPerpetualsCpiBuilder<'a, 'b> {
             access: None,
             user: None,
             receiver_output_token_account: None,
+            fee_token_account: None,
+            fee_vault_authority: None,
+            partner: None,
+            partner_fee_token_account: None,
             perpetuals_program: None,
+            instructions_sysvar: None,
+            trade_receipt: None,
+            user_stats: None,
+            trade_log: None,
+            system_program: None,
+            event_authority: None,
+            program: None,
             delegate: None,
             data: None,
+            client_order_id: None,
+            intent: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -700,6 +1066,40 @@ impl<'a, 'b> JupiterPerpetualsCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn fee_token_account(
+        &mut self,
+        fee_token_account: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_token_account = Some(fee_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_vault_authority(
+        &mut self,
+        fee_vault_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_vault_authority = Some(fee_vault_authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner(
+        &mut self,
+        partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.partner = partner;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn partner_fee_token_account(
+        &mut self,
+        partner_fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.partner_fee_token_account = partner_fee_token_account;
+        self
+    }
+    #[inline(always)]
     pub fn perpetuals_program(
         &mut self,
         perpetuals_program: &'b solana_account_info::AccountInfo<'a>,
@@ -707,6 +1107,57 @@ impl<'a, 'b> JupiterPerpetualsCpiBuilder<'a, 'b> {
         self.instruction.perpetuals_program = Some(perpetuals_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn instructions_sysvar(
+        &mut self,
+        instructions_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.instructions_sysvar = instructions_sysvar;
+        self
+    }
+    #[inline(always)]
+    pub fn trade_receipt(
+        &mut self,
+        trade_receipt: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.trade_receipt = Some(trade_receipt);
+        self
+    }
+    #[inline(always)]
+    pub fn user_stats(
+        &mut self,
+        user_stats: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.user_stats = Some(user_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn trade_log(&mut self, trade_log: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.trade_log = Some(trade_log);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn delegate(&mut self, delegate: Pubkey) -> &mut Self {
         self.instruction.delegate = Some(delegate);
@@ -717,6 +1168,17 @@ impl<'a, 'b> JupiterPerpetualsCpiBuilder<'a, 'b> {
         self.instruction.data = Some(data);
         self
     }
+    #[inline(always)]
+    pub fn client_order_id(&mut self, client_order_id: u64) -> &mut Self {
+        self.instruction.client_order_id = Some(client_order_id);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn intent(&mut self, intent: TradeIntent) -> &mut Self {
+        self.instruction.intent = Some(intent);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -758,6 +1220,12 @@ impl<'a, 'b> JupiterPerpetualsCpiBuilder<'a, 'b> {
                 .clone()
                 .expect("delegate is not set"),
             data: self.instruction.data.clone().expect("data is not set"),
+            client_order_id: self
+                .instruction
+                .client_order_id
+                .clone()
+                .expect("client_order_id is not set"),
+            intent: self.instruction.intent.clone(),
         };
         let instruction = JupiterPerpetualsCpi {
             __program: self.instruction.__program,
@@ -809,10 +1277,47 @@ impl<'a, 'b> JupiterPerpetualsCpiBuilder<'a, 'b> {
                 .receiver_output_token_account
                 .expect("receiver_output_token_account is not set"),
 
+            fee_token_account: self
+                .instruction
+                .fee_token_account
+                .expect("fee_token_account is not set"),
+
+            fee_vault_authority: self
+                .instruction
+                .fee_vault_authority
+                .expect("fee_vault_authority is not set"),
+
+            partner: self.instruction.partner,
+
+            partner_fee_token_account: self.instruction.partner_fee_token_account,
+
             perpetuals_program: self
                 .instruction
                 .perpetuals_program
                 .expect("perpetuals_program is not set"),
+
+            instructions_sysvar: self.instruction.instructions_sysvar,
+
+            trade_receipt: self
+                .instruction
+                .trade_receipt
+                .expect("trade_receipt is not set"),
+
+            user_stats: self.instruction.user_stats.expect("user_stats is not set"),
+
+            trade_log: self.instruction.trade_log.expect("trade_log is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -838,9 +1343,22 @@ struct JupiterPerpetualsCpiBuilderInstruction<'a, 'b> {
     access: Option<&'b solana_account_info::AccountInfo<'a>>,
     user: Option<&'b solana_account_info::AccountInfo<'a>>,
     receiver_output_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_vault_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    partner: Option<&'b solana_account_info::AccountInfo<'a>>,
+    partner_fee_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     perpetuals_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    instructions_sysvar: Option<&'b solana_account_info::AccountInfo<'a>>,
+    trade_receipt: Option<&'b solana_account_info::AccountInfo<'a>>,
+    user_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    trade_log: Option<&'b solana_account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     delegate: Option<Pubkey>,
     data: Option<Vec<u8>>,
+    client_order_id: Option<u64>,
+    intent: Option<TradeIntent>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -6,37 +6,95 @@
 //!
 
 pub(crate) mod r#accept_admin_transfer;
+pub(crate) mod r#add_destination;
+pub(crate) mod r#batch_trade;
+pub(crate) mod r#cancel_dca_plan;
+pub(crate) mod r#cancel_limit_order;
+pub(crate) mod r#claim_partner_fee;
+pub(crate) mod r#close_trade_receipt;
+pub(crate) mod r#close_user_stats;
+pub(crate) mod r#create_dca_plan;
+pub(crate) mod r#create_limit_order;
+pub(crate) mod r#create_partner;
 pub(crate) mod r#dflow_aggregator;
+pub(crate) mod r#dflow_close_order;
+pub(crate) mod r#dflow_open_order;
+pub(crate) mod r#dflow_settle_order;
+pub(crate) mod r#execute_dca_slice;
+pub(crate) mod r#expire_limit_order;
+pub(crate) mod r#fill_limit_order;
 pub(crate) mod r#fill_order_engine;
 pub(crate) mod r#grant_access;
 pub(crate) mod r#init_config;
+pub(crate) mod r#init_partner_fee_account;
+pub(crate) mod r#init_trade_log;
 pub(crate) mod r#jupiter_aggregator;
 pub(crate) mod r#jupiter_perpetuals;
+pub(crate) mod r#migrate_access;
+pub(crate) mod r#migrate_config;
+pub(crate) mod r#migrate_user_vault;
+pub(crate) mod r#modify_access_intent;
 pub(crate) mod r#modify_cooldown_duration;
+pub(crate) mod r#modify_max_commission;
+pub(crate) mod r#modify_partner_share;
+pub(crate) mod r#modify_protocol_fee;
+pub(crate) mod r#modify_vault_mode;
 pub(crate) mod r#okx_aggregator;
 pub(crate) mod r#pause;
 pub(crate) mod r#propose_new_admin;
+pub(crate) mod r#remove_destination;
+pub(crate) mod r#resize_trade_log;
 pub(crate) mod r#revoke_access;
 pub(crate) mod r#swap;
 pub(crate) mod r#token_receive;
 pub(crate) mod r#transfer_in;
 pub(crate) mod r#transfer_out;
 pub(crate) mod r#two_hop;
+pub(crate) mod r#withdraw_protocol_fee;
 
 pub use self::r#accept_admin_transfer::*;
+pub use self::r#add_destination::*;
+pub use self::r#batch_trade::*;
+pub use self::r#cancel_dca_plan::*;
+pub use self::r#cancel_limit_order::*;
+pub use self::r#claim_partner_fee::*;
+pub use self::r#close_trade_receipt::*;
+pub use self::r#close_user_stats::*;
+pub use self::r#create_dca_plan::*;
+pub use self::r#create_limit_order::*;
+pub use self::r#create_partner::*;
 pub use self::r#dflow_aggregator::*;
+pub use self::r#dflow_close_order::*;
+pub use self::r#dflow_open_order::*;
+pub use self::r#dflow_settle_order::*;
+pub use self::r#execute_dca_slice::*;
+pub use self::r#expire_limit_order::*;
+pub use self::r#fill_limit_order::*;
 pub use self::r#fill_order_engine::*;
 pub use self::r#grant_access::*;
 pub use self::r#init_config::*;
+pub use self::r#init_partner_fee_account::*;
+pub use self::r#init_trade_log::*;
 pub use self::r#jupiter_aggregator::*;
 pub use self::r#jupiter_perpetuals::*;
+pub use self::r#migrate_access::*;
+pub use self::r#migrate_config::*;
+pub use self::r#migrate_user_vault::*;
+pub use self::r#modify_access_intent::*;
 pub use self::r#modify_cooldown_duration::*;
+pub use self::r#modify_max_commission::*;
+pub use self::r#modify_partner_share::*;
+pub use self::r#modify_protocol_fee::*;
+pub use self::r#modify_vault_mode::*;
 pub use self::r#okx_aggregator::*;
 pub use self::r#pause::*;
 pub use self::r#propose_new_admin::*;
+pub use self::r#remove_destination::*;
+pub use self::r#resize_trade_log::*;
 pub use self::r#revoke_access::*;
 pub use self::r#swap::*;
 pub use self::r#token_receive::*;
 pub use self::r#transfer_in::*;
 pub use self::r#transfer_out::*;
 pub use self::r#two_hop::*;
+pub use self::r#withdraw_protocol_fee::*;
@@ -18,6 +18,10 @@ pub struct ModifyCooldownDuration {
     pub config: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl ModifyCooldownDuration {
@@ -34,13 +38,21 @@ impl ModifyCooldownDuration {
         args: ModifyCooldownDurationInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.admin, true));
         accounts.push(solana_instruction::AccountMeta::new(self.config, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = ModifyCooldownDurationInstructionData::new()
             .try_to_vec()
@@ -99,11 +111,15 @@ impl ModifyCooldownDurationInstructionArgs {
 ///   0. `[writable, signer]` admin
 ///   1. `[writable]` config
 ///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   3. `[]` event_authority
+///   4. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct ModifyCooldownDurationBuilder {
     admin: Option<solana_pubkey::Pubkey>,
     config: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     cooldown_duration: Option<i64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -129,6 +145,16 @@ impl ModifyCooldownDurationBuilder {
         self
     }
     #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn cooldown_duration(&mut self, cooldown_duration: i64) -> &mut Self {
         self.cooldown_duration = Some(cooldown_duration);
         self
@@ -156,6 +182,8 @@ impl ModifyCooldownDurationBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
         let args = ModifyCooldownDurationInstructionArgs {
             cooldown_duration: self
@@ -175,6 +203,10 @@ pub struct ModifyCooldownDurationCpiAccounts<'a, 'b> {
     pub config: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `modify_cooldown_duration` CPI instruction.
@@ -187,6 +219,10 @@ pub struct ModifyCooldownDurationCpi<'a, 'b> {
     pub config: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: ModifyCooldownDurationInstructionArgs,
 }
@@ -202,6 +238,8 @@ impl<'a, 'b> ModifyCooldownDurationCpi<'a, 'b> {
             admin: accounts.admin,
             config: accounts.config,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -228,7 +266,7 @@ impl<'a, 'b> ModifyCooldownDurationCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.admin.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.config.key,
@@ -238,6 +276,14 @@ impl<'a, 'b> ModifyCooldownDurationCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -256,11 +302,13 @@ impl<'a, 'b> ModifyCooldownDurationCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.admin.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -280,6 +328,8 @@ impl<'a, 'b> ModifyCooldownDurationCpi<'a, 'b> {
 ///   0. `[writable, signer]` admin
 ///   1. `[writable]` config
 ///   2. `[]` system_program
+///   3. `[]` event_authority
+///   4. `[]` program
 #[derive(Clone, Debug)]
 pub struct ModifyCooldownDurationCpiBuilder<'a, 'b> {
     instruction: Box<ModifyCooldownDurationCpiBuilderInstruction<'a, 'b>>,
@@ -292,6 +342,8 @@ impl<'a, 'b> ModifyCooldownDurationCpiBuilder<'a, 'b> {
             admin: None,
             config: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             cooldown_duration: None,
             __remaining_accounts: Vec::new(),
         });
@@ -316,6 +368,19 @@ impl<'a, 'b> ModifyCooldownDurationCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn cooldown_duration(&mut self, cooldown_duration: i64) -> &mut Self {
         self.instruction.cooldown_duration = Some(cooldown_duration);
         self
@@ -372,6 +437,13 @@ impl<'a, 'b> ModifyCooldownDurationCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -387,6 +459,8 @@ struct ModifyCooldownDurationCpiBuilderInstruction<'a, 'b> {
     admin: Option<&'b solana_account_info::AccountInfo<'a>>,
     config: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     cooldown_duration: Option<i64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -20,6 +20,10 @@ pub struct ProposeNewAdmin {
     pub config: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl ProposeNewAdmin {
@@ -32,7 +36,7 @@ impl ProposeNewAdmin {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             self.current_admin,
             true,
@@ -46,6 +50,14 @@ impl ProposeNewAdmin {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = ProposeNewAdminInstructionData::new().try_to_vec().unwrap();
 
@@ -89,12 +101,16 @@ impl Default for ProposeNewAdminInstructionData {
 ///   1. `[]` proposed_admin
 ///   2. `[writable]` config
 ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[]` event_authority
+///   5. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct ProposeNewAdminBuilder {
     current_admin: Option<solana_pubkey::Pubkey>,
     proposed_admin: Option<solana_pubkey::Pubkey>,
     config: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -123,6 +139,16 @@ impl ProposeNewAdminBuilder {
         self.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -147,6 +173,8 @@ impl ProposeNewAdminBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -162,6 +190,10 @@ pub struct ProposeNewAdminCpiAccounts<'a, 'b> {
     pub config: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `propose_new_admin` CPI instruction.
@@ -176,6 +208,10 @@ pub struct ProposeNewAdminCpi<'a, 'b> {
     pub config: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> ProposeNewAdminCpi<'a, 'b> {
@@ -189,6 +225,8 @@ impl<'a, 'b> ProposeNewAdminCpi<'a, 'b> {
             proposed_admin: accounts.proposed_admin,
             config: accounts.config,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
         }
     }
     #[inline(always)]
@@ -214,7 +252,7 @@ impl<'a, 'b> ProposeNewAdminCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.current_admin.key,
             true,
@@ -231,6 +269,14 @@ impl<'a, 'b> ProposeNewAdminCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -245,12 +291,14 @@ impl<'a, 'b> ProposeNewAdminCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.current_admin.clone());
         account_infos.push(self.proposed_admin.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -271,6 +319,8 @@ impl<'a, 'b> ProposeNewAdminCpi<'a, 'b> {
 ///   1. `[]` proposed_admin
 ///   2. `[writable]` config
 ///   3. `[]` system_program
+///   4. `[]` event_authority
+///   5. `[]` program
 #[derive(Clone, Debug)]
 pub struct ProposeNewAdminCpiBuilder<'a, 'b> {
     instruction: Box<ProposeNewAdminCpiBuilderInstruction<'a, 'b>>,
@@ -284,6 +334,8 @@ impl<'a, 'b> ProposeNewAdminCpiBuilder<'a, 'b> {
             proposed_admin: None,
             config: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -317,6 +369,19 @@ impl<'a, 'b> ProposeNewAdminCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -370,6 +435,13 @@ impl<'a, 'b> ProposeNewAdminCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -385,6 +457,8 @@ struct ProposeNewAdminCpiBuilderInstruction<'a, 'b> {
     proposed_admin: Option<&'b solana_account_info::AccountInfo<'a>>,
     config: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
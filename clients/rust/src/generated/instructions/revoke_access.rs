@@ -23,6 +23,10 @@ pub struct RevokeAccess {
     pub system_program: solana_pubkey::Pubkey,
 
     pub rent: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl RevokeAccess {
@@ -39,7 +43,7 @@ impl RevokeAccess {
         args: RevokeAccessInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.admin, true));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.config,
@@ -53,6 +57,14 @@ impl RevokeAccess {
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.rent, false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = RevokeAccessInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -111,6 +123,8 @@ impl RevokeAccessInstructionArgs {
 ///   2. `[writable]` access
 ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   4. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+///   5. `[]` event_authority
+///   6. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct RevokeAccessBuilder {
     admin: Option<solana_pubkey::Pubkey>,
@@ -118,6 +132,8 @@ pub struct RevokeAccessBuilder {
     access: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     rent: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     user: Option<Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -154,6 +170,16 @@ impl RevokeAccessBuilder {
         self
     }
     #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn user(&mut self, user: Pubkey) -> &mut Self {
         self.user = Some(user);
         self
@@ -185,6 +211,8 @@ impl RevokeAccessBuilder {
             rent: self.rent.unwrap_or(solana_pubkey::pubkey!(
                 "SysvarRent111111111111111111111111111111111"
             )),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
         let args = RevokeAccessInstructionArgs {
             user: self.user.clone().expect("user is not set"),
@@ -205,6 +233,10 @@ pub struct RevokeAccessCpiAccounts<'a, 'b> {
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub rent: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `revoke_access` CPI instruction.
@@ -221,6 +253,10 @@ pub struct RevokeAccessCpi<'a, 'b> {
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub rent: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: RevokeAccessInstructionArgs,
 }
@@ -238,6 +274,8 @@ impl<'a, 'b> RevokeAccessCpi<'a, 'b> {
             access: accounts.access,
             system_program: accounts.system_program,
             rent: accounts.rent,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -264,7 +302,7 @@ impl<'a, 'b> RevokeAccessCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.admin.key, true));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.config.key,
@@ -282,6 +320,14 @@ impl<'a, 'b> RevokeAccessCpi<'a, 'b> {
             *self.rent.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -298,13 +344,15 @@ impl<'a, 'b> RevokeAccessCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.admin.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.access.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.rent.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -326,6 +374,8 @@ impl<'a, 'b> RevokeAccessCpi<'a, 'b> {
 ///   2. `[writable]` access
 ///   3. `[]` system_program
 ///   4. `[]` rent
+///   5. `[]` event_authority
+///   6. `[]` program
 #[derive(Clone, Debug)]
 pub struct RevokeAccessCpiBuilder<'a, 'b> {
     instruction: Box<RevokeAccessCpiBuilderInstruction<'a, 'b>>,
@@ -340,6 +390,8 @@ impl<'a, 'b> RevokeAccessCpiBuilder<'a, 'b> {
             access: None,
             system_program: None,
             rent: None,
+            event_authority: None,
+            program: None,
             user: None,
             __remaining_accounts: Vec::new(),
         });
@@ -374,6 +426,19 @@ impl<'a, 'b> RevokeAccessCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn user(&mut self, user: Pubkey) -> &mut Self {
         self.instruction.user = Some(user);
         self
@@ -430,6 +495,13 @@ impl<'a, 'b> RevokeAccessCpiBuilder<'a, 'b> {
                 .expect("system_program is not set"),
 
             rent: self.instruction.rent.expect("rent is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -447,6 +519,8 @@ struct RevokeAccessCpiBuilderInstruction<'a, 'b> {
     access: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     rent: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     user: Option<Pubkey>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
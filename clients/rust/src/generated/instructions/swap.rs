@@ -37,6 +37,10 @@ pub struct Swap {
     pub user: solana_pubkey::Pubkey,
 
     pub jupiter_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl Swap {
@@ -50,7 +54,7 @@ impl Swap {
         args: SwapInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.input_mint,
             false,
@@ -89,6 +93,14 @@ impl Swap {
             self.jupiter_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = SwapInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -156,6 +168,8 @@ impl SwapInstructionArgs {
 ///   9. `[]` access
 ///   10. `[]` user
 ///   11. `[optional]` jupiter_program (default to `JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4`)
+///   12. `[]` event_authority
+///   13. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct SwapBuilder {
     input_mint: Option<solana_pubkey::Pubkey>,
@@ -170,6 +184,8 @@ pub struct SwapBuilder {
     access: Option<solana_pubkey::Pubkey>,
     user: Option<solana_pubkey::Pubkey>,
     jupiter_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     data: Option<Vec<u8>>,
     in_amount: Option<u64>,
     delegate: Option<Pubkey>,
@@ -248,6 +264,16 @@ impl SwapBuilder {
         self
     }
     #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn data(&mut self, data: Vec<u8>) -> &mut Self {
         self.data = Some(data);
         self
@@ -302,6 +328,8 @@ impl SwapBuilder {
             jupiter_program: self.jupiter_program.unwrap_or(solana_pubkey::pubkey!(
                 "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4"
             )),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
         let args = SwapInstructionArgs {
             data: self.data.clone().expect("data is not set"),
@@ -338,6 +366,10 @@ pub struct SwapCpiAccounts<'a, 'b> {
     pub user: &'b solana_account_info::AccountInfo<'a>,
 
     pub jupiter_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `swap` CPI instruction.
@@ -368,6 +400,10 @@ pub struct SwapCpi<'a, 'b> {
     pub user: &'b solana_account_info::AccountInfo<'a>,
 
     pub jupiter_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: SwapInstructionArgs,
 }
@@ -392,6 +428,8 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
             access: accounts.access,
             user: accounts.user,
             jupiter_program: accounts.jupiter_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -418,7 +456,7 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.input_mint.key,
             false,
@@ -464,6 +502,14 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
             *self.jupiter_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -480,7 +526,7 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(15 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.input_mint.clone());
         account_infos.push(self.input_mint_program.clone());
@@ -494,6 +540,8 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
         account_infos.push(self.access.clone());
         account_infos.push(self.user.clone());
         account_infos.push(self.jupiter_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -522,6 +570,8 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
 ///   9. `[]` access
 ///   10. `[]` user
 ///   11. `[]` jupiter_program
+///   12. `[]` event_authority
+///   13. `[]` program
 #[derive(Clone, Debug)]
 pub struct SwapCpiBuilder<'a, 'b> {
     instruction: Box<SwapCpiBuilderInstruction<'a, 'b>>,
@@ -543,6 +593,8 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
             access: None,
             user: None,
             jupiter_program: None,
+            event_authority: None,
+            program: None,
             data: None,
             in_amount: None,
             delegate: None,
@@ -632,6 +684,19 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn data(&mut self, data: Vec<u8>) -> &mut Self {
         self.instruction.data = Some(data);
         self
@@ -737,6 +802,13 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
                 .instruction
                 .jupiter_program
                 .expect("jupiter_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -761,6 +833,8 @@ struct SwapCpiBuilderInstruction<'a, 'b> {
     access: Option<&'b solana_account_info::AccountInfo<'a>>,
     user: Option<&'b solana_account_info::AccountInfo<'a>>,
     jupiter_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     data: Option<Vec<u8>>,
     in_amount: Option<u64>,
     delegate: Option<Pubkey>,
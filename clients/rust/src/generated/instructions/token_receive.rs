@@ -32,6 +32,12 @@ pub struct TokenReceive {
     pub receiver: solana_pubkey::Pubkey,
 
     pub access: solana_pubkey::Pubkey,
+
+    pub user_credit: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl TokenReceive {
@@ -44,7 +50,7 @@ impl TokenReceive {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -73,6 +79,18 @@ impl TokenReceive {
             self.access,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.user_credit,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = TokenReceiveInstructionData::new().try_to_vec().unwrap();
 
@@ -122,6 +140,9 @@ impl Default for TokenReceiveInstructionData {
 ///   7. `[writable]` delegate
 ///   8. `[writable]` receiver
 ///   9. `[]` access
+///   10. `[writable]` user_credit
+///   11. `[]` event_authority
+///   12. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct TokenReceiveBuilder {
     config: Option<solana_pubkey::Pubkey>,
@@ -134,6 +155,9 @@ pub struct TokenReceiveBuilder {
     delegate: Option<solana_pubkey::Pubkey>,
     receiver: Option<solana_pubkey::Pubkey>,
     access: Option<solana_pubkey::Pubkey>,
+    user_credit: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -197,6 +221,21 @@ impl TokenReceiveBuilder {
         self.access = Some(access);
         self
     }
+    #[inline(always)]
+    pub fn user_credit(&mut self, user_credit: solana_pubkey::Pubkey) -> &mut Self {
+        self.user_credit = Some(user_credit);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -231,6 +270,9 @@ impl TokenReceiveBuilder {
             delegate: self.delegate.expect("delegate is not set"),
             receiver: self.receiver.expect("receiver is not set"),
             access: self.access.expect("access is not set"),
+            user_credit: self.user_credit.expect("user_credit is not set"),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -258,6 +300,12 @@ pub struct TokenReceiveCpiAccounts<'a, 'b> {
     pub receiver: &'b solana_account_info::AccountInfo<'a>,
 
     pub access: &'b solana_account_info::AccountInfo<'a>,
+
+    pub user_credit: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `token_receive` CPI instruction.
@@ -284,6 +332,12 @@ pub struct TokenReceiveCpi<'a, 'b> {
     pub receiver: &'b solana_account_info::AccountInfo<'a>,
 
     pub access: &'b solana_account_info::AccountInfo<'a>,
+
+    pub user_credit: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> TokenReceiveCpi<'a, 'b> {
@@ -303,6 +357,9 @@ impl<'a, 'b> TokenReceiveCpi<'a, 'b> {
             delegate: accounts.delegate,
             receiver: accounts.receiver,
             access: accounts.access,
+            user_credit: accounts.user_credit,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
         }
     }
     #[inline(always)]
@@ -328,7 +385,7 @@ impl<'a, 'b> TokenReceiveCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -366,6 +423,18 @@ impl<'a, 'b> TokenReceiveCpi<'a, 'b> {
             *self.access.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.user_credit.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -380,7 +449,7 @@ impl<'a, 'b> TokenReceiveCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(14 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.output_mint.clone());
@@ -392,6 +461,9 @@ impl<'a, 'b> TokenReceiveCpi<'a, 'b> {
         account_infos.push(self.delegate.clone());
         account_infos.push(self.receiver.clone());
         account_infos.push(self.access.clone());
+        account_infos.push(self.user_credit.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -418,6 +490,9 @@ impl<'a, 'b> TokenReceiveCpi<'a, 'b> {
 ///   7. `[writable]` delegate
 ///   8. `[writable]` receiver
 ///   9. `[]` access
+///   10. `[writable]` user_credit
+///   11. `[]` event_authority
+///   12. `[]` program
 #[derive(Clone, Debug)]
 pub struct TokenReceiveCpiBuilder<'a, 'b> {
     instruction: Box<TokenReceiveCpiBuilderInstruction<'a, 'b>>,
@@ -437,6 +512,9 @@ impl<'a, 'b> TokenReceiveCpiBuilder<'a, 'b> {
             delegate: None,
             receiver: None,
             access: None,
+            user_credit: None,
+            event_authority: None,
+            program: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -503,6 +581,27 @@ impl<'a, 'b> TokenReceiveCpiBuilder<'a, 'b> {
         self.instruction.access = Some(access);
         self
     }
+    #[inline(always)]
+    pub fn user_credit(
+        &mut self,
+        user_credit: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.user_credit = Some(user_credit);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -571,6 +670,18 @@ impl<'a, 'b> TokenReceiveCpiBuilder<'a, 'b> {
             receiver: self.instruction.receiver.expect("receiver is not set"),
 
             access: self.instruction.access.expect("access is not set"),
+
+            user_credit: self
+                .instruction
+                .user_credit
+                .expect("user_credit is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -592,6 +703,9 @@ struct TokenReceiveCpiBuilderInstruction<'a, 'b> {
     delegate: Option<&'b solana_account_info::AccountInfo<'a>>,
     receiver: Option<&'b solana_account_info::AccountInfo<'a>>,
     access: Option<&'b solana_account_info::AccountInfo<'a>>,
+    user_credit: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -27,11 +27,20 @@ pub struct TransferIn {
 
     pub to_token_account: solana_pubkey::Pubkey,
 
+    pub access: solana_pubkey::Pubkey,
+
+    /// Ledger entry of the source owner for the deposited mint
+    pub user_credit: solana_pubkey::Pubkey,
+
     pub token_program: solana_pubkey::Pubkey,
 
     pub associated_token_program: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl TransferIn {
@@ -45,7 +54,7 @@ impl TransferIn {
         args: TransferInInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.operator, true));
         accounts.push(solana_instruction::AccountMeta::new(self.authority, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -65,6 +74,14 @@ impl TransferIn {
             self.to_token_account,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.access,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.user_credit,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.token_program,
             false,
@@ -77,6 +94,14 @@ impl TransferIn {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = TransferInInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -137,9 +162,13 @@ impl TransferInInstructionArgs {
 ///   4. `[]` config
 ///   5. `[writable]` vault
 ///   6. `[writable]` to_token_account
-///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   8. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[]` access
+///   8. `[writable]` user_credit
+///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   10. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   11. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   12. `[]` event_authority
+///   13. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct TransferInBuilder {
     operator: Option<solana_pubkey::Pubkey>,
@@ -149,9 +178,13 @@ pub struct TransferInBuilder {
     config: Option<solana_pubkey::Pubkey>,
     vault: Option<solana_pubkey::Pubkey>,
     to_token_account: Option<solana_pubkey::Pubkey>,
+    access: Option<solana_pubkey::Pubkey>,
+    user_credit: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
     associated_token_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     amounts: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -195,6 +228,17 @@ impl TransferInBuilder {
         self.to_token_account = Some(to_token_account);
         self
     }
+    #[inline(always)]
+    pub fn access(&mut self, access: solana_pubkey::Pubkey) -> &mut Self {
+        self.access = Some(access);
+        self
+    }
+    /// Ledger entry of the source owner for the deposited mint
+    #[inline(always)]
+    pub fn user_credit(&mut self, user_credit: solana_pubkey::Pubkey) -> &mut Self {
+        self.user_credit = Some(user_credit);
+        self
+    }
     /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
     #[inline(always)]
     pub fn token_program(&mut self, token_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -217,6 +261,16 @@ impl TransferInBuilder {
         self
     }
     #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn amounts(&mut self, amounts: u64) -> &mut Self {
         self.amounts = Some(amounts);
         self
@@ -248,6 +302,8 @@ impl TransferInBuilder {
             config: self.config.expect("config is not set"),
             vault: self.vault.expect("vault is not set"),
             to_token_account: self.to_token_account.expect("to_token_account is not set"),
+            access: self.access.expect("access is not set"),
+            user_credit: self.user_credit.expect("user_credit is not set"),
             token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
@@ -257,6 +313,8 @@ impl TransferInBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
         let args = TransferInInstructionArgs {
             amounts: self.amounts.clone().expect("amounts is not set"),
@@ -282,11 +340,20 @@ pub struct TransferInCpiAccounts<'a, 'b> {
 
     pub to_token_account: &'b solana_account_info::AccountInfo<'a>,
 
+    pub access: &'b solana_account_info::AccountInfo<'a>,
+
+    /// Ledger entry of the source owner for the deposited mint
+    pub user_credit: &'b solana_account_info::AccountInfo<'a>,
+
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `transfer_in` CPI instruction.
@@ -308,11 +375,20 @@ pub struct TransferInCpi<'a, 'b> {
 
     pub to_token_account: &'b solana_account_info::AccountInfo<'a>,
 
+    pub access: &'b solana_account_info::AccountInfo<'a>,
+
+    /// Ledger entry of the source owner for the deposited mint
+    pub user_credit: &'b solana_account_info::AccountInfo<'a>,
+
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: TransferInInstructionArgs,
 }
@@ -332,9 +408,13 @@ impl<'a, 'b> TransferInCpi<'a, 'b> {
             config: accounts.config,
             vault: accounts.vault,
             to_token_account: accounts.to_token_account,
+            access: accounts.access,
+            user_credit: accounts.user_credit,
             token_program: accounts.token_program,
             associated_token_program: accounts.associated_token_program,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -361,7 +441,7 @@ impl<'a, 'b> TransferInCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.operator.key,
             true,
@@ -387,6 +467,14 @@ impl<'a, 'b> TransferInCpi<'a, 'b> {
             *self.to_token_account.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.access.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.user_credit.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.token_program.key,
             false,
@@ -399,6 +487,14 @@ impl<'a, 'b> TransferInCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -415,7 +511,7 @@ impl<'a, 'b> TransferInCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(15 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.operator.clone());
         account_infos.push(self.authority.clone());
@@ -424,9 +520,13 @@ impl<'a, 'b> TransferInCpi<'a, 'b> {
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
         account_infos.push(self.to_token_account.clone());
+        account_infos.push(self.access.clone());
+        account_infos.push(self.user_credit.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.associated_token_program.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -450,9 +550,13 @@ impl<'a, 'b> TransferInCpi<'a, 'b> {
 ///   4. `[]` config
 ///   5. `[writable]` vault
 ///   6. `[writable]` to_token_account
-///   7. `[]` token_program
-///   8. `[]` associated_token_program
-///   9. `[]` system_program
+///   7. `[]` access
+///   8. `[writable]` user_credit
+///   9. `[]` token_program
+///   10. `[]` associated_token_program
+///   11. `[]` system_program
+///   12. `[]` event_authority
+///   13. `[]` program
 #[derive(Clone, Debug)]
 pub struct TransferInCpiBuilder<'a, 'b> {
     instruction: Box<TransferInCpiBuilderInstruction<'a, 'b>>,
@@ -469,9 +573,13 @@ impl<'a, 'b> TransferInCpiBuilder<'a, 'b> {
             config: None,
             vault: None,
             to_token_account: None,
+            access: None,
+            user_credit: None,
             token_program: None,
             associated_token_program: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             amounts: None,
             __remaining_accounts: Vec::new(),
         });
@@ -522,6 +630,20 @@ impl<'a, 'b> TransferInCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn access(&mut self, access: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.access = Some(access);
+        self
+    }
+    /// Ledger entry of the source owner for the deposited mint
+    #[inline(always)]
+    pub fn user_credit(
+        &mut self,
+        user_credit: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.user_credit = Some(user_credit);
+        self
+    }
+    #[inline(always)]
     pub fn token_program(
         &mut self,
         token_program: &'b solana_account_info::AccountInfo<'a>,
@@ -546,6 +668,19 @@ impl<'a, 'b> TransferInCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn amounts(&mut self, amounts: u64) -> &mut Self {
         self.instruction.amounts = Some(amounts);
         self
@@ -614,6 +749,13 @@ impl<'a, 'b> TransferInCpiBuilder<'a, 'b> {
                 .to_token_account
                 .expect("to_token_account is not set"),
 
+            access: self.instruction.access.expect("access is not set"),
+
+            user_credit: self
+                .instruction
+                .user_credit
+                .expect("user_credit is not set"),
+
             token_program: self
                 .instruction
                 .token_program
@@ -628,6 +770,13 @@ impl<'a, 'b> TransferInCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -647,9 +796,13 @@ struct TransferInCpiBuilderInstruction<'a, 'b> {
     config: Option<&'b solana_account_info::AccountInfo<'a>>,
     vault: Option<&'b solana_account_info::AccountInfo<'a>>,
     to_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    access: Option<&'b solana_account_info::AccountInfo<'a>>,
+    user_credit: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     amounts: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -25,9 +25,17 @@ pub struct TransferOut {
 
     pub to_token_account: solana_pubkey::Pubkey,
 
+    pub destination: Option<solana_pubkey::Pubkey>,
+
+    pub user_credit: Option<solana_pubkey::Pubkey>,
+
     pub token_program: solana_pubkey::Pubkey,
 
     pub associated_token_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl TransferOut {
@@ -41,7 +49,7 @@ impl TransferOut {
         args: TransferOutInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.operator, true));
         accounts.push(solana_instruction::AccountMeta::new(self.config, false));
         accounts.push(solana_instruction::AccountMeta::new(self.vault, false));
@@ -49,7 +57,7 @@ impl TransferOut {
             self.token_mint,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             self.from_token_account,
             false,
         ));
@@ -57,6 +65,25 @@ impl TransferOut {
             self.to_token_account,
             false,
         ));
+        if let Some(destination) = self.destination {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                destination,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        if let Some(user_credit) = self.user_credit {
+            accounts.push(solana_instruction::AccountMeta::new(user_credit, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.token_program,
             false,
@@ -65,6 +92,14 @@ impl TransferOut {
             self.associated_token_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = TransferOutInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -122,10 +157,14 @@ impl TransferOutInstructionArgs {
 ///   1. `[writable]` config
 ///   2. `[writable]` vault
 ///   3. `[]` token_mint
-///   4. `[]` from_token_account
+///   4. `[writable]` from_token_account
 ///   5. `[writable]` to_token_account
-///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   7. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   6. `[optional]` destination
+///   7. `[writable, optional]` user_credit
+///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   9. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   10. `[]` event_authority
+///   11. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct TransferOutBuilder {
     operator: Option<solana_pubkey::Pubkey>,
@@ -134,8 +173,12 @@ pub struct TransferOutBuilder {
     token_mint: Option<solana_pubkey::Pubkey>,
     from_token_account: Option<solana_pubkey::Pubkey>,
     to_token_account: Option<solana_pubkey::Pubkey>,
+    destination: Option<solana_pubkey::Pubkey>,
+    user_credit: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
     associated_token_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     amounts: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -174,6 +217,18 @@ impl TransferOutBuilder {
         self.to_token_account = Some(to_token_account);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn destination(&mut self, destination: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.destination = destination;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn user_credit(&mut self, user_credit: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.user_credit = user_credit;
+        self
+    }
     /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
     #[inline(always)]
     pub fn token_program(&mut self, token_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -190,6 +245,16 @@ impl TransferOutBuilder {
         self
     }
     #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn amounts(&mut self, amounts: u64) -> &mut Self {
         self.amounts = Some(amounts);
         self
@@ -220,12 +285,16 @@ impl TransferOutBuilder {
                 .from_token_account
                 .expect("from_token_account is not set"),
             to_token_account: self.to_token_account.expect("to_token_account is not set"),
+            destination: self.destination,
+            user_credit: self.user_credit,
             token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
             associated_token_program: self.associated_token_program.unwrap_or(
                 solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
             ),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
         let args = TransferOutInstructionArgs {
             amounts: self.amounts.clone().expect("amounts is not set"),
@@ -249,9 +318,17 @@ pub struct TransferOutCpiAccounts<'a, 'b> {
 
     pub to_token_account: &'b solana_account_info::AccountInfo<'a>,
 
+    pub destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub user_credit: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `transfer_out` CPI instruction.
@@ -271,9 +348,17 @@ pub struct TransferOutCpi<'a, 'b> {
 
     pub to_token_account: &'b solana_account_info::AccountInfo<'a>,
 
+    pub destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub user_credit: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: TransferOutInstructionArgs,
 }
@@ -292,8 +377,12 @@ impl<'a, 'b> TransferOutCpi<'a, 'b> {
             token_mint: accounts.token_mint,
             from_token_account: accounts.from_token_account,
             to_token_account: accounts.to_token_account,
+            destination: accounts.destination,
+            user_credit: accounts.user_credit,
             token_program: accounts.token_program,
             associated_token_program: accounts.associated_token_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -320,7 +409,7 @@ impl<'a, 'b> TransferOutCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.operator.key,
             true,
@@ -334,7 +423,7 @@ impl<'a, 'b> TransferOutCpi<'a, 'b> {
             *self.token_mint.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.from_token_account.key,
             false,
         ));
@@ -342,6 +431,28 @@ impl<'a, 'b> TransferOutCpi<'a, 'b> {
             *self.to_token_account.key,
             false,
         ));
+        if let Some(destination) = self.destination {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *destination.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
+        if let Some(user_credit) = self.user_credit {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *user_credit.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::JUPITER_DELEGATE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.token_program.key,
             false,
@@ -350,6 +461,14 @@ impl<'a, 'b> TransferOutCpi<'a, 'b> {
             *self.associated_token_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -366,7 +485,7 @@ impl<'a, 'b> TransferOutCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.operator.clone());
         account_infos.push(self.config.clone());
@@ -374,8 +493,16 @@ impl<'a, 'b> TransferOutCpi<'a, 'b> {
         account_infos.push(self.token_mint.clone());
         account_infos.push(self.from_token_account.clone());
         account_infos.push(self.to_token_account.clone());
+        if let Some(destination) = self.destination {
+            account_infos.push(destination.clone());
+        }
+        if let Some(user_credit) = self.user_credit {
+            account_infos.push(user_credit.clone());
+        }
         account_infos.push(self.token_program.clone());
         account_infos.push(self.associated_token_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -396,10 +523,14 @@ impl<'a, 'b> TransferOutCpi<'a, 'b> {
 ///   1. `[writable]` config
 ///   2. `[writable]` vault
 ///   3. `[]` token_mint
-///   4. `[]` from_token_account
+///   4. `[writable]` from_token_account
 ///   5. `[writable]` to_token_account
-///   6. `[]` token_program
-///   7. `[]` associated_token_program
+///   6. `[optional]` destination
+///   7. `[writable, optional]` user_credit
+///   8. `[]` token_program
+///   9. `[]` associated_token_program
+///   10. `[]` event_authority
+///   11. `[]` program
 #[derive(Clone, Debug)]
 pub struct TransferOutCpiBuilder<'a, 'b> {
     instruction: Box<TransferOutCpiBuilderInstruction<'a, 'b>>,
@@ -415,8 +546,12 @@ impl<'a, 'b> TransferOutCpiBuilder<'a, 'b> {
             token_mint: None,
             from_token_account: None,
             to_token_account: None,
+            destination: None,
+            user_credit: None,
             token_program: None,
             associated_token_program: None,
+            event_authority: None,
+            program: None,
             amounts: None,
             __remaining_accounts: Vec::new(),
         });
@@ -461,6 +596,24 @@ impl<'a, 'b> TransferOutCpiBuilder<'a, 'b> {
         self.instruction.to_token_account = Some(to_token_account);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.destination = destination;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn user_credit(
+        &mut self,
+        user_credit: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.user_credit = user_credit;
+        self
+    }
     #[inline(always)]
     pub fn token_program(
         &mut self,
@@ -478,6 +631,19 @@ impl<'a, 'b> TransferOutCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn amounts(&mut self, amounts: u64) -> &mut Self {
         self.instruction.amounts = Some(amounts);
         self
@@ -544,6 +710,10 @@ impl<'a, 'b> TransferOutCpiBuilder<'a, 'b> {
                 .to_token_account
                 .expect("to_token_account is not set"),
 
+            destination: self.instruction.destination,
+
+            user_credit: self.instruction.user_credit,
+
             token_program: self
                 .instruction
                 .token_program
@@ -553,6 +723,13 @@ impl<'a, 'b> TransferOutCpiBuilder<'a, 'b> {
                 .instruction
                 .associated_token_program
                 .expect("associated_token_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -571,8 +748,12 @@ struct TransferOutCpiBuilderInstruction<'a, 'b> {
     token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
     from_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     to_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    user_credit: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     amounts: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -22,4 +22,9 @@ pub struct GrantAccessEvent {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub access: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub partner: Pubkey,
 }
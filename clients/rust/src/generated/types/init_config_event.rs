@@ -23,4 +23,5 @@ pub struct InitConfigEvent {
     )]
     pub vault: Pubkey,
     pub cooldown_duration: i64,
+    pub namespace: u16,
 }
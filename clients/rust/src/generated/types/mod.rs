@@ -5,38 +5,120 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#add_destination_event;
 pub(crate) mod r#admin_transfer_completed_event;
 pub(crate) mod r#admin_transfer_proposed_event;
+pub(crate) mod r#batch_leg;
+pub(crate) mod r#batch_trade_event;
+pub(crate) mod r#batch_trade_leg_event;
+pub(crate) mod r#credit_user_event;
+pub(crate) mod r#dca_plan_cancelled_event;
+pub(crate) mod r#dca_plan_created_event;
+pub(crate) mod r#dca_slice_executed_event;
+pub(crate) mod r#debit_user_event;
+pub(crate) mod r#deposit_event;
 pub(crate) mod r#dflow_aggregator_event;
+pub(crate) mod r#dflow_order_opened_event;
+pub(crate) mod r#dflow_order_settled_event;
 pub(crate) mod r#fill_order_engine_event;
 pub(crate) mod r#grant_access_event;
 pub(crate) mod r#init_config_event;
+pub(crate) mod r#init_trade_log_event;
 pub(crate) mod r#jupiter_aggregator_event;
 pub(crate) mod r#jupiter_perpetuals_event;
 pub(crate) mod r#jupiter_swap_event;
+pub(crate) mod r#limit_order_cancelled_event;
+pub(crate) mod r#limit_order_created_event;
+pub(crate) mod r#limit_order_expired_event;
+pub(crate) mod r#limit_order_filled_event;
+pub(crate) mod r#migrate_account_event;
+pub(crate) mod r#migrate_user_vault_event;
+pub(crate) mod r#mint_stats;
+pub(crate) mod r#modify_access_intent_event;
 pub(crate) mod r#modify_cooldown_duration_event;
+pub(crate) mod r#modify_max_commission_event;
 pub(crate) mod r#modify_operator_event;
+pub(crate) mod r#modify_protocol_fee_event;
+pub(crate) mod r#modify_vault_mode_event;
+pub(crate) mod r#native_output_settled_event;
 pub(crate) mod r#okx_aggregator_event;
+pub(crate) mod r#partner_event;
+pub(crate) mod r#partner_fee_accrued_event;
+pub(crate) mod r#partner_fee_claimed_event;
 pub(crate) mod r#pause_event;
+pub(crate) mod r#protocol_fee_event;
+pub(crate) mod r#remove_destination_event;
+pub(crate) mod r#resize_trade_log_event;
 pub(crate) mod r#revoke_access_event;
 pub(crate) mod r#step_action;
 pub(crate) mod r#step_params;
+pub(crate) mod r#token_receive_event;
+pub(crate) mod r#trade_executed;
+pub(crate) mod r#trade_intent;
+pub(crate) mod r#trade_receipt_closed_event;
+pub(crate) mod r#trade_venue;
+pub(crate) mod r#transfer_fee_event;
 pub(crate) mod r#two_hop_event;
+pub(crate) mod r#user_stats_closed_event;
+pub(crate) mod r#venue_instruction;
+pub(crate) mod r#withdraw_event;
+pub(crate) mod r#withdraw_protocol_fee_event;
 
+pub use self::r#add_destination_event::*;
 pub use self::r#admin_transfer_completed_event::*;
 pub use self::r#admin_transfer_proposed_event::*;
+pub use self::r#batch_leg::*;
+pub use self::r#batch_trade_event::*;
+pub use self::r#batch_trade_leg_event::*;
+pub use self::r#credit_user_event::*;
+pub use self::r#dca_plan_cancelled_event::*;
+pub use self::r#dca_plan_created_event::*;
+pub use self::r#dca_slice_executed_event::*;
+pub use self::r#debit_user_event::*;
+pub use self::r#deposit_event::*;
 pub use self::r#dflow_aggregator_event::*;
+pub use self::r#dflow_order_opened_event::*;
+pub use self::r#dflow_order_settled_event::*;
 pub use self::r#fill_order_engine_event::*;
 pub use self::r#grant_access_event::*;
 pub use self::r#init_config_event::*;
+pub use self::r#init_trade_log_event::*;
 pub use self::r#jupiter_aggregator_event::*;
 pub use self::r#jupiter_perpetuals_event::*;
 pub use self::r#jupiter_swap_event::*;
+pub use self::r#limit_order_cancelled_event::*;
+pub use self::r#limit_order_created_event::*;
+pub use self::r#limit_order_expired_event::*;
+pub use self::r#limit_order_filled_event::*;
+pub use self::r#migrate_account_event::*;
+pub use self::r#migrate_user_vault_event::*;
+pub use self::r#mint_stats::*;
+pub use self::r#modify_access_intent_event::*;
 pub use self::r#modify_cooldown_duration_event::*;
+pub use self::r#modify_max_commission_event::*;
 pub use self::r#modify_operator_event::*;
+pub use self::r#modify_protocol_fee_event::*;
+pub use self::r#modify_vault_mode_event::*;
+pub use self::r#native_output_settled_event::*;
 pub use self::r#okx_aggregator_event::*;
+pub use self::r#partner_event::*;
+pub use self::r#partner_fee_accrued_event::*;
+pub use self::r#partner_fee_claimed_event::*;
 pub use self::r#pause_event::*;
+pub use self::r#protocol_fee_event::*;
+pub use self::r#remove_destination_event::*;
+pub use self::r#resize_trade_log_event::*;
 pub use self::r#revoke_access_event::*;
 pub use self::r#step_action::*;
 pub use self::r#step_params::*;
+pub use self::r#token_receive_event::*;
+pub use self::r#trade_executed::*;
+pub use self::r#trade_intent::*;
+pub use self::r#trade_receipt_closed_event::*;
+pub use self::r#trade_venue::*;
+pub use self::r#transfer_fee_event::*;
 pub use self::r#two_hop_event::*;
+pub use self::r#user_stats_closed_event::*;
+pub use self::r#venue_instruction::*;
+pub use self::r#withdraw_event::*;
+pub use self::r#withdraw_protocol_fee_event::*;
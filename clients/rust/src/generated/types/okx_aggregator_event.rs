@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::VenueInstruction;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -28,7 +29,9 @@ pub struct OkxAggregatorEvent {
     )]
     pub output_mint: Pubkey,
     pub input_amount: u64,
-    pub instruction_name: String,
+    pub instruction: VenueInstruction,
+    pub commission_amount: u64,
+    pub native_output: bool,
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -36,4 +36,5 @@ pub enum StepAction {
     DFlowSwap2,
     JlpPerpetualsAddLiquidity2,
     JlpPerpetualsRemoveLiquidity2,
+    JlpPerpetualsSwap2,
 }
@@ -1,5 +1,14 @@
 use anchor_lang::prelude::*;
 
+#[constant]
+pub const DEFAULT_NAMESPACE: u16 = 0;
+
+#[constant]
+pub const CONFIG_VERSION: u8 = 1;
+
+#[constant]
+pub const ACCESS_VERSION: u8 = 1;
+
 #[constant]
 pub const VAULT_SEED: &str = "jupiter-delegate-vault";
 
@@ -9,6 +18,71 @@ pub const CONFIG_SEED: &str = "jupiter-delegate-config";
 #[constant]
 pub const ACCESS_SEED: &str = "jupiter-delegate-access";
 
+#[constant]
+pub const DFLOW_ORDER_SEED: &str = "jupiter-delegate-dflow-order";
+
+#[constant]
+pub const DFLOW_ORDER_OUTPUT_SEED: &str = "jupiter-delegate-dflow-order-output";
+
+#[constant]
+pub const DFLOW_ORDER_REFUND_SEED: &str = "jupiter-delegate-dflow-order-refund";
+
+#[constant]
+pub const DCA_PLAN_SEED: &str = "jupiter-delegate-dca-plan";
+
+#[constant]
+pub const LIMIT_ORDER_SEED: &str = "jupiter-delegate-limit-order";
+
+#[constant]
+pub const TRADE_RECEIPT_SEED: &str = "jupiter-delegate-trade-receipt";
+
+#[constant]
+pub const TRADE_RECEIPT_RETENTION: i64 = 7 * 24 * 60 * 60; // 7 days
+
+#[constant]
+pub const USER_STATS_SEED: &str = "jupiter-delegate-user-stats";
+
+#[constant]
+pub const MAX_USER_STATS_MINTS: u8 = 8;
+
+#[constant]
+pub const DESTINATION_SEED: &str = "jupiter-delegate-destination";
+
+#[constant]
+pub const USER_CREDIT_SEED: &str = "jupiter-delegate-user-credit";
+
+#[constant]
+pub const TRADE_LOG_SEED: &str = "jupiter-delegate-trade-log";
+
+#[constant]
+pub const MAX_TRADE_LOG_CAPACITY: u32 = 4_096;
+
+#[constant]
+pub const TRADE_EXECUTED_VERSION: u8 = 1;
+
+#[constant]
+pub const INTENT_MESSAGE_PREFIX: &str = "jupiter-delegate-intent";
+
+#[constant]
+pub const MAX_BATCH_LEGS: u8 = 8;
+
+#[constant]
+pub const FEE_VAULT_SEED: &str = "jupiter-delegate-fee-vault";
+
+#[constant]
+pub const NATIVE_OUTPUT_SEED: &str = "jupiter-delegate-native-output";
+
+#[constant]
+pub const PARTNER_SEED: &str = "jupiter-delegate-partner";
+
+#[constant]
+pub const PARTNER_FEE_SEED: &str = "jupiter-delegate-partner-fee";
+
+#[constant]
+pub const MAX_FEE_BPS: u16 = 1_000; // 10%
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
 #[constant]
 pub const MINIMUM_TRADE_INTERVAL: i64 = 0; // 0 seconds
 
@@ -34,6 +108,38 @@ pub const DISCRIMINATOR_OKX_SWAP_TOB_V3_WITH_RECEIVER: &[u8] =
 
 pub const DISCRIMINATOR_OKX_SWAP: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
 
+pub const DISCRIMINATOR_OKX_COMMISSION_SPL_SWAP: &[u8] = &[235, 71, 211, 196, 114, 199, 143, 92];
+
+pub const DISCRIMINATOR_OKX_COMMISSION_SOL_SWAP: &[u8] = &[81, 128, 134, 73, 114, 73, 45, 94];
+
+pub const DISCRIMINATOR_OKX_COMMISSION_SPL_PROXY_SWAP: &[u8] = &[96, 67, 12, 151, 129, 164, 18, 71];
+
+pub const DISCRIMINATOR_OKX_COMMISSION_SOL_PROXY_SWAP: &[u8] = &[30, 33, 208, 91, 31, 157, 37, 18];
+
+pub const DISCRIMINATOR_OKX_PLATFORM_FEE_SPL_PROXY_SWAP_V2: &[u8] =
+    &[69, 164, 210, 89, 146, 214, 173, 67];
+
+pub const DISCRIMINATOR_OKX_PLATFORM_FEE_SOL_PROXY_SWAP_V2: &[u8] =
+    &[69, 200, 254, 247, 40, 52, 118, 202];
+
+// OKX commission account position in the commission / platform fee instructions
+pub const OKX_COMMISSION_ACCOUNT_INDEX: usize = 5;
+
+// OKX v2 / v3 commission_info: high bit is the direction, low 30 bits the rate over 10^9
+pub const OKX_COMMISSION_RATE_MASK_V2: u32 = (1 << 30) - 1;
+
+pub const OKX_COMMISSION_DENOMINATOR_V2: u64 = 1_000_000_000;
+
 pub const DISCRIMINATOR_DFLOW_SWAP: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
 
 pub const DISCRIMINATOR_DFLOW_SWAP2: &[u8] = &[65, 75, 63, 76, 235, 91, 91, 136];
+
+pub const DISCRIMINATOR_DFLOW_OPEN_ORDER: &[u8] = &[206, 88, 88, 143, 38, 136, 50, 224];
+
+pub const DISCRIMINATOR_DFLOW_CLOSE_ORDER: &[u8] = &[90, 103, 209, 28, 7, 63, 168, 4];
+
+pub const DISCRIMINATOR_DFLOW_ORDER_ACCOUNT: &[u8] = &[134, 173, 223, 185, 77, 86, 28, 51];
+
+// seed of the event authority added by #[event_cpi], fixed by anchor
+#[constant]
+pub const EVENT_AUTHORITY_SEED: &str = "__event_authority";
@@ -21,6 +21,10 @@ pub enum ErrorCode {
     InvalidCooldownDuration,
     #[msg("Config is paused")]
     ConfigPaused,
+    #[msg("Unsupported account version")]
+    UnsupportedAccountVersion,
+    #[msg("Account is already migrated")]
+    AccountAlreadyMigrated,
 
     // Swap
     #[msg("Swap too frequent")]
@@ -49,6 +53,12 @@ pub enum ErrorCode {
     OnlyAdminCanModifyOperator,
     #[msg("Only admin can pause")]
     OnlyAdminCanPause,
+    #[msg("Only admin can modify protocol fee")]
+    OnlyAdminCanModifyProtocolFee,
+    #[msg("Invalid fee bps")]
+    InvalidFeeBps,
+    #[msg("Only admin can modify max commission")]
+    OnlyAdminCanModifyMaxCommission,
 
     // New variants
     #[msg("Swap amount is too small")]
@@ -76,6 +86,10 @@ pub enum ErrorCode {
     #[msg("Invalid order engine data")]
     InvalidOrderEngineData,
 
+    // Perpetuals
+    #[msg("Invalid perpetuals data")]
+    InvalidPerpetualsData,
+
     // Two Hop
     #[msg("Two hop insufficient input amount")]
     TwoHopInsufficientInputAmount,
@@ -91,11 +105,133 @@ pub enum ErrorCode {
     FundVaultOutputTokenAccountNotFound,
     #[msg("Unsupported token program")]
     UnsupportedTokenProgram,
+    #[msg("Unsupported mint extension")]
+    UnsupportedMintExtension,
+    #[msg("Invalid transfer fee")]
+    InvalidTransferFee,
 
     // DelegateIsNotReceiver
     #[msg("Delegate is not receiver")]
     DelegateIsNotReceiver,
 
+    // Native Output
+    #[msg("Native output requires wrapped SOL output mint")]
+    NativeOutputRequiresWrappedSol,
+
+    // Venue Instruction
+    #[msg("Venue instruction is not supported")]
+    UnsupportedVenueInstruction,
+
+    // OKX Commission
+    #[msg("Invalid okx commission data")]
+    InvalidOkxCommissionData,
+    #[msg("Commission account is not owned by the fee treasury")]
+    InvalidCommissionAccount,
+    #[msg("Commission rate exceeds the configured maximum")]
+    CommissionRateTooHigh,
+    #[msg("OKX platform fee is not supported")]
+    OkxPlatformFeeNotSupported,
+    #[msg("OKX trim is not supported")]
+    OkxTrimNotSupported,
+
+    // DFlow Order
+    #[msg("Invalid dflow order data")]
+    InvalidDflowOrderData,
+    #[msg("Dflow order does not match the delegate order")]
+    DflowOrderMismatch,
+    #[msg("Dflow order is still open")]
+    DflowOrderStillOpen,
+
+    // DCA
+    #[msg("Invalid dca plan")]
+    InvalidDcaPlan,
+    #[msg("Dca slice is not due")]
+    DcaSliceNotDue,
+    #[msg("Dca plan budget is exhausted")]
+    DcaPlanCompleted,
+    #[msg("Dca slice output is out of price bounds")]
+    DcaPriceOutOfBounds,
+    #[msg("Dca slice spent no input")]
+    DcaSliceEmpty,
+
+    // Limit Order
+    #[msg("Invalid limit order")]
+    InvalidLimitOrder,
+    #[msg("Limit order is expired")]
+    LimitOrderExpired,
+    #[msg("Limit order is not expired")]
+    LimitOrderNotExpired,
+    #[msg("Limit order fill exceeds remaining size")]
+    LimitOrderFillTooLarge,
+    #[msg("Realized rate is below the limit rate")]
+    LimitOrderRateNotMet,
+
+    // Batch
+    #[msg("Invalid batch legs")]
+    InvalidBatchLegs,
+    #[msg("Invalid batch leg accounts")]
+    InvalidBatchLegAccounts,
+
+    // Vault
+    #[msg("Batch trades require the shared vault")]
+    BatchRequiresSharedVault,
+    #[msg("Only admin can modify vault mode")]
+    OnlyAdminCanModifyVaultMode,
+    #[msg("Invalid vault migration")]
+    InvalidVaultMigration,
+
+    // Trade Receipt
+    #[msg("Trade with this client order id was already executed")]
+    TradeAlreadyExecuted,
+    #[msg("Trade receipt retention period has not elapsed")]
+    TradeReceiptRetentionNotElapsed,
+    #[msg("Invalid trade receipt")]
+    InvalidTradeReceipt,
+
+    // User Credit
+    #[msg("Invalid user credit account")]
+    InvalidUserCredit,
+    #[msg("User credit account is required to park output in the vault")]
+    UserCreditRequired,
+    #[msg("Insufficient user credit")]
+    InsufficientUserCredit,
+
+    // Transfer Out
+    #[msg("Destination is neither registered nor credited in the ledger")]
+    DestinationNotAllowed,
+
+    // Trade Log
+    #[msg("Invalid trade log capacity")]
+    InvalidTradeLogCapacity,
+
+    // User Stats
+    #[msg("Invalid user stats account")]
+    InvalidUserStats,
+
+    // Trade Intent
+    #[msg("User requires a signed trade intent")]
+    IntentRequired,
+    #[msg("Invalid trade intent signature")]
+    InvalidIntentSignature,
+    #[msg("Trade does not match the signed intent")]
+    IntentMismatch,
+    #[msg("Trade intent has expired")]
+    IntentExpired,
+    #[msg("Input amount exceeds the signed intent")]
+    IntentInputExceeded,
+    #[msg("Output amount is below the signed intent")]
+    IntentOutputTooLow,
+    #[msg("Instruction does not support signed trade intents")]
+    IntentNotSupported,
+
+    // Partner
+    #[msg("Invalid partner")]
+    InvalidPartner,
+    #[msg("Invalid partner share bps")]
+    InvalidPartnerShareBps,
+    #[msg("Partner fee token account not found")]
+    PartnerFeeTokenAccountNotFound,
+
     #[msg("Insufficient funds")]
     InsufficientFunds,
     #[msg("Invalid token account")]
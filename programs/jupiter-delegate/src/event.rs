@@ -1,16 +1,22 @@
 use anchor_lang::prelude::*;
 
+#[cfg(feature = "legacy-events")]
+use crate::instructions::VenueInstruction;
+use crate::state::TradeVenue;
+
 #[event]
 pub struct InitConfigEvent {
     pub admin: Pubkey,
     pub vault: Pubkey,
     pub cooldown_duration: i64,
+    pub namespace: u16,
 }
 
 #[event]
 pub struct GrantAccessEvent {
     pub user: Pubkey,
     pub access: Pubkey,
+    pub partner: Pubkey,
 }
 
 #[event]
@@ -19,6 +25,13 @@ pub struct RevokeAccessEvent {
     pub access: Pubkey,
 }
 
+#[event]
+pub struct ModifyAccessIntentEvent {
+    pub user: Pubkey,
+    pub access: Pubkey,
+    pub intent_required: bool,
+}
+
 #[event]
 pub struct AdminTransferProposedEvent {
     pub config: Pubkey,
@@ -51,6 +64,80 @@ pub struct PauseEvent {
     pub toggle: bool,
 }
 
+#[event]
+pub struct ModifyMaxCommissionEvent {
+    pub config: Pubkey,
+    pub max_commission_bps: u16,
+}
+
+#[event]
+pub struct ModifyVaultModeEvent {
+    pub config: Pubkey,
+    pub per_user_vault: bool,
+}
+
+#[event]
+pub struct MigrateUserVaultEvent {
+    pub user: Pubkey,
+    pub user_vault: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MigrateAccountEvent {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub new_len: u64,
+}
+
+#[event]
+pub struct ModifyProtocolFeeEvent {
+    pub config: Pubkey,
+    pub fee_bps: u16,
+    pub fee_treasury: Pubkey,
+}
+
+#[event]
+pub struct WithdrawProtocolFeeEvent {
+    pub config: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub fee_treasury: Pubkey,
+}
+
+#[event]
+pub struct ProtocolFeeEvent {
+    pub mint: Pubkey,
+    pub gross: u64,
+    pub fee: u64,
+    pub net: u64,
+}
+
+#[event]
+pub struct PartnerEvent {
+    pub partner: Pubkey,
+    pub payout_owner: Pubkey,
+    pub share_bps: u16,
+}
+
+#[event]
+pub struct PartnerFeeAccruedEvent {
+    pub partner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PartnerFeeClaimedEvent {
+    pub partner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub payout_owner: Pubkey,
+}
+
+#[cfg(feature = "legacy-events")]
 #[event]
 pub struct JupiterSwapEvent {
     pub user: Pubkey,
@@ -60,16 +147,19 @@ pub struct JupiterSwapEvent {
     pub operator: Pubkey,
 }
 
+#[cfg(feature = "legacy-events")]
 #[event]
 pub struct JupiterAggregatorEvent {
     pub user: Pubkey,
     pub input_mint: Pubkey,
     pub output_mint: Pubkey,
     pub input_amount: u64,
-    pub instruction_name: String,
+    pub instruction: VenueInstruction,
+    pub native_output: bool,
     pub operator: Pubkey,
 }
 
+#[cfg(feature = "legacy-events")]
 #[event]
 pub struct FillOrderEngineEvent {
     pub user: Pubkey,
@@ -79,26 +169,70 @@ pub struct FillOrderEngineEvent {
     pub operator: Pubkey,
 }
 
+#[cfg(feature = "legacy-events")]
 #[event]
 pub struct DflowAggregatorEvent {
     pub user: Pubkey,
     pub input_mint: Pubkey,
     pub output_mint: Pubkey,
     pub input_amount: u64,
-    pub instruction_name: String,
+    pub instruction: VenueInstruction,
+    pub native_output: bool,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct TransferFeeEvent {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub gross: u64,
+    pub fee: u64,
+    pub net: u64,
+}
+
+#[event]
+pub struct NativeOutputSettledEvent {
+    pub receiver: Pubkey,
+    pub amount: u64,
+    pub rent_refunded: u64,
+}
+
+#[event]
+pub struct DflowOrderOpenedEvent {
+    pub user: Pubkey,
+    pub order: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_amount: u64,
     pub operator: Pubkey,
 }
 
+#[event]
+pub struct DflowOrderSettledEvent {
+    pub user: Pubkey,
+    pub order: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub output_amount: u64,
+    pub refund_amount: u64,
+    pub operator: Pubkey,
+}
+
+#[cfg(feature = "legacy-events")]
 #[event]
 pub struct OkxAggregatorEvent {
     pub user: Pubkey,
     pub input_mint: Pubkey,
     pub output_mint: Pubkey,
     pub input_amount: u64,
-    pub instruction_name: String,
+    pub instruction: VenueInstruction,
+    pub commission_amount: u64,
+    pub native_output: bool,
     pub operator: Pubkey,
 }
 
+#[cfg(feature = "legacy-events")]
 #[event]
 pub struct JupiterPerpetualsEvent {
     pub user: Pubkey,
@@ -109,6 +243,7 @@ pub struct JupiterPerpetualsEvent {
     pub operator: Pubkey,
 }
 
+#[cfg(feature = "legacy-events")]
 #[event]
 pub struct TwoHopEvent {
     pub user: Pubkey,
@@ -124,3 +259,248 @@ pub struct TwoHopEvent {
     pub step2_action: String,
     pub operator: Pubkey,
 }
+
+#[event]
+pub struct DcaPlanCreatedEvent {
+    pub user: Pubkey,
+    pub plan: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub amount_per_slice: u64,
+    pub interval: i64,
+    pub total_budget: u64,
+}
+
+#[event]
+pub struct DcaPlanCancelledEvent {
+    pub user: Pubkey,
+    pub plan: Pubkey,
+    pub slices_done: u64,
+    pub amount_spent: u64,
+}
+
+#[event]
+pub struct DcaSliceExecutedEvent {
+    pub user: Pubkey,
+    pub plan: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub slices_done: u64,
+    pub amount_spent: u64,
+    pub action: String,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct LimitOrderCreatedEvent {
+    pub user: Pubkey,
+    pub order: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_amount: u64,
+    pub rate_numerator: u64,
+    pub rate_denominator: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct LimitOrderFilledEvent {
+    pub user: Pubkey,
+    pub order: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub remaining_amount: u64,
+    pub action: String,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct LimitOrderExpiredEvent {
+    pub user: Pubkey,
+    pub order: Pubkey,
+    pub remaining_amount: u64,
+}
+
+#[event]
+pub struct LimitOrderCancelledEvent {
+    pub user: Pubkey,
+    pub order: Pubkey,
+    pub remaining_amount: u64,
+}
+
+#[event]
+pub struct BatchTradeEvent {
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub users: u8,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub action: String,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct BatchTradeLegEvent {
+    pub user: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub refund_amount: u64,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct TradeReceiptClosedEvent {
+    pub user: Pubkey,
+    pub client_order_id: u64,
+    pub receipt: Pubkey,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct UserStatsClosedEvent {
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub trade_count: u64,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct InitTradeLogEvent {
+    pub trade_log: Pubkey,
+    pub capacity: u32,
+}
+
+#[event]
+pub struct ResizeTradeLogEvent {
+    pub trade_log: Pubkey,
+    pub old_capacity: u32,
+    pub new_capacity: u32,
+}
+
+#[event]
+pub struct CreditUserEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub credit: u64, // balance after the credit
+}
+
+#[event]
+pub struct DebitUserEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub credit: u64, // balance after the debit
+    pub receiver_token_account: Pubkey,
+}
+
+#[event]
+pub struct DepositEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub received: u64, // credited to the user, net of transfer fees
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct AddDestinationEvent {
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+}
+
+#[event]
+pub struct RemoveDestinationEvent {
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+}
+
+#[event]
+pub struct WithdrawEvent {
+    pub user: Option<Pubkey>, // set when debited from the owner's ledger credit
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub destination_owner: Pubkey,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct TokenReceiveEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub operator: Pubkey,
+}
+
+/// Emitted once per executed trade, whatever the venue.
+#[event]
+pub struct TradeExecuted {
+    pub version: u8,   // see TRADE_EXECUTED_VERSION
+    pub sequence: u64, // per-config trade counter, increases by one per trade
+    pub venue: TradeVenue,
+    pub user: Pubkey,
+    pub operator: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub client_order_id: u64,
+    pub input_amount: u64,      // spent by the trade, net of transfer fees
+    pub refund_amount: u64,     // unused input returned to the delegate
+    pub output_amount: u64,     // delivered to the user, net of protocol fees
+    pub parked_amount: u64,     // left in the vault for token_receive
+    pub fee_amount: u64,        // protocol fee withheld, partner share included
+    pub commission_amount: u64, // venue commission charged on the trade
+    pub slot: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{Discriminator, Event};
+
+    #[test]
+    fn test_fund_movement_events() {
+        // 与 clients/rust 解码使用的 discriminator 一致
+        assert_eq!(
+            DepositEvent::DISCRIMINATOR,
+            [120, 248, 61, 83, 31, 142, 107, 144]
+        );
+        assert_eq!(
+            WithdrawEvent::DISCRIMINATOR,
+            [22, 9, 133, 26, 160, 44, 71, 192]
+        );
+        assert_eq!(
+            TokenReceiveEvent::DISCRIMINATOR,
+            [66, 196, 89, 115, 239, 106, 179, 229]
+        );
+
+        // 转给登记目的地时不记录 user
+        let event = WithdrawEvent {
+            user: None,
+            mint: Pubkey::new_unique(),
+            amount: 42,
+            source: Pubkey::new_unique(),
+            destination: Pubkey::new_unique(),
+            destination_owner: Pubkey::new_unique(),
+            operator: Pubkey::new_unique(),
+        };
+        let data = event.data();
+        assert_eq!(&data[..8], WithdrawEvent::DISCRIMINATOR);
+        assert_eq!(data.len(), 8 + 1 + 32 + 8 + 32 * 4);
+        assert_eq!(
+            WithdrawEvent::try_from_slice(&data[8..]).unwrap().amount,
+            42
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, DESTINATION_SEED};
+use crate::error::ErrorCode;
+use crate::event::{AddDestinationEvent, RemoveDestinationEvent};
+use crate::state::{namespace_seed, Config, Destination};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct AddDestination<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        has_one = admin @ ErrorCode::AdminAuthorityMismatch,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = Destination::LEN,
+        seeds = [DESTINATION_SEED.as_bytes(), namespace_seed(&config.namespace), owner.as_ref()],
+        bump,
+    )]
+    pub destination: Account<'info, Destination>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_add_destination(ctx: Context<AddDestination>, owner: Pubkey) -> Result<()> {
+    let destination = &mut ctx.accounts.destination;
+    destination.owner = owner;
+    destination.bump = ctx.bumps.destination;
+
+    emit_cpi!(AddDestinationEvent {
+        owner,
+        destination: destination.key(),
+    });
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveDestination<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        has_one = admin @ ErrorCode::AdminAuthorityMismatch,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            DESTINATION_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            destination.owner.as_ref(),
+        ],
+        bump = destination.bump,
+    )]
+    pub destination: Account<'info, Destination>,
+}
+
+pub fn process_remove_destination(ctx: Context<RemoveDestination>) -> Result<()> {
+    emit_cpi!(RemoveDestinationEvent {
+        owner: ctx.accounts.destination.owner,
+        destination: ctx.accounts.destination.key(),
+    });
+    Ok(())
+}
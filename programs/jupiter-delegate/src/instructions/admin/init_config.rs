@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{CONFIG_SEED, MINIMUM_TRADE_INTERVAL, VAULT_SEED};
+use crate::constants::{CONFIG_SEED, CONFIG_VERSION, MINIMUM_TRADE_INTERVAL, VAULT_SEED};
 use crate::error::ErrorCode;
 use crate::event::InitConfigEvent;
-use crate::state::Config;
+use crate::state::{namespace_seed, Config};
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(namespace: u16)]
 pub struct InitConfig<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -13,13 +15,13 @@ pub struct InitConfig<'info> {
         init_if_needed,
         payer = admin,
         space = Config::LEN,
-        seeds = [CONFIG_SEED.as_bytes()],
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&namespace)],
         bump,
     )]
     pub config: Account<'info, Config>,
     #[account(
         mut,
-        seeds = [VAULT_SEED.as_bytes()],
+        seeds = [VAULT_SEED.as_bytes(), namespace_seed(&namespace)],
         bump,
     )]
     pub vault: SystemAccount<'info>,
@@ -29,6 +31,7 @@ pub struct InitConfig<'info> {
 
 pub fn process_init_config(
     ctx: Context<InitConfig>,
+    namespace: u16,
     operator: Pubkey,
     cooldown_duration: i64,
 ) -> Result<()> {
@@ -44,13 +47,21 @@ pub fn process_init_config(
     config.pending_admin = Pubkey::default();
     config.last_trade_timestamp = 0;
     config.cooldown_duration = cooldown_duration;
+    config.fee_bps = 0;
+    config.fee_treasury = ctx.accounts.admin.key();
+    config.max_commission_bps = 0;
+    config.per_user_vault = false;
+    config.namespace = namespace;
+    config.trade_sequence = 0;
+    config.version = CONFIG_VERSION;
     config.bump = ctx.bumps.config;
     config.is_initialized = true;
 
-    emit!(InitConfigEvent {
+    emit_cpi!(InitConfigEvent {
         admin: ctx.accounts.admin.key(),
         vault: ctx.accounts.vault.key(),
         cooldown_duration,
+        namespace,
     });
     Ok(())
 }
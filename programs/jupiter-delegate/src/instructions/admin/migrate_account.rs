@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{ACCESS_SEED, ACCESS_VERSION, CONFIG_SEED, CONFIG_VERSION};
+use crate::error::ErrorCode;
+use crate::event::MigrateAccountEvent;
+use crate::instructions::utils::resize_account;
+use crate::state::{namespace_seed, Access, Config};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// May still hold an older layout
+    /// CHECK: address and admin are checked in the handler once upgraded
+    #[account(mut, owner = crate::ID)]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    // 1. 校验地址与 admin, 旧布局缺少的字段按补零读取
+    let config_info = ctx.accounts.config.to_account_info();
+    let mut data = config_info.try_borrow_data()?.to_vec();
+    data.resize(data.len().max(Config::LEN), 0);
+    let mut config = Config::try_deserialize(&mut &data[..])?;
+    let config_address = Pubkey::create_program_address(
+        &[
+            CONFIG_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            &[config.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::Unauthorized)?;
+    require_keys_eq!(config_address, config_info.key(), ErrorCode::Unauthorized);
+    require_keys_eq!(
+        config.admin,
+        ctx.accounts.admin.key(),
+        ErrorCode::AdminAuthorityMismatch
+    );
+
+    // 2. 扩容到当前布局
+    resize_account(
+        &config_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Config::LEN,
+    )?;
+
+    // 3. 升级版本
+    let from_version = config.version;
+    require!(
+        from_version < CONFIG_VERSION,
+        ErrorCode::AccountAlreadyMigrated
+    );
+    config.version = CONFIG_VERSION;
+    config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+    emit_cpi!(MigrateAccountEvent {
+        account: config_info.key(),
+        from_version,
+        to_version: CONFIG_VERSION,
+        new_len: Config::LEN as u64,
+    });
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateAccess<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        has_one = admin @ ErrorCode::AdminAuthorityMismatch,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// May still hold an older layout
+    /// CHECK: address is checked in the handler once upgraded
+    #[account(mut, owner = crate::ID)]
+    pub access: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_migrate_access(ctx: Context<MigrateAccess>) -> Result<()> {
+    // 1. 扩容到当前布局, 新增字段补零
+    let access_info = ctx.accounts.access.to_account_info();
+    resize_account(
+        &access_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Access::LEN,
+    )?;
+
+    // 2. 校验地址属于当前 config 的命名空间
+    let mut access = Access::try_deserialize(&mut &access_info.try_borrow_data()?[..])?;
+    let access_address = Pubkey::create_program_address(
+        &[
+            ACCESS_SEED.as_bytes(),
+            namespace_seed(&ctx.accounts.config.namespace),
+            access.user.as_ref(),
+            &[access.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::Unauthorized)?;
+    require_keys_eq!(access_address, access_info.key(), ErrorCode::Unauthorized);
+
+    // 3. 升级版本
+    let from_version = access.version;
+    require!(
+        from_version < ACCESS_VERSION,
+        ErrorCode::AccountAlreadyMigrated
+    );
+    access.version = ACCESS_VERSION;
+    access.try_serialize(&mut &mut access_info.try_borrow_mut_data()?[..])?;
+
+    emit_cpi!(MigrateAccountEvent {
+        account: access_info.key(),
+        from_version,
+        to_version: ACCESS_VERSION,
+        new_len: Access::LEN as u64,
+    });
+    Ok(())
+}
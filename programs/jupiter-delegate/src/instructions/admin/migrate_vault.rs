@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{CONFIG_SEED, USER_CREDIT_SEED, VAULT_SEED};
+use crate::error::ErrorCode;
+use crate::event::{DebitUserEvent, MigrateUserVaultEvent};
+use crate::instructions::utils::{transfer_from_vault, EventCpi, VaultSeeds, VaultTransfer};
+use crate::state::{namespace_seed, Config, UserCredit};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateUserVault<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        has_one = admin @ ErrorCode::AdminAuthorityMismatch,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: owner of the migrated balance
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace), user.key().as_ref()],
+        bump
+    )]
+    pub user_vault: SystemAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = user @ ErrorCode::InvalidUserCredit,
+        has_one = mint @ ErrorCode::InvalidUserCredit,
+        seeds = [
+            USER_CREDIT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = user_credit.bump,
+    )]
+    pub user_credit: Account<'info, UserCredit>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = user_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub user_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves `amount` of the user's ledger credit from the shared vault into the
+/// user's vault, debiting the credit so it cannot be withdrawn again.
+pub fn process_migrate_user_vault<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateUserVault<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    require!(
+        amount > 0 && ctx.accounts.vault_token_account.amount >= amount,
+        ErrorCode::InvalidVaultMigration
+    );
+
+    // 1. 从用户账本余额中扣减, 只能迁移属于该用户的部分
+    let user_credit = &mut ctx.accounts.user_credit;
+    user_credit.debit(amount)?;
+    emit_cpi!(DebitUserEvent {
+        user: user_credit.user,
+        mint: user_credit.mint,
+        amount,
+        credit: user_credit.amount,
+        receiver_token_account: ctx.accounts.user_vault_token_account.key(),
+    });
+
+    // 2. 从共享 vault 转到用户 vault
+    let received = transfer_from_vault(
+        VaultTransfer {
+            mint: &ctx.accounts.mint,
+            mint_program: &ctx.accounts.token_program,
+            vault: &ctx.accounts.vault,
+            vault_seeds: VaultSeeds::shared(&ctx.accounts.config, ctx.bumps.vault),
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.user_vault_token_account,
+        amount,
+    )?;
+
+    emit_cpi!(MigrateUserVaultEvent {
+        user: ctx.accounts.user.key(),
+        user_vault: ctx.accounts.user_vault.key(),
+        mint: ctx.accounts.mint.key(),
+        amount: received.net,
+    });
+    Ok(())
+}
@@ -1,9 +1,19 @@
+mod destination;
 mod init_config;
+mod migrate_account;
+mod migrate_vault;
 mod modify_access;
 mod modify_config;
+mod trade_log;
 mod transfer_admin;
+mod withdraw_fee;
 
+pub use destination::*;
 pub use init_config::*;
+pub use migrate_account::*;
+pub use migrate_vault::*;
 pub use modify_access::*;
 pub use modify_config::*;
+pub use trade_log::*;
 pub use transfer_admin::*;
+pub use withdraw_fee::*;
@@ -1,16 +1,20 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::ACCESS_SEED;
+use crate::constants::{ACCESS_SEED, ACCESS_VERSION, CONFIG_SEED};
 use crate::error::ErrorCode;
-use crate::event::{GrantAccessEvent, RevokeAccessEvent};
-use crate::state::{Access, Config};
+use crate::event::{GrantAccessEvent, ModifyAccessIntentEvent, RevokeAccessEvent};
+use crate::state::{namespace_seed, Access, Config, Partner};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(user: Pubkey)]
 pub struct GrantAccess<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
     #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
         has_one = admin,
     )]
     pub config: Account<'info, Config>,
@@ -18,10 +22,11 @@ pub struct GrantAccess<'info> {
         init_if_needed,
         payer = admin,
         space = Access::LEN,
-        seeds = [ACCESS_SEED.as_bytes(), user.as_ref()],
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.as_ref()],
         bump,
     )]
     pub access: Account<'info, Access>,
+    pub partner: Option<Account<'info, Partner>>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -32,28 +37,41 @@ pub fn process_grant_access(ctx: Context<GrantAccess>, user: Pubkey) -> Result<(
     access.user = user;
     access.is_granted = true;
     access.bump = ctx.bumps.access;
+    access.version = ACCESS_VERSION;
+    access.partner = ctx
+        .accounts
+        .partner
+        .as_ref()
+        .map(|partner| partner.key())
+        .unwrap_or_default();
 
-    emit!(GrantAccessEvent {
+    emit_cpi!(GrantAccessEvent {
         user,
-        access: ctx.accounts.access.key(),
+        access: access.key(),
+        partner: access.partner,
     });
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(user: Pubkey)]
 pub struct RevokeAccess<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
     #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
         has_one = admin,
     )]
     pub config: Account<'info, Config>,
     #[account(
         mut,
         close = admin,
-        seeds = [ACCESS_SEED.as_bytes(), user.as_ref()],
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.as_ref()],
         bump,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
     )]
     pub access: Account<'info, Access>,
     pub system_program: Program<'info, System>,
@@ -65,9 +83,47 @@ pub fn process_revoke_access(ctx: Context<RevokeAccess>, user: Pubkey) -> Result
     require!(access.is_granted, ErrorCode::AccessNotGranted);
     access.is_granted = false;
 
-    emit!(RevokeAccessEvent {
+    emit_cpi!(RevokeAccessEvent {
         user,
         access: ctx.accounts.access.key(),
     });
     Ok(())
 }
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct ModifyAccessIntent<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        has_one = admin,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.as_ref()],
+        bump = access.bump,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub access: Account<'info, Access>,
+}
+
+/// Requires (or stops requiring) a user-signed intent for each of the user's trades.
+pub fn process_modify_access_intent(
+    ctx: Context<ModifyAccessIntent>,
+    user: Pubkey,
+    intent_required: bool,
+) -> Result<()> {
+    let access = &mut ctx.accounts.access;
+    access.intent_required = intent_required;
+
+    emit_cpi!(ModifyAccessIntentEvent {
+        user,
+        access: access.key(),
+        intent_required,
+    });
+    Ok(())
+}
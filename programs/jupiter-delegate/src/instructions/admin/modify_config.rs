@@ -1,18 +1,23 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{CONFIG_SEED, MINIMUM_TRADE_INTERVAL};
+use crate::constants::{BPS_DENOMINATOR, CONFIG_SEED, MAX_FEE_BPS, MINIMUM_TRADE_INTERVAL};
 use crate::error::ErrorCode;
-use crate::event::{ModifyCooldownDurationEvent, ModifyOperatorEvent, PauseEvent};
-use crate::state::Config;
+use crate::event::{
+    ModifyCooldownDurationEvent, ModifyMaxCommissionEvent, ModifyOperatorEvent,
+    ModifyProtocolFeeEvent, ModifyVaultModeEvent, PauseEvent,
+};
+use crate::state::{namespace_seed, Config};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ModifyCooldownDuration<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
     #[account(
         mut,
-        seeds = [CONFIG_SEED.as_bytes()],
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
         bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
         constraint = config.admin == admin.key() @ ErrorCode::OnlyAdminCanModifyCooldownDuration,
     )]
     pub config: Account<'info, Config>,
@@ -29,21 +34,23 @@ pub fn process_modify_cooldown_duration(
         ErrorCode::InvalidCooldownDuration
     );
     config.cooldown_duration = cooldown_duration;
-    emit!(ModifyCooldownDurationEvent {
+    emit_cpi!(ModifyCooldownDurationEvent {
         config: config.key(),
         cooldown_duration,
     });
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ModifyOperator<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
     #[account(
         mut,
-        seeds = [CONFIG_SEED.as_bytes()],
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
         bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
         constraint = config.admin == admin.key() @ ErrorCode::OnlyAdminCanModifyOperator,
     )]
     pub config: Account<'info, Config>,
@@ -52,21 +59,23 @@ pub struct ModifyOperator<'info> {
 pub fn process_modify_operator(ctx: Context<ModifyOperator>, operator: Pubkey) -> Result<()> {
     let config = &mut ctx.accounts.config;
     config.operator = operator;
-    emit!(ModifyOperatorEvent {
+    emit_cpi!(ModifyOperatorEvent {
         config: config.key(),
         operator,
     });
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Pause<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
     #[account(
         mut,
-        seeds = [CONFIG_SEED.as_bytes()],
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
         bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
         constraint = config.admin == admin.key() @ ErrorCode::OnlyAdminCanPause,
     )]
     pub config: Account<'info, Config>,
@@ -76,9 +85,101 @@ pub struct Pause<'info> {
 pub fn process_pause(ctx: Context<Pause>, toggle: bool) -> Result<()> {
     let config = &mut ctx.accounts.config;
     config.is_paused = toggle;
-    emit!(PauseEvent {
+    emit_cpi!(PauseEvent {
         config: config.key(),
         toggle,
     });
     Ok(())
 }
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ModifyProtocolFee<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        constraint = config.admin == admin.key() @ ErrorCode::OnlyAdminCanModifyProtocolFee,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn process_modify_protocol_fee(
+    ctx: Context<ModifyProtocolFee>,
+    fee_bps: u16,
+    fee_treasury: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(fee_bps <= MAX_FEE_BPS, ErrorCode::InvalidFeeBps);
+    config.fee_bps = fee_bps;
+    config.fee_treasury = fee_treasury;
+    emit_cpi!(ModifyProtocolFeeEvent {
+        config: config.key(),
+        fee_bps,
+        fee_treasury,
+    });
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ModifyMaxCommission<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        constraint = config.admin == admin.key() @ ErrorCode::OnlyAdminCanModifyMaxCommission,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn process_modify_max_commission(
+    ctx: Context<ModifyMaxCommission>,
+    max_commission_bps: u16,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(
+        max_commission_bps as u64 <= BPS_DENOMINATOR,
+        ErrorCode::InvalidFeeBps
+    );
+    config.max_commission_bps = max_commission_bps;
+    emit_cpi!(ModifyMaxCommissionEvent {
+        config: config.key(),
+        max_commission_bps,
+    });
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ModifyVaultMode<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        constraint = config.admin == admin.key() @ ErrorCode::OnlyAdminCanModifyVaultMode,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn process_modify_vault_mode(
+    ctx: Context<ModifyVaultMode>,
+    per_user_vault: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.per_user_vault = per_user_vault;
+    emit_cpi!(ModifyVaultModeEvent {
+        config: config.key(),
+        per_user_vault,
+    });
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, MAX_TRADE_LOG_CAPACITY, TRADE_LOG_SEED};
+use crate::error::ErrorCode;
+use crate::event::{InitTradeLogEvent, ResizeTradeLogEvent};
+use crate::instructions::utils::resize_account;
+use crate::state::{namespace_seed, read_trade_log_page, Config, TradeLog};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(capacity: u32)]
+pub struct InitTradeLog<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        has_one = admin @ ErrorCode::AdminAuthorityMismatch,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = TradeLog::space(capacity),
+        seeds = [TRADE_LOG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump,
+    )]
+    pub trade_log: AccountLoader<'info, TradeLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the recent-trades log. Accounts created through CPI are limited in
+/// size, larger logs are grown afterwards with `resize_trade_log`.
+pub fn process_init_trade_log(ctx: Context<InitTradeLog>, capacity: u32) -> Result<()> {
+    require!(
+        capacity > 0 && capacity <= MAX_TRADE_LOG_CAPACITY,
+        ErrorCode::InvalidTradeLogCapacity
+    );
+    let mut trade_log = ctx.accounts.trade_log.load_init()?;
+    trade_log.total = 0;
+    trade_log.first_sequence = 0;
+    trade_log.capacity = capacity;
+    trade_log.bump = ctx.bumps.trade_log;
+
+    emit_cpi!(InitTradeLogEvent {
+        trade_log: ctx.accounts.trade_log.key(),
+        capacity,
+    });
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResizeTradeLog<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        has_one = admin @ ErrorCode::AdminAuthorityMismatch,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [TRADE_LOG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = trade_log.load()?.bump,
+    )]
+    pub trade_log: AccountLoader<'info, TradeLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Changes the log capacity, keeping the most recent entries. Each call may
+/// grow the account by at most 10 KiB.
+pub fn process_resize_trade_log(ctx: Context<ResizeTradeLog>, capacity: u32) -> Result<()> {
+    require!(
+        capacity > 0 && capacity <= MAX_TRADE_LOG_CAPACITY,
+        ErrorCode::InvalidTradeLogCapacity
+    );
+    let trade_log_info = ctx.accounts.trade_log.to_account_info();
+
+    // 1. 读出保留的最近成交
+    let (old_capacity, total) = {
+        let trade_log = ctx.accounts.trade_log.load()?;
+        (trade_log.capacity, trade_log.total)
+    };
+    let kept = read_trade_log_page(
+        &trade_log_info.try_borrow_data()?,
+        total.saturating_sub(capacity as u64),
+        capacity as usize,
+    )?;
+
+    // 2. 调整账户大小, 多退少补租金
+    resize_account(
+        &trade_log_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        TradeLog::space(capacity),
+    )?;
+
+    // 3. 按新容量重新排布
+    {
+        let mut data = trade_log_info.try_borrow_mut_data()?;
+        let (header, entries) = data.split_at_mut(TradeLog::HEADER_LEN);
+        let trade_log: &mut TradeLog = bytemuck::from_bytes_mut(&mut header[8..]);
+        trade_log.resize(bytemuck::cast_slice_mut(entries), kept, capacity);
+    }
+
+    emit_cpi!(ResizeTradeLogEvent {
+        trade_log: trade_log_info.key(),
+        old_capacity,
+        new_capacity: capacity,
+    });
+    Ok(())
+}
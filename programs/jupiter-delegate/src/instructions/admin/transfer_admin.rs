@@ -3,8 +3,9 @@ use anchor_lang::prelude::*;
 use crate::constants::CONFIG_SEED;
 use crate::error::ErrorCode;
 use crate::event::{AdminTransferCompletedEvent, AdminTransferProposedEvent};
-use crate::state::Config;
+use crate::state::{namespace_seed, Config};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ProposeNewAdmin<'info> {
     #[account(mut)]
@@ -15,8 +16,9 @@ pub struct ProposeNewAdmin<'info> {
 
     #[account(
         mut,
-        seeds = [CONFIG_SEED.as_bytes()],
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
         bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
         constraint = config.admin == current_admin.key() @ ErrorCode::OnlyAdminCanProposeNewAdmin,
     )]
     pub config: Box<Account<'info, Config>>,
@@ -24,6 +26,7 @@ pub struct ProposeNewAdmin<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AcceptAdminTransfer<'info> {
     #[account(mut)]
@@ -31,8 +34,9 @@ pub struct AcceptAdminTransfer<'info> {
 
     #[account(
         mut,
-        seeds = [CONFIG_SEED.as_bytes()],
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
         bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
         constraint = config.pending_admin == new_admin.key() @ ErrorCode::OnlyProposedAdminCanAccept,
     )]
     pub config: Box<Account<'info, Config>>,
@@ -55,7 +59,7 @@ pub fn process_propose_new_admin(ctx: Context<ProposeNewAdmin>) -> Result<()> {
 
     config.pending_admin = ctx.accounts.proposed_admin.key();
 
-    emit!(AdminTransferProposedEvent {
+    emit_cpi!(AdminTransferProposedEvent {
         config: config.key(),
         current_admin: ctx.accounts.current_admin.key(),
         proposed_admin: ctx.accounts.proposed_admin.key(),
@@ -77,7 +81,7 @@ pub fn process_accept_admin_transfer(ctx: Context<AcceptAdminTransfer>) -> Resul
     config.admin = ctx.accounts.new_admin.key();
     config.pending_admin = Pubkey::default();
 
-    emit!(AdminTransferCompletedEvent {
+    emit_cpi!(AdminTransferCompletedEvent {
         config: config.key(),
         previous_admin,
         new_admin: ctx.accounts.new_admin.key(),
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{CONFIG_SEED, FEE_VAULT_SEED, VAULT_SEED};
+use crate::error::ErrorCode;
+use crate::event::WithdrawProtocolFeeEvent;
+use crate::instructions::utils::{transfer_from_vault, EventCpi, VaultSeeds, VaultTransfer};
+use crate::state::{namespace_seed, Config};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawProtocolFee<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        has_one = admin @ ErrorCode::AdminAuthorityMismatch,
+        has_one = fee_treasury,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_bytes(), namespace_seed(&config.namespace), mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program,
+    )]
+    pub fee_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: owner of the fee treasury, checked against config
+    pub fee_treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = fee_treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_withdraw_protocol_fee<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawProtocolFee<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    require!(
+        ctx.accounts.fee_token_account.amount >= amount,
+        ErrorCode::InsufficientFunds
+    );
+
+    transfer_from_vault(
+        VaultTransfer {
+            mint: &ctx.accounts.mint,
+            mint_program: &ctx.accounts.token_program,
+            vault: &ctx.accounts.vault,
+            vault_seeds: VaultSeeds::shared(&ctx.accounts.config, ctx.bumps.vault),
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
+        &ctx.accounts.fee_token_account,
+        &ctx.accounts.treasury_token_account,
+        amount,
+    )?;
+
+    emit_cpi!(WithdrawProtocolFeeEvent {
+        config: ctx.accounts.config.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        fee_treasury: ctx.accounts.fee_treasury.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,687 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, CloseAccount, Mint, TokenAccount, TokenInterface,
+};
+
+use crate::{
+    append_trade_log,
+    constants::{
+        ACCESS_SEED, CONFIG_SEED, DFLOW_ORDER_OUTPUT_SEED, DFLOW_ORDER_REFUND_SEED,
+        DFLOW_ORDER_SEED, DISCRIMINATOR_DFLOW_CLOSE_ORDER, DISCRIMINATOR_DFLOW_OPEN_ORDER,
+        DISCRIMINATOR_DFLOW_ORDER_ACCOUNT, FEE_VAULT_SEED, TRADE_EXECUTED_VERSION, TRADE_LOG_SEED,
+        TRADE_RECEIPT_SEED, USER_STATS_SEED, VAULT_SEED,
+    },
+    dflow_aggregator,
+    dflow_aggregator::program::SwapOrchestrator,
+    dflow_program_id,
+    error::ErrorCode,
+    execute_cross_program_invocation, is_token_account_empty, load_partner_share,
+    record_trade_receipt, record_user_stats,
+    state::{
+        namespace_seed, Access, Config, DflowOrder, TradeLog, TradeLogEntry, TradeVenue, UserStats,
+    },
+    transfer_from_vault, transfer_output_tokens, validate_and_transfer_input, validate_operator,
+    DelegatedInput, DflowOrderOpenedEvent, DflowOrderSettledEvent, EventCpi, ExecutedTrade,
+    Partner, ProtocolFee, TradeExecuted, TradeReceipt, VaultSeeds, VaultTransfer,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DflowOpenOrderParams {
+    pub data: Vec<u8>,
+    pub order_account_id: u64,
+    pub delegate: Pubkey,
+    pub client_order_id: u64,
+}
+
+impl DflowOpenOrderParams {
+    fn get_open_order_params(&self) -> Result<dflow_aggregator::types::OpenOrderParams> {
+        require!(
+            self.data.len() > 8 && self.data[..8].eq(DISCRIMINATOR_DFLOW_OPEN_ORDER),
+            ErrorCode::InvalidDflowOrderData
+        );
+        let params = dflow_aggregator::types::OpenOrderParams::try_from_slice(&self.data[8..])
+            .map_err(|_| ErrorCode::InvalidDflowOrderData)?;
+        require!(
+            params.order_account_id == self.order_account_id,
+            ErrorCode::InvalidDflowOrderData
+        );
+        Ok(params)
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: DflowOpenOrderParams)]
+pub struct DflowOpenOrder<'info> {
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub input_mint_program: Interface<'info, TokenInterface>,
+    pub output_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub output_mint_program: Interface<'info, TokenInterface>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            config.vault_user_seed(user.key),
+        ],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub delegate_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = input_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = input_mint_program,
+    )]
+    pub vault_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.key().as_ref()],
+        bump,
+        constraint = access.is_granted @ ErrorCode::AccessNotGranted,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        constraint = !access.intent_required @ ErrorCode::IntentNotSupported,
+    )]
+    pub access: Account<'info, Access>,
+
+    /// CHECK: This is the user's account
+    pub user: UncheckedAccount<'info>,
+
+    /// CHECK: DFlow order account, created by the open_order CPI
+    #[account(mut)]
+    pub order: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = DflowOrder::LEN,
+        seeds = [
+            DFLOW_ORDER_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &args.order_account_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub dflow_order: Box<Account<'info, DflowOrder>>,
+
+    #[account(
+        init,
+        payer = operator,
+        seeds = [DFLOW_ORDER_OUTPUT_SEED.as_bytes(), dflow_order.key().as_ref()],
+        bump,
+        token::mint = output_mint,
+        token::authority = vault,
+        token::token_program = output_mint_program,
+    )]
+    pub order_output_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = operator,
+        seeds = [DFLOW_ORDER_REFUND_SEED.as_bytes(), dflow_order.key().as_ref()],
+        bump,
+        token::mint = input_mint,
+        token::authority = vault,
+        token::token_program = input_mint_program,
+    )]
+    pub order_refund_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub dflow_program: Program<'info, SwapOrchestrator>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = TradeReceipt::LEN,
+        seeds = [
+            TRADE_RECEIPT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &args.client_order_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub trade_receipt: Box<Account<'info, TradeReceipt>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_dflow_open_order<'info>(
+    ctx: Context<'_, '_, '_, 'info, DflowOpenOrder<'info>>,
+    args: DflowOpenOrderParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    let vault_seeds = VaultSeeds::new(&ctx.accounts.config, ctx.accounts.user.key, ctx.bumps.vault);
+
+    let params = args.get_open_order_params()?;
+
+    // 1. 验证并转移输入代币
+    let received = validate_and_transfer_input(
+        &ctx.accounts.operator.to_account_info(),
+        &mut ctx.accounts.config,
+        &ctx.accounts.vault.to_account_info(),
+        vault_seeds,
+        DelegatedInput {
+            delegate: &args.delegate,
+            delegate_input_token_account: &ctx.accounts.delegate_input_token_account,
+            input_mint: &ctx.accounts.input_mint.to_account_info(),
+            input_mint_program: &ctx.accounts.input_mint_program.to_account_info(),
+            vault_input_token_account: &ctx.accounts.vault_input_token_account.to_account_info(),
+            decimals: ctx.accounts.input_mint.decimals,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
+        params.input_amount,
+    )?;
+
+    // 2. CPI open_order, vault 作为 input authority / closer
+    require!(
+        ctx.remaining_accounts
+            .iter()
+            .any(|account| account.key == ctx.accounts.order.key),
+        ErrorCode::DflowOrderMismatch
+    );
+    ctx.accounts.vault_input_token_account.reload()?;
+    let initial_input_balance = ctx.accounts.vault_input_token_account.amount;
+
+    execute_cross_program_invocation(
+        ctx.accounts.dflow_program.key,
+        &dflow_program_id(),
+        ctx.remaining_accounts,
+        &ctx.accounts.vault.key(),
+        vault_seeds,
+        args.data,
+        None,
+    )?;
+
+    ctx.accounts.vault_input_token_account.reload()?;
+    let escrowed_amount =
+        initial_input_balance.saturating_sub(ctx.accounts.vault_input_token_account.amount);
+    require!(escrowed_amount > 0, ErrorCode::InvalidDflowOrderData);
+    require!(
+        escrowed_amount <= received.net,
+        ErrorCode::InsufficientFunds
+    );
+
+    // 3. 检查 order 由 DFlow 创建, 以及 closer 与结算账户
+    // order 在 CPI 中才创建, 因此 owner 在 CPI 之后检查
+    require_keys_eq!(
+        *ctx.accounts.order.owner,
+        dflow_program_id(),
+        ErrorCode::DflowOrderMismatch
+    );
+    {
+        let data = ctx.accounts.order.try_borrow_data()?;
+        require!(
+            data.len() >= 8 + 32 * 3 && data[..8].eq(DISCRIMINATOR_DFLOW_ORDER_ACCOUNT),
+            ErrorCode::DflowOrderMismatch
+        );
+        let closer = Pubkey::new_from_array(*arrayref::array_ref![data, 8, 32]);
+        let output_token_account = Pubkey::new_from_array(*arrayref::array_ref![data, 40, 32]);
+        let return_input_token_account =
+            Pubkey::new_from_array(*arrayref::array_ref![data, 72, 32]);
+        require_keys_eq!(
+            closer,
+            ctx.accounts.vault.key(),
+            ErrorCode::DflowOrderMismatch
+        );
+        require_keys_eq!(
+            output_token_account,
+            ctx.accounts.order_output_token_account.key(),
+            ErrorCode::DflowOrderMismatch
+        );
+        require_keys_eq!(
+            return_input_token_account,
+            ctx.accounts.order_refund_token_account.key(),
+            ErrorCode::DflowOrderMismatch
+        );
+    }
+
+    // 4. 未托管的输入退回 delegate
+    transfer_from_vault(
+        VaultTransfer {
+            mint: &ctx.accounts.input_mint,
+            mint_program: &ctx.accounts.input_mint_program,
+            vault: &ctx.accounts.vault,
+            vault_seeds,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
+        &ctx.accounts.vault_input_token_account,
+        &ctx.accounts.delegate_input_token_account,
+        received.net - escrowed_amount,
+    )?;
+
+    // 5. 记录 order
+    let dflow_order = &mut ctx.accounts.dflow_order;
+    dflow_order.order = ctx.accounts.order.key();
+    dflow_order.user = ctx.accounts.user.key();
+    dflow_order.delegate = args.delegate;
+    dflow_order.input_mint = ctx.accounts.input_mint.key();
+    dflow_order.output_mint = ctx.accounts.output_mint.key();
+    dflow_order.order_account_id = args.order_account_id;
+    dflow_order.in_amount = escrowed_amount;
+    dflow_order.opened_at = Clock::get()?.unix_timestamp;
+    dflow_order.bump = ctx.bumps.dflow_order;
+    dflow_order.per_user_vault = ctx.accounts.config.per_user_vault;
+    dflow_order.client_order_id = args.client_order_id;
+
+    // 6. 记录成交回执, 输出在结算时补记
+    record_trade_receipt(
+        &mut ctx.accounts.trade_receipt,
+        ExecutedTrade {
+            user: ctx.accounts.user.key(),
+            client_order_id: args.client_order_id,
+            operator: ctx.accounts.operator.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            input_amount: escrowed_amount,
+            output_amount: 0,
+        },
+        ctx.bumps.trade_receipt,
+    )?;
+
+    // 7. emit event
+    emit_cpi!(DflowOrderOpenedEvent {
+        user: ctx.accounts.user.key(),
+        order: ctx.accounts.order.key(),
+        input_mint: ctx.accounts.input_mint.key(),
+        output_mint: ctx.accounts.output_mint.key(),
+        input_amount: escrowed_amount,
+        operator: ctx.accounts.operator.key(),
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DflowCloseOrder<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            dflow_order.vault_user_seed(),
+        ],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [
+            DFLOW_ORDER_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            dflow_order.user.as_ref(),
+            &dflow_order.order_account_id.to_le_bytes(),
+        ],
+        bump = dflow_order.bump,
+    )]
+    pub dflow_order: Box<Account<'info, DflowOrder>>,
+
+    /// CHECK: DFlow order account recorded at open
+    #[account(mut, address = dflow_order.order)]
+    pub order: UncheckedAccount<'info>,
+
+    pub dflow_program: Program<'info, SwapOrchestrator>,
+}
+
+pub fn process_dflow_close_order<'info>(
+    ctx: Context<'_, '_, '_, 'info, DflowCloseOrder<'info>>,
+) -> Result<()> {
+    let vault_seeds = VaultSeeds::with_mode(
+        &ctx.accounts.config,
+        ctx.accounts.dflow_order.per_user_vault,
+        &ctx.accounts.dflow_order.user,
+        ctx.bumps.vault,
+    );
+
+    validate_operator(&ctx.accounts.operator, &ctx.accounts.config)?;
+
+    // vault 是 order 的 closer, 剩余输入退回 order_refund_token_account
+    execute_cross_program_invocation(
+        ctx.accounts.dflow_program.key,
+        &dflow_program_id(),
+        ctx.remaining_accounts,
+        &ctx.accounts.vault.key(),
+        vault_seeds,
+        DISCRIMINATOR_DFLOW_CLOSE_ORDER.to_vec(),
+        None,
+    )?;
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DflowSettleOrder<'info> {
+    #[account(address = dflow_order.input_mint)]
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub input_mint_program: Interface<'info, TokenInterface>,
+    #[account(address = dflow_order.output_mint)]
+    pub output_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub output_mint_program: Interface<'info, TokenInterface>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            dflow_order.vault_user_seed(),
+        ],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        close = operator,
+        seeds = [
+            DFLOW_ORDER_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            dflow_order.user.as_ref(),
+            &dflow_order.order_account_id.to_le_bytes(),
+        ],
+        bump = dflow_order.bump,
+    )]
+    pub dflow_order: Box<Account<'info, DflowOrder>>,
+
+    /// CHECK: DFlow order account recorded at open, closed once filled or closed
+    #[account(address = dflow_order.order)]
+    pub order: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [DFLOW_ORDER_OUTPUT_SEED.as_bytes(), dflow_order.key().as_ref()],
+        bump,
+        token::mint = output_mint,
+        token::authority = vault,
+        token::token_program = output_mint_program,
+    )]
+    pub order_output_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [DFLOW_ORDER_REFUND_SEED.as_bytes(), dflow_order.key().as_ref()],
+        bump,
+        token::mint = input_mint,
+        token::authority = vault,
+        token::token_program = input_mint_program,
+    )]
+    pub order_refund_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: This is the user's account
+    #[account(address = dflow_order.user)]
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.key().as_ref()],
+        bump,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub access: Account<'info, Access>,
+
+    /// CHECK: This is the delegate account
+    #[account(address = dflow_order.delegate)]
+    pub delegate: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = output_mint,
+        associated_token::authority = user,
+        associated_token::token_program = output_mint_program,
+    )]
+    pub receiver_output_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = input_mint,
+        associated_token::authority = delegate,
+        associated_token::token_program = input_mint_program,
+    )]
+    pub delegate_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        seeds = [
+            FEE_VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            output_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = output_mint,
+        token::authority = fee_vault_authority,
+        token::token_program = output_mint_program,
+    )]
+    pub fee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Shared vault, authority of the protocol fee vaults in either vault mode
+    #[account(seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)], bump)]
+    pub fee_vault_authority: UncheckedAccount<'info>,
+
+    #[account(address = access.partner @ ErrorCode::InvalidPartner)]
+    pub partner: Option<Box<Account<'info, Partner>>>,
+
+    #[account(mut)]
+    pub partner_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [
+            TRADE_RECEIPT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            dflow_order.user.as_ref(),
+            &dflow_order.client_order_id.to_le_bytes(),
+        ],
+        bump = trade_receipt.bump,
+    )]
+    pub trade_receipt: Box<Account<'info, TradeReceipt>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = UserStats::LEN,
+        seeds = [
+            USER_STATS_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    #[account(
+        mut,
+        seeds = [TRADE_LOG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = trade_log.load()?.bump,
+    )]
+    pub trade_log: AccountLoader<'info, TradeLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_dflow_settle_order<'info>(
+    ctx: Context<'_, '_, '_, 'info, DflowSettleOrder<'info>>,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    let vault_seeds = VaultSeeds::with_mode(
+        &ctx.accounts.config,
+        ctx.accounts.dflow_order.per_user_vault,
+        &ctx.accounts.dflow_order.user,
+        ctx.bumps.vault,
+    );
+
+    validate_operator(&ctx.accounts.operator, &ctx.accounts.config)?;
+
+    // 1. fill 或 close 之后 dflow order 账户会被关闭
+    require!(
+        ctx.accounts.order.data_is_empty(),
+        ErrorCode::DflowOrderStillOpen
+    );
+
+    // 2. 输出给 user, 剩余输入退回 delegate
+    let output_amount = ctx.accounts.order_output_token_account.amount;
+    let refund_amount = ctx.accounts.order_refund_token_account.amount;
+    let output = transfer_output_tokens(
+        VaultTransfer {
+            mint: &ctx.accounts.output_mint,
+            mint_program: &ctx.accounts.output_mint_program,
+            vault: &ctx.accounts.vault,
+            vault_seeds,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
+        &ctx.accounts.order_output_token_account,
+        Some(&ctx.accounts.receiver_output_token_account),
+        0,
+        Some(ProtocolFee::new(
+            ctx.accounts.config.fee_bps,
+            &ctx.accounts.fee_token_account,
+            load_partner_share(
+                &ctx.accounts.access,
+                ctx.accounts.partner.as_deref(),
+                ctx.accounts.partner_fee_token_account.as_deref(),
+                &ctx.accounts.output_mint.key(),
+            )?,
+        )),
+        None,
+    )?;
+    transfer_output_tokens(
+        VaultTransfer {
+            mint: &ctx.accounts.input_mint,
+            mint_program: &ctx.accounts.input_mint_program,
+            vault: &ctx.accounts.vault,
+            vault_seeds,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
+        &ctx.accounts.order_refund_token_account,
+        Some(&ctx.accounts.delegate_input_token_account),
+        0,
+        None,
+        None,
+    )?;
+
+    // 3. 关闭 order 的托管账户, 仍有余额 (dust 或 withheld fee) 时保留, 不阻塞结算
+    let signed_seeds = &vault_seeds.signer_seeds();
+    for (token_account, token_program) in [
+        (
+            ctx.accounts.order_output_token_account.to_account_info(),
+            ctx.accounts.output_mint_program.to_account_info(),
+        ),
+        (
+            ctx.accounts.order_refund_token_account.to_account_info(),
+            ctx.accounts.input_mint_program.to_account_info(),
+        ),
+    ] {
+        if !is_token_account_empty(&token_account)? {
+            continue;
+        }
+        close_account(CpiContext::new_with_signer(
+            token_program,
+            CloseAccount {
+                account: token_account,
+                destination: ctx.accounts.operator.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            &[signed_seeds],
+        ))?;
+    }
+
+    // 4. 补记成交回执, 用户统计与成交日志
+    let input_amount = ctx.accounts.dflow_order.filled_input_amount(refund_amount);
+    let trade_receipt = &mut ctx.accounts.trade_receipt;
+    trade_receipt.input_amount = input_amount;
+    trade_receipt.output_amount = output.delivered;
+    record_user_stats(
+        &mut ctx.accounts.user_stats,
+        ctx.accounts.user.key(),
+        ctx.bumps.user_stats,
+        &ctx.accounts.input_mint.key(),
+        input_amount,
+        &ctx.accounts.output_mint.key(),
+        output,
+    )?;
+    append_trade_log(
+        &ctx.accounts.trade_log,
+        TradeLogEntry {
+            user: ctx.accounts.user.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            operator: ctx.accounts.operator.key(),
+            input_amount,
+            output_amount: output.delivered,
+            slot: Clock::get()?.slot,
+            venue: TradeVenue::Dflow as u8,
+            padding: [0; 7],
+        },
+    )?;
+
+    // 5. emit event
+    emit_cpi!(TradeExecuted {
+        version: TRADE_EXECUTED_VERSION,
+        sequence: ctx.accounts.config.next_trade_sequence(),
+        venue: TradeVenue::Dflow,
+        user: ctx.accounts.user.key(),
+        operator: ctx.accounts.operator.key(),
+        input_mint: ctx.accounts.input_mint.key(),
+        output_mint: ctx.accounts.output_mint.key(),
+        client_order_id: ctx.accounts.dflow_order.client_order_id,
+        input_amount,
+        refund_amount,
+        output_amount: output.delivered,
+        parked_amount: output.parked,
+        fee_amount: output.protocol_fee,
+        commission_amount: 0,
+        slot: Clock::get()?.slot,
+    });
+    emit_cpi!(DflowOrderSettledEvent {
+        user: ctx.accounts.user.key(),
+        order: ctx.accounts.order.key(),
+        input_mint: ctx.accounts.input_mint.key(),
+        output_mint: ctx.accounts.output_mint.key(),
+        output_amount,
+        refund_amount,
+        operator: ctx.accounts.operator.key(),
+    });
+
+    Ok(())
+}
@@ -1,14 +1,22 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
+#[cfg(feature = "legacy-events")]
+use crate::FillOrderEngineEvent;
 use crate::{
-    constants::{ACCESS_SEED, VAULT_SEED},
+    append_trade_log,
+    constants::{
+        ACCESS_SEED, CONFIG_SEED, FEE_VAULT_SEED, TRADE_EXECUTED_VERSION, TRADE_LOG_SEED,
+        TRADE_RECEIPT_SEED, USER_STATS_SEED, VAULT_SEED,
+    },
     error::ErrorCode,
     execute_cross_program_invocation,
     jupiter_order_engine::program::OrderEngine,
-    jupiter_order_engine_program_id,
-    state::Config,
-    validate_and_transfer_input, Access, FillOrderEngineEvent,
+    jupiter_order_engine_program_id, load_partner_share, record_trade_receipt, record_user_stats,
+    state::{namespace_seed, Config},
+    validate_and_transfer_input, verify_trade_intent, Access, DelegatedInput, EventCpi,
+    ExecutedTrade, Partner, ProtocolFee, TradeExecuted, TradeIntent, TradeLog, TradeLogEntry,
+    TradeReceipt, TradeTerms, TradeVenue, UserStats, VaultSeeds, VaultTransfer, VenueOutput,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -16,9 +24,13 @@ pub struct FillOrderEngineParams {
     pub data: Vec<u8>,
     pub in_amount: u64,
     pub delegate: Pubkey,
+    pub client_order_id: u64,
+    pub intent: Option<TradeIntent>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(params: FillOrderEngineParams)]
 pub struct FillOrderEngine<'info> {
     pub input_mint: InterfaceAccount<'info, Mint>,
     pub input_mint_program: Interface<'info, TokenInterface>,
@@ -29,7 +41,11 @@ pub struct FillOrderEngine<'info> {
     pub operator: Signer<'info>,
     #[account(
         mut,
-        seeds=[VAULT_SEED.as_bytes()],
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            config.vault_user_seed(user.key),
+        ],
         bump
     )]
     pub vault: SystemAccount<'info>,
@@ -47,14 +63,20 @@ pub struct FillOrderEngine<'info> {
         associated_token::token_program = output_mint_program,
     )]
     pub vault_output_token_account: InterfaceAccount<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
     pub config: Box<Account<'info, Config>>,
     #[account(mut)]
     pub delegate_input_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(
-        seeds = [ACCESS_SEED.as_bytes(), user.key().as_ref()],
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.key().as_ref()],
         bump,
-        constraint = access.is_granted @ ErrorCode::AccessNotGranted
+        constraint = access.is_granted @ ErrorCode::AccessNotGranted,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
     )]
     pub access: Account<'info, Access>,
     /// CHECK: This is the user's account
@@ -68,46 +90,210 @@ pub struct FillOrderEngine<'info> {
     )]
     pub receiver_output_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        init_if_needed,
+        payer = operator,
+        seeds = [
+            FEE_VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            output_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = output_mint,
+        token::authority = fee_vault_authority,
+        token::token_program = output_mint_program,
+    )]
+    pub fee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Shared vault, authority of the protocol fee vaults in either vault mode
+    #[account(seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)], bump)]
+    pub fee_vault_authority: UncheckedAccount<'info>,
+
+    #[account(address = access.partner @ ErrorCode::InvalidPartner)]
+    pub partner: Option<Box<Account<'info, Partner>>>,
+
+    #[account(mut)]
+    pub partner_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     /// CHECK: Jupiter Order Engine program
     pub jupiter_order_engine_program: Program<'info, OrderEngine>,
+
+    /// CHECK: Instructions sysvar, required to verify a user-signed intent
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = TradeReceipt::LEN,
+        seeds = [
+            TRADE_RECEIPT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &params.client_order_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub trade_receipt: Box<Account<'info, TradeReceipt>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = UserStats::LEN,
+        seeds = [
+            USER_STATS_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    #[account(
+        mut,
+        seeds = [TRADE_LOG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = trade_log.load()?.bump,
+    )]
+    pub trade_log: AccountLoader<'info, TradeLog>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn process_fill_order_engine<'a>(
     ctx: Context<'_, '_, '_, 'a, FillOrderEngine<'a>>,
     params: FillOrderEngineParams,
 ) -> Result<()> {
-    // 1. 验证并转移输入代币
-    validate_and_transfer_input(
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    let vault_seeds = VaultSeeds::new(&ctx.accounts.config, ctx.accounts.user.key, ctx.bumps.vault);
+
+    // 1. 校验用户签名的交易意图
+    let min_output_amount = verify_trade_intent(
+        params.intent.as_ref(),
+        &ctx.accounts.access,
+        &ctx.accounts.config.key(),
+        ctx.accounts.instructions_sysvar.as_deref(),
+        TradeTerms {
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            in_amount: params.in_amount,
+            client_order_id: params.client_order_id,
+        },
+    )?;
+
+    // 2. 验证并转移输入代币
+    let received = validate_and_transfer_input(
         &ctx.accounts.operator.to_account_info(),
         &mut ctx.accounts.config,
         &ctx.accounts.vault.to_account_info(),
-        ctx.bumps.vault,
-        &ctx.accounts.delegate_input_token_account,
-        &ctx.accounts.input_mint.to_account_info(),
-        &ctx.accounts.input_mint_program.to_account_info(),
-        &ctx.accounts.vault_input_token_account.to_account_info(),
+        vault_seeds,
+        DelegatedInput {
+            delegate: &params.delegate,
+            delegate_input_token_account: &ctx.accounts.delegate_input_token_account,
+            input_mint: &ctx.accounts.input_mint.to_account_info(),
+            input_mint_program: &ctx.accounts.input_mint_program.to_account_info(),
+            vault_input_token_account: &ctx.accounts.vault_input_token_account.to_account_info(),
+            decimals: ctx.accounts.input_mint.decimals,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
         params.in_amount,
-        ctx.accounts.input_mint.decimals,
-        &params.delegate,
     )?;
 
-    // 2. CPI
-    execute_cross_program_invocation(
+    // 3. CPI
+    let output = execute_cross_program_invocation(
         ctx.accounts.jupiter_order_engine_program.key,
         &jupiter_order_engine_program_id(),
         ctx.remaining_accounts,
         &ctx.accounts.vault.key(),
-        ctx.bumps.vault,
+        vault_seeds,
         params.data,
-        Some(&mut ctx.accounts.vault_output_token_account),
-        Some(&ctx.accounts.receiver_output_token_account),
-        Some(&ctx.accounts.output_mint),
-        Some(&ctx.accounts.output_mint_program),
-        Some(&ctx.accounts.vault),
+        Some(VenueOutput {
+            vault_output_token_account: &mut ctx.accounts.vault_output_token_account,
+            receiver_output_token_account: Some(&ctx.accounts.receiver_output_token_account),
+            protocol_fee: Some(ProtocolFee::new(
+                ctx.accounts.config.fee_bps,
+                &ctx.accounts.fee_token_account,
+                load_partner_share(
+                    &ctx.accounts.access,
+                    ctx.accounts.partner.as_deref(),
+                    ctx.accounts.partner_fee_token_account.as_deref(),
+                    &ctx.accounts.output_mint.key(),
+                )?,
+            )),
+            native_output: None,
+            vault_transfer: VaultTransfer {
+                mint: &ctx.accounts.output_mint,
+                mint_program: &ctx.accounts.output_mint_program,
+                vault: &ctx.accounts.vault,
+                vault_seeds,
+                hook_accounts: ctx.remaining_accounts,
+                event_cpi,
+            },
+        }),
     )?;
 
-    // 3. emit event
-    emit!(FillOrderEngineEvent {
+    // 4. 检查 intent 最小输出, 记录成交回执, 用户统计与成交日志
+    require!(
+        output.delivered >= min_output_amount,
+        ErrorCode::IntentOutputTooLow
+    );
+    record_trade_receipt(
+        &mut ctx.accounts.trade_receipt,
+        ExecutedTrade {
+            user: ctx.accounts.user.key(),
+            client_order_id: params.client_order_id,
+            operator: ctx.accounts.operator.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            input_amount: received.net,
+            output_amount: output.delivered,
+        },
+        ctx.bumps.trade_receipt,
+    )?;
+    record_user_stats(
+        &mut ctx.accounts.user_stats,
+        ctx.accounts.user.key(),
+        ctx.bumps.user_stats,
+        &ctx.accounts.input_mint.key(),
+        received.net,
+        &ctx.accounts.output_mint.key(),
+        output,
+    )?;
+    append_trade_log(
+        &ctx.accounts.trade_log,
+        TradeLogEntry {
+            user: ctx.accounts.user.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            operator: ctx.accounts.operator.key(),
+            input_amount: received.net,
+            output_amount: output.delivered,
+            slot: Clock::get()?.slot,
+            venue: TradeVenue::JupiterOrderEngine as u8,
+            padding: [0; 7],
+        },
+    )?;
+
+    // 5. emit event
+    emit_cpi!(TradeExecuted {
+        version: TRADE_EXECUTED_VERSION,
+        sequence: ctx.accounts.config.next_trade_sequence(),
+        venue: TradeVenue::JupiterOrderEngine,
+        user: ctx.accounts.user.key(),
+        operator: ctx.accounts.operator.key(),
+        input_mint: ctx.accounts.input_mint.key(),
+        output_mint: ctx.accounts.output_mint.key(),
+        client_order_id: params.client_order_id,
+        input_amount: received.net,
+        refund_amount: 0,
+        output_amount: output.delivered,
+        parked_amount: output.parked,
+        fee_amount: output.protocol_fee,
+        commission_amount: 0,
+        slot: Clock::get()?.slot,
+    });
+    #[cfg(feature = "legacy-events")]
+    emit_cpi!(FillOrderEngineEvent {
         user: ctx.accounts.user.key(),
         input_mint: ctx.accounts.input_mint.key(),
         output_mint: ctx.accounts.output_mint.key(),
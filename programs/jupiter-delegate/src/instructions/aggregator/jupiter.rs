@@ -1,25 +1,39 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
+#[cfg(feature = "legacy-events")]
+use crate::JupiterAggregatorEvent;
 use crate::{
-    constants::{ACCESS_SEED, VAULT_SEED},
+    append_trade_log,
+    constants::{
+        ACCESS_SEED, CONFIG_SEED, FEE_VAULT_SEED, NATIVE_OUTPUT_SEED, TRADE_EXECUTED_VERSION,
+        TRADE_LOG_SEED, TRADE_RECEIPT_SEED, USER_STATS_SEED, VAULT_SEED,
+    },
+    credit_user,
     error::ErrorCode,
     execute_cross_program_invocation,
     jupiter_aggregator::program::Jupiter,
-    jupiter_program_id,
-    state::Config,
-    validate_and_transfer_input, Access, JupiterAggregatorEvent,
+    jupiter_program_id, load_native_output, load_partner_share, record_trade_receipt,
+    record_user_stats,
+    state::{namespace_seed, Config},
+    validate_and_transfer_input, verify_trade_intent, Access, CreditAccounts, DelegatedInput,
+    EventCpi, ExecutedTrade, Partner, ProtocolFee, TradeExecuted, TradeIntent, TradeLog,
+    TradeLogEntry, TradeReceipt, TradeTerms, TradeVenue, UserStats, VaultSeeds, VaultTransfer,
+    VenueInstruction, VenueOutput,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct JupiterAggregatorParams {
     pub data: Vec<u8>,
     pub in_amount: u64,
-    pub instruction_name: String,
     pub delegate: Pubkey,
+    pub client_order_id: u64,
+    pub intent: Option<TradeIntent>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(args: JupiterAggregatorParams)]
 pub struct JupiterAggregator<'info> {
     pub input_mint: InterfaceAccount<'info, Mint>,
     pub input_mint_program: Interface<'info, TokenInterface>,
@@ -31,7 +45,11 @@ pub struct JupiterAggregator<'info> {
 
     #[account(
         mut,
-        seeds=[VAULT_SEED.as_bytes()],
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            config.vault_user_seed(user.key),
+        ],
         bump
     )]
     pub vault: SystemAccount<'info>,
@@ -55,17 +73,24 @@ pub struct JupiterAggregator<'info> {
     )]
     pub vault_output_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
     pub config: Box<Account<'info, Config>>,
 
     #[account(
-        seeds = [ACCESS_SEED.as_bytes(), user.key().as_ref()],
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.key().as_ref()],
         bump,
-        constraint = access.is_granted @ ErrorCode::AccessNotGranted
+        constraint = access.is_granted @ ErrorCode::AccessNotGranted,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
     )]
     pub access: Account<'info, Access>,
 
-    /// CHECK: This is the user's account
+    /// CHECK: This is the user's account, receives native output
+    #[account(mut)]
     pub user: UncheckedAccount<'info>,
 
     /// CHECK: Receiver output token account
@@ -75,52 +100,270 @@ pub struct JupiterAggregator<'info> {
         associated_token::authority = user,
         associated_token::token_program = output_mint_program,
     )]
-    pub receiver_output_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub receiver_output_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = operator,
+        seeds = [NATIVE_OUTPUT_SEED.as_bytes(), vault.key().as_ref()],
+        bump,
+        token::mint = output_mint,
+        token::authority = vault,
+        token::token_program = output_mint_program,
+    )]
+    pub native_output_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        seeds = [
+            FEE_VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            output_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = output_mint,
+        token::authority = fee_vault_authority,
+        token::token_program = output_mint_program,
+    )]
+    pub fee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Shared vault, authority of the protocol fee vaults in either vault mode
+    #[account(seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)], bump)]
+    pub fee_vault_authority: UncheckedAccount<'info>,
+
+    #[account(address = access.partner @ ErrorCode::InvalidPartner)]
+    pub partner: Option<Box<Account<'info, Partner>>>,
+
+    #[account(mut)]
+    pub partner_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     pub jupiter_program: Program<'info, Jupiter>,
+
+    /// CHECK: Instructions sysvar, required to verify a user-signed intent
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = TradeReceipt::LEN,
+        seeds = [
+            TRADE_RECEIPT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &args.client_order_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub trade_receipt: Box<Account<'info, TradeReceipt>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = UserStats::LEN,
+        seeds = [
+            USER_STATS_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    #[account(
+        mut,
+        seeds = [TRADE_LOG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = trade_log.load()?.bump,
+    )]
+    pub trade_log: AccountLoader<'info, TradeLog>,
+
+    /// Ledger entry of the user for the output mint, required when the output
+    /// is parked in the shared vault
+    /// CHECK: checked and created in credit_user
+    #[account(mut)]
+    pub user_credit: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn process_jupiter_aggregator<'a>(
     ctx: Context<'_, '_, '_, 'a, JupiterAggregator<'a>>,
     args: JupiterAggregatorParams,
 ) -> Result<()> {
-    // 1. 验证并转移输入代币
-    validate_and_transfer_input(
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    let vault_seeds = VaultSeeds::new(&ctx.accounts.config, ctx.accounts.user.key, ctx.bumps.vault);
+
+    // 只允许 venue 的 swap 类指令
+    #[cfg_attr(not(feature = "legacy-events"), allow(unused_variables))]
+    let instruction = VenueInstruction::from_data(TradeVenue::Jupiter, &args.data)?;
+
+    // 1. 校验用户签名的交易意图
+    let min_output_amount = verify_trade_intent(
+        args.intent.as_ref(),
+        &ctx.accounts.access,
+        &ctx.accounts.config.key(),
+        ctx.accounts.instructions_sysvar.as_deref(),
+        TradeTerms {
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            in_amount: args.in_amount,
+            client_order_id: args.client_order_id,
+        },
+    )?;
+
+    // 2. 验证并转移输入代币
+    let received = validate_and_transfer_input(
         &ctx.accounts.operator.to_account_info(),
         &mut ctx.accounts.config,
         &ctx.accounts.vault.to_account_info(),
-        ctx.bumps.vault,
-        &ctx.accounts.delegate_input_token_account,
-        &ctx.accounts.input_mint.to_account_info(),
-        &ctx.accounts.input_mint_program.to_account_info(),
-        &ctx.accounts.vault_input_token_account.to_account_info(),
+        vault_seeds,
+        DelegatedInput {
+            delegate: &args.delegate,
+            delegate_input_token_account: &ctx.accounts.delegate_input_token_account,
+            input_mint: &ctx.accounts.input_mint.to_account_info(),
+            input_mint_program: &ctx.accounts.input_mint_program.to_account_info(),
+            vault_input_token_account: &ctx.accounts.vault_input_token_account.to_account_info(),
+            decimals: ctx.accounts.input_mint.decimals,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
         args.in_amount,
-        ctx.accounts.input_mint.decimals,
-        &args.delegate,
     )?;
 
-    // 2. CPI
-    execute_cross_program_invocation(
+    // 3. 确定输出方式: 原生 SOL 或代币账户
+    let native_output = load_native_output(
+        ctx.accounts.native_output_token_account.as_deref(),
+        &ctx.accounts.user,
+        &ctx.accounts.operator,
+        &ctx.accounts.system_program,
+        &ctx.accounts.output_mint.key(),
+    )?;
+    #[cfg(feature = "legacy-events")]
+    let is_native_output = native_output.is_some();
+
+    // 4. CPI
+    let output = execute_cross_program_invocation(
         ctx.accounts.jupiter_program.key,
         &jupiter_program_id(),
         ctx.remaining_accounts,
         &ctx.accounts.vault.key(),
-        ctx.bumps.vault,
+        vault_seeds,
         args.data,
-        Some(&mut ctx.accounts.vault_output_token_account),
-        Some(&ctx.accounts.receiver_output_token_account),
-        Some(&ctx.accounts.output_mint),
-        Some(&ctx.accounts.output_mint_program),
-        Some(&ctx.accounts.vault),
+        Some(VenueOutput {
+            vault_output_token_account: &mut ctx.accounts.vault_output_token_account,
+            receiver_output_token_account: ctx.accounts.receiver_output_token_account.as_ref(),
+            protocol_fee: Some(ProtocolFee::new(
+                ctx.accounts.config.fee_bps,
+                &ctx.accounts.fee_token_account,
+                load_partner_share(
+                    &ctx.accounts.access,
+                    ctx.accounts.partner.as_deref(),
+                    ctx.accounts.partner_fee_token_account.as_deref(),
+                    &ctx.accounts.output_mint.key(),
+                )?,
+            )),
+            native_output,
+            vault_transfer: VaultTransfer {
+                mint: &ctx.accounts.output_mint,
+                mint_program: &ctx.accounts.output_mint_program,
+                vault: &ctx.accounts.vault,
+                vault_seeds,
+                hook_accounts: ctx.remaining_accounts,
+                event_cpi,
+            },
+        }),
+    )?;
+
+    // 未指定接收账户时输出暂存在共享 vault, 记入用户账本
+    if output.parked > 0 && !ctx.accounts.config.per_user_vault {
+        let user_credit = ctx
+            .accounts
+            .user_credit
+            .as_ref()
+            .ok_or(ErrorCode::UserCreditRequired)?;
+        credit_user(
+            CreditAccounts {
+                user_credit: &user_credit.to_account_info(),
+                payer: &ctx.accounts.operator.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                event_cpi,
+            },
+            ctx.accounts.config.namespace,
+            ctx.accounts.user.key,
+            &ctx.accounts.output_mint.key(),
+            output.parked,
+        )?;
+    }
+
+    // 5. 检查 intent 最小输出, 记录成交回执, 用户统计与成交日志
+    require!(
+        output.delivered >= min_output_amount,
+        ErrorCode::IntentOutputTooLow
+    );
+    record_trade_receipt(
+        &mut ctx.accounts.trade_receipt,
+        ExecutedTrade {
+            user: ctx.accounts.user.key(),
+            client_order_id: args.client_order_id,
+            operator: ctx.accounts.operator.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            input_amount: received.net,
+            output_amount: output.delivered,
+        },
+        ctx.bumps.trade_receipt,
+    )?;
+    record_user_stats(
+        &mut ctx.accounts.user_stats,
+        ctx.accounts.user.key(),
+        ctx.bumps.user_stats,
+        &ctx.accounts.input_mint.key(),
+        received.net,
+        &ctx.accounts.output_mint.key(),
+        output,
+    )?;
+    append_trade_log(
+        &ctx.accounts.trade_log,
+        TradeLogEntry {
+            user: ctx.accounts.user.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            operator: ctx.accounts.operator.key(),
+            input_amount: received.net,
+            output_amount: output.delivered,
+            slot: Clock::get()?.slot,
+            venue: TradeVenue::Jupiter as u8,
+            padding: [0; 7],
+        },
     )?;
 
-    // 3. emit event
-    emit!(JupiterAggregatorEvent {
+    // 6. emit event
+    emit_cpi!(TradeExecuted {
+        version: TRADE_EXECUTED_VERSION,
+        sequence: ctx.accounts.config.next_trade_sequence(),
+        venue: TradeVenue::Jupiter,
+        user: ctx.accounts.user.key(),
+        operator: ctx.accounts.operator.key(),
+        input_mint: ctx.accounts.input_mint.key(),
+        output_mint: ctx.accounts.output_mint.key(),
+        client_order_id: args.client_order_id,
+        input_amount: received.net,
+        refund_amount: 0,
+        output_amount: output.delivered,
+        parked_amount: output.parked,
+        fee_amount: output.protocol_fee,
+        commission_amount: 0,
+        slot: Clock::get()?.slot,
+    });
+    #[cfg(feature = "legacy-events")]
+    emit_cpi!(JupiterAggregatorEvent {
         user: ctx.accounts.user.key(),
         input_mint: ctx.accounts.input_mint.key(),
         output_mint: ctx.accounts.output_mint.key(),
         input_amount: args.in_amount,
-        instruction_name: args.instruction_name,
+        instruction,
+        native_output: is_native_output,
         operator: ctx.accounts.operator.key(),
     });
 
@@ -6,3 +6,7 @@ mod jupiter;
 pub use jupiter::*;
 mod fill_order_engine;
 pub use fill_order_engine::*;
+mod dflow_order;
+pub use dflow_order::*;
+mod venue;
+pub use venue::*;
@@ -1,25 +1,202 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
+#[cfg(feature = "legacy-events")]
+use crate::OkxAggregatorEvent;
 use crate::{
-    constants::{ACCESS_SEED, VAULT_SEED},
+    append_trade_log,
+    constants::{
+        ACCESS_SEED, BPS_DENOMINATOR, CONFIG_SEED, DISCRIMINATOR_OKX_COMMISSION_SOL_PROXY_SWAP,
+        DISCRIMINATOR_OKX_COMMISSION_SOL_SWAP, DISCRIMINATOR_OKX_COMMISSION_SPL_PROXY_SWAP,
+        DISCRIMINATOR_OKX_COMMISSION_SPL_SWAP, DISCRIMINATOR_OKX_PLATFORM_FEE_SOL_PROXY_SWAP_V2,
+        DISCRIMINATOR_OKX_PLATFORM_FEE_SPL_PROXY_SWAP_V2, DISCRIMINATOR_OKX_SWAP_TOB_V3,
+        DISCRIMINATOR_OKX_SWAP_TOB_V3_WITH_RECEIVER, DISCRIMINATOR_OKX_SWAP_V3, FEE_VAULT_SEED,
+        NATIVE_OUTPUT_SEED, OKX_COMMISSION_ACCOUNT_INDEX, OKX_COMMISSION_DENOMINATOR_V2,
+        OKX_COMMISSION_RATE_MASK_V2, TRADE_EXECUTED_VERSION, TRADE_LOG_SEED, TRADE_RECEIPT_SEED,
+        USER_STATS_SEED, VAULT_SEED,
+    },
+    credit_user,
     error::ErrorCode,
-    execute_cross_program_invocation,
-    okx_aggregator::program::DexSolana,
-    okx_program_id,
-    state::Config,
-    validate_and_transfer_input, Access, OkxAggregatorEvent,
+    execute_cross_program_invocation, load_native_output, load_partner_share,
+    okx_aggregator::{
+        program::DexSolana,
+        types::{CommissionSwapArgs, SwapArgs},
+    },
+    okx_program_id, record_trade_receipt, record_user_stats,
+    state::{namespace_seed, Config},
+    validate_and_transfer_input, verify_trade_intent, Access, CreditAccounts, DelegatedInput,
+    EventCpi, ExecutedTrade, Partner, ProtocolFee, TradeExecuted, TradeIntent, TradeLog,
+    TradeLogEntry, TradeReceipt, TradeTerms, TradeVenue, UserStats, VaultSeeds, VaultTransfer,
+    VenueInstruction, VenueOutput,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct OkxAggregatorParams {
     pub data: Vec<u8>,
     pub in_amount: u64,
-    pub instruction_name: String,
     pub delegate: Pubkey,
+    pub client_order_id: u64,
+    pub intent: Option<TradeIntent>,
 }
 
+/// Commission charged by an OKX commission / platform fee instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OkxCommission {
+    pub rate_bps: u64,
+    // SOL variants pay commission in lamports to a system account,
+    // SPL variants pay it to a token account, v3 variants pay either
+    // depending on the swap mints (None)
+    pub is_sol: Option<bool>,
+}
+
+impl OkxAggregatorParams {
+    /// Decode the commission of the OKX instruction in `data`, if it carries one
+    pub fn get_commission(&self) -> Result<Option<OkxCommission>> {
+        require!(self.data.len() >= 8, ErrorCode::InvalidOkxCommissionData);
+        let (discriminator, mut rest) = self.data.split_at(8);
+
+        let commission = match discriminator {
+            DISCRIMINATOR_OKX_COMMISSION_SPL_SWAP | DISCRIMINATOR_OKX_COMMISSION_SOL_SWAP => {
+                let args = CommissionSwapArgs::deserialize(&mut rest)
+                    .map_err(|_| ErrorCode::InvalidOkxCommissionData)?;
+                OkxCommission {
+                    rate_bps: args.commission_rate as u64,
+                    is_sol: Some(discriminator == DISCRIMINATOR_OKX_COMMISSION_SOL_SWAP),
+                }
+            }
+            DISCRIMINATOR_OKX_COMMISSION_SPL_PROXY_SWAP
+            | DISCRIMINATOR_OKX_COMMISSION_SOL_PROXY_SWAP => {
+                SwapArgs::deserialize(&mut rest)
+                    .map_err(|_| ErrorCode::InvalidOkxCommissionData)?;
+                let commission_rate =
+                    u16::deserialize(&mut rest).map_err(|_| ErrorCode::InvalidOkxCommissionData)?;
+                OkxCommission {
+                    rate_bps: commission_rate as u64,
+                    is_sol: Some(discriminator == DISCRIMINATOR_OKX_COMMISSION_SOL_PROXY_SWAP),
+                }
+            }
+            DISCRIMINATOR_OKX_PLATFORM_FEE_SPL_PROXY_SWAP_V2
+            | DISCRIMINATOR_OKX_PLATFORM_FEE_SOL_PROXY_SWAP_V2 => {
+                SwapArgs::deserialize(&mut rest)
+                    .map_err(|_| ErrorCode::InvalidOkxCommissionData)?;
+                let (commission_info, platform_fee_rate, trim_rate) =
+                    <(u32, u32, u8)>::deserialize(&mut rest)
+                        .map_err(|_| ErrorCode::InvalidOkxCommissionData)?;
+                // platform fee 与 trim 不受 max_commission_bps 约束, 只允许为 0
+                require!(
+                    platform_fee_rate == 0,
+                    ErrorCode::OkxPlatformFeeNotSupported
+                );
+                require!(trim_rate == 0, ErrorCode::OkxTrimNotSupported);
+                OkxCommission {
+                    rate_bps: commission_rate_bps_v2(commission_info),
+                    is_sol: Some(discriminator == DISCRIMINATOR_OKX_PLATFORM_FEE_SOL_PROXY_SWAP_V2),
+                }
+            }
+            DISCRIMINATOR_OKX_SWAP_V3 => {
+                SwapArgs::deserialize(&mut rest)
+                    .map_err(|_| ErrorCode::InvalidOkxCommissionData)?;
+                let (commission_info, platform_fee_rate) = <(u32, u16)>::deserialize(&mut rest)
+                    .map_err(|_| ErrorCode::InvalidOkxCommissionData)?;
+                require!(
+                    platform_fee_rate == 0,
+                    ErrorCode::OkxPlatformFeeNotSupported
+                );
+                OkxCommission {
+                    rate_bps: commission_rate_bps_v2(commission_info),
+                    is_sol: None,
+                }
+            }
+            DISCRIMINATOR_OKX_SWAP_TOB_V3 | DISCRIMINATOR_OKX_SWAP_TOB_V3_WITH_RECEIVER => {
+                SwapArgs::deserialize(&mut rest)
+                    .map_err(|_| ErrorCode::InvalidOkxCommissionData)?;
+                let (commission_info, trim_rate, platform_fee_rate) =
+                    <(u32, u8, u16)>::deserialize(&mut rest)
+                        .map_err(|_| ErrorCode::InvalidOkxCommissionData)?;
+                require!(
+                    platform_fee_rate == 0,
+                    ErrorCode::OkxPlatformFeeNotSupported
+                );
+                require!(trim_rate == 0, ErrorCode::OkxTrimNotSupported);
+                OkxCommission {
+                    rate_bps: commission_rate_bps_v2(commission_info),
+                    is_sol: None,
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(commission))
+    }
+}
+
+/// v2 / v3 rates are expressed over 10^9, round up when converting to bps
+fn commission_rate_bps_v2(commission_info: u32) -> u64 {
+    let rate = (commission_info & OKX_COMMISSION_RATE_MASK_V2) as u64;
+    (rate * BPS_DENOMINATOR).div_ceil(OKX_COMMISSION_DENOMINATOR_V2)
+}
+
+/// A system account is paid in lamports, anything else must be a token account
+fn is_sol_commission_account(commission: &OkxCommission, commission_account: &AccountInfo) -> bool {
+    commission
+        .is_sol
+        .unwrap_or(*commission_account.owner == anchor_lang::system_program::ID)
+}
+
+/// Check the commission account and return its balance before the swap
+fn validate_commission_account(
+    commission: &OkxCommission,
+    remaining_accounts: &[AccountInfo],
+    config: &Config,
+) -> Result<u64> {
+    require!(
+        commission.rate_bps <= config.max_commission_bps as u64,
+        ErrorCode::CommissionRateTooHigh
+    );
+    let commission_account = remaining_accounts
+        .get(OKX_COMMISSION_ACCOUNT_INDEX)
+        .ok_or(ErrorCode::InvalidCommissionAccount)?;
+
+    if is_sol_commission_account(commission, commission_account) {
+        require_keys_eq!(
+            commission_account.key(),
+            config.fee_treasury,
+            ErrorCode::InvalidCommissionAccount
+        );
+        Ok(commission_account.lamports())
+    } else {
+        require!(
+            *commission_account.owner == anchor_spl::token::ID
+                || *commission_account.owner == anchor_spl::token_2022::ID,
+            ErrorCode::InvalidCommissionAccount
+        );
+        let token_account =
+            TokenAccount::try_deserialize(&mut &commission_account.try_borrow_data()?[..])
+                .map_err(|_| ErrorCode::InvalidCommissionAccount)?;
+        require_keys_eq!(
+            token_account.owner,
+            config.fee_treasury,
+            ErrorCode::InvalidCommissionAccount
+        );
+        Ok(token_account.amount)
+    }
+}
+
+fn commission_account_balance(
+    commission: &OkxCommission,
+    remaining_accounts: &[AccountInfo],
+) -> Result<u64> {
+    let commission_account = &remaining_accounts[OKX_COMMISSION_ACCOUNT_INDEX];
+    if is_sol_commission_account(commission, commission_account) {
+        return Ok(commission_account.lamports());
+    }
+    let token_account =
+        TokenAccount::try_deserialize(&mut &commission_account.try_borrow_data()?[..])?;
+    Ok(token_account.amount)
+}
+
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(args: OkxAggregatorParams)]
 pub struct OkxAggregator<'info> {
     pub input_mint: InterfaceAccount<'info, Mint>,
     pub input_mint_program: Interface<'info, TokenInterface>,
@@ -31,7 +208,11 @@ pub struct OkxAggregator<'info> {
 
     #[account(
         mut,
-        seeds=[VAULT_SEED.as_bytes()],
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            config.vault_user_seed(user.key),
+        ],
         bump
     )]
     pub vault: SystemAccount<'info>,
@@ -55,17 +236,24 @@ pub struct OkxAggregator<'info> {
     )]
     pub vault_output_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
     pub config: Box<Account<'info, Config>>,
 
     #[account(
-        seeds = [ACCESS_SEED.as_bytes(), user.key().as_ref()],
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.key().as_ref()],
         bump,
-        constraint = access.is_granted @ ErrorCode::AccessNotGranted
+        constraint = access.is_granted @ ErrorCode::AccessNotGranted,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
     )]
     pub access: Account<'info, Access>,
 
-    /// CHECK: This is the user's account
+    /// CHECK: This is the user's account, receives native output
+    #[account(mut)]
     pub user: UncheckedAccount<'info>,
 
     /// CHECK: Receiver output token account
@@ -75,54 +263,413 @@ pub struct OkxAggregator<'info> {
         associated_token::authority = user,
         associated_token::token_program = output_mint_program,
     )]
-    pub receiver_output_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub receiver_output_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = operator,
+        seeds = [NATIVE_OUTPUT_SEED.as_bytes(), vault.key().as_ref()],
+        bump,
+        token::mint = output_mint,
+        token::authority = vault,
+        token::token_program = output_mint_program,
+    )]
+    pub native_output_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        seeds = [
+            FEE_VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            output_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = output_mint,
+        token::authority = fee_vault_authority,
+        token::token_program = output_mint_program,
+    )]
+    pub fee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Shared vault, authority of the protocol fee vaults in either vault mode
+    #[account(seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)], bump)]
+    pub fee_vault_authority: UncheckedAccount<'info>,
+
+    #[account(address = access.partner @ ErrorCode::InvalidPartner)]
+    pub partner: Option<Box<Account<'info, Partner>>>,
+
+    #[account(mut)]
+    pub partner_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     pub okx_program: Program<'info, DexSolana>,
+
+    /// CHECK: Instructions sysvar, required to verify a user-signed intent
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = TradeReceipt::LEN,
+        seeds = [
+            TRADE_RECEIPT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &args.client_order_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub trade_receipt: Box<Account<'info, TradeReceipt>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = UserStats::LEN,
+        seeds = [
+            USER_STATS_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    #[account(
+        mut,
+        seeds = [TRADE_LOG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = trade_log.load()?.bump,
+    )]
+    pub trade_log: AccountLoader<'info, TradeLog>,
+
+    /// Ledger entry of the user for the output mint, required when the output
+    /// is parked in the shared vault
+    /// CHECK: checked and created in credit_user
+    #[account(mut)]
+    pub user_credit: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn process_okx_aggregator<'info>(
     ctx: Context<'_, '_, '_, 'info, OkxAggregator<'info>>,
     args: OkxAggregatorParams,
 ) -> Result<()> {
-    // 1. 验证并转移输入代币
-    validate_and_transfer_input(
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    let vault_seeds = VaultSeeds::new(&ctx.accounts.config, ctx.accounts.user.key, ctx.bumps.vault);
+
+    // 只允许 venue 的 swap 类指令
+    #[cfg_attr(not(feature = "legacy-events"), allow(unused_variables))]
+    let instruction = VenueInstruction::from_data(TradeVenue::Okx, &args.data)?;
+
+    // 1. 校验用户签名的交易意图
+    let min_output_amount = verify_trade_intent(
+        args.intent.as_ref(),
+        &ctx.accounts.access,
+        &ctx.accounts.config.key(),
+        ctx.accounts.instructions_sysvar.as_deref(),
+        TradeTerms {
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            in_amount: args.in_amount,
+            client_order_id: args.client_order_id,
+        },
+    )?;
+
+    // 2. 验证并转移输入代币
+    let received = validate_and_transfer_input(
         &ctx.accounts.operator.to_account_info(),
         &mut ctx.accounts.config,
         &ctx.accounts.vault.to_account_info(),
-        ctx.bumps.vault,
-        &ctx.accounts.delegate_input_token_account,
-        &ctx.accounts.input_mint.to_account_info(),
-        &ctx.accounts.input_mint_program.to_account_info(),
-        &ctx.accounts.vault_input_token_account.to_account_info(),
+        vault_seeds,
+        DelegatedInput {
+            delegate: &args.delegate,
+            delegate_input_token_account: &ctx.accounts.delegate_input_token_account,
+            input_mint: &ctx.accounts.input_mint.to_account_info(),
+            input_mint_program: &ctx.accounts.input_mint_program.to_account_info(),
+            vault_input_token_account: &ctx.accounts.vault_input_token_account.to_account_info(),
+            decimals: ctx.accounts.input_mint.decimals,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
         args.in_amount,
-        ctx.accounts.input_mint.decimals,
-        &args.delegate,
     )?;
 
-    // 2. CPI
-    execute_cross_program_invocation(
+    // 3. 确定输出方式: 原生 SOL 或代币账户
+    let native_output = load_native_output(
+        ctx.accounts.native_output_token_account.as_deref(),
+        &ctx.accounts.user,
+        &ctx.accounts.operator,
+        &ctx.accounts.system_program,
+        &ctx.accounts.output_mint.key(),
+    )?;
+    #[cfg(feature = "legacy-events")]
+    let is_native_output = native_output.is_some();
+
+    // 4. 校验佣金账户与佣金费率
+    let commission = args.get_commission()?;
+    let commission_balance_before = match &commission {
+        Some(commission) => {
+            validate_commission_account(commission, ctx.remaining_accounts, &ctx.accounts.config)?
+        }
+        None => 0,
+    };
+
+    // 5. CPI
+    let output = execute_cross_program_invocation(
         ctx.accounts.okx_program.key,
         &okx_program_id(),
         ctx.remaining_accounts,
         &ctx.accounts.vault.key(),
-        ctx.bumps.vault,
+        vault_seeds,
         args.data,
-        Some(&mut ctx.accounts.vault_output_token_account),
-        Some(&ctx.accounts.receiver_output_token_account),
-        Some(&ctx.accounts.output_mint),
-        Some(&ctx.accounts.output_mint_program),
-        Some(&ctx.accounts.vault),
+        Some(VenueOutput {
+            vault_output_token_account: &mut ctx.accounts.vault_output_token_account,
+            receiver_output_token_account: ctx.accounts.receiver_output_token_account.as_ref(),
+            protocol_fee: Some(ProtocolFee::new(
+                ctx.accounts.config.fee_bps,
+                &ctx.accounts.fee_token_account,
+                load_partner_share(
+                    &ctx.accounts.access,
+                    ctx.accounts.partner.as_deref(),
+                    ctx.accounts.partner_fee_token_account.as_deref(),
+                    &ctx.accounts.output_mint.key(),
+                )?,
+            )),
+            native_output,
+            vault_transfer: VaultTransfer {
+                mint: &ctx.accounts.output_mint,
+                mint_program: &ctx.accounts.output_mint_program,
+                vault: &ctx.accounts.vault,
+                vault_seeds,
+                hook_accounts: ctx.remaining_accounts,
+                event_cpi,
+            },
+        }),
     )?;
 
-    // 3. emit event
-    emit!(OkxAggregatorEvent {
+    // 未指定接收账户时输出暂存在共享 vault, 记入用户账本
+    if output.parked > 0 && !ctx.accounts.config.per_user_vault {
+        let user_credit = ctx
+            .accounts
+            .user_credit
+            .as_ref()
+            .ok_or(ErrorCode::UserCreditRequired)?;
+        credit_user(
+            CreditAccounts {
+                user_credit: &user_credit.to_account_info(),
+                payer: &ctx.accounts.operator.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                event_cpi,
+            },
+            ctx.accounts.config.namespace,
+            ctx.accounts.user.key,
+            &ctx.accounts.output_mint.key(),
+            output.parked,
+        )?;
+    }
+
+    // 6. 计算实际收取的佣金
+    let commission_amount = match &commission {
+        Some(commission) => commission_account_balance(commission, ctx.remaining_accounts)?
+            .saturating_sub(commission_balance_before),
+        None => 0,
+    };
+
+    // 7. 检查 intent 最小输出, 记录成交回执, 用户统计与成交日志
+    require!(
+        output.delivered >= min_output_amount,
+        ErrorCode::IntentOutputTooLow
+    );
+    record_trade_receipt(
+        &mut ctx.accounts.trade_receipt,
+        ExecutedTrade {
+            user: ctx.accounts.user.key(),
+            client_order_id: args.client_order_id,
+            operator: ctx.accounts.operator.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            input_amount: received.net,
+            output_amount: output.delivered,
+        },
+        ctx.bumps.trade_receipt,
+    )?;
+    record_user_stats(
+        &mut ctx.accounts.user_stats,
+        ctx.accounts.user.key(),
+        ctx.bumps.user_stats,
+        &ctx.accounts.input_mint.key(),
+        received.net,
+        &ctx.accounts.output_mint.key(),
+        output,
+    )?;
+    append_trade_log(
+        &ctx.accounts.trade_log,
+        TradeLogEntry {
+            user: ctx.accounts.user.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            operator: ctx.accounts.operator.key(),
+            input_amount: received.net,
+            output_amount: output.delivered,
+            slot: Clock::get()?.slot,
+            venue: TradeVenue::Okx as u8,
+            padding: [0; 7],
+        },
+    )?;
+
+    // 8. emit event
+    emit_cpi!(TradeExecuted {
+        version: TRADE_EXECUTED_VERSION,
+        sequence: ctx.accounts.config.next_trade_sequence(),
+        venue: TradeVenue::Okx,
+        user: ctx.accounts.user.key(),
+        operator: ctx.accounts.operator.key(),
+        input_mint: ctx.accounts.input_mint.key(),
+        output_mint: ctx.accounts.output_mint.key(),
+        client_order_id: args.client_order_id,
+        input_amount: received.net,
+        refund_amount: 0,
+        output_amount: output.delivered,
+        parked_amount: output.parked,
+        fee_amount: output.protocol_fee,
+        commission_amount,
+        slot: Clock::get()?.slot,
+    });
+    #[cfg(feature = "legacy-events")]
+    emit_cpi!(OkxAggregatorEvent {
         user: ctx.accounts.user.key(),
         input_mint: ctx.accounts.input_mint.key(),
         output_mint: ctx.accounts.output_mint.key(),
         input_amount: args.in_amount,
-        instruction_name: args.instruction_name,
+        instruction,
+        commission_amount,
+        native_output: is_native_output,
         operator: ctx.accounts.operator.key(),
     });
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap_args() -> SwapArgs {
+        SwapArgs {
+            amount_in: 1_000,
+            expect_amount_out: 990,
+            min_return: 980,
+            amounts: vec![1_000],
+            routes: vec![],
+        }
+    }
+
+    fn params(data: Vec<u8>) -> OkxAggregatorParams {
+        OkxAggregatorParams {
+            data,
+            in_amount: 1_000,
+            delegate: Pubkey::default(),
+            client_order_id: 0,
+            intent: None,
+        }
+    }
+
+    #[test]
+    fn test_get_commission() {
+        let mut data = DISCRIMINATOR_OKX_COMMISSION_SPL_PROXY_SWAP.to_vec();
+        swap_args().serialize(&mut data).unwrap();
+        (25u16, true, 7u64).serialize(&mut data).unwrap();
+        assert_eq!(
+            params(data).get_commission().unwrap(),
+            Some(OkxCommission {
+                rate_bps: 25,
+                is_sol: Some(false)
+            })
+        );
+
+        // 0.3% over 10^9 with the direction bit set
+        let mut data = DISCRIMINATOR_OKX_PLATFORM_FEE_SOL_PROXY_SWAP_V2.to_vec();
+        swap_args().serialize(&mut data).unwrap();
+        ((1u32 << 31) | 3_000_000, 0u32, 0u8, 7u64)
+            .serialize(&mut data)
+            .unwrap();
+        assert_eq!(
+            params(data).get_commission().unwrap(),
+            Some(OkxCommission {
+                rate_bps: 30,
+                is_sol: Some(true)
+            })
+        );
+
+        // v2 platform fee 在 commission 之外另收, 拒绝
+        let mut data = DISCRIMINATOR_OKX_PLATFORM_FEE_SPL_PROXY_SWAP_V2.to_vec();
+        swap_args().serialize(&mut data).unwrap();
+        (3_000_000u32, 1u32, 0u8, 7u64)
+            .serialize(&mut data)
+            .unwrap();
+        assert!(params(data).get_commission().is_err());
+
+        // v2 trim 同样拒绝
+        let mut data = DISCRIMINATOR_OKX_PLATFORM_FEE_SPL_PROXY_SWAP_V2.to_vec();
+        swap_args().serialize(&mut data).unwrap();
+        (3_000_000u32, 0u32, 1u8, 7u64)
+            .serialize(&mut data)
+            .unwrap();
+        assert_eq!(
+            params(data).get_commission().unwrap_err(),
+            ErrorCode::OkxTrimNotSupported.into()
+        );
+
+        // v3 commission 与 v2 同样编码, 收款账户类型由账户本身决定
+        let mut data = DISCRIMINATOR_OKX_SWAP_V3.to_vec();
+        swap_args().serialize(&mut data).unwrap();
+        (3_000_000u32, 0u16, 7u64).serialize(&mut data).unwrap();
+        assert_eq!(
+            params(data).get_commission().unwrap(),
+            Some(OkxCommission {
+                rate_bps: 30,
+                is_sol: None
+            })
+        );
+
+        let mut data = DISCRIMINATOR_OKX_SWAP_V3.to_vec();
+        swap_args().serialize(&mut data).unwrap();
+        (3_000_000u32, 1u16, 7u64).serialize(&mut data).unwrap();
+        assert_eq!(
+            params(data).get_commission().unwrap_err(),
+            ErrorCode::OkxPlatformFeeNotSupported.into()
+        );
+
+        for discriminator in [
+            DISCRIMINATOR_OKX_SWAP_TOB_V3,
+            DISCRIMINATOR_OKX_SWAP_TOB_V3_WITH_RECEIVER,
+        ] {
+            let mut data = discriminator.to_vec();
+            swap_args().serialize(&mut data).unwrap();
+            (2_000_001u32, 0u8, 0u16, 7u64)
+                .serialize(&mut data)
+                .unwrap();
+            assert_eq!(
+                params(data).get_commission().unwrap(),
+                Some(OkxCommission {
+                    rate_bps: 21,
+                    is_sol: None
+                })
+            );
+
+            let mut data = discriminator.to_vec();
+            swap_args().serialize(&mut data).unwrap();
+            (2_000_000u32, 1u8, 0u16, 7u64)
+                .serialize(&mut data)
+                .unwrap();
+            assert_eq!(
+                params(data).get_commission().unwrap_err(),
+                ErrorCode::OkxTrimNotSupported.into()
+            );
+        }
+
+        let mut data = vec![0u8; 8];
+        swap_args().serialize(&mut data).unwrap();
+        assert_eq!(params(data).get_commission().unwrap(), None);
+    }
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        DISCRIMINATOR_DFLOW_SWAP, DISCRIMINATOR_DFLOW_SWAP2,
+        DISCRIMINATOR_JUPITER_AGGREGATOR_ROUTE, DISCRIMINATOR_JUPITER_AGGREGATOR_ROUTE_V2,
+        DISCRIMINATOR_JUPITER_AGGREGATOR_SHARED_ACCOUNTS_ROUTE,
+        DISCRIMINATOR_JUPITER_AGGREGATOR_SHARED_ACCOUNTS_ROUTE_V2,
+        DISCRIMINATOR_OKX_COMMISSION_SOL_PROXY_SWAP, DISCRIMINATOR_OKX_COMMISSION_SOL_SWAP,
+        DISCRIMINATOR_OKX_COMMISSION_SPL_PROXY_SWAP, DISCRIMINATOR_OKX_COMMISSION_SPL_SWAP,
+        DISCRIMINATOR_OKX_PLATFORM_FEE_SOL_PROXY_SWAP_V2,
+        DISCRIMINATOR_OKX_PLATFORM_FEE_SPL_PROXY_SWAP_V2, DISCRIMINATOR_OKX_SWAP,
+        DISCRIMINATOR_OKX_SWAP_TOB_V3, DISCRIMINATOR_OKX_SWAP_TOB_V3_WITH_RECEIVER,
+        DISCRIMINATOR_OKX_SWAP_V3,
+    },
+    error::ErrorCode,
+    state::TradeVenue,
+};
+
+/// Aggregator instruction a trade is routed to, derived from the discriminator
+/// of the instruction data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VenueInstruction {
+    JupiterSharedAccountsRoute,
+    JupiterSharedAccountsRouteV2,
+    JupiterRoute,
+    JupiterRouteV2,
+    DflowSwap,
+    DflowSwap2,
+    OkxSwap,
+    OkxSwapV3,
+    OkxSwapTobV3,
+    OkxSwapTobV3WithReceiver,
+    OkxCommissionSplSwap,
+    OkxCommissionSolSwap,
+    OkxCommissionSplProxySwap,
+    OkxCommissionSolProxySwap,
+    OkxPlatformFeeSplProxySwapV2,
+    OkxPlatformFeeSolProxySwapV2,
+}
+
+impl VenueInstruction {
+    /// Only the swap instructions of the venue are allowed, anything else
+    /// (`claim`, `close_token`, ...) is rejected.
+    pub fn from_data(venue: TradeVenue, data: &[u8]) -> Result<Self> {
+        require!(data.len() >= 8, ErrorCode::UnsupportedVenueInstruction);
+        let instruction = match (venue, &data[..8]) {
+            (TradeVenue::Jupiter, DISCRIMINATOR_JUPITER_AGGREGATOR_SHARED_ACCOUNTS_ROUTE) => {
+                Self::JupiterSharedAccountsRoute
+            }
+            (TradeVenue::Jupiter, DISCRIMINATOR_JUPITER_AGGREGATOR_SHARED_ACCOUNTS_ROUTE_V2) => {
+                Self::JupiterSharedAccountsRouteV2
+            }
+            (TradeVenue::Jupiter, DISCRIMINATOR_JUPITER_AGGREGATOR_ROUTE) => Self::JupiterRoute,
+            (TradeVenue::Jupiter, DISCRIMINATOR_JUPITER_AGGREGATOR_ROUTE_V2) => {
+                Self::JupiterRouteV2
+            }
+            (TradeVenue::Dflow, DISCRIMINATOR_DFLOW_SWAP) => Self::DflowSwap,
+            (TradeVenue::Dflow, DISCRIMINATOR_DFLOW_SWAP2) => Self::DflowSwap2,
+            (TradeVenue::Okx, DISCRIMINATOR_OKX_SWAP) => Self::OkxSwap,
+            (TradeVenue::Okx, DISCRIMINATOR_OKX_SWAP_V3) => Self::OkxSwapV3,
+            (TradeVenue::Okx, DISCRIMINATOR_OKX_SWAP_TOB_V3) => Self::OkxSwapTobV3,
+            (TradeVenue::Okx, DISCRIMINATOR_OKX_SWAP_TOB_V3_WITH_RECEIVER) => {
+                Self::OkxSwapTobV3WithReceiver
+            }
+            (TradeVenue::Okx, DISCRIMINATOR_OKX_COMMISSION_SPL_SWAP) => Self::OkxCommissionSplSwap,
+            (TradeVenue::Okx, DISCRIMINATOR_OKX_COMMISSION_SOL_SWAP) => Self::OkxCommissionSolSwap,
+            (TradeVenue::Okx, DISCRIMINATOR_OKX_COMMISSION_SPL_PROXY_SWAP) => {
+                Self::OkxCommissionSplProxySwap
+            }
+            (TradeVenue::Okx, DISCRIMINATOR_OKX_COMMISSION_SOL_PROXY_SWAP) => {
+                Self::OkxCommissionSolProxySwap
+            }
+            (TradeVenue::Okx, DISCRIMINATOR_OKX_PLATFORM_FEE_SPL_PROXY_SWAP_V2) => {
+                Self::OkxPlatformFeeSplProxySwapV2
+            }
+            (TradeVenue::Okx, DISCRIMINATOR_OKX_PLATFORM_FEE_SOL_PROXY_SWAP_V2) => {
+                Self::OkxPlatformFeeSolProxySwapV2
+            }
+            _ => return err!(ErrorCode::UnsupportedVenueInstruction),
+        };
+        Ok(instruction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_data() {
+        let mut data = DISCRIMINATOR_OKX_SWAP.to_vec();
+        data.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(
+            VenueInstruction::from_data(TradeVenue::Okx, &data).unwrap(),
+            VenueInstruction::OkxSwap
+        );
+        // okx and dflow share the `swap` discriminator
+        assert_eq!(
+            VenueInstruction::from_data(TradeVenue::Dflow, &data).unwrap(),
+            VenueInstruction::DflowSwap
+        );
+        assert!(VenueInstruction::from_data(TradeVenue::Jupiter, &data).is_err());
+
+        // jupiter `claim` and `close_token`
+        let claim = [62, 198, 214, 193, 213, 159, 108, 210];
+        let close_token = [26, 74, 236, 151, 104, 64, 183, 249];
+        assert!(VenueInstruction::from_data(TradeVenue::Jupiter, &claim).is_err());
+        assert!(VenueInstruction::from_data(TradeVenue::Jupiter, &close_token).is_err());
+        assert!(VenueInstruction::from_data(TradeVenue::Jupiter, &claim[..4]).is_err());
+    }
+}
@@ -0,0 +1,3 @@
+mod trade;
+
+pub use trade::*;
@@ -0,0 +1,462 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    append_trade_log, check_trade_cooldown, collect_protocol_fee,
+    constants::{
+        ACCESS_SEED, CONFIG_SEED, FEE_VAULT_SEED, MAX_BATCH_LEGS, TRADE_EXECUTED_VERSION,
+        TRADE_LOG_SEED, VAULT_SEED,
+    },
+    create_trade_receipt,
+    error::ErrorCode,
+    execute_venue_step,
+    instructions::two_hop::types::StepAction,
+    load_or_create_user_stats, load_partner_share, record_user_stats,
+    state::{namespace_seed, Access, Config, Partner, TradeLog, TradeLogEntry, TradeReceipt},
+    transfer_delegated_input, transfer_from_vault, validate_operator, write_trade_receipt,
+    write_user_stats, BatchTradeEvent, BatchTradeLegEvent, DelegatedInput, EventCpi, OutputAmounts,
+    ProtocolFee, TradeExecuted, VaultSeeds, VaultTransfer, VenueStep,
+};
+
+/// Accounts per leg at the front of the remaining accounts:
+/// user, access, delegate_input_token_account, receiver_output_token_account, trade_receipt,
+/// user_stats, partner, partner_fee_token_account. Users without a partner pass the program
+/// id for the last two, as for optional accounts.
+pub const BATCH_LEG_ACCOUNTS: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchLeg {
+    pub delegate: Pubkey, // must be the leg user, whose access is checked
+    pub in_amount: u64,
+    pub client_order_id: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchTradeParams {
+    pub action: StepAction,
+    pub data: Vec<u8>,
+    pub legs: Vec<BatchLeg>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BatchTrade<'info> {
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub input_mint_program: Interface<'info, TokenInterface>,
+    pub output_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub output_mint_program: Interface<'info, TokenInterface>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = input_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = input_mint_program,
+    )]
+    pub vault_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = output_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = output_mint_program,
+    )]
+    pub vault_output_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        seeds = [
+            FEE_VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            output_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = output_mint,
+        token::authority = fee_vault_authority,
+        token::token_program = output_mint_program,
+    )]
+    pub fee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Shared vault, authority of the protocol fee vaults in either vault mode
+    #[account(seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)], bump)]
+    pub fee_vault_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Venue program, checked against the action in execute_venue_step
+    pub venue_program: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TRADE_LOG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = trade_log.load()?.bump,
+    )]
+    pub trade_log: AccountLoader<'info, TradeLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+struct BatchLegAccounts<'info> {
+    user: Pubkey,
+    access: Account<'info, Access>,
+    partner: Option<Account<'info, Partner>>,
+    partner_fee_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    delegate_input_token_account: InterfaceAccount<'info, TokenAccount>,
+    receiver_output_token_account: InterfaceAccount<'info, TokenAccount>,
+    trade_receipt: &'info AccountInfo<'info>,
+    receipt_bump: u8,
+    user_stats: &'info AccountInfo<'info>,
+}
+
+fn load_leg_accounts<'info>(
+    accounts: &'info [AccountInfo<'info>],
+    leg: &BatchLeg,
+    namespace: u16,
+    output_mint: &Pubkey,
+    output_mint_program: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<BatchLegAccounts<'info>> {
+    let user = accounts[0].key();
+    // access 按 user 推导, 输入只能从 user 自己的账户划出
+    require_keys_eq!(leg.delegate, user, ErrorCode::InvalidBatchLegAccounts);
+
+    let access = Account::<Access>::try_from(&accounts[1])?;
+    let access_address = Pubkey::create_program_address(
+        &[
+            ACCESS_SEED.as_bytes(),
+            namespace_seed(&namespace),
+            user.as_ref(),
+            &[access.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::InvalidBatchLegAccounts)?;
+    require_keys_eq!(
+        access_address,
+        accounts[1].key(),
+        ErrorCode::InvalidBatchLegAccounts
+    );
+    require!(access.is_granted, ErrorCode::AccessNotGranted);
+    require!(
+        access.has_supported_version(),
+        ErrorCode::UnsupportedAccountVersion
+    );
+    require!(!access.intent_required, ErrorCode::IntentNotSupported);
+
+    let (partner, partner_fee_token_account) = if access.partner == Pubkey::default() {
+        (None, None)
+    } else {
+        (
+            Some(Account::<Partner>::try_from(&accounts[6])?),
+            Some(InterfaceAccount::<TokenAccount>::try_from(&accounts[7])?),
+        )
+    };
+
+    let delegate_input_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[2])?;
+    let receiver_output_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[3])?;
+    require_keys_eq!(
+        get_associated_token_address_with_program_id(&user, output_mint, output_mint_program),
+        receiver_output_token_account.key(),
+        ErrorCode::InvalidBatchLegAccounts
+    );
+
+    // 重复的 client_order_id 在此被拒绝
+    let receipt_bump = create_trade_receipt(
+        &accounts[4],
+        payer,
+        system_program,
+        namespace,
+        &user,
+        leg.client_order_id,
+    )?;
+
+    Ok(BatchLegAccounts {
+        user,
+        access,
+        partner,
+        partner_fee_token_account,
+        delegate_input_token_account,
+        receiver_output_token_account,
+        trade_receipt: &accounts[4],
+        receipt_bump,
+        user_stats: &accounts[5],
+    })
+}
+
+/// Splits `total` pro rata to `weights`, rounding down. The rounding dust goes to the last leg.
+pub fn split_pro_rata(total: u64, weights: &[u64]) -> Vec<u64> {
+    let total_weight: u128 = weights.iter().map(|w| *w as u128).sum();
+    if total_weight == 0 {
+        return vec![0; weights.len()];
+    }
+    let mut shares: Vec<u64> = weights
+        .iter()
+        .map(|w| ((total as u128) * (*w as u128) / total_weight) as u64)
+        .collect();
+    let dust = total - shares.iter().sum::<u64>();
+    if let Some(last) = shares.last_mut() {
+        *last += dust;
+    }
+    shares
+}
+
+pub fn process_batch_trade<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchTrade<'info>>,
+    args: BatchTradeParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    require!(
+        !args.legs.is_empty() && args.legs.len() <= MAX_BATCH_LEGS as usize,
+        ErrorCode::InvalidBatchLegs
+    );
+    let leg_accounts_len = args.legs.len() * BATCH_LEG_ACCOUNTS;
+    require!(
+        ctx.remaining_accounts.len() > leg_accounts_len,
+        ErrorCode::InvalidBatchLegAccounts
+    );
+    let (leg_infos, venue_accounts) = ctx.remaining_accounts.split_at(leg_accounts_len);
+
+    // 1. 基本检查, 多个用户共用一次 CPI, 只能在共享 vault 模式下进行
+    validate_operator(&ctx.accounts.operator, &ctx.accounts.config)?;
+    require!(
+        !ctx.accounts.config.per_user_vault,
+        ErrorCode::BatchRequiresSharedVault
+    );
+    check_trade_cooldown(&mut ctx.accounts.config)?;
+    let vault_seeds = VaultSeeds::shared(&ctx.accounts.config, ctx.bumps.vault);
+
+    // 2. 从每个 delegate 转入 vault
+    let mut legs = Vec::with_capacity(args.legs.len());
+    let mut weights = Vec::with_capacity(args.legs.len());
+    let mut total_in_amount: u64 = 0;
+    for (leg, infos) in args.legs.iter().zip(leg_infos.chunks(BATCH_LEG_ACCOUNTS)) {
+        require!(leg.in_amount > 0, ErrorCode::InvalidBatchLegs);
+        let accounts = load_leg_accounts(
+            infos,
+            leg,
+            ctx.accounts.config.namespace,
+            &ctx.accounts.output_mint.key(),
+            &ctx.accounts.output_mint_program.key(),
+            &ctx.accounts.operator.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        // vault 按扣除转账手续费后的实收数量记账
+        let received = transfer_delegated_input(
+            &ctx.accounts.vault.to_account_info(),
+            vault_seeds,
+            DelegatedInput {
+                delegate: &leg.delegate,
+                delegate_input_token_account: &accounts.delegate_input_token_account,
+                input_mint: &ctx.accounts.input_mint.to_account_info(),
+                input_mint_program: &ctx.accounts.input_mint_program.to_account_info(),
+                vault_input_token_account: &ctx
+                    .accounts
+                    .vault_input_token_account
+                    .to_account_info(),
+                decimals: ctx.accounts.input_mint.decimals,
+                hook_accounts: ctx.remaining_accounts,
+                event_cpi,
+            },
+            leg.in_amount,
+        )?
+        .net;
+        total_in_amount = total_in_amount.checked_add(received).expect("overflow");
+        weights.push(received);
+        legs.push(accounts);
+    }
+
+    // 3. 一次聚合 CPI
+    let (amount_in, amount_out) = execute_venue_step(
+        VenueStep {
+            action: &args.action,
+            data: &args.data,
+            venue_program: &ctx.accounts.venue_program,
+            remaining_accounts: venue_accounts,
+        },
+        &ctx.accounts.vault,
+        vault_seeds,
+        &mut ctx.accounts.vault_input_token_account,
+        &mut ctx.accounts.vault_output_token_account,
+    )?;
+    require!(amount_in <= total_in_amount, ErrorCode::InsufficientFunds);
+
+    // 4. 按输入比例分配输出并逐 leg 扣除协议费, 未使用的输入按比例退回
+    let output_shares = split_pro_rata(amount_out, &weights);
+    let refund_shares = split_pro_rata(total_in_amount - amount_in, &weights);
+    let clock = Clock::get()?;
+    let output_transfer = VaultTransfer {
+        mint: &ctx.accounts.output_mint,
+        mint_program: &ctx.accounts.output_mint_program,
+        vault: &ctx.accounts.vault,
+        vault_seeds,
+        hook_accounts: ctx.remaining_accounts,
+        event_cpi,
+    };
+    let input_transfer = VaultTransfer {
+        mint: &ctx.accounts.input_mint,
+        mint_program: &ctx.accounts.input_mint_program,
+        vault: &ctx.accounts.vault,
+        vault_seeds,
+        hook_accounts: ctx.remaining_accounts,
+        event_cpi,
+    };
+    for (i, (leg, accounts)) in args.legs.iter().zip(legs.iter()).enumerate() {
+        let net_output_amount = collect_protocol_fee(
+            output_transfer,
+            &ctx.accounts.vault_output_token_account,
+            output_shares[i],
+            Some(ProtocolFee::new(
+                ctx.accounts.config.fee_bps,
+                &ctx.accounts.fee_token_account,
+                load_partner_share(
+                    &accounts.access,
+                    accounts.partner.as_ref(),
+                    accounts.partner_fee_token_account.as_ref(),
+                    &ctx.accounts.output_mint.key(),
+                )?,
+            )),
+        )?;
+        let fee_amount = output_shares[i] - net_output_amount;
+        let delivered = transfer_from_vault(
+            output_transfer,
+            &ctx.accounts.vault_output_token_account,
+            &accounts.receiver_output_token_account,
+            net_output_amount,
+        )?;
+        transfer_from_vault(
+            input_transfer,
+            &ctx.accounts.vault_input_token_account,
+            &accounts.delegate_input_token_account,
+            refund_shares[i],
+        )?;
+
+        write_trade_receipt(
+            accounts.trade_receipt,
+            &TradeReceipt {
+                user: accounts.user,
+                client_order_id: leg.client_order_id,
+                operator: ctx.accounts.operator.key(),
+                input_mint: ctx.accounts.input_mint.key(),
+                output_mint: ctx.accounts.output_mint.key(),
+                input_amount: weights[i].saturating_sub(refund_shares[i]),
+                output_amount: delivered.net,
+                executed_at: clock.unix_timestamp,
+                bump: accounts.receipt_bump,
+            },
+        )?;
+
+        // 同一用户可出现在多个 leg, 统计逐 leg 读出并写回
+        let (mut user_stats, user_stats_bump) = load_or_create_user_stats(
+            accounts.user_stats,
+            &ctx.accounts.operator.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.config.namespace,
+            &accounts.user,
+        )?;
+        record_user_stats(
+            &mut user_stats,
+            accounts.user,
+            user_stats_bump,
+            &ctx.accounts.input_mint.key(),
+            weights[i].saturating_sub(refund_shares[i]),
+            &ctx.accounts.output_mint.key(),
+            OutputAmounts {
+                protocol_fee: fee_amount,
+                delivered: delivered.net,
+                parked: 0,
+            },
+        )?;
+        write_user_stats(accounts.user_stats, &user_stats)?;
+        append_trade_log(
+            &ctx.accounts.trade_log,
+            TradeLogEntry {
+                user: accounts.user,
+                input_mint: ctx.accounts.input_mint.key(),
+                output_mint: ctx.accounts.output_mint.key(),
+                operator: ctx.accounts.operator.key(),
+                input_amount: weights[i].saturating_sub(refund_shares[i]),
+                output_amount: delivered.net,
+                slot: clock.slot,
+                venue: args.action.venue() as u8,
+                padding: [0; 7],
+            },
+        )?;
+
+        emit_cpi!(TradeExecuted {
+            version: TRADE_EXECUTED_VERSION,
+            sequence: ctx.accounts.config.next_trade_sequence(),
+            venue: args.action.venue(),
+            user: accounts.user,
+            operator: ctx.accounts.operator.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            client_order_id: leg.client_order_id,
+            input_amount: weights[i].saturating_sub(refund_shares[i]),
+            refund_amount: refund_shares[i],
+            output_amount: delivered.net,
+            parked_amount: 0,
+            fee_amount,
+            commission_amount: 0,
+            slot: clock.slot,
+        });
+        emit_cpi!(BatchTradeLegEvent {
+            user: accounts.user,
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            input_amount: weights[i].saturating_sub(refund_shares[i]),
+            output_amount: net_output_amount,
+            refund_amount: refund_shares[i],
+            operator: ctx.accounts.operator.key(),
+        });
+    }
+
+    // 5. emit event
+    emit_cpi!(BatchTradeEvent {
+        input_mint: ctx.accounts.input_mint.key(),
+        output_mint: ctx.accounts.output_mint.key(),
+        users: legs.len() as u8,
+        input_amount: amount_in,
+        output_amount: amount_out,
+        action: args.action.to_string(),
+        operator: ctx.accounts.operator.key(),
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_pro_rata() {
+        assert_eq!(split_pro_rata(100, &[1, 1, 1]), vec![33, 33, 34]);
+        assert_eq!(split_pro_rata(1000, &[100, 300]), vec![250, 750]);
+        assert_eq!(split_pro_rata(0, &[5, 7]), vec![0, 0]);
+        assert_eq!(split_pro_rata(10, &[0, 0]), vec![0, 0]);
+        assert_eq!(
+            split_pro_rata(u64::MAX, &[u64::MAX, 1]).iter().sum::<u64>(),
+            u64::MAX
+        );
+    }
+}
@@ -0,0 +1,353 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    append_trade_log,
+    constants::{
+        ACCESS_SEED, CONFIG_SEED, DCA_PLAN_SEED, FEE_VAULT_SEED, TRADE_EXECUTED_VERSION,
+        TRADE_LOG_SEED, TRADE_RECEIPT_SEED, USER_STATS_SEED, VAULT_SEED,
+    },
+    error::ErrorCode,
+    execute_venue_step,
+    instructions::two_hop::types::StepAction,
+    load_partner_share, record_trade_receipt, record_user_stats,
+    state::{namespace_seed, Access, Config, DcaPlan},
+    transfer_from_vault, transfer_output_tokens, validate_and_transfer_input,
+    DcaSliceExecutedEvent, DelegatedInput, EventCpi, ExecutedTrade, Partner, ProtocolFee,
+    TradeExecuted, TradeLog, TradeLogEntry, TradeReceipt, UserStats, VaultSeeds, VaultTransfer,
+    VenueStep,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DcaSliceParams {
+    pub action: StepAction,
+    pub data: Vec<u8>,
+    pub client_order_id: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: DcaSliceParams)]
+pub struct ExecuteDcaSlice<'info> {
+    #[account(address = dca_plan.input_mint)]
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub input_mint_program: Interface<'info, TokenInterface>,
+    #[account(address = dca_plan.output_mint)]
+    pub output_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub output_mint_program: Interface<'info, TokenInterface>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            config.vault_user_seed(user.key),
+        ],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = user,
+        seeds = [
+            DCA_PLAN_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &dca_plan.plan_id.to_le_bytes(),
+        ],
+        bump = dca_plan.bump,
+    )]
+    pub dca_plan: Box<Account<'info, DcaPlan>>,
+
+    #[account(
+        mut,
+        associated_token::mint = input_mint,
+        associated_token::authority = user,
+        associated_token::token_program = input_mint_program,
+    )]
+    pub delegate_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = input_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = input_mint_program,
+    )]
+    pub vault_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = output_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = output_mint_program,
+    )]
+    pub vault_output_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.key().as_ref()],
+        bump,
+        constraint = access.is_granted @ ErrorCode::AccessNotGranted,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub access: Account<'info, Access>,
+
+    /// CHECK: This is the user's account
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = output_mint,
+        associated_token::authority = user,
+        associated_token::token_program = output_mint_program,
+    )]
+    pub receiver_output_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        seeds = [
+            FEE_VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            output_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = output_mint,
+        token::authority = fee_vault_authority,
+        token::token_program = output_mint_program,
+    )]
+    pub fee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Shared vault, authority of the protocol fee vaults in either vault mode
+    #[account(seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)], bump)]
+    pub fee_vault_authority: UncheckedAccount<'info>,
+
+    #[account(address = access.partner @ ErrorCode::InvalidPartner)]
+    pub partner: Option<Box<Account<'info, Partner>>>,
+
+    #[account(mut)]
+    pub partner_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: Venue program, checked against the action in execute_venue_step
+    pub venue_program: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = TradeReceipt::LEN,
+        seeds = [
+            TRADE_RECEIPT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &args.client_order_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub trade_receipt: Box<Account<'info, TradeReceipt>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = UserStats::LEN,
+        seeds = [
+            USER_STATS_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    #[account(
+        mut,
+        seeds = [TRADE_LOG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = trade_log.load()?.bump,
+    )]
+    pub trade_log: AccountLoader<'info, TradeLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_execute_dca_slice<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteDcaSlice<'info>>,
+    args: DcaSliceParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    let vault_seeds = VaultSeeds::new(&ctx.accounts.config, ctx.accounts.user.key, ctx.bumps.vault);
+
+    // 1. 检查 slice 是否到期
+    let now = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.dca_plan.is_due(now), ErrorCode::DcaSliceNotDue);
+    let slice_amount = ctx.accounts.dca_plan.next_slice_amount();
+    require!(slice_amount > 0, ErrorCode::DcaPlanCompleted);
+
+    // 2. 验证并转移输入代币, vault 实收数量扣除转账手续费
+    let received = validate_and_transfer_input(
+        &ctx.accounts.operator.to_account_info(),
+        &mut ctx.accounts.config,
+        &ctx.accounts.vault.to_account_info(),
+        vault_seeds,
+        DelegatedInput {
+            delegate: &ctx.accounts.user.key(),
+            delegate_input_token_account: &ctx.accounts.delegate_input_token_account,
+            input_mint: &ctx.accounts.input_mint.to_account_info(),
+            input_mint_program: &ctx.accounts.input_mint_program.to_account_info(),
+            vault_input_token_account: &ctx.accounts.vault_input_token_account.to_account_info(),
+            decimals: ctx.accounts.input_mint.decimals,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
+        slice_amount,
+    )?;
+
+    // 3. CPI
+    let (amount_in, amount_out) = execute_venue_step(
+        VenueStep {
+            action: &args.action,
+            data: &args.data,
+            venue_program: &ctx.accounts.venue_program,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        &ctx.accounts.vault,
+        vault_seeds,
+        &mut ctx.accounts.vault_input_token_account,
+        &mut ctx.accounts.vault_output_token_account,
+    )?;
+    require!(amount_in > 0, ErrorCode::DcaSliceEmpty);
+    require!(amount_in <= received.net, ErrorCode::InsufficientFunds);
+
+    // 4. 输出给 user, 未使用的输入退回
+    let initial_output_balance = ctx.accounts.vault_output_token_account.amount - amount_out;
+    let output = transfer_output_tokens(
+        VaultTransfer {
+            mint: &ctx.accounts.output_mint,
+            mint_program: &ctx.accounts.output_mint_program,
+            vault: &ctx.accounts.vault,
+            vault_seeds,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
+        &ctx.accounts.vault_output_token_account,
+        Some(&ctx.accounts.receiver_output_token_account),
+        initial_output_balance,
+        Some(ProtocolFee::new(
+            ctx.accounts.config.fee_bps,
+            &ctx.accounts.fee_token_account,
+            load_partner_share(
+                &ctx.accounts.access,
+                ctx.accounts.partner.as_deref(),
+                ctx.accounts.partner_fee_token_account.as_deref(),
+                &ctx.accounts.output_mint.key(),
+            )?,
+        )),
+        None,
+    )?;
+    let refund = transfer_from_vault(
+        VaultTransfer {
+            mint: &ctx.accounts.input_mint,
+            mint_program: &ctx.accounts.input_mint_program,
+            vault: &ctx.accounts.vault,
+            vault_seeds,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
+        &ctx.accounts.vault_input_token_account,
+        &ctx.accounts.delegate_input_token_account,
+        received.net - amount_in,
+    )?;
+
+    // 5. 按用户实收数量检查价格区间, 协议费与转账手续费已扣除
+    require!(
+        ctx.accounts
+            .dca_plan
+            .is_within_price_bounds(amount_in, output.delivered),
+        ErrorCode::DcaPriceOutOfBounds
+    );
+
+    // 6. 记录成交回执, 用户统计与成交日志
+    record_trade_receipt(
+        &mut ctx.accounts.trade_receipt,
+        ExecutedTrade {
+            user: ctx.accounts.user.key(),
+            client_order_id: args.client_order_id,
+            operator: ctx.accounts.operator.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            input_amount: amount_in,
+            output_amount: output.delivered,
+        },
+        ctx.bumps.trade_receipt,
+    )?;
+    record_user_stats(
+        &mut ctx.accounts.user_stats,
+        ctx.accounts.user.key(),
+        ctx.bumps.user_stats,
+        &ctx.accounts.input_mint.key(),
+        amount_in,
+        &ctx.accounts.output_mint.key(),
+        output,
+    )?;
+    append_trade_log(
+        &ctx.accounts.trade_log,
+        TradeLogEntry {
+            user: ctx.accounts.user.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            operator: ctx.accounts.operator.key(),
+            input_amount: amount_in,
+            output_amount: output.delivered,
+            slot: Clock::get()?.slot,
+            venue: args.action.venue() as u8,
+            padding: [0; 7],
+        },
+    )?;
+
+    // 7. 更新 plan, 预算按用户实际付出扣减, 退回的输入不计入
+    let spent_amount = slice_amount - refund.net;
+    let dca_plan = &mut ctx.accounts.dca_plan;
+    dca_plan.record_slice(spent_amount, output.delivered, now)?;
+
+    // 8. emit event
+    emit_cpi!(TradeExecuted {
+        version: TRADE_EXECUTED_VERSION,
+        sequence: ctx.accounts.config.next_trade_sequence(),
+        venue: args.action.venue(),
+        user: ctx.accounts.user.key(),
+        operator: ctx.accounts.operator.key(),
+        input_mint: ctx.accounts.input_mint.key(),
+        output_mint: ctx.accounts.output_mint.key(),
+        client_order_id: args.client_order_id,
+        input_amount: amount_in,
+        refund_amount: received.net - amount_in,
+        output_amount: output.delivered,
+        parked_amount: output.parked,
+        fee_amount: output.protocol_fee,
+        commission_amount: 0,
+        slot: Clock::get()?.slot,
+    });
+    emit_cpi!(DcaSliceExecutedEvent {
+        user: ctx.accounts.user.key(),
+        plan: dca_plan.key(),
+        input_mint: ctx.accounts.input_mint.key(),
+        output_mint: ctx.accounts.output_mint.key(),
+        input_amount: amount_in,
+        output_amount: output.delivered,
+        slices_done: dca_plan.slices_done,
+        amount_spent: dca_plan.amount_spent,
+        action: args.action.to_string(),
+        operator: ctx.accounts.operator.key(),
+    });
+
+    Ok(())
+}
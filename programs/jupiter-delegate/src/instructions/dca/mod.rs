@@ -0,0 +1,5 @@
+mod execute;
+mod plan;
+
+pub use execute::*;
+pub use plan::*;
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::constants::{ACCESS_SEED, CONFIG_SEED, DCA_PLAN_SEED};
+use crate::error::ErrorCode;
+use crate::event::{DcaPlanCancelledEvent, DcaPlanCreatedEvent};
+use crate::state::{namespace_seed, Access, Config, DcaPlan};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateDcaPlanParams {
+    pub plan_id: u64,
+    pub amount_per_slice: u64,
+    pub interval: i64,
+    pub total_budget: u64,
+    pub min_output_amount: Option<u64>,
+    pub max_output_amount: Option<u64>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: CreateDcaPlanParams)]
+pub struct CreateDcaPlan<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.key().as_ref()],
+        bump,
+        constraint = access.is_granted @ ErrorCode::AccessNotGranted,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub access: Account<'info, Access>,
+    pub input_mint: InterfaceAccount<'info, Mint>,
+    pub output_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = user,
+        space = DcaPlan::LEN,
+        seeds = [
+            DCA_PLAN_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &params.plan_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub dca_plan: Account<'info, DcaPlan>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_create_dca_plan(
+    ctx: Context<CreateDcaPlan>,
+    params: CreateDcaPlanParams,
+) -> Result<()> {
+    let dca_plan = &mut ctx.accounts.dca_plan;
+    dca_plan.user = ctx.accounts.user.key();
+    dca_plan.plan_id = params.plan_id;
+    dca_plan.input_mint = ctx.accounts.input_mint.key();
+    dca_plan.output_mint = ctx.accounts.output_mint.key();
+    dca_plan.amount_per_slice = params.amount_per_slice;
+    dca_plan.interval = params.interval;
+    dca_plan.total_budget = params.total_budget;
+    dca_plan.min_output_amount = params.min_output_amount;
+    dca_plan.max_output_amount = params.max_output_amount;
+    dca_plan.slices_done = 0;
+    dca_plan.amount_spent = 0;
+    dca_plan.amount_received = 0;
+    dca_plan.last_executed_at = 0;
+    dca_plan.created_at = Clock::get()?.unix_timestamp;
+    dca_plan.bump = ctx.bumps.dca_plan;
+    require!(dca_plan.is_valid(), ErrorCode::InvalidDcaPlan);
+
+    emit_cpi!(DcaPlanCreatedEvent {
+        user: dca_plan.user,
+        plan: dca_plan.key(),
+        input_mint: dca_plan.input_mint,
+        output_mint: dca_plan.output_mint,
+        amount_per_slice: dca_plan.amount_per_slice,
+        interval: dca_plan.interval,
+        total_budget: dca_plan.total_budget,
+    });
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelDcaPlan<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        close = user,
+        has_one = user,
+        seeds = [
+            DCA_PLAN_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &dca_plan.plan_id.to_le_bytes(),
+        ],
+        bump = dca_plan.bump,
+    )]
+    pub dca_plan: Account<'info, DcaPlan>,
+}
+
+pub fn process_cancel_dca_plan(ctx: Context<CancelDcaPlan>) -> Result<()> {
+    let dca_plan = &ctx.accounts.dca_plan;
+
+    emit_cpi!(DcaPlanCancelledEvent {
+        user: dca_plan.user,
+        plan: dca_plan.key(),
+        slices_done: dca_plan.slices_done,
+        amount_spent: dca_plan.amount_spent,
+    });
+    Ok(())
+}
@@ -4,14 +4,17 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
+#[cfg(feature = "legacy-events")]
+use crate::JupiterSwapEvent;
 use crate::{
-    constants::{ACCESS_SEED, VAULT_SEED},
+    constants::{ACCESS_SEED, CONFIG_SEED, TRADE_EXECUTED_VERSION, VAULT_SEED},
     error::ErrorCode,
     execute_cross_program_invocation,
     jupiter_aggregator::program::Jupiter,
     jupiter_program_id,
-    state::Config,
-    validate_and_transfer_input, validate_receiver_token_account, Access, JupiterSwapEvent,
+    state::{namespace_seed, Config},
+    validate_and_transfer_input, validate_receiver_token_account, Access, DelegatedInput, EventCpi,
+    TradeExecuted, TradeVenue, VaultSeeds,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -21,6 +24,7 @@ pub struct JupiterSwapParams {
     pub delegate: Pubkey,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct JupiterSwap<'info> {
     pub input_mint: InterfaceAccount<'info, Mint>,
@@ -32,7 +36,11 @@ pub struct JupiterSwap<'info> {
     pub operator: Signer<'info>,
     #[account(
         mut,
-        seeds=[VAULT_SEED.as_bytes()],
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            config.vault_user_seed(user.key),
+        ],
         bump
     )]
     pub vault: SystemAccount<'info>,
@@ -43,14 +51,20 @@ pub struct JupiterSwap<'info> {
         associated_token::token_program = input_mint_program,
     )]
     pub vault_input_token_account: InterfaceAccount<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
     pub config: Box<Account<'info, Config>>,
     #[account(mut)]
     pub delegate_input_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(
-        seeds = [ACCESS_SEED.as_bytes(), user.key().as_ref()],
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.key().as_ref()],
         bump,
-        constraint = access.is_granted @ ErrorCode::AccessNotGranted
+        constraint = access.is_granted @ ErrorCode::AccessNotGranted,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
     )]
     pub access: Account<'info, Access>,
     /// CHECK: This is the user's account
@@ -58,20 +72,30 @@ pub struct JupiterSwap<'info> {
     pub jupiter_program: Program<'info, Jupiter>,
 }
 
-pub fn process_jupiter_swap(ctx: Context<JupiterSwap>, params: JupiterSwapParams) -> Result<()> {
+pub fn process_jupiter_swap<'info>(
+    ctx: Context<'_, '_, '_, 'info, JupiterSwap<'info>>,
+    params: JupiterSwapParams,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    let vault_seeds = VaultSeeds::new(&ctx.accounts.config, ctx.accounts.user.key, ctx.bumps.vault);
+
     // 1. 验证并转移输入代币
-    validate_and_transfer_input(
+    let received = validate_and_transfer_input(
         &ctx.accounts.operator.to_account_info(),
         &mut ctx.accounts.config,
         &ctx.accounts.vault.to_account_info(),
-        ctx.bumps.vault,
-        &ctx.accounts.delegate_input_token_account,
-        &ctx.accounts.input_mint.to_account_info(),
-        &ctx.accounts.input_mint_program.to_account_info(),
-        &ctx.accounts.vault_input_token_account.to_account_info(),
+        vault_seeds,
+        DelegatedInput {
+            delegate: &params.delegate,
+            delegate_input_token_account: &ctx.accounts.delegate_input_token_account,
+            input_mint: &ctx.accounts.input_mint.to_account_info(),
+            input_mint_program: &ctx.accounts.input_mint_program.to_account_info(),
+            vault_input_token_account: &ctx.accounts.vault_input_token_account.to_account_info(),
+            decimals: ctx.accounts.input_mint.decimals,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
         params.in_amount,
-        ctx.accounts.input_mint.decimals,
-        &params.delegate,
     )?;
 
     // 2. 验证接收者代币账户存在
@@ -88,17 +112,31 @@ pub fn process_jupiter_swap(ctx: Context<JupiterSwap>, params: JupiterSwapParams
         &jupiter_program_id(),
         ctx.remaining_accounts,
         &ctx.accounts.vault.key(),
-        ctx.bumps.vault,
+        vault_seeds,
         params.data,
         None,
-        None,
-        None,
-        None,
-        None,
     )?;
 
     // 4. emit event
-    emit!(JupiterSwapEvent {
+    emit_cpi!(TradeExecuted {
+        version: TRADE_EXECUTED_VERSION,
+        sequence: ctx.accounts.config.next_trade_sequence(),
+        venue: TradeVenue::Jupiter,
+        user: ctx.accounts.user.key(),
+        operator: ctx.accounts.operator.key(),
+        input_mint: ctx.accounts.input_mint.key(),
+        output_mint: ctx.accounts.output_mint.key(),
+        client_order_id: 0,
+        input_amount: received.net,
+        refund_amount: 0,
+        output_amount: 0,
+        parked_amount: 0,
+        fee_amount: 0,
+        commission_amount: 0,
+        slot: Clock::get()?.slot,
+    });
+    #[cfg(feature = "legacy-events")]
+    emit_cpi!(JupiterSwapEvent {
         user: ctx.accounts.user.key(),
         input_mint: ctx.accounts.input_mint.key(),
         output_mint: ctx.accounts.output_mint.key(),
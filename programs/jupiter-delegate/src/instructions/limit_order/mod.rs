@@ -0,0 +1,5 @@
+mod fill;
+mod order;
+
+pub use fill::*;
+pub use order::*;
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::constants::{ACCESS_SEED, CONFIG_SEED, LIMIT_ORDER_SEED};
+use crate::error::ErrorCode;
+use crate::event::{LimitOrderCancelledEvent, LimitOrderCreatedEvent, LimitOrderExpiredEvent};
+use crate::state::{namespace_seed, Access, Config, LimitOrder};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateLimitOrderParams {
+    pub order_id: u64,
+    pub input_amount: u64,
+    pub rate_numerator: u64,
+    pub rate_denominator: u64,
+    pub expires_at: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: CreateLimitOrderParams)]
+pub struct CreateLimitOrder<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.key().as_ref()],
+        bump,
+        constraint = access.is_granted @ ErrorCode::AccessNotGranted,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub access: Account<'info, Access>,
+    pub input_mint: InterfaceAccount<'info, Mint>,
+    pub output_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = user,
+        space = LimitOrder::LEN,
+        seeds = [
+            LIMIT_ORDER_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &params.order_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub limit_order: Account<'info, LimitOrder>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_create_limit_order(
+    ctx: Context<CreateLimitOrder>,
+    params: CreateLimitOrderParams,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        params.input_amount > 0 && params.rate_denominator > 0 && params.expires_at > now,
+        ErrorCode::InvalidLimitOrder
+    );
+
+    let limit_order = &mut ctx.accounts.limit_order;
+    limit_order.user = ctx.accounts.user.key();
+    limit_order.order_id = params.order_id;
+    limit_order.input_mint = ctx.accounts.input_mint.key();
+    limit_order.output_mint = ctx.accounts.output_mint.key();
+    limit_order.input_amount = params.input_amount;
+    limit_order.remaining_amount = params.input_amount;
+    limit_order.received_amount = 0;
+    limit_order.rate_numerator = params.rate_numerator;
+    limit_order.rate_denominator = params.rate_denominator;
+    limit_order.expires_at = params.expires_at;
+    limit_order.bump = ctx.bumps.limit_order;
+
+    emit_cpi!(LimitOrderCreatedEvent {
+        user: limit_order.user,
+        order: limit_order.key(),
+        input_mint: limit_order.input_mint,
+        output_mint: limit_order.output_mint,
+        input_amount: limit_order.input_amount,
+        rate_numerator: limit_order.rate_numerator,
+        rate_denominator: limit_order.rate_denominator,
+        expires_at: limit_order.expires_at,
+    });
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelLimitOrder<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        close = user,
+        has_one = user,
+        seeds = [
+            LIMIT_ORDER_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &limit_order.order_id.to_le_bytes(),
+        ],
+        bump = limit_order.bump,
+    )]
+    pub limit_order: Account<'info, LimitOrder>,
+}
+
+pub fn process_cancel_limit_order(ctx: Context<CancelLimitOrder>) -> Result<()> {
+    emit_cpi!(LimitOrderCancelledEvent {
+        user: ctx.accounts.limit_order.user,
+        order: ctx.accounts.limit_order.key(),
+        remaining_amount: ctx.accounts.limit_order.remaining_amount,
+    });
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExpireLimitOrder<'info> {
+    pub executor: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK: This is the user's account, receives the order rent
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = user,
+        has_one = user,
+        seeds = [
+            LIMIT_ORDER_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &limit_order.order_id.to_le_bytes(),
+        ],
+        bump = limit_order.bump,
+    )]
+    pub limit_order: Account<'info, LimitOrder>,
+}
+
+pub fn process_expire_limit_order(ctx: Context<ExpireLimitOrder>) -> Result<()> {
+    require!(
+        ctx.accounts
+            .limit_order
+            .is_expired(Clock::get()?.unix_timestamp),
+        ErrorCode::LimitOrderNotExpired
+    );
+
+    emit_cpi!(LimitOrderExpiredEvent {
+        user: ctx.accounts.limit_order.user,
+        order: ctx.accounts.limit_order.key(),
+        remaining_amount: ctx.accounts.limit_order.remaining_amount,
+    });
+    Ok(())
+}
@@ -1,15 +1,27 @@
 mod admin;
 mod aggregator;
+mod batch;
+mod dca;
 mod jupiter;
+mod limit_order;
+mod partner;
 mod perpetuals;
+mod receipt;
 mod simple;
+mod stats;
 mod two_hop;
 pub mod utils;
 
 pub use admin::*;
 pub use aggregator::*;
+pub use batch::*;
+pub use dca::*;
 pub use jupiter::*;
+pub use limit_order::*;
+pub use partner::*;
 pub use perpetuals::*;
+pub use receipt::*;
 pub use simple::*;
+pub use stats::*;
 pub use two_hop::*;
 pub use utils::*;
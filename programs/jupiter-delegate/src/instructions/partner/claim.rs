@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{CONFIG_SEED, PARTNER_FEE_SEED, PARTNER_SEED, VAULT_SEED};
+use crate::error::ErrorCode;
+use crate::event::PartnerFeeClaimedEvent;
+use crate::instructions::utils::{transfer_from_vault, EventCpi, VaultSeeds, VaultTransfer};
+use crate::state::{namespace_seed, Config, Partner};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimPartnerFee<'info> {
+    #[account(mut)]
+    pub payout_owner: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            PARTNER_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            payout_owner.key().as_ref(),
+        ],
+        bump = partner.bump,
+        has_one = payout_owner,
+    )]
+    pub partner: Account<'info, Partner>,
+
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [PARTNER_FEE_SEED.as_bytes(), partner.key().as_ref(), mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program,
+    )]
+    pub partner_fee_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payout_owner,
+        associated_token::mint = mint,
+        associated_token::authority = payout_owner,
+        associated_token::token_program = token_program,
+    )]
+    pub payout_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_claim_partner_fee<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimPartnerFee<'info>>,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    let amount = ctx.accounts.partner_fee_token_account.amount;
+    require!(amount > 0, ErrorCode::InsufficientFunds);
+
+    transfer_from_vault(
+        VaultTransfer {
+            mint: &ctx.accounts.mint,
+            mint_program: &ctx.accounts.token_program,
+            vault: &ctx.accounts.vault,
+            vault_seeds: VaultSeeds::shared(&ctx.accounts.config, ctx.bumps.vault),
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
+        &ctx.accounts.partner_fee_token_account,
+        &ctx.accounts.payout_token_account,
+        amount,
+    )?;
+
+    emit_cpi!(PartnerFeeClaimedEvent {
+        partner: ctx.accounts.partner.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        payout_owner: ctx.accounts.payout_owner.key(),
+    });
+    Ok(())
+}
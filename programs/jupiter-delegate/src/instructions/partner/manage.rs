@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{BPS_DENOMINATOR, CONFIG_SEED, PARTNER_FEE_SEED, PARTNER_SEED, VAULT_SEED};
+use crate::error::ErrorCode;
+use crate::event::PartnerEvent;
+use crate::state::{namespace_seed, Config, Partner};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreatePartner<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        has_one = admin @ ErrorCode::AdminAuthorityMismatch,
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK: This is the partner's payout owner
+    pub payout_owner: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = admin,
+        space = Partner::LEN,
+        seeds = [
+            PARTNER_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            payout_owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub partner: Account<'info, Partner>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_create_partner(ctx: Context<CreatePartner>, share_bps: u16) -> Result<()> {
+    require!(
+        share_bps as u64 <= BPS_DENOMINATOR,
+        ErrorCode::InvalidPartnerShareBps
+    );
+    let partner = &mut ctx.accounts.partner;
+    partner.payout_owner = ctx.accounts.payout_owner.key();
+    partner.share_bps = share_bps;
+    partner.bump = ctx.bumps.partner;
+
+    emit_cpi!(PartnerEvent {
+        partner: partner.key(),
+        payout_owner: partner.payout_owner,
+        share_bps,
+    });
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ModifyPartnerShare<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        has_one = admin @ ErrorCode::AdminAuthorityMismatch,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [
+            PARTNER_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            partner.payout_owner.as_ref(),
+        ],
+        bump = partner.bump,
+    )]
+    pub partner: Account<'info, Partner>,
+}
+
+pub fn process_modify_partner_share(
+    ctx: Context<ModifyPartnerShare>,
+    share_bps: u16,
+) -> Result<()> {
+    require!(
+        share_bps as u64 <= BPS_DENOMINATOR,
+        ErrorCode::InvalidPartnerShareBps
+    );
+    let partner = &mut ctx.accounts.partner;
+    partner.share_bps = share_bps;
+
+    emit_cpi!(PartnerEvent {
+        partner: partner.key(),
+        payout_owner: partner.payout_owner,
+        share_bps,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitPartnerFeeAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [
+            PARTNER_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            partner.payout_owner.as_ref(),
+        ],
+        bump = partner.bump,
+    )]
+    pub partner: Account<'info, Partner>,
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [PARTNER_FEE_SEED.as_bytes(), partner.key().as_ref(), mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program,
+    )]
+    pub partner_fee_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_init_partner_fee_account(_ctx: Context<InitPartnerFeeAccount>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,5 @@
+mod claim;
+mod manage;
+
+pub use claim::*;
+pub use manage::*;
@@ -6,6 +6,9 @@ pub const DISCRIMINATOR_ADD_LIQUIDITY: &[u8; 8] = &[228, 162, 78, 28, 70, 219, 1
 // remove_liquidity2
 pub const DISCRIMINATOR_REMOVE_LIQUIDITY: &[u8; 8] = &[230, 215, 82, 127, 241, 101, 227, 146];
 
+// swap2
+pub const DISCRIMINATOR_SWAP2: &[u8; 8] = &[65, 75, 63, 76, 235, 91, 91, 136];
+
 // instant_increase_position
 pub const DISCRIMINATOR_INSTANT_INCREASE_POSITION: &[u8; 8] =
     &[164, 126, 68, 182, 223, 166, 64, 183];
@@ -2,45 +2,66 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use super::{
-    AddLiquidity2Params, JupiterPerpetualsAction, RemoveLiquidity2Params,
-    DISCRIMINATOR_ADD_LIQUIDITY, DISCRIMINATOR_REMOVE_LIQUIDITY,
+    AddLiquidity2Params, JupiterPerpetualsAction, RemoveLiquidity2Params, Swap2Params,
+    DISCRIMINATOR_ADD_LIQUIDITY, DISCRIMINATOR_REMOVE_LIQUIDITY, DISCRIMINATOR_SWAP2,
 };
+#[cfg(feature = "legacy-events")]
+use crate::JupiterPerpetualsEvent;
 use crate::{
-    error::ErrorCode, execute_cross_program_invocation, jupiter_perpetuals,
-    jupiter_perpetuals::program::Perpetuals, jupiter_perpetuals_program_id,
-    validate_and_transfer_input, Access, Config, JupiterPerpetualsEvent, ACCESS_SEED, VAULT_SEED,
+    append_trade_log, error::ErrorCode, execute_cross_program_invocation, jupiter_perpetuals,
+    jupiter_perpetuals::program::Perpetuals, jupiter_perpetuals_program_id, load_partner_share,
+    namespace_seed, record_trade_receipt, record_user_stats, validate_and_transfer_input,
+    verify_trade_intent, Access, Config, DelegatedInput, EventCpi, ExecutedTrade, Partner,
+    ProtocolFee, TradeExecuted, TradeIntent, TradeLog, TradeLogEntry, TradeReceipt, TradeTerms,
+    TradeVenue, UserStats, VaultSeeds, VaultTransfer, VenueOutput, ACCESS_SEED, CONFIG_SEED,
+    FEE_VAULT_SEED, TRADE_EXECUTED_VERSION, TRADE_LOG_SEED, TRADE_RECEIPT_SEED, USER_STATS_SEED,
+    VAULT_SEED,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct JupiterLiquidityParams {
     pub delegate: Pubkey,
     pub data: Vec<u8>,
+    pub client_order_id: u64,
+    pub intent: Option<TradeIntent>,
 }
 
 impl JupiterLiquidityParams {
-    fn get_action(&self) -> JupiterPerpetualsAction {
+    fn get_action(&self) -> Result<JupiterPerpetualsAction> {
+        require!(self.data.len() >= 8, ErrorCode::InvalidPerpetualsData);
         let (discriminator, rest) = self.data.split_at(8);
         let discriminator = arrayref::array_ref![discriminator, 0, 8];
         if discriminator.eq(DISCRIMINATOR_ADD_LIQUIDITY) {
-            let p = jupiter_perpetuals::types::AddLiquidity2Params::try_from_slice(&rest).unwrap();
-            return JupiterPerpetualsAction::AddLiquidity(AddLiquidity2Params::new(
-                p.token_amount_in,
-                p.min_lp_amount_out,
-                p.token_amount_pre_swap,
+            let p = jupiter_perpetuals::types::AddLiquidity2Params::try_from_slice(rest)
+                .map_err(|_| ErrorCode::InvalidPerpetualsData)?;
+            return Ok(JupiterPerpetualsAction::AddLiquidity(
+                AddLiquidity2Params::new(
+                    p.token_amount_in,
+                    p.min_lp_amount_out,
+                    p.token_amount_pre_swap,
+                ),
             ));
         } else if discriminator.eq(DISCRIMINATOR_REMOVE_LIQUIDITY) {
-            let p =
-                jupiter_perpetuals::types::RemoveLiquidity2Params::try_from_slice(&rest).unwrap();
-            return JupiterPerpetualsAction::RemoveLiquidity(RemoveLiquidity2Params::new(
-                p.lp_amount_in,
-                p.min_amount_out,
+            let p = jupiter_perpetuals::types::RemoveLiquidity2Params::try_from_slice(rest)
+                .map_err(|_| ErrorCode::InvalidPerpetualsData)?;
+            return Ok(JupiterPerpetualsAction::RemoveLiquidity(
+                RemoveLiquidity2Params::new(p.lp_amount_in, p.min_amount_out),
             ));
+        } else if discriminator.eq(DISCRIMINATOR_SWAP2) {
+            let p = jupiter_perpetuals::types::Swap2Params::try_from_slice(rest)
+                .map_err(|_| ErrorCode::InvalidPerpetualsData)?;
+            return Ok(JupiterPerpetualsAction::Swap(Swap2Params::new(
+                p.amount_in,
+                p.min_amount_out,
+            )));
         }
-        panic!("invalidata data")
+        err!(ErrorCode::InvalidPerpetualsData)
     }
 }
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(args: JupiterLiquidityParams)]
 pub struct JupiterPerpetuals<'info> {
     pub input_mint: Box<InterfaceAccount<'info, Mint>>,
     pub input_mint_program: Interface<'info, TokenInterface>,
@@ -50,7 +71,15 @@ pub struct JupiterPerpetuals<'info> {
     #[account(mut)]
     pub operator: Signer<'info>,
 
-    #[account(mut, seeds=[VAULT_SEED.as_bytes()], bump)]
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            config.vault_user_seed(user.key),
+        ],
+        bump
+    )]
     pub vault: SystemAccount<'info>,
 
     #[account(mut)]
@@ -72,13 +101,19 @@ pub struct JupiterPerpetuals<'info> {
     )]
     pub vault_output_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
     pub config: Box<Account<'info, Config>>,
 
     #[account(
-        seeds = [ACCESS_SEED.as_bytes(), user.key().as_ref()],
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.key().as_ref()],
         bump,
         constraint = access.is_granted @ ErrorCode::AccessNotGranted,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
     )]
     pub access: Account<'info, Access>,
 
@@ -93,43 +128,206 @@ pub struct JupiterPerpetuals<'info> {
     )]
     pub receiver_output_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        init_if_needed,
+        payer = operator,
+        seeds = [
+            FEE_VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            output_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = output_mint,
+        token::authority = fee_vault_authority,
+        token::token_program = output_mint_program,
+    )]
+    pub fee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Shared vault, authority of the protocol fee vaults in either vault mode
+    #[account(seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)], bump)]
+    pub fee_vault_authority: UncheckedAccount<'info>,
+
+    #[account(address = access.partner @ ErrorCode::InvalidPartner)]
+    pub partner: Option<Box<Account<'info, Partner>>>,
+
+    #[account(mut)]
+    pub partner_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     pub perpetuals_program: Program<'info, Perpetuals>,
+
+    /// CHECK: Instructions sysvar, required to verify a user-signed intent
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = TradeReceipt::LEN,
+        seeds = [
+            TRADE_RECEIPT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &args.client_order_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub trade_receipt: Box<Account<'info, TradeReceipt>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = UserStats::LEN,
+        seeds = [
+            USER_STATS_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    #[account(
+        mut,
+        seeds = [TRADE_LOG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = trade_log.load()?.bump,
+    )]
+    pub trade_log: AccountLoader<'info, TradeLog>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn process_jupiter_perpetuals<'a>(
     ctx: Context<'_, '_, '_, 'a, JupiterPerpetuals<'a>>,
     args: JupiterLiquidityParams,
 ) -> Result<()> {
-    let action = args.get_action();
-    validate_and_transfer_input(
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    let vault_seeds = VaultSeeds::new(&ctx.accounts.config, ctx.accounts.user.key, ctx.bumps.vault);
+
+    let action = args.get_action()?;
+
+    let min_output_amount = verify_trade_intent(
+        args.intent.as_ref(),
+        &ctx.accounts.access,
+        &ctx.accounts.config.key(),
+        ctx.accounts.instructions_sysvar.as_deref(),
+        TradeTerms {
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            in_amount: action.get_input_amount(),
+            client_order_id: args.client_order_id,
+        },
+    )?;
+
+    let received = validate_and_transfer_input(
         &ctx.accounts.operator.to_account_info(),
         &mut ctx.accounts.config,
         &ctx.accounts.vault.to_account_info(),
-        ctx.bumps.vault,
-        &ctx.accounts.delegate_input_token_account,
-        &ctx.accounts.input_mint.to_account_info(),
-        &ctx.accounts.input_mint_program.to_account_info(),
-        &ctx.accounts.vault_input_token_account.to_account_info(),
+        vault_seeds,
+        DelegatedInput {
+            delegate: &args.delegate,
+            delegate_input_token_account: &ctx.accounts.delegate_input_token_account,
+            input_mint: &ctx.accounts.input_mint.to_account_info(),
+            input_mint_program: &ctx.accounts.input_mint_program.to_account_info(),
+            vault_input_token_account: &ctx.accounts.vault_input_token_account.to_account_info(),
+            decimals: ctx.accounts.input_mint.decimals,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
         action.get_input_amount(),
-        ctx.accounts.input_mint.decimals,
-        &args.delegate,
     )?;
 
-    execute_cross_program_invocation(
+    let output = execute_cross_program_invocation(
         ctx.accounts.perpetuals_program.key,
         &jupiter_perpetuals_program_id(),
         ctx.remaining_accounts,
         &ctx.accounts.vault.key(),
-        ctx.bumps.vault,
+        vault_seeds,
         args.data,
-        Some(&mut ctx.accounts.vault_output_token_account),
-        Some(&ctx.accounts.receiver_output_token_account),
-        Some(&ctx.accounts.output_mint),
-        Some(&ctx.accounts.output_mint_program),
-        Some(&ctx.accounts.vault),
+        Some(VenueOutput {
+            vault_output_token_account: &mut ctx.accounts.vault_output_token_account,
+            receiver_output_token_account: Some(&ctx.accounts.receiver_output_token_account),
+            protocol_fee: Some(ProtocolFee::new(
+                ctx.accounts.config.fee_bps,
+                &ctx.accounts.fee_token_account,
+                load_partner_share(
+                    &ctx.accounts.access,
+                    ctx.accounts.partner.as_deref(),
+                    ctx.accounts.partner_fee_token_account.as_deref(),
+                    &ctx.accounts.output_mint.key(),
+                )?,
+            )),
+            native_output: None,
+            vault_transfer: VaultTransfer {
+                mint: &ctx.accounts.output_mint,
+                mint_program: &ctx.accounts.output_mint_program,
+                vault: &ctx.accounts.vault,
+                vault_seeds,
+                hook_accounts: ctx.remaining_accounts,
+                event_cpi,
+            },
+        }),
     )?;
 
-    emit!(JupiterPerpetualsEvent {
+    require!(
+        output.delivered >= min_output_amount,
+        ErrorCode::IntentOutputTooLow
+    );
+    record_trade_receipt(
+        &mut ctx.accounts.trade_receipt,
+        ExecutedTrade {
+            user: ctx.accounts.user.key(),
+            client_order_id: args.client_order_id,
+            operator: ctx.accounts.operator.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            input_amount: received.net,
+            output_amount: output.delivered,
+        },
+        ctx.bumps.trade_receipt,
+    )?;
+    record_user_stats(
+        &mut ctx.accounts.user_stats,
+        ctx.accounts.user.key(),
+        ctx.bumps.user_stats,
+        &ctx.accounts.input_mint.key(),
+        received.net,
+        &ctx.accounts.output_mint.key(),
+        output,
+    )?;
+    append_trade_log(
+        &ctx.accounts.trade_log,
+        TradeLogEntry {
+            user: ctx.accounts.user.key(),
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            operator: ctx.accounts.operator.key(),
+            input_amount: received.net,
+            output_amount: output.delivered,
+            slot: Clock::get()?.slot,
+            venue: TradeVenue::JupiterPerpetuals as u8,
+            padding: [0; 7],
+        },
+    )?;
+
+    emit_cpi!(TradeExecuted {
+        version: TRADE_EXECUTED_VERSION,
+        sequence: ctx.accounts.config.next_trade_sequence(),
+        venue: TradeVenue::JupiterPerpetuals,
+        user: ctx.accounts.user.key(),
+        operator: ctx.accounts.operator.key(),
+        input_mint: ctx.accounts.input_mint.key(),
+        output_mint: ctx.accounts.output_mint.key(),
+        client_order_id: args.client_order_id,
+        input_amount: received.net,
+        refund_amount: 0,
+        output_amount: output.delivered,
+        parked_amount: output.parked,
+        fee_amount: output.protocol_fee,
+        commission_amount: 0,
+        slot: Clock::get()?.slot,
+    });
+    #[cfg(feature = "legacy-events")]
+    emit_cpi!(JupiterPerpetualsEvent {
         user: ctx.accounts.user.key(),
         input_mint: ctx.accounts.input_mint.key(),
         output_mint: ctx.accounts.output_mint.key(),
@@ -150,10 +348,12 @@ mod tests {
     fn test_jupiter_perpetuals_params() {
         let args = JupiterLiquidityParams {
             delegate: Pubkey::new_unique(),
+            client_order_id: 0,
+            intent: None,
             data: hex::decode("e4a24e1c46db7473809698000000000087991b0000000000010000000000000000")
                 .unwrap(),
         };
-        let action = args.get_action();
+        let action = args.get_action().unwrap();
         assert_eq!(action.get_input_amount(), 10000000);
         assert_eq!(
             action,
@@ -166,13 +366,46 @@ mod tests {
 
         let args = JupiterLiquidityParams {
             delegate: Pubkey::new_unique(),
+            client_order_id: 0,
+            intent: None,
             data: hex::decode("e6d7527ff165e392ba061200000000000000000000000000").unwrap(),
         };
-        let action = args.get_action();
+        let action = args.get_action().unwrap();
         assert_eq!(action.get_input_amount(), 1181370);
         assert_eq!(
             action,
             JupiterPerpetualsAction::RemoveLiquidity(RemoveLiquidity2Params::new(1181370, 0,))
         );
+
+        let args = JupiterLiquidityParams {
+            delegate: Pubkey::new_unique(),
+            client_order_id: 0,
+            intent: None,
+            data: hex::decode("414b3f4ceb5b5b88809698000000000087991b0000000000").unwrap(),
+        };
+        let action = args.get_action().unwrap();
+        assert_eq!(action.get_input_amount(), 10000000);
+        assert_eq!(
+            action,
+            JupiterPerpetualsAction::Swap(Swap2Params::new(10000000, 1808775))
+        );
+
+        // 截断或未知的指令数据返回错误而不是 panic
+        for data in [
+            "414b3f4ceb5b5b8880969800",
+            "414b3f4c",
+            "0000000000000000809698000000000087991b0000000000",
+        ] {
+            let args = JupiterLiquidityParams {
+                delegate: Pubkey::new_unique(),
+                client_order_id: 0,
+                intent: None,
+                data: hex::decode(data).unwrap(),
+            };
+            assert_eq!(
+                args.get_action().unwrap_err(),
+                ErrorCode::InvalidPerpetualsData.into()
+            );
+        }
     }
 }
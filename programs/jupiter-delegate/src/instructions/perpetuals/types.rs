@@ -34,10 +34,26 @@ impl RemoveLiquidity2Params {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub struct Swap2Params {
+    amount_in: u64,
+    min_amount_out: u64,
+}
+
+impl Swap2Params {
+    pub fn new(amount_in: u64, min_amount_out: u64) -> Self {
+        Self {
+            amount_in,
+            min_amount_out,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum JupiterPerpetualsAction {
     AddLiquidity(AddLiquidity2Params),
     RemoveLiquidity(RemoveLiquidity2Params),
+    Swap(Swap2Params),
 }
 
 impl JupiterPerpetualsAction {
@@ -45,6 +61,7 @@ impl JupiterPerpetualsAction {
         match self {
             JupiterPerpetualsAction::AddLiquidity(p) => p.token_amount_in,
             JupiterPerpetualsAction::RemoveLiquidity(p) => p.lp_amount_in,
+            JupiterPerpetualsAction::Swap(p) => p.amount_in,
         }
     }
 }
@@ -54,6 +71,7 @@ impl ToString for JupiterPerpetualsAction {
         match self {
             JupiterPerpetualsAction::AddLiquidity(_p) => "add_liquidity2".to_string(),
             JupiterPerpetualsAction::RemoveLiquidity(_p) => "remove_liquidity2".to_string(),
+            JupiterPerpetualsAction::Swap(_p) => "swap2".to_string(),
         }
     }
 }
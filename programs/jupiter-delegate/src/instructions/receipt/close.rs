@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, TRADE_RECEIPT_SEED};
+use crate::error::ErrorCode;
+use crate::event::TradeReceiptClosedEvent;
+use crate::state::{namespace_seed, Config, TradeReceipt};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseTradeReceipt<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = operator,
+        has_one = operator @ ErrorCode::InvalidOperator,
+        seeds = [
+            TRADE_RECEIPT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            trade_receipt.user.as_ref(),
+            &trade_receipt.client_order_id.to_le_bytes(),
+        ],
+        bump = trade_receipt.bump,
+    )]
+    pub trade_receipt: Account<'info, TradeReceipt>,
+}
+
+/// Returns the receipt rent to the operator that paid it once the retention period is over.
+pub fn process_close_trade_receipt(ctx: Context<CloseTradeReceipt>) -> Result<()> {
+    let trade_receipt = &ctx.accounts.trade_receipt;
+    require!(
+        trade_receipt.is_closable(Clock::get()?.unix_timestamp),
+        ErrorCode::TradeReceiptRetentionNotElapsed
+    );
+
+    emit_cpi!(TradeReceiptClosedEvent {
+        user: trade_receipt.user,
+        client_order_id: trade_receipt.client_order_id,
+        receipt: trade_receipt.key(),
+        operator: ctx.accounts.operator.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,3 @@
+mod close;
+
+pub use close::*;
@@ -1,17 +1,23 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::get_associated_token_address_with_program_id;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    constants::{ACCESS_SEED, VAULT_SEED},
+    constants::{ACCESS_SEED, CONFIG_SEED, USER_CREDIT_SEED, VAULT_SEED},
     error::ErrorCode,
-    state::{Access, Config},
+    event::{DebitUserEvent, TokenReceiveEvent},
+    instructions::utils::{transfer_from_vault, EventCpi, VaultSeeds, VaultTransfer},
+    state::{namespace_seed, Access, Config, UserCredit},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct TokenReceive<'info> {
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
     pub config: Account<'info, Config>,
     pub output_mint: InterfaceAccount<'info, Mint>,
     pub output_mint_program: Interface<'info, TokenInterface>,
@@ -28,7 +34,7 @@ pub struct TokenReceive<'info> {
 
     #[account(
         mut,
-        seeds=[VAULT_SEED.as_bytes()],
+        seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)],
         bump
     )]
     pub vault: SystemAccount<'info>,
@@ -48,14 +54,34 @@ pub struct TokenReceive<'info> {
     #[account(mut)]
     pub receiver: UncheckedAccount<'info>,
     #[account(
-        seeds = [ACCESS_SEED.as_bytes(), receiver.key().as_ref()],
+        seeds = [
+            ACCESS_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            receiver.key().as_ref(),
+        ],
         bump,
-        constraint = access.is_granted @ ErrorCode::AccessNotGranted
+        constraint = access.is_granted @ ErrorCode::AccessNotGranted,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
     )]
     pub access: Account<'info, Access>,
+    #[account(
+        mut,
+        seeds = [
+            USER_CREDIT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            receiver.key().as_ref(),
+            output_mint.key().as_ref(),
+        ],
+        bump = user_credit.bump,
+    )]
+    pub user_credit: Account<'info, UserCredit>,
 }
 
-pub fn process_token_receive(ctx: Context<TokenReceive>) -> Result<()> {
+pub fn process_token_receive<'info>(
+    ctx: Context<'_, '_, '_, 'info, TokenReceive<'info>>,
+) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+
     // 0. 验证 executor 权限
     require!(
         ctx.accounts.executor.key() == ctx.accounts.config.operator
@@ -67,9 +93,11 @@ pub fn process_token_receive(ctx: Context<TokenReceive>) -> Result<()> {
         ErrorCode::ConfigNotInitialized
     );
     require!(!ctx.accounts.config.is_paused, ErrorCode::ConfigPaused);
-    // 1. 验证接收者代币账户存在
+    // 1. 只支付 receiver 在账本中的余额, 验证接收者代币账户存在
+    let amounts = ctx.accounts.user_credit.amount;
+    require!(amounts > 0, ErrorCode::InsufficientUserCredit);
     require!(
-        ctx.accounts.vault_output_token_account.amount > 0,
+        ctx.accounts.vault_output_token_account.amount >= amounts,
         ErrorCode::VaultOutputTokenAccountIsInsufficient
     );
     let receiver_output_token_account = get_associated_token_address_with_program_id(
@@ -82,23 +110,20 @@ pub fn process_token_receive(ctx: Context<TokenReceive>) -> Result<()> {
         ErrorCode::FundVaultOutputTokenAccountNotFound
     );
 
-    let amounts = ctx.accounts.vault_output_token_account.amount;
-
-    // 2. vault 转移代币到 receiver
-    let signed_seeds = &[VAULT_SEED.as_bytes(), &[ctx.bumps.vault]];
-    transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.output_mint_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.vault_output_token_account.to_account_info(),
-                to: ctx.accounts.receiver_output_token_account.to_account_info(),
-                authority: ctx.accounts.vault.to_account_info(),
-                mint: ctx.accounts.output_mint.to_account_info(),
-            },
-            &[signed_seeds],
-        ),
+    // 2. vault 转移代币到 receiver, 处理转账手续费与 transfer hook
+    let vault_seeds = VaultSeeds::shared(&ctx.accounts.config, ctx.bumps.vault);
+    transfer_from_vault(
+        VaultTransfer {
+            mint: &ctx.accounts.output_mint,
+            mint_program: &ctx.accounts.output_mint_program,
+            vault: &ctx.accounts.vault,
+            vault_seeds,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
+        &ctx.accounts.vault_output_token_account,
+        &ctx.accounts.receiver_output_token_account,
         amounts,
-        ctx.accounts.output_mint.decimals,
     )?;
 
     // 3.检查 delegate 是否是 receiver
@@ -107,5 +132,24 @@ pub fn process_token_receive(ctx: Context<TokenReceive>) -> Result<()> {
         ErrorCode::DelegateIsNotReceiver
     );
 
+    // 4. 扣减账本余额
+    let user_credit = &mut ctx.accounts.user_credit;
+    user_credit.debit(amounts)?;
+
+    emit_cpi!(DebitUserEvent {
+        user: user_credit.user,
+        mint: user_credit.mint,
+        amount: amounts,
+        credit: user_credit.amount,
+        receiver_token_account: ctx.accounts.receiver_output_token_account.key(),
+    });
+    emit_cpi!(TokenReceiveEvent {
+        user: ctx.accounts.receiver.key(),
+        mint: ctx.accounts.output_mint.key(),
+        amount: amounts,
+        source: ctx.accounts.vault_output_token_account.key(),
+        destination: ctx.accounts.receiver_output_token_account.key(),
+        operator: ctx.accounts.executor.key(),
+    });
     Ok(())
 }
@@ -1,7 +1,9 @@
 use crate::{
-    constants::{CONFIG_SEED, VAULT_SEED},
+    constants::{ACCESS_SEED, CONFIG_SEED, VAULT_SEED},
     error::ErrorCode,
-    state::Config,
+    event::DepositEvent,
+    instructions::utils::{credit_user, CreditAccounts, EventCpi, VaultSeeds},
+    state::{namespace_seed, Access, Config},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
@@ -9,6 +11,7 @@ use anchor_spl::token_interface::{
     transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct TransferIn<'info> {
     #[account(mut)]
@@ -29,14 +32,15 @@ pub struct TransferIn<'info> {
     pub from_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
-        seeds = [CONFIG_SEED.as_bytes()],
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
         bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
     )]
     pub config: Account<'info, Config>,
 
     #[account(
         mut,
-        seeds=[VAULT_SEED.as_bytes()],
+        seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)],
         bump
     )]
     pub vault: SystemAccount<'info>,
@@ -50,6 +54,23 @@ pub struct TransferIn<'info> {
     )]
     pub to_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        seeds = [
+            ACCESS_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            from_token_account.owner.as_ref(),
+        ],
+        bump,
+        constraint = access.is_granted @ ErrorCode::AccessNotGranted,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
+    pub access: Account<'info, Access>,
+
+    /// Ledger entry of the source owner for the deposited mint
+    /// CHECK: checked and created in credit_user
+    #[account(mut)]
+    pub user_credit: UncheckedAccount<'info>,
+
     pub token_program: Interface<'info, TokenInterface>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -58,6 +79,7 @@ pub struct TransferIn<'info> {
 }
 
 pub fn prorcess_transfer_in(ctx: Context<TransferIn>, amounts: u64) -> Result<()> {
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
     require!(
         ctx.accounts.operator.key() == ctx.accounts.config.operator
             || ctx.accounts.operator.key() == ctx.accounts.config.admin,
@@ -117,7 +139,8 @@ pub fn prorcess_transfer_in(ctx: Context<TransferIn>, amounts: u64) -> Result<()
             ctx.accounts.token_mint.decimals,
         )?;
     } else {
-        let signed_seeds = &[VAULT_SEED.as_bytes(), &[ctx.bumps.vault]];
+        let vault_seeds = VaultSeeds::shared(&ctx.accounts.config, ctx.bumps.vault);
+        let signed_seeds = &vault_seeds.signer_seeds();
         transfer_checked(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
@@ -134,5 +157,32 @@ pub fn prorcess_transfer_in(ctx: Context<TransferIn>, amounts: u64) -> Result<()
         )?;
     }
 
+    // 按 vault 实收数量记入来源账户所有者的账本
+    let initial_balance = ctx.accounts.to_token_account.amount;
+    ctx.accounts.to_token_account.reload()?;
+    let received = ctx.accounts.to_token_account.amount - initial_balance;
+    let user = ctx.accounts.from_token_account.owner;
+    credit_user(
+        CreditAccounts {
+            user_credit: &ctx.accounts.user_credit.to_account_info(),
+            payer: &ctx.accounts.operator.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            event_cpi,
+        },
+        ctx.accounts.config.namespace,
+        &user,
+        &ctx.accounts.token_mint.key(),
+        received,
+    )?;
+
+    emit_cpi!(DepositEvent {
+        user,
+        mint: ctx.accounts.token_mint.key(),
+        amount: amounts,
+        received,
+        source: ctx.accounts.from_token_account.key(),
+        destination: ctx.accounts.to_token_account.key(),
+        operator: ctx.accounts.operator.key(),
+    });
     Ok(())
 }
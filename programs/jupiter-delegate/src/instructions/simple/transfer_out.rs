@@ -1,21 +1,33 @@
-use crate::{constants::VAULT_SEED, error::ErrorCode, state::Config};
+use crate::{
+    constants::{CONFIG_SEED, DESTINATION_SEED, USER_CREDIT_SEED, VAULT_SEED},
+    error::ErrorCode,
+    event::{DebitUserEvent, WithdrawEvent},
+    instructions::utils::VaultSeeds,
+    state::{authorize_withdrawal, namespace_seed, Config, Destination, UserCredit},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{
     transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct TransferOut<'info> {
     #[account(mut)]
     pub operator: Signer<'info>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
     pub config: Box<Account<'info, Config>>,
 
     #[account(
         mut,
-        seeds=[VAULT_SEED.as_bytes()],
+        seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)],
         bump
     )]
     pub vault: SystemAccount<'info>,
@@ -23,6 +35,7 @@ pub struct TransferOut<'info> {
     pub token_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
+        mut,
         associated_token::mint = token_mint,
         associated_token::authority = vault,
         associated_token::token_program = token_program,
@@ -36,6 +49,28 @@ pub struct TransferOut<'info> {
     )]
     pub to_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        seeds = [
+            DESTINATION_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            to_token_account.owner.as_ref(),
+        ],
+        bump = destination.bump,
+    )]
+    pub destination: Option<Account<'info, Destination>>,
+
+    #[account(
+        mut,
+        seeds = [
+            USER_CREDIT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            to_token_account.owner.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump = user_credit.bump,
+    )]
+    pub user_credit: Option<Account<'info, UserCredit>>,
+
     pub token_program: Interface<'info, TokenInterface>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -71,8 +106,25 @@ pub fn prorcess_transfer_out(ctx: Context<TransferOut>, amounts: u64) -> Result<
     );
     ctx.accounts.config.last_trade_timestamp = now;
 
+    // 只能转给登记的目的地, 或从账本余额中扣减转给用户
+    let user = authorize_withdrawal(
+        ctx.accounts.destination.as_deref(),
+        ctx.accounts.user_credit.as_deref_mut(),
+        amounts,
+    )?;
+    if let (Some(user), Some(user_credit)) = (user, &ctx.accounts.user_credit) {
+        emit_cpi!(DebitUserEvent {
+            user,
+            mint: user_credit.mint,
+            amount: amounts,
+            credit: user_credit.amount,
+            receiver_token_account: ctx.accounts.to_token_account.key(),
+        });
+    }
+
     // 4. 从 vault 转账到 to_token_account
-    let signed_seeds = &[VAULT_SEED.as_bytes(), &[ctx.bumps.vault]];
+    let vault_seeds = VaultSeeds::shared(&ctx.accounts.config, ctx.bumps.vault);
+    let signed_seeds = &vault_seeds.signer_seeds();
     transfer_checked(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
@@ -88,5 +140,14 @@ pub fn prorcess_transfer_out(ctx: Context<TransferOut>, amounts: u64) -> Result<
         ctx.accounts.token_mint.decimals,
     )?;
 
+    emit_cpi!(WithdrawEvent {
+        user,
+        mint: ctx.accounts.token_mint.key(),
+        amount: amounts,
+        source: ctx.accounts.from_token_account.key(),
+        destination: ctx.accounts.to_token_account.key(),
+        destination_owner: ctx.accounts.to_token_account.owner,
+        operator: ctx.accounts.operator.key(),
+    });
     Ok(())
 }
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{ACCESS_SEED, CONFIG_SEED, USER_STATS_SEED};
+use crate::error::ErrorCode;
+use crate::event::UserStatsClosedEvent;
+use crate::state::{namespace_seed, Config, UserStats};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseUserStats<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+        has_one = admin @ ErrorCode::AdminAuthorityMismatch,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: revoking access closes this account, so it must be empty
+    #[account(
+        seeds = [
+            ACCESS_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user_stats.user.as_ref(),
+        ],
+        bump,
+        constraint = access.data_is_empty() @ ErrorCode::AccessAlreadyGranted,
+    )]
+    pub access: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            USER_STATS_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user_stats.user.as_ref(),
+        ],
+        bump = user_stats.bump,
+    )]
+    pub user_stats: Account<'info, UserStats>,
+}
+
+/// Closes the statistics of an offboarded user, i.e. once their access was revoked.
+pub fn process_close_user_stats(ctx: Context<CloseUserStats>) -> Result<()> {
+    let user_stats = &ctx.accounts.user_stats;
+    emit_cpi!(UserStatsClosedEvent {
+        user: user_stats.user,
+        user_stats: user_stats.key(),
+        trade_count: user_stats.trade_count,
+        admin: ctx.accounts.admin.key(),
+    });
+    Ok(())
+}
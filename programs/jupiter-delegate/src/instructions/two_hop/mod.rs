@@ -1,9 +1,16 @@
 pub mod types;
 
 use super::types::StepAction;
+#[cfg(feature = "legacy-events")]
+use crate::TwoHopEvent;
 use crate::{
-    error::ErrorCode, execute_cross_program_invocation, transfer_output_tokens,
-    validate_and_transfer_input, Access, Config, TwoHopEvent, ACCESS_SEED, VAULT_SEED,
+    append_trade_log, error::ErrorCode, execute_cross_program_invocation, load_partner_share,
+    namespace_seed, record_trade_receipt, record_user_stats, transfer_output_tokens,
+    validate_and_transfer_input, verify_trade_intent, Access, Config, DelegatedInput, EventCpi,
+    ExecutedTrade, OutputAmounts, Partner, ProtocolFee, TradeExecuted, TradeIntent, TradeLog,
+    TradeLogEntry, TradeReceipt, TradeTerms, TradeVenue, UserStats, VaultSeeds, VaultTransfer,
+    ACCESS_SEED, CONFIG_SEED, FEE_VAULT_SEED, TRADE_EXECUTED_VERSION, TRADE_LOG_SEED,
+    TRADE_RECEIPT_SEED, USER_STATS_SEED, VAULT_SEED,
 };
 use anchor_lang::{prelude::*, solana_program::account_info::next_account_infos};
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
@@ -23,9 +30,13 @@ pub struct TwoHopParams {
     pub delegate: Pubkey,
     pub step1: StepParams,
     pub step2: StepParams,
+    pub client_order_id: u64,
+    pub intent: Option<TradeIntent>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(args: TwoHopParams)]
 pub struct TwoHop<'info> {
     pub input_mint_one: Box<InterfaceAccount<'info, Mint>>,
     pub input_mint_program_one: Interface<'info, TokenInterface>,
@@ -41,7 +52,11 @@ pub struct TwoHop<'info> {
 
     #[account(
         mut,
-        seeds=[VAULT_SEED.as_bytes()],
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            config.vault_user_seed(user.key),
+        ],
         bump,
     )]
     pub vault: SystemAccount<'info>,
@@ -81,13 +96,19 @@ pub struct TwoHop<'info> {
     )]
     pub vault_output_token_account_two: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = config.bump,
+        constraint = config.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
+    )]
     pub config: Box<Account<'info, Config>>,
 
     #[account(
-        seeds = [ACCESS_SEED.as_bytes(), user.key().as_ref()],
+        seeds = [ACCESS_SEED.as_bytes(), namespace_seed(&config.namespace), user.key().as_ref()],
         bump,
         constraint = access.is_granted @ ErrorCode::AccessNotGranted,
+        constraint = access.has_supported_version() @ ErrorCode::UnsupportedAccountVersion,
     )]
     pub access: Account<'info, Access>,
 
@@ -109,24 +130,112 @@ pub struct TwoHop<'info> {
         associated_token::token_program = output_mint_program_two,
     )]
     pub receiver_output_token_account_two: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        seeds = [
+            FEE_VAULT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            output_mint_two.key().as_ref(),
+        ],
+        bump,
+        token::mint = output_mint_two,
+        token::authority = fee_vault_authority,
+        token::token_program = output_mint_program_two,
+    )]
+    pub fee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Shared vault, authority of the protocol fee vaults in either vault mode
+    #[account(seeds = [VAULT_SEED.as_bytes(), namespace_seed(&config.namespace)], bump)]
+    pub fee_vault_authority: UncheckedAccount<'info>,
+
+    #[account(address = access.partner @ ErrorCode::InvalidPartner)]
+    pub partner: Option<Box<Account<'info, Partner>>>,
+
+    #[account(mut)]
+    pub partner_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: Instructions sysvar, required to verify a user-signed intent
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = TradeReceipt::LEN,
+        seeds = [
+            TRADE_RECEIPT_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+            &args.client_order_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub trade_receipt: Box<Account<'info, TradeReceipt>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = UserStats::LEN,
+        seeds = [
+            USER_STATS_SEED.as_bytes(),
+            namespace_seed(&config.namespace),
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    #[account(
+        mut,
+        seeds = [TRADE_LOG_SEED.as_bytes(), namespace_seed(&config.namespace)],
+        bump = trade_log.load()?.bump,
+    )]
+    pub trade_log: AccountLoader<'info, TradeLog>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn process_two_hop<'a>(
     ctx: Context<'_, '_, '_, 'a, TwoHop<'a>>,
     args: TwoHopParams,
 ) -> Result<()> {
-    validate_and_transfer_input(
+    let event_cpi = EventCpi::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+    let vault_seeds = VaultSeeds::new(&ctx.accounts.config, ctx.accounts.user.key, ctx.bumps.vault);
+
+    let min_output_amount = verify_trade_intent(
+        args.intent.as_ref(),
+        &ctx.accounts.access,
+        &ctx.accounts.config.key(),
+        ctx.accounts.instructions_sysvar.as_deref(),
+        TradeTerms {
+            input_mint: ctx.accounts.input_mint_one.key(),
+            output_mint: ctx.accounts.output_mint_two.key(),
+            in_amount: args.step1.amount_in,
+            client_order_id: args.client_order_id,
+        },
+    )?;
+
+    let received = validate_and_transfer_input(
         &ctx.accounts.operator.to_account_info(),
         &mut ctx.accounts.config,
         &ctx.accounts.vault.to_account_info(),
-        ctx.bumps.vault,
-        &ctx.accounts.delegate_input_token_account,
-        &ctx.accounts.input_mint_one.to_account_info(),
-        &ctx.accounts.input_mint_program_one.to_account_info(),
-        &ctx.accounts.vault_input_token_account_one.to_account_info(),
+        vault_seeds,
+        DelegatedInput {
+            delegate: &args.delegate,
+            delegate_input_token_account: &ctx.accounts.delegate_input_token_account,
+            input_mint: &ctx.accounts.input_mint_one.to_account_info(),
+            input_mint_program: &ctx.accounts.input_mint_program_one.to_account_info(),
+            vault_input_token_account: &ctx
+                .accounts
+                .vault_input_token_account_one
+                .to_account_info(),
+            decimals: ctx.accounts.input_mint_one.decimals,
+            hook_accounts: ctx.remaining_accounts,
+            event_cpi,
+        },
         args.step1.amount_in,
-        ctx.accounts.input_mint_one.decimals,
-        &args.delegate,
     )?;
 
     let mut remain_accounts = ctx.remaining_accounts.iter();
@@ -135,7 +244,7 @@ pub fn process_two_hop<'a>(
         &args.step1,
         &mut remain_accounts,
         &ctx.accounts.vault,
-        ctx.bumps.vault,
+        vault_seeds,
         &mut ctx.accounts.vault_input_token_account_one,
         &mut ctx.accounts.vault_output_token_account_one,
     )?;
@@ -144,49 +253,137 @@ pub fn process_two_hop<'a>(
         &args.step2,
         &mut remain_accounts,
         &ctx.accounts.vault,
-        ctx.bumps.vault,
+        vault_seeds,
         &mut ctx.accounts.vault_input_token_account_two,
         &mut ctx.accounts.vault_output_token_account_two,
     )?;
 
     ctx.accounts.vault_output_token_account_two.reload()?;
+    let mut output = OutputAmounts::default();
     if ctx.accounts.vault_output_token_account_two.amount > 0 {
-        transfer_output_tokens(
+        output = transfer_output_tokens(
+            VaultTransfer {
+                mint: &ctx.accounts.output_mint_two,
+                mint_program: &ctx.accounts.output_mint_program_two,
+                vault: &ctx.accounts.vault,
+                vault_seeds,
+                hook_accounts: ctx.remaining_accounts,
+                event_cpi,
+            },
             &ctx.accounts.vault_output_token_account_two,
             Some(&ctx.accounts.receiver_output_token_account_two),
-            &ctx.accounts.output_mint_two,
-            &ctx.accounts.output_mint_program_two,
-            &ctx.accounts.vault,
-            ctx.bumps.vault,
             ctx.accounts.vault_output_token_account_two.amount,
+            Some(ProtocolFee::new(
+                ctx.accounts.config.fee_bps,
+                &ctx.accounts.fee_token_account,
+                load_partner_share(
+                    &ctx.accounts.access,
+                    ctx.accounts.partner.as_deref(),
+                    ctx.accounts.partner_fee_token_account.as_deref(),
+                    &ctx.accounts.output_mint_two.key(),
+                )?,
+            )),
+            None,
         )?;
     }
     ctx.accounts.vault_output_token_account_one.reload()?;
     if ctx.accounts.vault_output_token_account_one.amount > 0 {
         transfer_output_tokens(
+            VaultTransfer {
+                mint: &ctx.accounts.output_mint_one,
+                mint_program: &ctx.accounts.output_mint_program_one,
+                vault: &ctx.accounts.vault,
+                vault_seeds,
+                hook_accounts: ctx.remaining_accounts,
+                event_cpi,
+            },
             &ctx.accounts.vault_output_token_account_one,
             Some(&ctx.accounts.receiver_output_token_account_one),
-            &ctx.accounts.output_mint_one,
-            &ctx.accounts.output_mint_program_one,
-            &ctx.accounts.vault,
-            ctx.bumps.vault,
             ctx.accounts.vault_output_token_account_two.amount,
+            None,
+            None,
         )?;
     }
     ctx.accounts.vault_input_token_account_one.reload()?;
+    let mut refund = OutputAmounts::default();
     if ctx.accounts.vault_input_token_account_one.amount > 0 {
-        transfer_output_tokens(
+        refund = transfer_output_tokens(
+            VaultTransfer {
+                mint: &ctx.accounts.input_mint_one,
+                mint_program: &ctx.accounts.input_mint_program_one,
+                vault: &ctx.accounts.vault,
+                vault_seeds,
+                hook_accounts: ctx.remaining_accounts,
+                event_cpi,
+            },
             &ctx.accounts.vault_input_token_account_one,
             Some(&ctx.accounts.delegate_input_token_account),
-            &ctx.accounts.input_mint_one,
-            &ctx.accounts.input_mint_program_one,
-            &ctx.accounts.vault,
-            ctx.bumps.vault,
             ctx.accounts.vault_input_token_account_one.amount,
+            None,
+            None,
         )?;
     }
 
-    emit!(TwoHopEvent {
+    require!(
+        output.delivered >= min_output_amount,
+        ErrorCode::IntentOutputTooLow
+    );
+    record_trade_receipt(
+        &mut ctx.accounts.trade_receipt,
+        ExecutedTrade {
+            user: ctx.accounts.user.key(),
+            client_order_id: args.client_order_id,
+            operator: ctx.accounts.operator.key(),
+            input_mint: ctx.accounts.input_mint_one.key(),
+            output_mint: ctx.accounts.output_mint_two.key(),
+            input_amount: received.net,
+            output_amount: output.delivered,
+        },
+        ctx.bumps.trade_receipt,
+    )?;
+    record_user_stats(
+        &mut ctx.accounts.user_stats,
+        ctx.accounts.user.key(),
+        ctx.bumps.user_stats,
+        &ctx.accounts.input_mint_one.key(),
+        received.net,
+        &ctx.accounts.output_mint_two.key(),
+        output,
+    )?;
+    append_trade_log(
+        &ctx.accounts.trade_log,
+        TradeLogEntry {
+            user: ctx.accounts.user.key(),
+            input_mint: ctx.accounts.input_mint_one.key(),
+            output_mint: ctx.accounts.output_mint_two.key(),
+            operator: ctx.accounts.operator.key(),
+            input_amount: received.net,
+            output_amount: output.delivered,
+            slot: Clock::get()?.slot,
+            venue: TradeVenue::TwoHop as u8,
+            padding: [0; 7],
+        },
+    )?;
+
+    emit_cpi!(TradeExecuted {
+        version: TRADE_EXECUTED_VERSION,
+        sequence: ctx.accounts.config.next_trade_sequence(),
+        venue: TradeVenue::TwoHop,
+        user: ctx.accounts.user.key(),
+        operator: ctx.accounts.operator.key(),
+        input_mint: ctx.accounts.input_mint_one.key(),
+        output_mint: ctx.accounts.output_mint_two.key(),
+        client_order_id: args.client_order_id,
+        input_amount: received.net,
+        refund_amount: refund.delivered,
+        output_amount: output.delivered,
+        parked_amount: output.parked,
+        fee_amount: output.protocol_fee,
+        commission_amount: 0,
+        slot: Clock::get()?.slot,
+    });
+    #[cfg(feature = "legacy-events")]
+    emit_cpi!(TwoHopEvent {
         user: ctx.accounts.user.key(),
         step1_input_mint: ctx.accounts.input_mint_one.key(),
         step1_output_mint: ctx.accounts.output_mint_one.key(),
@@ -208,7 +405,7 @@ fn process_step<'info>(
     args: &StepParams,
     remain_accounts: &mut Iter<AccountInfo<'info>>,
     vault: &SystemAccount<'info>,
-    bump: u8,
+    vault_seeds: VaultSeeds,
     vault_input_token_account: &mut Box<InterfaceAccount<'info, TokenAccount>>,
     vault_output_token_account: &mut Box<InterfaceAccount<'info, TokenAccount>>,
 ) -> Result<(u64, u64)> {
@@ -223,13 +420,9 @@ fn process_step<'info>(
         &program_account.key(),
         accounts,
         &vault.key(),
-        bump,
+        vault_seeds,
         args.action.to_program_instruction_data(&args.data),
         None,
-        None,
-        None,
-        None,
-        None,
     )?;
 
     vault_input_token_account.reload()?;
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    instructions::perpetuals, DISCRIMINATOR_DFLOW_SWAP, DISCRIMINATOR_DFLOW_SWAP2,
-    DISCRIMINATOR_JUPITER_AGGREGATOR_ROUTE, DISCRIMINATOR_JUPITER_AGGREGATOR_SHARED_ACCOUNTS_ROUTE,
+    dflow_program_id, instructions::perpetuals, jupiter_order_engine_program_id,
+    jupiter_perpetuals_program_id, jupiter_program_id, okx_program_id, TradeVenue,
+    DISCRIMINATOR_DFLOW_SWAP, DISCRIMINATOR_DFLOW_SWAP2, DISCRIMINATOR_JUPITER_AGGREGATOR_ROUTE,
+    DISCRIMINATOR_JUPITER_AGGREGATOR_SHARED_ACCOUNTS_ROUTE,
     DISCRIMINATOR_JUPITER_AGGREGATOR_SHARED_ACCOUNTS_ROUTE_V2,
     DISCRIMINATOR_JUPITER_ORDER_ENGINE_FILL, DISCRIMINATOR_OKX_SWAP, DISCRIMINATOR_OKX_SWAP_TOB_V3,
     DISCRIMINATOR_OKX_SWAP_TOB_V3_WITH_RECEIVER, DISCRIMINATOR_OKX_SWAP_V3,
@@ -23,9 +25,46 @@ pub enum StepAction {
     DFlowSwap2,
     JlpPerpetualsAddLiquidity2,
     JlpPerpetualsRemoveLiquidity2,
+    JlpPerpetualsSwap2,
 }
 
 impl StepAction {
+    pub fn program_id(&self) -> Pubkey {
+        match self {
+            StepAction::JupiterSharedAccountsRoute
+            | StepAction::JupiterSharedAccountsRouteV2
+            | StepAction::JupiterRoute
+            | StepAction::JupiterRouteV2 => jupiter_program_id(),
+            StepAction::JupiterOrderEngineFill => jupiter_order_engine_program_id(),
+            StepAction::OkxSwapTobV3
+            | StepAction::OkxSwapV3
+            | StepAction::OkxSwapTobV3WithReceiver
+            | StepAction::OkxSwap => okx_program_id(),
+            StepAction::DFlowSwap | StepAction::DFlowSwap2 => dflow_program_id(),
+            StepAction::JlpPerpetualsAddLiquidity2
+            | StepAction::JlpPerpetualsRemoveLiquidity2
+            | StepAction::JlpPerpetualsSwap2 => jupiter_perpetuals_program_id(),
+        }
+    }
+
+    pub fn venue(&self) -> TradeVenue {
+        match self {
+            StepAction::JupiterSharedAccountsRoute
+            | StepAction::JupiterSharedAccountsRouteV2
+            | StepAction::JupiterRoute
+            | StepAction::JupiterRouteV2 => TradeVenue::Jupiter,
+            StepAction::JupiterOrderEngineFill => TradeVenue::JupiterOrderEngine,
+            StepAction::OkxSwapTobV3
+            | StepAction::OkxSwapV3
+            | StepAction::OkxSwapTobV3WithReceiver
+            | StepAction::OkxSwap => TradeVenue::Okx,
+            StepAction::DFlowSwap | StepAction::DFlowSwap2 => TradeVenue::Dflow,
+            StepAction::JlpPerpetualsAddLiquidity2
+            | StepAction::JlpPerpetualsRemoveLiquidity2
+            | StepAction::JlpPerpetualsSwap2 => TradeVenue::JupiterPerpetuals,
+        }
+    }
+
     pub fn to_program_instruction_data(&self, data: &[u8]) -> Vec<u8> {
         let mut instruction_data = vec![];
         let discriminator = match self {
@@ -50,6 +89,7 @@ impl StepAction {
             StepAction::JlpPerpetualsRemoveLiquidity2 => {
                 perpetuals::discriminator::DISCRIMINATOR_REMOVE_LIQUIDITY
             }
+            StepAction::JlpPerpetualsSwap2 => perpetuals::discriminator::DISCRIMINATOR_SWAP2,
         };
         instruction_data.extend_from_slice(discriminator);
         instruction_data.extend_from_slice(data);
@@ -73,6 +113,7 @@ impl ToString for StepAction {
             StepAction::DFlowSwap2 => "dflow_swap2",
             StepAction::JlpPerpetualsAddLiquidity2 => "jlp_perpetuals_add_liquidity2",
             StepAction::JlpPerpetualsRemoveLiquidity2 => "jlp_perpetuals_remove_liquidity2",
+            StepAction::JlpPerpetualsSwap2 => "jlp_perpetuals_swap2",
         }
         .to_string()
     }
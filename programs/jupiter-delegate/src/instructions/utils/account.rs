@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use std::cmp::Ordering;
+
+/// Creates the PDA `account` with `space` bytes owned by this program, for
+/// instructions that cannot declare it in their accounts. Like Anchor's `init`,
+/// an address already funded with lamports is topped up, allocated and
+/// assigned instead, so nobody can block the creation by pre-funding it.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+    if lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    if rent > lamports {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent - lamports,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )
+}
+
+/// Resizes a program-owned `account` to `new_len`, topping up rent from
+/// `payer` when it grows and returning the excess rent when it shrinks.
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() == new_len {
+        return Ok(());
+    }
+
+    let rent = Rent::get()?.minimum_balance(new_len);
+    let lamports = account.lamports();
+    match rent.cmp(&lamports) {
+        Ordering::Greater => transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent - lamports,
+        )?,
+        Ordering::Less => {
+            account.sub_lamports(lamports - rent)?;
+            payer.add_lamports(lamports - rent)?;
+        }
+        Ordering::Equal => {}
+    }
+    account.resize(new_len)?;
+    Ok(())
+}
@@ -1,103 +1,127 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
-use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
-use std::collections::HashSet;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed_unchecked};
+use anchor_spl::token_interface::TokenAccount;
 
-use super::transfer::transfer_output_tokens;
-use crate::constants::VAULT_SEED;
+use super::fee::ProtocolFee;
+use super::transfer::{transfer_output_tokens, NativeOutput, OutputAmounts, VaultTransfer};
+use super::vault::VaultSeeds;
+use crate::instructions::two_hop::types::StepAction;
 
-pub fn prepare_cross_program_accounts<'info>(
-    remaining_accounts: &[AccountInfo<'info>],
+/// Account metas of the venue instruction, the vault being the only signer.
+///
+/// The account infos are passed to the CPI as the borrowed `remaining_accounts`
+/// slice, duplicates included, so only the metas are allocated.
+pub fn prepare_cross_program_accounts(
+    remaining_accounts: &[AccountInfo],
     vault: &Pubkey,
-) -> (Vec<AccountMeta>, Vec<AccountInfo<'info>>) {
-    let accounts: Vec<AccountMeta> = remaining_accounts
+) -> Vec<AccountMeta> {
+    remaining_accounts
         .iter()
-        .map(|acc| {
-            let is_signer = acc.key == vault;
-            AccountMeta {
-                pubkey: *acc.key,
-                is_signer,
-                is_writable: acc.is_writable,
-            }
+        .map(|acc| AccountMeta {
+            pubkey: *acc.key,
+            is_signer: acc.key == vault,
+            is_writable: acc.is_writable,
         })
-        .collect();
-
-    let mut seen = HashSet::new();
-    let accounts_infos: Vec<AccountInfo> = remaining_accounts
-        .iter()
-        .map(|acc| AccountInfo { ..acc.clone() })
-        .filter(|acc| seen.insert(*acc.key))
-        .collect();
+        .collect()
+}
 
-    (accounts, accounts_infos)
+/// Vault output account of a venue call and how its output is settled.
+pub struct VenueOutput<'a, 'info> {
+    pub vault_output_token_account: &'a mut InterfaceAccount<'info, TokenAccount>,
+    pub receiver_output_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub protocol_fee: Option<ProtocolFee<'a, 'info>>,
+    pub native_output: Option<NativeOutput<'a, 'info>>,
+    pub vault_transfer: VaultTransfer<'a, 'info>,
 }
 
+/// Returns the output settled to the user, or parked in the vault when there is no receiver.
 pub fn execute_cross_program_invocation<'info>(
     target_program_id: &Pubkey,
     expected_program_id: &Pubkey,
     remaining_accounts: &[AccountInfo<'info>],
     vault_key: &Pubkey,
-    vault_bump: u8,
+    vault_seeds: VaultSeeds,
     instruction_data: Vec<u8>,
-    vault_output_token_account: Option<&mut InterfaceAccount<'info, TokenAccount>>,
-    receiver_output_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
-    output_mint: Option<&InterfaceAccount<'info, Mint>>,
-    output_mint_program: Option<&Interface<'info, TokenInterface>>,
-    vault: Option<&SystemAccount<'info>>,
-) -> Result<()> {
+    venue_output: Option<VenueOutput<'_, 'info>>,
+) -> Result<OutputAmounts> {
     // 1. 检查目标程序 ID
     require_keys_eq!(*target_program_id, *expected_program_id);
 
     // 2. 准备 CPI 账户
-    let (account_metas, account_infos) =
-        prepare_cross_program_accounts(remaining_accounts, vault_key);
+    let account_metas = prepare_cross_program_accounts(remaining_accounts, vault_key);
 
     // 3. 记录输出代币余额
-    let initial_output_balance = if let Some(ref vault_output_account) = vault_output_token_account
-    {
-        vault_output_account.amount
-    } else {
-        0
-    };
+    let initial_output_balance = venue_output.as_ref().map_or(0, |venue_output| {
+        venue_output.vault_output_token_account.amount
+    });
 
     // 4. 调用目标聚合器
-    let signed_seeds = &[VAULT_SEED.as_bytes(), &[vault_bump]];
-    invoke_signed(
+    // 跳过 RefCell 借用检查: 调用期间不持有任何 remaining_accounts 的借用
+    let signed_seeds = &vault_seeds.signer_seeds();
+    invoke_signed_unchecked(
         &Instruction {
             program_id: *target_program_id,
             accounts: account_metas,
             data: instruction_data,
         },
-        &account_infos,
+        remaining_accounts,
         &[signed_seeds],
     )?;
 
     // 5. 转移输出代币
-    if let (
-        Some(vault_output_account),
-        Some(receiver),
-        Some(mint),
-        Some(mint_program),
-        Some(vault_account),
-    ) = (
-        vault_output_token_account,
-        receiver_output_token_account,
-        output_mint,
-        output_mint_program,
-        vault,
-    ) {
-        vault_output_account.reload()?;
+    let Some(venue_output) = venue_output else {
+        return Ok(OutputAmounts::default());
+    };
+    venue_output.vault_output_token_account.reload()?;
+    transfer_output_tokens(
+        venue_output.vault_transfer,
+        venue_output.vault_output_token_account,
+        venue_output.receiver_output_token_account,
+        initial_output_balance,
+        venue_output.protocol_fee,
+        venue_output.native_output,
+    )
+}
+
+/// Venue instruction of a single trade step.
+pub struct VenueStep<'a, 'info> {
+    pub action: &'a StepAction,
+    pub data: &'a [u8],
+    pub venue_program: &'a AccountInfo<'info>,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+pub fn execute_venue_step<'info>(
+    step: VenueStep<'_, 'info>,
+    vault: &SystemAccount<'info>,
+    vault_seeds: VaultSeeds,
+    vault_input_token_account: &mut InterfaceAccount<'info, TokenAccount>,
+    vault_output_token_account: &mut InterfaceAccount<'info, TokenAccount>,
+) -> Result<(u64, u64)> {
+    // 1. 记录 vault 余额
+    vault_input_token_account.reload()?;
+    vault_output_token_account.reload()?;
+    let initial_input_balance = vault_input_token_account.amount;
+    let initial_output_balance = vault_output_token_account.amount;
+
+    // 2. 调用 action 对应的 venue
+    execute_cross_program_invocation(
+        step.venue_program.key,
+        &step.action.program_id(),
+        step.remaining_accounts,
+        &vault.key(),
+        vault_seeds,
+        step.action.to_program_instruction_data(step.data),
+        None,
+    )?;
 
-        transfer_output_tokens(
-            vault_output_account,
-            Some(receiver),
-            mint,
-            mint_program,
-            vault_account,
-            vault_bump,
-            initial_output_balance,
-        )?;
-    }
+    // 3. 计算实际输入 / 输出
+    vault_input_token_account.reload()?;
+    vault_output_token_account.reload()?;
+    let amount_in = initial_input_balance.saturating_sub(vault_input_token_account.amount);
+    let amount_out = vault_output_token_account
+        .amount
+        .saturating_sub(initial_output_balance);
 
-    Ok(())
+    Ok((amount_in, amount_out))
 }
@@ -0,0 +1,42 @@
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+use anchor_lang::Event;
+
+use crate::constants::EVENT_AUTHORITY_SEED;
+
+/// Event authority of an `#[event_cpi]` context, for helpers that emit
+/// outside the handler where `emit_cpi!` has no `ctx` in scope.
+#[derive(Clone, Copy)]
+pub struct EventCpi<'a, 'info> {
+    pub event_authority: &'a AccountInfo<'info>,
+    pub bump: u8,
+}
+
+impl<'a, 'info> EventCpi<'a, 'info> {
+    pub fn new(event_authority: &'a AccountInfo<'info>, bump: u8) -> Self {
+        Self {
+            event_authority,
+            bump,
+        }
+    }
+
+    /// Same self-CPI as `emit_cpi!`: the event lands in the inner instructions.
+    pub fn emit<E: Event>(&self, event: E) -> Result<()> {
+        let data: Vec<u8> = EVENT_IX_TAG_LE
+            .iter()
+            .copied()
+            .chain(event.data())
+            .collect();
+        invoke_signed(
+            &Instruction::new_with_bytes(
+                crate::ID,
+                &data,
+                vec![AccountMeta::new_readonly(*self.event_authority.key, true)],
+            ),
+            &[self.event_authority.clone()],
+            &[&[EVENT_AUTHORITY_SEED.as_bytes(), &[self.bump]]],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use super::transfer::{transfer_from_vault, VaultTransfer};
+use crate::{
+    constants::{BPS_DENOMINATOR, PARTNER_FEE_SEED},
+    error::ErrorCode,
+    event::{PartnerFeeAccruedEvent, ProtocolFeeEvent},
+    state::{Access, Partner},
+};
+
+#[derive(Clone, Copy)]
+pub struct PartnerShare<'a, 'info> {
+    pub partner: Pubkey,
+    pub share_bps: u16,
+    pub fee_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+}
+
+#[derive(Clone, Copy)]
+pub struct ProtocolFee<'a, 'info> {
+    pub fee_bps: u16,
+    pub fee_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub partner: Option<PartnerShare<'a, 'info>>,
+}
+
+impl<'a, 'info> ProtocolFee<'a, 'info> {
+    pub fn new(
+        fee_bps: u16,
+        fee_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+        partner: Option<PartnerShare<'a, 'info>>,
+    ) -> Self {
+        Self {
+            fee_bps,
+            fee_token_account,
+            partner,
+        }
+    }
+}
+
+/// Resolves the partner linked from the user's access, if any.
+pub fn load_partner_share<'a, 'info>(
+    access: &Access,
+    partner: Option<&'a Account<'info, Partner>>,
+    partner_fee_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    mint: &Pubkey,
+) -> Result<Option<PartnerShare<'a, 'info>>> {
+    if access.partner == Pubkey::default() {
+        return Ok(None);
+    }
+
+    let partner = partner.ok_or(ErrorCode::InvalidPartner)?;
+    require_keys_eq!(partner.key(), access.partner, ErrorCode::InvalidPartner);
+    let fee_token_account =
+        partner_fee_token_account.ok_or(ErrorCode::PartnerFeeTokenAccountNotFound)?;
+    let (expected_fee_token_account, _) = Pubkey::find_program_address(
+        &[
+            PARTNER_FEE_SEED.as_bytes(),
+            partner.key().as_ref(),
+            mint.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        fee_token_account.key(),
+        expected_fee_token_account,
+        ErrorCode::PartnerFeeTokenAccountNotFound
+    );
+
+    Ok(Some(PartnerShare {
+        partner: partner.key(),
+        share_bps: partner.share_bps,
+        fee_token_account,
+    }))
+}
+
+pub fn calculate_protocol_fee(amount: u64, fee_bps: u16) -> u64 {
+    ((amount as u128) * (fee_bps as u128) / (BPS_DENOMINATOR as u128)) as u64
+}
+
+/// Carves the protocol fee out of `gross` into the fee vault and returns the net amount.
+pub fn collect_protocol_fee<'info>(
+    vault_transfer: VaultTransfer<'_, 'info>,
+    vault_token_account: &InterfaceAccount<'info, TokenAccount>,
+    gross: u64,
+    protocol_fee: Option<ProtocolFee<'_, 'info>>,
+) -> Result<u64> {
+    let Some(protocol_fee) = protocol_fee else {
+        return Ok(gross);
+    };
+
+    let fee = calculate_protocol_fee(gross, protocol_fee.fee_bps);
+    let partner_fee = protocol_fee
+        .partner
+        .map_or(0, |partner| calculate_protocol_fee(fee, partner.share_bps));
+    transfer_from_vault(
+        vault_transfer,
+        vault_token_account,
+        protocol_fee.fee_token_account,
+        fee - partner_fee,
+    )?;
+    if let Some(partner) = protocol_fee.partner {
+        transfer_from_vault(
+            vault_transfer,
+            vault_token_account,
+            partner.fee_token_account,
+            partner_fee,
+        )?;
+
+        vault_transfer.event_cpi.emit(PartnerFeeAccruedEvent {
+            partner: partner.partner,
+            mint: vault_transfer.mint.key(),
+            amount: partner_fee,
+        })?;
+    }
+    let net = gross - fee;
+
+    vault_transfer.event_cpi.emit(ProtocolFeeEvent {
+        mint: vault_transfer.mint.key(),
+        gross,
+        fee,
+        net,
+    })?;
+    Ok(net)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_protocol_fee() {
+        assert_eq!(calculate_protocol_fee(1_000_000, 0), 0);
+        assert_eq!(calculate_protocol_fee(1_000_000, 30), 3_000);
+        assert_eq!(calculate_protocol_fee(999, 30), 2);
+        assert_eq!(calculate_protocol_fee(u64::MAX, 10_000), u64::MAX);
+    }
+}
@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+use crate::{
+    constants::{INTENT_MESSAGE_PREFIX, TRADE_RECEIPT_RETENTION},
+    error::ErrorCode,
+    state::Access,
+};
+
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+const ED25519_PUBKEY_LEN: usize = 32;
+const ED25519_SIGNATURE_LEN: usize = 64;
+
+/// Trade terms signed off-chain by the user.
+///
+/// The nonce must equal the trade's `client_order_id`, so the trade receipt
+/// marks it as used.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TradeIntent {
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub max_input_amount: u64,
+    pub min_output_amount: u64,
+    pub expires_at: i64,
+    pub nonce: u64,
+}
+
+impl TradeIntent {
+    /// Message the user signs: prefix, config address and the serialized intent
+    pub fn message(&self, config: &Pubkey) -> Result<Vec<u8>> {
+        let mut message = INTENT_MESSAGE_PREFIX.as_bytes().to_vec();
+        message.extend_from_slice(config.as_ref());
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+}
+
+/// Terms of the trade about to run, checked against the signed intent.
+pub struct TradeTerms {
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub in_amount: u64,
+    pub client_order_id: u64,
+}
+
+/// Verifies `intent` against the trade about to run and returns the minimum
+/// output the user must receive. Returns 0 when the user trades without intents.
+pub fn verify_trade_intent(
+    intent: Option<&TradeIntent>,
+    access: &Access,
+    config: &Pubkey,
+    instructions_sysvar: Option<&AccountInfo>,
+    terms: TradeTerms,
+) -> Result<u64> {
+    let Some(intent) = intent else {
+        require!(!access.intent_required, ErrorCode::IntentRequired);
+        return Ok(0);
+    };
+
+    // 1. 校验 Ed25519 签名覆盖了该 intent
+    let instructions_sysvar = instructions_sysvar.ok_or(ErrorCode::InvalidIntentSignature)?;
+    let message = intent.message(config)?;
+    verify_ed25519_instruction(instructions_sysvar, &access.user, &message)?;
+
+    // 2. 逐项检查 intent 字段
+    require_keys_eq!(
+        intent.input_mint,
+        terms.input_mint,
+        ErrorCode::IntentMismatch
+    );
+    require_keys_eq!(
+        intent.output_mint,
+        terms.output_mint,
+        ErrorCode::IntentMismatch
+    );
+    require!(
+        intent.nonce == terms.client_order_id,
+        ErrorCode::IntentMismatch
+    );
+    require!(
+        terms.in_amount <= intent.max_input_amount,
+        ErrorCode::IntentInputExceeded
+    );
+    // 过期时间不能晚于回执保留期, 否则回执关闭后 nonce 可被重放
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now < intent.expires_at && intent.expires_at <= now.saturating_add(TRADE_RECEIPT_RETENTION),
+        ErrorCode::IntentExpired
+    );
+
+    Ok(intent.min_output_amount)
+}
+
+/// Requires the instruction before the current one to be an Ed25519 program
+/// instruction holding one signature by `signer` over `message`.
+fn verify_ed25519_instruction(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, ErrorCode::InvalidIntentSignature);
+    let instruction = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+    require_keys_eq!(
+        instruction.program_id,
+        ed25519_program::ID,
+        ErrorCode::InvalidIntentSignature
+    );
+
+    let (signed_by, signed_message) = parse_ed25519_instruction(&instruction.data)?;
+    require_keys_eq!(signed_by, *signer, ErrorCode::InvalidIntentSignature);
+    require!(signed_message == message, ErrorCode::InvalidIntentSignature);
+    Ok(())
+}
+
+/// Returns the public key and message of a single-signature Ed25519 instruction
+/// whose offsets all point into its own data.
+pub fn parse_ed25519_instruction(data: &[u8]) -> Result<(Pubkey, &[u8])> {
+    require!(
+        data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_LEN && data[0] == 1,
+        ErrorCode::InvalidIntentSignature
+    );
+    let offsets = &data[ED25519_OFFSETS_START..ED25519_OFFSETS_START + ED25519_OFFSETS_LEN];
+    let read = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+    let signature_offset = read(0) as usize;
+    let public_key_offset = read(4) as usize;
+    let message_offset = read(8) as usize;
+    let message_size = read(10) as usize;
+
+    // signature / public key / message 必须都在本指令数据中
+    require!(
+        read(2) == u16::MAX && read(6) == u16::MAX && read(12) == u16::MAX,
+        ErrorCode::InvalidIntentSignature
+    );
+    require!(
+        data.len() >= signature_offset + ED25519_SIGNATURE_LEN,
+        ErrorCode::InvalidIntentSignature
+    );
+    let public_key = data
+        .get(public_key_offset..public_key_offset + ED25519_PUBKEY_LEN)
+        .ok_or(ErrorCode::InvalidIntentSignature)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(ErrorCode::InvalidIntentSignature)?;
+
+    Ok((Pubkey::try_from(public_key).unwrap(), message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ed25519_data(public_key: &Pubkey, message: &[u8], instruction_index: u16) -> Vec<u8> {
+        let public_key_offset = (ED25519_OFFSETS_START + ED25519_OFFSETS_LEN) as u16;
+        let signature_offset = public_key_offset + ED25519_PUBKEY_LEN as u16;
+        let message_offset = signature_offset + ED25519_SIGNATURE_LEN as u16;
+
+        let mut data = vec![1, 0];
+        for value in [
+            signature_offset,
+            instruction_index,
+            public_key_offset,
+            instruction_index,
+            message_offset,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(public_key.as_ref());
+        data.extend_from_slice(&[7; ED25519_SIGNATURE_LEN]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction() {
+        let public_key = Pubkey::new_unique();
+        let intent = TradeIntent {
+            input_mint: Pubkey::new_unique(),
+            output_mint: Pubkey::new_unique(),
+            max_input_amount: 1_000,
+            min_output_amount: 990,
+            expires_at: 100,
+            nonce: 1,
+        };
+        let message = intent.message(&Pubkey::new_unique()).unwrap();
+
+        let data = ed25519_data(&public_key, &message, u16::MAX);
+        let (signed_by, signed_message) = parse_ed25519_instruction(&data).unwrap();
+        assert_eq!(signed_by, public_key);
+        assert_eq!(signed_message, &message[..]);
+
+        // 数据指向其他指令时拒绝
+        let data = ed25519_data(&public_key, &message, 0);
+        assert!(parse_ed25519_instruction(&data).is_err());
+        assert!(parse_ed25519_instruction(&data[..10]).is_err());
+    }
+}
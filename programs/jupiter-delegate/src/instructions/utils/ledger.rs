@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+
+use super::account::create_pda_account;
+use super::event::EventCpi;
+use crate::{
+    constants::USER_CREDIT_SEED,
+    error::ErrorCode,
+    event::CreditUserEvent,
+    state::{namespace_seed, UserCredit},
+};
+
+/// Ledger entry to credit and the accounts paying for its creation.
+#[derive(Clone, Copy)]
+pub struct CreditAccounts<'a, 'info> {
+    pub user_credit: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub event_cpi: EventCpi<'a, 'info>,
+}
+
+/// Credits `amount` of `mint` parked in the shared vault to `user`, creating
+/// the ledger entry on first use.
+pub fn credit_user(
+    accounts: CreditAccounts,
+    namespace: u16,
+    user: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let CreditAccounts {
+        user_credit,
+        payer,
+        system_program,
+        event_cpi,
+    } = accounts;
+    let (user_credit_address, bump) = Pubkey::find_program_address(
+        &[
+            USER_CREDIT_SEED.as_bytes(),
+            namespace_seed(&namespace),
+            user.as_ref(),
+            mint.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        user_credit_address,
+        user_credit.key(),
+        ErrorCode::InvalidUserCredit
+    );
+
+    let mut entry = if user_credit.data_is_empty() {
+        create_pda_account(
+            user_credit,
+            payer,
+            system_program,
+            UserCredit::LEN,
+            &[
+                USER_CREDIT_SEED.as_bytes(),
+                namespace_seed(&namespace),
+                user.as_ref(),
+                mint.as_ref(),
+                &[bump],
+            ],
+        )?;
+        UserCredit {
+            user: *user,
+            mint: *mint,
+            amount: 0,
+            bump,
+        }
+    } else {
+        UserCredit::try_deserialize(&mut &user_credit.try_borrow_data()?[..])?
+    };
+
+    entry.credit(amount);
+    entry.try_serialize(&mut &mut user_credit.try_borrow_mut_data()?[..])?;
+
+    event_cpi.emit(CreditUserEvent {
+        user: *user,
+        mint: *mint,
+        amount,
+        credit: entry.amount,
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_credit_address(user: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[USER_CREDIT_SEED.as_bytes(), user.as_ref(), mint.as_ref()],
+            &crate::ID,
+        )
+    }
+
+    #[test]
+    fn test_credit_user() {
+        let user = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let (address, bump) = user_credit_address(&user, &mint);
+        let mut data = vec![0; UserCredit::LEN];
+        UserCredit {
+            user,
+            mint,
+            amount: 100,
+            bump,
+        }
+        .try_serialize(&mut &mut data[..])
+        .unwrap();
+
+        let (mut lamports, mut other_lamports) = (0, 0);
+        let user_credit = AccountInfo::new(
+            &address,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+        let other_key = Pubkey::new_unique();
+        let other = AccountInfo::new(
+            &other_key,
+            false,
+            false,
+            &mut other_lamports,
+            &mut [],
+            &crate::ID,
+            false,
+            0,
+        );
+        let accounts = CreditAccounts {
+            user_credit: &user_credit,
+            payer: &other,
+            system_program: &other,
+            event_cpi: EventCpi::new(&other, 0),
+        };
+
+        credit_user(accounts, 0, &user, &mint, 50).unwrap();
+        credit_user(accounts, 0, &user, &mint, 25).unwrap();
+        let credit =
+            UserCredit::try_deserialize(&mut &user_credit.try_borrow_data().unwrap()[..]).unwrap();
+        assert_eq!(credit.user, user);
+        assert_eq!(credit.amount, 175);
+
+        // 另一个用户或 mint 的账本不能被记账
+        assert_eq!(
+            credit_user(accounts, 0, &Pubkey::new_unique(), &mint, 50).unwrap_err(),
+            ErrorCode::InvalidUserCredit.into()
+        );
+        assert_eq!(
+            credit_user(accounts, 1, &user, &mint, 50).unwrap_err(),
+            ErrorCode::InvalidUserCredit.into()
+        );
+    }
+}
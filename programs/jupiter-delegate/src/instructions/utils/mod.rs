@@ -1,9 +1,29 @@
+mod account;
 mod cpi;
 mod declare;
+mod event;
+mod fee;
+mod intent;
+mod ledger;
+mod receipt;
+mod stats;
+mod token;
+mod trade_log;
 mod transfer;
 mod validate;
+mod vault;
 
+pub use account::*;
 pub use cpi::*;
 pub use declare::*;
+pub use event::*;
+pub use fee::*;
+pub use intent::*;
+pub use ledger::*;
+pub use receipt::*;
+pub use stats::*;
+pub use token::*;
+pub use trade_log::*;
 pub use transfer::*;
 pub use validate::*;
+pub use vault::*;
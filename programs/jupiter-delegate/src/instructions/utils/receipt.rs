@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use super::account::create_pda_account;
+use crate::{
+    constants::TRADE_RECEIPT_SEED,
+    error::ErrorCode,
+    state::{namespace_seed, TradeReceipt},
+};
+
+/// Realized amounts of a trade, recorded on its receipt.
+pub struct ExecutedTrade {
+    pub user: Pubkey,
+    pub client_order_id: u64,
+    pub operator: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_amount: u64,
+}
+
+/// Records the realized amounts of a trade on its receipt.
+pub fn record_trade_receipt(
+    receipt: &mut TradeReceipt,
+    trade: ExecutedTrade,
+    bump: u8,
+) -> Result<()> {
+    receipt.user = trade.user;
+    receipt.client_order_id = trade.client_order_id;
+    receipt.operator = trade.operator;
+    receipt.input_mint = trade.input_mint;
+    receipt.output_mint = trade.output_mint;
+    receipt.input_amount = trade.input_amount;
+    receipt.output_amount = trade.output_amount;
+    receipt.executed_at = Clock::get()?.unix_timestamp;
+    receipt.bump = bump;
+    Ok(())
+}
+
+/// Creates the receipt PDA of (user, client_order_id) for instructions that
+/// cannot declare it in their accounts. Fails if the trade was already executed.
+pub fn create_trade_receipt<'info>(
+    receipt: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    namespace: u16,
+    user: &Pubkey,
+    client_order_id: u64,
+) -> Result<u8> {
+    let client_order_id = client_order_id.to_le_bytes();
+    let (receipt_address, bump) = Pubkey::find_program_address(
+        &[
+            TRADE_RECEIPT_SEED.as_bytes(),
+            namespace_seed(&namespace),
+            user.as_ref(),
+            &client_order_id,
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        receipt_address,
+        receipt.key(),
+        ErrorCode::InvalidTradeReceipt
+    );
+    require!(receipt.data_is_empty(), ErrorCode::TradeAlreadyExecuted);
+
+    create_pda_account(
+        receipt,
+        payer,
+        system_program,
+        TradeReceipt::LEN,
+        &[
+            TRADE_RECEIPT_SEED.as_bytes(),
+            namespace_seed(&namespace),
+            user.as_ref(),
+            &client_order_id,
+            &[bump],
+        ],
+    )?;
+    Ok(bump)
+}
+
+/// Writes a receipt created by `create_trade_receipt`.
+pub fn write_trade_receipt(receipt_info: &AccountInfo, receipt: &TradeReceipt) -> Result<()> {
+    receipt.try_serialize(&mut &mut receipt_info.try_borrow_mut_data()?[..])
+}
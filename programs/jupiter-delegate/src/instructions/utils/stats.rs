@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use super::{account::create_pda_account, OutputAmounts};
+use crate::{
+    constants::USER_STATS_SEED,
+    error::ErrorCode,
+    state::{namespace_seed, UserStats},
+};
+
+/// Adds a trade to the user's statistics, filling in the identity of freshly
+/// created accounts.
+pub fn record_user_stats(
+    user_stats: &mut UserStats,
+    user: Pubkey,
+    bump: u8,
+    input_mint: &Pubkey,
+    input_amount: u64,
+    output_mint: &Pubkey,
+    output: OutputAmounts,
+) -> Result<()> {
+    user_stats.user = user;
+    user_stats.bump = bump;
+    user_stats.record_trade(
+        input_mint,
+        input_amount,
+        output_mint,
+        output.delivered,
+        output.protocol_fee,
+        Clock::get()?.unix_timestamp,
+    );
+    Ok(())
+}
+
+/// Loads the statistics PDA of `user`, creating it first for instructions that
+/// cannot declare it in their accounts.
+pub fn load_or_create_user_stats<'info>(
+    user_stats: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    namespace: u16,
+    user: &Pubkey,
+) -> Result<(UserStats, u8)> {
+    let (user_stats_address, bump) = Pubkey::find_program_address(
+        &[
+            USER_STATS_SEED.as_bytes(),
+            namespace_seed(&namespace),
+            user.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        user_stats_address,
+        user_stats.key(),
+        ErrorCode::InvalidUserStats
+    );
+
+    if !user_stats.data_is_empty() {
+        let stats = UserStats::try_deserialize(&mut &user_stats.try_borrow_data()?[..])?;
+        return Ok((stats, bump));
+    }
+
+    create_pda_account(
+        user_stats,
+        payer,
+        system_program,
+        UserStats::LEN,
+        &[
+            USER_STATS_SEED.as_bytes(),
+            namespace_seed(&namespace),
+            user.as_ref(),
+            &[bump],
+        ],
+    )?;
+    let stats = UserStats {
+        user: *user,
+        trade_count: 0,
+        last_trade_at: 0,
+        mints: Default::default(),
+        bump,
+    };
+    Ok((stats, bump))
+}
+
+pub fn write_user_stats(user_stats_info: &AccountInfo, user_stats: &UserStats) -> Result<()> {
+    user_stats.try_serialize(&mut &mut user_stats_info.try_borrow_mut_data()?[..])
+}
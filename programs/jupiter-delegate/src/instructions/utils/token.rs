@@ -0,0 +1,357 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::ID as TOKEN_PROGRAM_ID;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+        transfer_hook::TransferHook,
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+    onchain::invoke_transfer_checked,
+    state::{Account as TokenAccountState, Mint as MintState},
+};
+use anchor_spl::token_interface::{transfer_checked, TransferChecked};
+
+use super::event::EventCpi;
+use crate::{error::ErrorCode, event::TransferFeeEvent};
+
+/// Amounts of a single token transfer: `gross` leaves the source, `net` reaches
+/// the destination and `fee` is withheld by a Token-2022 transfer-fee mint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferAmounts {
+    pub gross: u64,
+    pub fee: u64,
+    pub net: u64,
+}
+
+fn is_supported_mint_extension(extension: ExtensionType) -> bool {
+    matches!(
+        extension,
+        ExtensionType::TransferFeeConfig
+            | ExtensionType::TransferHook
+            | ExtensionType::MintCloseAuthority
+            | ExtensionType::InterestBearingConfig
+            | ExtensionType::MetadataPointer
+            | ExtensionType::TokenMetadata
+            | ExtensionType::GroupPointer
+            | ExtensionType::TokenGroup
+            | ExtensionType::GroupMemberPointer
+            | ExtensionType::TokenGroupMember
+    )
+}
+
+/// Rejects mints outside the token programs and Token-2022 mints carrying
+/// extensions the vault cannot settle safely.
+pub fn validate_mint_extensions(mint: &AccountInfo) -> Result<()> {
+    if *mint.owner == TOKEN_PROGRAM_ID {
+        return Ok(());
+    }
+    require_keys_eq!(
+        *mint.owner,
+        spl_token_2022::ID,
+        ErrorCode::UnsupportedTokenProgram
+    );
+
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+    for extension in mint_state.get_extension_types()? {
+        require!(
+            is_supported_mint_extension(extension),
+            ErrorCode::UnsupportedMintExtension
+        );
+    }
+    Ok(())
+}
+
+/// Whether a token account holds neither tokens nor withheld transfer fees,
+/// both of which make the token program reject `close_account`.
+pub fn is_token_account_empty(token_account: &AccountInfo) -> Result<bool> {
+    let data = token_account.try_borrow_data()?;
+    let account_state = StateWithExtensions::<TokenAccountState>::unpack(&data)?;
+    let withheld_amount = account_state
+        .get_extension::<TransferFeeAmount>()
+        .map_or(0, |transfer_fee_amount| {
+            u64::from(transfer_fee_amount.withheld_amount)
+        });
+    Ok(account_state.base.amount == 0 && withheld_amount == 0)
+}
+
+/// Transfer fee withheld when moving `amount` of `mint` in the current epoch.
+pub fn calculate_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+    calculate_epoch_transfer_fee(mint, Clock::get()?.epoch, amount)
+}
+
+fn calculate_epoch_transfer_fee(mint: &AccountInfo, epoch: u64, amount: u64) -> Result<u64> {
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+    let Ok(transfer_fee_config) = mint_state.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+    let fee = transfer_fee_config
+        .calculate_epoch_fee(epoch, amount)
+        .ok_or(ErrorCode::InvalidTransferFee)?;
+    Ok(fee)
+}
+
+fn has_transfer_hook(mint: &AccountInfo) -> Result<bool> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(false);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+    Ok(mint_state
+        .get_extension::<TransferHook>()
+        .is_ok_and(|hook| Option::<Pubkey>::from(hook.program_id).is_some()))
+}
+
+/// `transfer_checked` that accounts for Token-2022 transfer fees and forwards
+/// transfer-hook extra accounts, resolved by key from `hook_accounts`.
+pub fn transfer_checked_with_fee<'info>(
+    cpi_ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+    hook_accounts: &[AccountInfo<'info>],
+    event_cpi: EventCpi<'_, 'info>,
+) -> Result<TransferAmounts> {
+    let from = cpi_ctx.accounts.from.clone();
+    let mint = cpi_ctx.accounts.mint.clone();
+    let to = cpi_ctx.accounts.to.clone();
+
+    // 1. 检查 mint 扩展并计算转账手续费
+    validate_mint_extensions(&mint)?;
+    let fee = calculate_transfer_fee(&mint, amount)?;
+
+    // 2. 转账, 带 transfer hook 的 mint 需要转发额外账户
+    if has_transfer_hook(&mint)? {
+        invoke_transfer_checked(
+            cpi_ctx.program.key,
+            from.clone(),
+            mint.clone(),
+            to.clone(),
+            cpi_ctx.accounts.authority.clone(),
+            hook_accounts,
+            amount,
+            decimals,
+            cpi_ctx.signer_seeds,
+        )?;
+    } else {
+        transfer_checked(cpi_ctx, amount, decimals)?;
+    }
+
+    let amounts = TransferAmounts {
+        gross: amount,
+        fee,
+        net: amount - fee,
+    };
+    if fee > 0 {
+        event_cpi.emit(TransferFeeEvent {
+            mint: mint.key(),
+            from: from.key(),
+            to: to.key(),
+            gross: amounts.gross,
+            fee: amounts.fee,
+            net: amounts.net,
+        })?;
+    }
+    Ok(amounts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token_2022::spl_token_2022::{
+        extension::{
+            non_transferable::NonTransferable, BaseStateWithExtensionsMut, StateWithExtensionsMut,
+        },
+        state::AccountState,
+    };
+
+    fn token_account_data(amount: u64, withheld_amount: Option<u64>) -> Vec<u8> {
+        let extensions = withheld_amount.map_or(vec![], |_| vec![ExtensionType::TransferFeeAmount]);
+        let space =
+            ExtensionType::try_calculate_account_len::<TokenAccountState>(&extensions).unwrap();
+        let mut data = vec![0; space];
+        let mut account_state =
+            StateWithExtensionsMut::<TokenAccountState>::unpack_uninitialized(&mut data).unwrap();
+        account_state.base = TokenAccountState {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount,
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        account_state.pack_base();
+        if let Some(withheld_amount) = withheld_amount {
+            account_state.init_account_type().unwrap();
+            account_state
+                .init_extension::<TransferFeeAmount>(true)
+                .unwrap()
+                .withheld_amount = withheld_amount.into();
+        }
+        data
+    }
+
+    fn mint_data(extensions: &[ExtensionType]) -> Vec<u8> {
+        let space = ExtensionType::try_calculate_account_len::<MintState>(extensions).unwrap();
+        let mut data = vec![0; space];
+        let mut mint_state =
+            StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+        for extension in extensions {
+            match extension {
+                ExtensionType::TransferFeeConfig => {
+                    // 1%, 最多 5_000
+                    let transfer_fee_config = mint_state
+                        .init_extension::<TransferFeeConfig>(true)
+                        .unwrap();
+                    for transfer_fee in [
+                        &mut transfer_fee_config.older_transfer_fee,
+                        &mut transfer_fee_config.newer_transfer_fee,
+                    ] {
+                        transfer_fee.maximum_fee = 5_000.into();
+                        transfer_fee.transfer_fee_basis_points = 100.into();
+                    }
+                }
+                ExtensionType::TransferHook => {
+                    mint_state
+                        .init_extension::<TransferHook>(true)
+                        .unwrap()
+                        .program_id
+                        .0 = Pubkey::new_unique();
+                }
+                ExtensionType::NonTransferable => {
+                    mint_state.init_extension::<NonTransferable>(true).unwrap();
+                }
+                _ => unreachable!(),
+            }
+        }
+        mint_state.base = MintState {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        mint_state.pack_base();
+        mint_state.init_account_type().unwrap();
+        data
+    }
+
+    fn with_account<T>(
+        mut data: Vec<u8>,
+        owner: &Pubkey,
+        f: impl FnOnce(&AccountInfo) -> Result<T>,
+    ) -> Result<T> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let account =
+            AccountInfo::new(&key, false, true, &mut lamports, &mut data, owner, false, 0);
+        f(&account)
+    }
+
+    #[test]
+    fn test_validate_mint_extensions() {
+        let legacy_mint = vec![0; MintState::LEN];
+        assert!(with_account(legacy_mint, &TOKEN_PROGRAM_ID, validate_mint_extensions).is_ok());
+        for extensions in [
+            vec![],
+            vec![ExtensionType::TransferFeeConfig],
+            vec![
+                ExtensionType::TransferFeeConfig,
+                ExtensionType::TransferHook,
+            ],
+        ] {
+            assert!(with_account(
+                mint_data(&extensions),
+                &spl_token_2022::ID,
+                validate_mint_extensions
+            )
+            .is_ok());
+        }
+        assert_eq!(
+            with_account(
+                mint_data(&[ExtensionType::NonTransferable]),
+                &spl_token_2022::ID,
+                validate_mint_extensions
+            )
+            .unwrap_err(),
+            ErrorCode::UnsupportedMintExtension.into()
+        );
+        assert_eq!(
+            with_account(
+                mint_data(&[]),
+                &Pubkey::new_unique(),
+                validate_mint_extensions
+            )
+            .unwrap_err(),
+            ErrorCode::UnsupportedTokenProgram.into()
+        );
+    }
+
+    #[test]
+    fn test_transfer_fee_and_hook() {
+        let fee = |extensions: &[ExtensionType], amount| {
+            with_account(mint_data(extensions), &spl_token_2022::ID, |mint| {
+                calculate_epoch_transfer_fee(mint, 0, amount)
+            })
+            .unwrap()
+        };
+        assert_eq!(fee(&[], 1_000_000), 0);
+        assert_eq!(fee(&[ExtensionType::TransferFeeConfig], 10_000), 100);
+        assert_eq!(fee(&[ExtensionType::TransferFeeConfig], 101), 2);
+        assert_eq!(fee(&[ExtensionType::TransferFeeConfig], 1_000_000), 5_000);
+        assert_eq!(
+            with_account(vec![0; MintState::LEN], &TOKEN_PROGRAM_ID, |mint| {
+                calculate_transfer_fee(mint, 1_000_000)
+            })
+            .unwrap(),
+            0
+        );
+
+        let hook = |extensions: &[ExtensionType]| {
+            with_account(
+                mint_data(extensions),
+                &spl_token_2022::ID,
+                has_transfer_hook,
+            )
+            .unwrap()
+        };
+        assert!(!hook(&[ExtensionType::TransferFeeConfig]));
+        assert!(hook(&[ExtensionType::TransferHook]));
+        assert!(!with_account(
+            vec![0; MintState::LEN],
+            &TOKEN_PROGRAM_ID,
+            has_transfer_hook
+        )
+        .unwrap());
+    }
+
+    fn is_empty(mut data: Vec<u8>, owner: &Pubkey) -> bool {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let token_account =
+            AccountInfo::new(&key, false, true, &mut lamports, &mut data, owner, false, 0);
+        is_token_account_empty(&token_account).unwrap()
+    }
+
+    #[test]
+    fn test_is_token_account_empty() {
+        assert!(is_empty(token_account_data(0, None), &TOKEN_PROGRAM_ID));
+        assert!(!is_empty(token_account_data(1, None), &TOKEN_PROGRAM_ID));
+        assert!(is_empty(
+            token_account_data(0, Some(0)),
+            &spl_token_2022::ID
+        ));
+        // withheld fee 未收取前不能关闭
+        assert!(!is_empty(
+            token_account_data(0, Some(5)),
+            &spl_token_2022::ID
+        ));
+        assert!(!is_empty(
+            token_account_data(3, Some(0)),
+            &spl_token_2022::ID
+        ));
+    }
+}
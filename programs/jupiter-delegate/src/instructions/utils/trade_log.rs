@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{TradeLog, TradeLogEntry};
+
+/// Appends `entry` to the ring buffer that follows the `TradeLog` header.
+pub fn append_trade_log(trade_log: &AccountLoader<TradeLog>, entry: TradeLogEntry) -> Result<()> {
+    let trade_log_info = trade_log.to_account_info();
+    let mut data = trade_log_info.try_borrow_mut_data()?;
+    let (header, entries) = data.split_at_mut(TradeLog::HEADER_LEN);
+    let log: &mut TradeLog = bytemuck::from_bytes_mut(&mut header[8..]);
+    let entries_len = log.capacity as usize * TradeLog::ENTRY_LEN;
+    log.push(bytemuck::cast_slice_mut(&mut entries[..entries_len]), entry);
+    Ok(())
+}
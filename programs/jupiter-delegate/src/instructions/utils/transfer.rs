@@ -1,49 +1,280 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::spl_token::native_mint;
 use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+    close_account, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
 };
 
-use crate::constants::VAULT_SEED;
+use super::event::EventCpi;
+use super::fee::{collect_protocol_fee, ProtocolFee};
+use super::token::{transfer_checked_with_fee, TransferAmounts};
+use super::vault::VaultSeeds;
+use crate::{error::ErrorCode, event::NativeOutputSettledEvent};
+
+/// Mint, signing vault and hook accounts of transfers out of the vault, shared
+/// by the legs of a settlement.
+#[derive(Clone, Copy)]
+pub struct VaultTransfer<'a, 'info> {
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub mint_program: &'a Interface<'info, TokenInterface>,
+    pub vault: &'a SystemAccount<'info>,
+    pub vault_seeds: VaultSeeds,
+    pub hook_accounts: &'a [AccountInfo<'info>],
+    pub event_cpi: EventCpi<'a, 'info>,
+}
+
+/// Settles WSOL output as native lamports through a temporary vault-owned WSOL account.
+#[derive(Clone, Copy)]
+pub struct NativeOutput<'a, 'info> {
+    pub temp_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub receiver: &'a AccountInfo<'info>,
+    pub rent_payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> NativeOutput<'a, 'info> {
+    pub fn new(
+        temp_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+        receiver: &'a AccountInfo<'info>,
+        rent_payer: &'a AccountInfo<'info>,
+        system_program: &'a AccountInfo<'info>,
+        output_mint: &Pubkey,
+    ) -> Result<Self> {
+        require_keys_eq!(
+            *output_mint,
+            native_mint::ID,
+            ErrorCode::NativeOutputRequiresWrappedSol
+        );
+        Ok(Self {
+            temp_token_account,
+            receiver,
+            rent_payer,
+            system_program,
+        })
+    }
+}
+
+/// Resolves the output mode: native lamports when a temporary WSOL account is
+/// provided, otherwise the receiver's output token account, or the vault when
+/// there is no receiver and the output is parked for the user.
+pub fn load_native_output<'a, 'info>(
+    temp_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    receiver: &'a AccountInfo<'info>,
+    rent_payer: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    output_mint: &Pubkey,
+) -> Result<Option<NativeOutput<'a, 'info>>> {
+    temp_token_account
+        .map(|temp_token_account| {
+            NativeOutput::new(
+                temp_token_account,
+                receiver,
+                rent_payer,
+                system_program,
+                output_mint,
+            )
+        })
+        .transpose()
+}
+
+/// Output of a trade settled by `transfer_output_tokens`.
+#[derive(Clone, Copy, Default)]
+pub struct OutputAmounts {
+    pub protocol_fee: u64, // protocol fee withheld, partner share included
+    pub delivered: u64,    // delivered to the user
+    pub parked: u64,       // left in the vault for a later token_receive
+}
 
 pub fn transfer_output_tokens<'info>(
+    vault_transfer: VaultTransfer<'_, 'info>,
     vault_output_token_account: &InterfaceAccount<'info, TokenAccount>,
     receiver_output_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
-    output_mint: &InterfaceAccount<'info, Mint>,
-    output_mint_program: &Interface<'info, TokenInterface>,
-    vault: &SystemAccount<'info>,
-    vault_bump: u8,
     initial_output_balance: u64,
-) -> Result<()> {
-    if let Some(receiver_token_account) = receiver_output_token_account {
+    protocol_fee: Option<ProtocolFee<'_, 'info>>,
+    native_output: Option<NativeOutput<'_, 'info>>,
+) -> Result<OutputAmounts> {
+    let mut output = OutputAmounts::default();
+    if receiver_output_token_account.is_some() || native_output.is_some() {
         let output_token_balance_delta = vault_output_token_account.amount - initial_output_balance;
 
-        msg!("Debug: initial_output_balance: {}", initial_output_balance);
-        msg!(
-            "Debug: vault_output_token_account.amount: {}",
-            vault_output_token_account.amount
-        );
-        msg!(
-            "Debug: output_token_balance_delta: {}",
-            output_token_balance_delta
-        );
+        #[cfg(feature = "debug-logs")]
+        {
+            msg!("Debug: initial_output_balance: {}", initial_output_balance);
+            msg!(
+                "Debug: vault_output_token_account.amount: {}",
+                vault_output_token_account.amount
+            );
+            msg!(
+                "Debug: output_token_balance_delta: {}",
+                output_token_balance_delta
+            );
+        }
 
-        if output_token_balance_delta > 0 {
-            let signed_seeds = &[VAULT_SEED.as_bytes(), &[vault_bump]];
-            transfer_checked(
-                CpiContext::new_with_signer(
-                    output_mint_program.to_account_info(),
-                    TransferChecked {
-                        from: vault_output_token_account.to_account_info(),
-                        to: receiver_token_account.to_account_info(),
-                        authority: vault.to_account_info(),
-                        mint: output_mint.to_account_info(),
-                    },
-                    &[signed_seeds],
-                ),
+        let net_output_amount = if output_token_balance_delta > 0 {
+            collect_protocol_fee(
+                vault_transfer,
+                vault_output_token_account,
                 output_token_balance_delta,
-                output_mint.decimals,
+                protocol_fee,
+            )?
+        } else {
+            0
+        };
+        output.protocol_fee = output_token_balance_delta - net_output_amount;
+
+        // 临时 WSOL 账户总是关闭, 没有输出时也退还租金
+        if let Some(native_output) = native_output {
+            output.delivered = settle_native_output(
+                vault_transfer,
+                vault_output_token_account,
+                net_output_amount,
+                native_output,
             )?;
+            return Ok(output);
+        }
+
+        if let Some(receiver_token_account) = receiver_output_token_account {
+            output.delivered = transfer_from_vault(
+                vault_transfer,
+                vault_output_token_account,
+                receiver_token_account,
+                net_output_amount,
+            )?
+            .net;
         }
+    } else {
+        output.parked = vault_output_token_account.amount - initial_output_balance;
+    }
+    Ok(output)
+}
+
+/// Splits the lamports released by closing the temporary WSOL account: the
+/// rent-exempt minimum goes back to the rent payer, the rest to the user.
+pub fn split_released_lamports(released: u64, rent_exempt_minimum: u64) -> (u64, u64) {
+    let rent = released.min(rent_exempt_minimum);
+    (released - rent, rent)
+}
+
+/// Returns the lamports delivered to the receiver.
+fn settle_native_output<'info>(
+    vault_transfer: VaultTransfer<'_, 'info>,
+    vault_output_token_account: &InterfaceAccount<'info, TokenAccount>,
+    amount: u64,
+    native_output: NativeOutput<'_, 'info>,
+) -> Result<u64> {
+    let vault = vault_transfer.vault;
+    let signed_seeds = &vault_transfer.vault_seeds.signer_seeds();
+    let temp_token_account = native_output.temp_token_account;
+    let rent_exempt_minimum =
+        Rent::get()?.minimum_balance(temp_token_account.to_account_info().data_len());
+
+    // 1. 将 WSOL 转入临时账户
+    transfer_from_vault(
+        vault_transfer,
+        vault_output_token_account,
+        temp_token_account,
+        amount,
+    )?;
+
+    // 2. 关闭临时账户, lamports 回到 vault, 按 vault 余额变化计算释放数量
+    let vault_lamports_before = vault.lamports();
+    close_account(CpiContext::new_with_signer(
+        vault_transfer.mint_program.to_account_info(),
+        CloseAccount {
+            account: temp_token_account.to_account_info(),
+            destination: vault.to_account_info(),
+            authority: vault.to_account_info(),
+        },
+        &[signed_seeds],
+    ))?;
+    let released = vault.lamports() - vault_lamports_before;
+    let (delivered, rent_refunded) = split_released_lamports(released, rent_exempt_minimum);
+
+    // 3. 将原生 SOL 转给用户, 只有免租金额退还给 operator
+    transfer(
+        CpiContext::new_with_signer(
+            native_output.system_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: native_output.receiver.to_account_info(),
+            },
+            &[signed_seeds],
+        ),
+        delivered,
+    )?;
+    transfer(
+        CpiContext::new_with_signer(
+            native_output.system_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: native_output.rent_payer.to_account_info(),
+            },
+            &[signed_seeds],
+        ),
+        rent_refunded,
+    )?;
+
+    vault_transfer.event_cpi.emit(NativeOutputSettledEvent {
+        receiver: native_output.receiver.key(),
+        amount: delivered,
+        rent_refunded,
+    })?;
+
+    Ok(delivered)
+}
+
+pub fn transfer_from_vault<'info>(
+    vault_transfer: VaultTransfer<'_, 'info>,
+    vault_token_account: &InterfaceAccount<'info, TokenAccount>,
+    to_token_account: &InterfaceAccount<'info, TokenAccount>,
+    amount: u64,
+) -> Result<TransferAmounts> {
+    if amount == 0 {
+        return Ok(TransferAmounts {
+            gross: 0,
+            fee: 0,
+            net: 0,
+        });
+    }
+
+    let signed_seeds = &vault_transfer.vault_seeds.signer_seeds();
+    transfer_checked_with_fee(
+        CpiContext::new_with_signer(
+            vault_transfer.mint_program.to_account_info(),
+            TransferChecked {
+                from: vault_token_account.to_account_info(),
+                mint: vault_transfer.mint.to_account_info(),
+                to: to_token_account.to_account_info(),
+                authority: vault_transfer.vault.to_account_info(),
+            },
+            &[signed_seeds],
+        ),
+        amount,
+        vault_transfer.mint.decimals,
+        vault_transfer.hook_accounts,
+        vault_transfer.event_cpi,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_released_lamports() {
+        let rent_exempt_minimum = 2_039_280;
+        assert_eq!(
+            split_released_lamports(rent_exempt_minimum + 1_000_000, rent_exempt_minimum),
+            (1_000_000, rent_exempt_minimum)
+        );
+        // 临时账户中预存的 lamports 归用户, 不归 operator
+        assert_eq!(
+            split_released_lamports(rent_exempt_minimum + 1_000_500, rent_exempt_minimum),
+            (1_000_500, rent_exempt_minimum)
+        );
+        assert_eq!(
+            split_released_lamports(1_000, rent_exempt_minimum),
+            (0, 1_000)
+        );
     }
-    Ok(())
 }
@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::get_associated_token_address,
-    token_interface::{transfer_checked, TokenAccount, TransferChecked},
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{TokenAccount, TransferChecked},
 };
 
-use crate::{constants::VAULT_SEED, error::ErrorCode, state::Config};
+use super::event::EventCpi;
+use super::token::{transfer_checked_with_fee, TransferAmounts};
+use super::vault::VaultSeeds;
+use crate::{error::ErrorCode, state::Config};
 
 pub fn validate_receiver_token_account(
     remaining_accounts: &[AccountInfo],
@@ -20,28 +23,71 @@ pub fn validate_receiver_token_account(
     Ok(())
 }
 
+/// Input leg of a trade: the user's token account approved to the vault and the
+/// vault account it is moved into.
+#[derive(Clone, Copy)]
+pub struct DelegatedInput<'a, 'info> {
+    pub delegate: &'a Pubkey,
+    pub delegate_input_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub input_mint: &'a AccountInfo<'info>,
+    pub input_mint_program: &'a AccountInfo<'info>,
+    pub vault_input_token_account: &'a AccountInfo<'info>,
+    pub decimals: u8,
+    pub hook_accounts: &'a [AccountInfo<'info>],
+    pub event_cpi: EventCpi<'a, 'info>,
+}
+
 pub fn validate_and_transfer_input<'info>(
     operator: &AccountInfo<'info>,
     config: &mut Account<'info, Config>,
     vault: &AccountInfo<'info>,
-    vault_bump: u8,
-    delegate_input_token_account: &InterfaceAccount<'info, TokenAccount>,
-    input_mint: &AccountInfo<'info>,
-    input_mint_program: &AccountInfo<'info>,
-    vault_input_token_account: &AccountInfo<'info>,
+    vault_seeds: VaultSeeds,
+    input: DelegatedInput<'_, 'info>,
     in_amount: u64,
-    decimal: u8,
-    delegate_pubkey: &Pubkey,
-) -> Result<()> {
+) -> Result<TransferAmounts> {
     // 1. 基本检查
+    validate_operator(operator, config)?;
+
+    // 2. 检查冷却时间
+    check_trade_cooldown(config)?;
+
+    // 3. 验证委托账户并从 delegate 转账到 vault
+    transfer_delegated_input(vault, vault_seeds, input, in_amount)
+}
+
+pub fn validate_operator(operator: &AccountInfo, config: &Config) -> Result<()> {
     require!(
         operator.key() == config.operator || operator.key() == config.admin,
         ErrorCode::InvalidOperator
     );
     require!(config.is_initialized, ErrorCode::ConfigNotInitialized);
     require!(!config.is_paused, ErrorCode::ConfigPaused);
+    Ok(())
+}
 
-    // 2. 验证委托账户
+pub fn check_trade_cooldown(config: &mut Config) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        config
+            .last_trade_timestamp
+            .checked_add(config.cooldown_duration)
+            .expect("overflow")
+            < now,
+        ErrorCode::SwapTooFrequent
+    );
+    config.last_trade_timestamp = now;
+    Ok(())
+}
+
+pub fn transfer_delegated_input<'info>(
+    vault: &AccountInfo<'info>,
+    vault_seeds: VaultSeeds,
+    input: DelegatedInput<'_, 'info>,
+    in_amount: u64,
+) -> Result<TransferAmounts> {
+    let delegate_input_token_account = input.delegate_input_token_account;
+
+    // 1. 验证委托账户
     require!(
         delegate_input_token_account.delegate.contains(&vault.key()),
         ErrorCode::DelegateNotApproved
@@ -51,39 +97,31 @@ pub fn validate_and_transfer_input<'info>(
         ErrorCode::InsufficientDelegatedAmount
     );
     require_keys_eq!(
-        get_associated_token_address(delegate_pubkey, input_mint.key),
+        get_associated_token_address_with_program_id(
+            input.delegate,
+            input.input_mint.key,
+            input.input_mint_program.key,
+        ),
         delegate_input_token_account.key(),
         ErrorCode::InvalidDelegateTokenAccount
     );
 
-    // 3. 检查冷却时间
-    let now = Clock::get()?.unix_timestamp;
-    require!(
-        config
-            .last_trade_timestamp
-            .checked_add(config.cooldown_duration)
-            .expect("overflow")
-            < now,
-        ErrorCode::SwapTooFrequent
-    );
-    config.last_trade_timestamp = now;
-
-    // 4. 从 delegate 转账到 vault
-    let signed_seeds = &[VAULT_SEED.as_bytes(), &[vault_bump]];
-    transfer_checked(
+    // 2. 从 delegate 转账到 vault, vault 实际收到扣除转账手续费后的数量
+    let signed_seeds = &vault_seeds.signer_seeds();
+    transfer_checked_with_fee(
         CpiContext::new_with_signer(
-            input_mint_program.to_account_info(),
+            input.input_mint_program.clone(),
             TransferChecked {
                 from: delegate_input_token_account.to_account_info(),
-                to: vault_input_token_account.to_account_info(),
-                authority: vault.to_account_info(),
-                mint: input_mint.to_account_info(),
+                mint: input.input_mint.clone(),
+                to: input.vault_input_token_account.clone(),
+                authority: vault.clone(),
             },
             &[signed_seeds],
         ),
         in_amount,
-        decimal,
-    )?;
-
-    Ok(())
+        input.decimals,
+        input.hook_accounts,
+        input.event_cpi,
+    )
 }
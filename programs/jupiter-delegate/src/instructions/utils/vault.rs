@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::VAULT_SEED,
+    state::{namespace_seed, Config},
+};
+
+/// Signer seeds of the vault PDA, either the shared vault or a vault seeded by user.
+///
+/// The shared vault signs with an empty user seed, which derives the same
+/// address as `[VAULT_SEED, namespace]`.
+#[derive(Clone, Copy)]
+pub struct VaultSeeds {
+    pub namespace: u16,
+    pub user: Option<Pubkey>,
+    pub bump: [u8; 1],
+}
+
+impl VaultSeeds {
+    pub fn new(config: &Config, user: &Pubkey, bump: u8) -> Self {
+        Self::with_mode(config, config.per_user_vault, user, bump)
+    }
+
+    pub fn with_mode(config: &Config, per_user_vault: bool, user: &Pubkey, bump: u8) -> Self {
+        Self {
+            namespace: config.namespace,
+            user: per_user_vault.then_some(*user),
+            bump: [bump],
+        }
+    }
+
+    pub fn shared(config: &Config, bump: u8) -> Self {
+        Self {
+            namespace: config.namespace,
+            user: None,
+            bump: [bump],
+        }
+    }
+
+    pub fn signer_seeds(&self) -> [&[u8]; 4] {
+        [
+            VAULT_SEED.as_bytes(),
+            namespace_seed(&self.namespace),
+            self.user.as_ref().map_or(&[], |user| user.as_ref()),
+            &self.bump,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_seeds_derive_vault_address() {
+        let (vault, bump) = Pubkey::find_program_address(&[VAULT_SEED.as_bytes()], &crate::ID);
+        let seeds = VaultSeeds {
+            namespace: 0,
+            user: None,
+            bump: [bump],
+        };
+        assert_eq!(
+            Pubkey::create_program_address(&seeds.signer_seeds(), &crate::ID).unwrap(),
+            vault
+        );
+
+        let user = Pubkey::new_unique();
+        let (user_vault, user_bump) = Pubkey::find_program_address(
+            &[VAULT_SEED.as_bytes(), &7u16.to_le_bytes(), user.as_ref()],
+            &crate::ID,
+        );
+        let seeds = VaultSeeds {
+            namespace: 7,
+            user: Some(user),
+            bump: [user_bump],
+        };
+        assert_eq!(
+            Pubkey::create_program_address(&seeds.signer_seeds(), &crate::ID).unwrap(),
+            user_vault
+        );
+    }
+}
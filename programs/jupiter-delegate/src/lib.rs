@@ -53,10 +53,11 @@ pub mod jupiter_delegate {
 
     pub fn init_config(
         ctx: Context<InitConfig>,
+        namespace: u16,
         operator: Pubkey,
         cooldown_duration: i64,
     ) -> Result<()> {
-        process_init_config(ctx, operator, cooldown_duration)
+        process_init_config(ctx, namespace, operator, cooldown_duration)
     }
 
     pub fn grant_access(ctx: Context<GrantAccess>, user: Pubkey) -> Result<()> {
@@ -67,6 +68,22 @@ pub mod jupiter_delegate {
         process_revoke_access(ctx, user)
     }
 
+    pub fn add_destination(ctx: Context<AddDestination>, owner: Pubkey) -> Result<()> {
+        process_add_destination(ctx, owner)
+    }
+
+    pub fn remove_destination(ctx: Context<RemoveDestination>) -> Result<()> {
+        process_remove_destination(ctx)
+    }
+
+    pub fn modify_access_intent(
+        ctx: Context<ModifyAccessIntent>,
+        user: Pubkey,
+        intent_required: bool,
+    ) -> Result<()> {
+        process_modify_access_intent(ctx, user, intent_required)
+    }
+
     pub fn swap(_ctx: Context<JupiterSwap>, _params: JupiterSwapParams) -> Result<()> {
         // process_jupiter_swap(ctx, params)
         Ok(())
@@ -104,6 +121,23 @@ pub mod jupiter_delegate {
         Ok(())
     }
 
+    pub fn dflow_open_order<'a>(
+        ctx: Context<'_, '_, '_, 'a, DflowOpenOrder<'a>>,
+        params: DflowOpenOrderParams,
+    ) -> Result<()> {
+        process_dflow_open_order(ctx, params)
+    }
+
+    pub fn dflow_close_order<'a>(ctx: Context<'_, '_, '_, 'a, DflowCloseOrder<'a>>) -> Result<()> {
+        process_dflow_close_order(ctx)
+    }
+
+    pub fn dflow_settle_order<'a>(
+        ctx: Context<'_, '_, '_, 'a, DflowSettleOrder<'a>>,
+    ) -> Result<()> {
+        process_dflow_settle_order(ctx)
+    }
+
     pub fn okx_aggregator<'a>(
         ctx: Context<'_, '_, '_, 'a, OkxAggregator<'a>>,
         params: OkxAggregatorParams,
@@ -119,6 +153,58 @@ pub mod jupiter_delegate {
         Ok(())
     }
 
+    pub fn create_dca_plan(ctx: Context<CreateDcaPlan>, params: CreateDcaPlanParams) -> Result<()> {
+        process_create_dca_plan(ctx, params)
+    }
+
+    pub fn cancel_dca_plan(ctx: Context<CancelDcaPlan>) -> Result<()> {
+        process_cancel_dca_plan(ctx)
+    }
+
+    pub fn execute_dca_slice<'a>(
+        ctx: Context<'_, '_, '_, 'a, ExecuteDcaSlice<'a>>,
+        params: DcaSliceParams,
+    ) -> Result<()> {
+        process_execute_dca_slice(ctx, params)
+    }
+
+    pub fn create_limit_order(
+        ctx: Context<CreateLimitOrder>,
+        params: CreateLimitOrderParams,
+    ) -> Result<()> {
+        process_create_limit_order(ctx, params)
+    }
+
+    pub fn cancel_limit_order(ctx: Context<CancelLimitOrder>) -> Result<()> {
+        process_cancel_limit_order(ctx)
+    }
+
+    pub fn expire_limit_order(ctx: Context<ExpireLimitOrder>) -> Result<()> {
+        process_expire_limit_order(ctx)
+    }
+
+    pub fn fill_limit_order<'a>(
+        ctx: Context<'_, '_, '_, 'a, FillLimitOrder<'a>>,
+        params: FillLimitOrderParams,
+    ) -> Result<()> {
+        process_fill_limit_order(ctx, params)
+    }
+
+    pub fn close_trade_receipt(ctx: Context<CloseTradeReceipt>) -> Result<()> {
+        process_close_trade_receipt(ctx)
+    }
+
+    pub fn close_user_stats(ctx: Context<CloseUserStats>) -> Result<()> {
+        process_close_user_stats(ctx)
+    }
+
+    pub fn batch_trade<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchTrade<'info>>,
+        params: BatchTradeParams,
+    ) -> Result<()> {
+        process_batch_trade(ctx, params)
+    }
+
     pub fn propose_new_admin(ctx: Context<ProposeNewAdmin>) -> Result<()> {
         process_propose_new_admin(ctx)
     }
@@ -138,7 +224,72 @@ pub mod jupiter_delegate {
         process_pause(ctx, toggle)
     }
 
-    pub fn token_receive(ctx: Context<TokenReceive>) -> Result<()> {
+    pub fn modify_protocol_fee(
+        ctx: Context<ModifyProtocolFee>,
+        fee_bps: u16,
+        fee_treasury: Pubkey,
+    ) -> Result<()> {
+        process_modify_protocol_fee(ctx, fee_bps, fee_treasury)
+    }
+
+    pub fn modify_max_commission(
+        ctx: Context<ModifyMaxCommission>,
+        max_commission_bps: u16,
+    ) -> Result<()> {
+        process_modify_max_commission(ctx, max_commission_bps)
+    }
+
+    pub fn modify_vault_mode(ctx: Context<ModifyVaultMode>, per_user_vault: bool) -> Result<()> {
+        process_modify_vault_mode(ctx, per_user_vault)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        process_migrate_config(ctx)
+    }
+
+    pub fn migrate_access(ctx: Context<MigrateAccess>) -> Result<()> {
+        process_migrate_access(ctx)
+    }
+
+    pub fn init_trade_log(ctx: Context<InitTradeLog>, capacity: u32) -> Result<()> {
+        process_init_trade_log(ctx, capacity)
+    }
+
+    pub fn resize_trade_log(ctx: Context<ResizeTradeLog>, capacity: u32) -> Result<()> {
+        process_resize_trade_log(ctx, capacity)
+    }
+
+    pub fn migrate_user_vault<'a>(
+        ctx: Context<'_, '_, '_, 'a, MigrateUserVault<'a>>,
+        amount: u64,
+    ) -> Result<()> {
+        process_migrate_user_vault(ctx, amount)
+    }
+
+    pub fn withdraw_protocol_fee<'a>(
+        ctx: Context<'_, '_, '_, 'a, WithdrawProtocolFee<'a>>,
+        amount: u64,
+    ) -> Result<()> {
+        process_withdraw_protocol_fee(ctx, amount)
+    }
+
+    pub fn create_partner(ctx: Context<CreatePartner>, share_bps: u16) -> Result<()> {
+        process_create_partner(ctx, share_bps)
+    }
+
+    pub fn modify_partner_share(ctx: Context<ModifyPartnerShare>, share_bps: u16) -> Result<()> {
+        process_modify_partner_share(ctx, share_bps)
+    }
+
+    pub fn init_partner_fee_account(ctx: Context<InitPartnerFeeAccount>) -> Result<()> {
+        process_init_partner_fee_account(ctx)
+    }
+
+    pub fn claim_partner_fee<'a>(ctx: Context<'_, '_, '_, 'a, ClaimPartnerFee<'a>>) -> Result<()> {
+        process_claim_partner_fee(ctx)
+    }
+
+    pub fn token_receive<'a>(ctx: Context<'_, '_, '_, 'a, TokenReceive<'a>>) -> Result<()> {
         process_token_receive(ctx)
     }
 
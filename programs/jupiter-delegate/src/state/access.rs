@@ -1,13 +1,24 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::ACCESS_VERSION;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Access {
     pub user: Pubkey,
     pub is_granted: bool,
     pub bump: u8,
+    pub partner: Pubkey,       // Pubkey::default() when the user has no partner
+    pub version: u8,           // layout version, see ACCESS_VERSION
+    pub intent_required: bool, // trades need an intent signed by the user
+    pub reserved: [u8; 31],    // reserved for future fields
 }
 
 impl Access {
     pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Whether this program understands the account layout
+    pub fn has_supported_version(&self) -> bool {
+        self.version == ACCESS_VERSION
+    }
 }
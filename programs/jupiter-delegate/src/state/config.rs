@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{CONFIG_VERSION, DEFAULT_NAMESPACE};
+
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
@@ -12,8 +14,80 @@ pub struct Config {
     pub is_paused: bool,
     pub cooldown_duration: i64, // cooldown duration in seconds
     pub bump: u8,
+    pub fee_bps: u16,            // protocol fee on settled output, in bps
+    pub fee_treasury: Pubkey,    // owner of the treasury receiving withdrawn fees
+    pub max_commission_bps: u16, // cap on venue commission rates, in bps
+    pub per_user_vault: bool,    // trade through vaults seeded by user
+    pub namespace: u16,          // deployment namespace included in every PDA seed
+    pub version: u8,             // layout version, see CONFIG_VERSION
+    pub trade_sequence: u64,     // sequence number of the next TradeExecuted event
+    pub reserved: [u8; 56],      // reserved for future fields
+}
+
+/// Namespace seed component, little-endian. The default namespace uses an empty
+/// seed so it derives the same addresses as a deployment without namespaces.
+pub fn namespace_seed(namespace: &u16) -> &[u8] {
+    if *namespace == DEFAULT_NAMESPACE {
+        &[]
+    } else {
+        bytemuck::bytes_of(namespace)
+    }
 }
 
 impl Config {
     pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Whether this program understands the account layout
+    pub fn has_supported_version(&self) -> bool {
+        self.version == CONFIG_VERSION
+    }
+
+    /// Returns the sequence number for a new trade and advances the counter
+    pub fn next_trade_sequence(&mut self) -> u64 {
+        let sequence = self.trade_sequence;
+        self.trade_sequence += 1;
+        sequence
+    }
+
+    /// Extra vault seed for `user`: the user key in per-user mode, empty for the shared vault
+    pub fn vault_user_seed<'a>(&self, user: &'a Pubkey) -> &'a [u8] {
+        if self.per_user_vault {
+            user.as_ref()
+        } else {
+            &[]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_trade_sequence() {
+        let mut config = Config {
+            admin: Pubkey::new_unique(),
+            operator: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+            pending_admin: Pubkey::default(),
+            last_trade_timestamp: 0,
+            is_initialized: true,
+            is_paused: false,
+            cooldown_duration: 0,
+            bump: 0,
+            fee_bps: 0,
+            fee_treasury: Pubkey::default(),
+            max_commission_bps: 0,
+            per_user_vault: false,
+            namespace: DEFAULT_NAMESPACE,
+            version: CONFIG_VERSION,
+            trade_sequence: 0,
+            reserved: [0; 56],
+        };
+        assert!(config.has_supported_version());
+        assert_eq!(config.next_trade_sequence(), 0);
+        assert_eq!(config.next_trade_sequence(), 1);
+        assert_eq!(config.next_trade_sequence(), 2);
+        assert_eq!(config.trade_sequence, 3);
+    }
 }
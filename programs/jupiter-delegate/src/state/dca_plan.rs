@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+#[account]
+#[derive(InitSpace)]
+pub struct DcaPlan {
+    pub user: Pubkey,
+    pub plan_id: u64,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub amount_per_slice: u64,
+    pub interval: i64, // interval between slices in seconds
+    pub total_budget: u64,
+    pub min_output_amount: Option<u64>, // lower bound on delivered output, per full slice
+    pub max_output_amount: Option<u64>, // upper bound on delivered output, per full slice
+    pub slices_done: u64,
+    pub amount_spent: u64, // pulled from the user, refunds excluded
+    pub amount_received: u64,
+    pub last_executed_at: i64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl DcaPlan {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Slices must be spaced out in time and the budget must cover a full slice.
+    pub fn is_valid(&self) -> bool {
+        self.amount_per_slice > 0
+            && self.interval > 0
+            && self.total_budget >= self.amount_per_slice
+            && match (self.min_output_amount, self.max_output_amount) {
+                (Some(min), Some(max)) => min <= max,
+                _ => true,
+            }
+    }
+
+    pub fn remaining_budget(&self) -> u64 {
+        self.total_budget.saturating_sub(self.amount_spent)
+    }
+
+    pub fn next_slice_amount(&self) -> u64 {
+        self.amount_per_slice.min(self.remaining_budget())
+    }
+
+    /// Price bounds are expressed per full slice and scaled to the input actually spent.
+    pub fn is_within_price_bounds(&self, amount_in: u64, amount_out: u64) -> bool {
+        let scale = |bound: u64| {
+            (bound as u128)
+                .saturating_mul(amount_in as u128)
+                .checked_div(self.amount_per_slice as u128)
+                .unwrap_or(0)
+        };
+        let amount_out = amount_out as u128;
+        self.min_output_amount
+            .is_none_or(|min| amount_out >= scale(min))
+            && self
+                .max_output_amount
+                .is_none_or(|max| amount_out <= scale(max))
+    }
+
+    pub fn is_due(&self, now: i64) -> bool {
+        self.slices_done == 0
+            || self
+                .last_executed_at
+                .checked_add(self.interval)
+                .is_some_and(|next| next <= now)
+    }
+
+    /// `spent_amount` is what left the user, transfer fees included and
+    /// refunds excluded, `output_amount` what the user received.
+    pub fn record_slice(&mut self, spent_amount: u64, output_amount: u64, now: i64) -> Result<()> {
+        require!(spent_amount > 0, ErrorCode::DcaSliceEmpty);
+        self.slices_done = self.slices_done.checked_add(1).expect("overflow");
+        self.amount_spent = self
+            .amount_spent
+            .checked_add(spent_amount)
+            .expect("overflow");
+        self.amount_received = self
+            .amount_received
+            .checked_add(output_amount)
+            .expect("overflow");
+        self.last_executed_at = now;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dca_plan() -> DcaPlan {
+        DcaPlan {
+            user: Pubkey::new_unique(),
+            plan_id: 0,
+            input_mint: Pubkey::new_unique(),
+            output_mint: Pubkey::new_unique(),
+            amount_per_slice: 1_000,
+            interval: 60,
+            total_budget: 2_500,
+            min_output_amount: Some(100),
+            max_output_amount: Some(200),
+            slices_done: 0,
+            amount_spent: 0,
+            amount_received: 0,
+            last_executed_at: 0,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_dca_plan_is_valid() {
+        assert!(dca_plan().is_valid());
+        assert!(!DcaPlan {
+            interval: 0,
+            ..dca_plan()
+        }
+        .is_valid());
+        assert!(!DcaPlan {
+            interval: -1,
+            ..dca_plan()
+        }
+        .is_valid());
+        assert!(!DcaPlan {
+            amount_per_slice: 0,
+            ..dca_plan()
+        }
+        .is_valid());
+        assert!(!DcaPlan {
+            total_budget: 999,
+            ..dca_plan()
+        }
+        .is_valid());
+        assert!(!DcaPlan {
+            min_output_amount: Some(201),
+            ..dca_plan()
+        }
+        .is_valid());
+        assert!(DcaPlan {
+            min_output_amount: None,
+            ..dca_plan()
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn test_dca_plan_schedule() {
+        let mut plan = dca_plan();
+        assert!(plan.is_due(0));
+        assert_eq!(plan.next_slice_amount(), 1_000);
+
+        plan.slices_done = 1;
+        plan.amount_spent = 1_000;
+        plan.last_executed_at = 1_000;
+        assert!(!plan.is_due(1_059));
+        assert!(plan.is_due(1_060));
+
+        // 最后一个 slice 只花剩余预算
+        plan.slices_done = 2;
+        plan.amount_spent = 2_000;
+        assert_eq!(plan.next_slice_amount(), 500);
+        plan.amount_spent = 2_500;
+        assert_eq!(plan.next_slice_amount(), 0);
+    }
+
+    #[test]
+    fn test_dca_plan_price_bounds() {
+        let plan = dca_plan();
+        assert!(plan.is_within_price_bounds(1_000, 100));
+        assert!(plan.is_within_price_bounds(1_000, 200));
+        assert!(!plan.is_within_price_bounds(1_000, 99));
+        assert!(!plan.is_within_price_bounds(1_000, 201));
+        // 部分 slice 按实际输入缩放
+        assert!(plan.is_within_price_bounds(500, 50));
+        assert!(!plan.is_within_price_bounds(500, 49));
+        assert!(!plan.is_within_price_bounds(500, 101));
+
+        let unbounded = DcaPlan {
+            min_output_amount: None,
+            max_output_amount: None,
+            ..plan
+        };
+        assert!(unbounded.is_within_price_bounds(1_000, 0));
+        assert!(unbounded.is_within_price_bounds(1_000, u64::MAX));
+    }
+
+    #[test]
+    fn test_dca_plan_record_slice() {
+        let mut plan = dca_plan();
+        // 退回的输入不计入已花费, 预算留给后续 slice
+        plan.record_slice(600, 80, 1_000).unwrap();
+        assert_eq!(plan.slices_done, 1);
+        assert_eq!(plan.amount_spent, 600);
+        assert_eq!(plan.amount_received, 80);
+        assert_eq!(plan.last_executed_at, 1_000);
+        assert_eq!(plan.next_slice_amount(), 1_000);
+
+        // 没有花费输入的 slice 被拒绝
+        assert_eq!(
+            plan.record_slice(0, 0, 1_060).unwrap_err(),
+            ErrorCode::DcaSliceEmpty.into()
+        );
+        assert_eq!(plan.slices_done, 1);
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use super::UserCredit;
+use crate::error::ErrorCode;
+
+/// Owner whose token accounts may receive `transfer_out` withdrawals.
+#[account]
+#[derive(InitSpace)]
+pub struct Destination {
+    pub owner: Pubkey,
+    pub bump: u8,
+}
+
+impl Destination {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Authorizes a `transfer_out` of `amount`: a registered destination receives
+/// freely, any other owner only out of its ledger credit, which is debited.
+/// Returns the user whose credit was debited.
+pub fn authorize_withdrawal(
+    destination: Option<&Destination>,
+    user_credit: Option<&mut UserCredit>,
+    amount: u64,
+) -> Result<Option<Pubkey>> {
+    if destination.is_some() {
+        return Ok(None);
+    }
+
+    let user_credit = user_credit.ok_or(ErrorCode::DestinationNotAllowed)?;
+    user_credit.debit(amount)?;
+    Ok(Some(user_credit.user))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorize_withdrawal() {
+        let destination = Destination {
+            owner: Pubkey::new_unique(),
+            bump: 0,
+        };
+        let mut user_credit = UserCredit {
+            user: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 1_000,
+            bump: 0,
+        };
+
+        // 登记的目的地不扣减账本
+        assert_eq!(
+            authorize_withdrawal(Some(&destination), Some(&mut user_credit), 5_000).unwrap(),
+            None
+        );
+        assert_eq!(user_credit.amount, 1_000);
+
+        assert_eq!(
+            authorize_withdrawal(None, None, 1).unwrap_err(),
+            ErrorCode::DestinationNotAllowed.into()
+        );
+        assert_eq!(
+            authorize_withdrawal(None, Some(&mut user_credit), 600).unwrap(),
+            Some(user_credit.user)
+        );
+        assert_eq!(user_credit.amount, 400);
+        assert_eq!(
+            authorize_withdrawal(None, Some(&mut user_credit), 401).unwrap_err(),
+            ErrorCode::InsufficientUserCredit.into()
+        );
+        assert_eq!(user_credit.amount, 400);
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct DflowOrder {
+    pub order: Pubkey,
+    pub user: Pubkey,
+    pub delegate: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub order_account_id: u64,
+    pub in_amount: u64, // input amount escrowed in the dflow order vault
+    pub opened_at: i64,
+    pub bump: u8,
+    pub per_user_vault: bool, // vault mode at open, settlement signs with the same vault
+    pub client_order_id: u64, // trade receipt completed at settlement
+}
+
+impl DflowOrder {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub fn vault_user_seed(&self) -> &[u8] {
+        if self.per_user_vault {
+            self.user.as_ref()
+        } else {
+            &[]
+        }
+    }
+
+    /// Input consumed by the order once the unfilled remainder is refunded.
+    pub fn filled_input_amount(&self, refund_amount: u64) -> u64 {
+        self.in_amount.saturating_sub(refund_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settlement_amounts() {
+        let user = Pubkey::new_unique();
+        let mut order = DflowOrder {
+            order: Pubkey::new_unique(),
+            user,
+            delegate: Pubkey::new_unique(),
+            input_mint: Pubkey::new_unique(),
+            output_mint: Pubkey::new_unique(),
+            order_account_id: 1,
+            in_amount: 1_000,
+            opened_at: 0,
+            bump: 255,
+            per_user_vault: false,
+            client_order_id: 7,
+        };
+        assert_eq!(order.filled_input_amount(0), 1_000);
+        assert_eq!(order.filled_input_amount(400), 600);
+        // close 之后全额退回
+        assert_eq!(order.filled_input_amount(1_000), 0);
+        assert_eq!(order.filled_input_amount(1_001), 0);
+
+        assert!(order.vault_user_seed().is_empty());
+        order.per_user_vault = true;
+        assert_eq!(order.vault_user_seed(), user.as_ref());
+    }
+}
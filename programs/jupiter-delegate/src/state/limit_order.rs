@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+#[account]
+#[derive(InitSpace)]
+pub struct LimitOrder {
+    pub user: Pubkey,
+    pub order_id: u64,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_amount: u64,
+    pub remaining_amount: u64,
+    pub received_amount: u64,
+    pub rate_numerator: u64,   // limit rate, output side
+    pub rate_denominator: u64, // limit rate, input side
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl LimitOrder {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    pub fn is_rate_met(&self, amount_in: u64, amount_out: u64) -> bool {
+        amount_in > 0
+            && (amount_out as u128) * (self.rate_denominator as u128)
+                >= (amount_in as u128) * (self.rate_numerator as u128)
+    }
+
+    /// `input_amount` is what left the user, transfer fees included and
+    /// refunds excluded, `output_amount` what the user received.
+    pub fn record_fill(&mut self, input_amount: u64, output_amount: u64) -> Result<()> {
+        self.remaining_amount = self
+            .remaining_amount
+            .checked_sub(input_amount)
+            .ok_or(ErrorCode::LimitOrderFillTooLarge)?;
+        self.received_amount = self
+            .received_amount
+            .checked_add(output_amount)
+            .expect("overflow");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limit_order_rate() {
+        let mut order = LimitOrder {
+            user: Pubkey::new_unique(),
+            order_id: 0,
+            input_mint: Pubkey::new_unique(),
+            output_mint: Pubkey::new_unique(),
+            input_amount: 1_000_000,
+            remaining_amount: 1_000_000,
+            received_amount: 0,
+            rate_numerator: 150,
+            rate_denominator: 1,
+            expires_at: 100,
+            bump: 0,
+        };
+        assert!(order.is_rate_met(1_000, 150_000));
+        assert!(order.is_rate_met(1_000, 150_001));
+        assert!(!order.is_rate_met(1_000, 149_999));
+        assert!(!order.is_rate_met(0, 150_000));
+        assert!(!order.is_expired(99));
+        assert!(order.is_expired(100));
+
+        order.record_fill(400_000, 60_000_000).unwrap();
+        order.record_fill(600_000, 90_000_000).unwrap();
+        assert_eq!(order.remaining_amount, 0);
+        assert_eq!(order.received_amount, 150_000_000);
+        // 转账手续费不能让 order 超额成交
+        assert!(order.record_fill(1, 150).is_err());
+    }
+}
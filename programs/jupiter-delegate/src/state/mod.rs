@@ -1,5 +1,23 @@
 pub mod access;
 pub mod config;
+pub mod dca_plan;
+pub mod destination;
+pub mod dflow_order;
+pub mod limit_order;
+pub mod partner;
+pub mod trade_log;
+pub mod trade_receipt;
+pub mod user_credit;
+pub mod user_stats;
 
 pub use access::*;
 pub use config::*;
+pub use dca_plan::*;
+pub use destination::*;
+pub use dflow_order::*;
+pub use limit_order::*;
+pub use partner::*;
+pub use trade_log::*;
+pub use trade_receipt::*;
+pub use user_credit::*;
+pub use user_stats::*;
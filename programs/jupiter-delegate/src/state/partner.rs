@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Partner {
+    pub payout_owner: Pubkey,
+    pub share_bps: u16, // share of the protocol fee, in bps
+    pub bump: u8,
+}
+
+impl Partner {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
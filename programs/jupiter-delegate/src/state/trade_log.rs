@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+
+/// Venue a logged trade was routed through.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum TradeVenue {
+    Jupiter,
+    JupiterOrderEngine,
+    Okx,
+    Dflow,
+    JupiterPerpetuals,
+    TwoHop,
+}
+
+#[zero_copy]
+#[derive(Debug, PartialEq)]
+pub struct TradeLogEntry {
+    pub user: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub operator: Pubkey,
+    pub input_amount: u64,  // spent by the trade, net of transfer fees
+    pub output_amount: u64, // delivered to the user, net of protocol fees
+    pub slot: u64,
+    pub venue: u8, // TradeVenue
+    pub padding: [u8; 7],
+}
+
+/// Header of the recent-trades ring buffer. `capacity` entries follow the
+/// header in the account data; trade `sequence` lives at `sequence % capacity`.
+#[account(zero_copy)]
+pub struct TradeLog {
+    pub total: u64,          // trades ever appended, also the next sequence number
+    pub first_sequence: u64, // oldest sequence kept by the last resize, earlier slots are stale
+    pub capacity: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl TradeLog {
+    pub const HEADER_LEN: usize = 8 + std::mem::size_of::<TradeLog>();
+    pub const ENTRY_LEN: usize = std::mem::size_of::<TradeLogEntry>();
+
+    pub fn space(capacity: u32) -> usize {
+        Self::HEADER_LEN + capacity as usize * Self::ENTRY_LEN
+    }
+
+    /// Sequence number of the oldest entry still held.
+    pub fn oldest(&self) -> u64 {
+        self.first_sequence
+            .max(self.total.saturating_sub(self.capacity as u64))
+    }
+
+    pub fn push(&mut self, entries: &mut [TradeLogEntry], entry: TradeLogEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        entries[(self.total % self.capacity as u64) as usize] = entry;
+        self.total += 1;
+    }
+
+    pub fn get<'a>(
+        &self,
+        entries: &'a [TradeLogEntry],
+        sequence: u64,
+    ) -> Option<&'a TradeLogEntry> {
+        (sequence >= self.oldest() && sequence < self.total)
+            .then(|| &entries[(sequence % self.capacity as u64) as usize])
+    }
+
+    /// Switches to `capacity` and lays the `kept` entries out again. Slots
+    /// that are not refilled hold stale entries, so the log keeps starting
+    /// at the oldest sequence held before the resize.
+    pub fn resize(
+        &mut self,
+        entries: &mut [TradeLogEntry],
+        kept: Vec<(u64, TradeLogEntry)>,
+        capacity: u32,
+    ) {
+        self.first_sequence = self.oldest();
+        self.capacity = capacity;
+        for (sequence, entry) in kept {
+            entries[(sequence % capacity as u64) as usize] = entry;
+        }
+    }
+}
+
+/// Reads up to `limit` entries starting at `sequence` from raw `TradeLog`
+/// account data, oldest first. Sequences already overwritten are skipped.
+pub fn read_trade_log_page(
+    data: &[u8],
+    sequence: u64,
+    limit: usize,
+) -> Result<Vec<(u64, TradeLogEntry)>> {
+    require!(
+        data.len() >= TradeLog::HEADER_LEN && data[..8] == *TradeLog::DISCRIMINATOR,
+        ErrorCode::AccountDidNotDeserialize
+    );
+    let log: TradeLog = bytemuck::pod_read_unaligned(&data[8..TradeLog::HEADER_LEN]);
+    require!(
+        data.len() >= TradeLog::space(log.capacity),
+        ErrorCode::AccountDidNotDeserialize
+    );
+
+    let start = sequence.max(log.oldest());
+    Ok((start..log.total)
+        .take(limit)
+        .map(|sequence| {
+            let offset = TradeLog::HEADER_LEN
+                + (sequence % log.capacity as u64) as usize * TradeLog::ENTRY_LEN;
+            let entry = bytemuck::pod_read_unaligned(&data[offset..offset + TradeLog::ENTRY_LEN]);
+            (sequence, entry)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(input_amount: u64) -> TradeLogEntry {
+        TradeLogEntry {
+            user: Pubkey::default(),
+            input_mint: Pubkey::default(),
+            output_mint: Pubkey::default(),
+            operator: Pubkey::default(),
+            input_amount,
+            output_amount: 0,
+            slot: 0,
+            venue: TradeVenue::Jupiter as u8,
+            padding: [0; 7],
+        }
+    }
+
+    #[test]
+    fn test_trade_log_ring_buffer() {
+        let mut log = TradeLog {
+            total: 0,
+            first_sequence: 0,
+            capacity: 3,
+            bump: 0,
+            padding: [0; 3],
+        };
+        let mut entries = [entry(0); 3];
+        for i in 1..=5 {
+            log.push(&mut entries, entry(i));
+        }
+        assert_eq!(log.total, 5);
+        assert_eq!(log.oldest(), 2);
+        assert!(log.get(&entries, 1).is_none());
+        assert_eq!(log.get(&entries, 2).unwrap().input_amount, 3);
+        assert_eq!(log.get(&entries, 4).unwrap().input_amount, 5);
+        assert!(log.get(&entries, 5).is_none());
+
+        // 按账户原始数据分页读取
+        let mut data = TradeLog::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&log));
+        data.extend_from_slice(bytemuck::cast_slice(&entries));
+        let page = read_trade_log_page(&data, 0, 2).unwrap();
+        assert_eq!(
+            page.iter()
+                .map(|(sequence, entry)| (*sequence, entry.input_amount))
+                .collect::<Vec<_>>(),
+            vec![(2, 3), (3, 4)]
+        );
+        assert_eq!(read_trade_log_page(&data, 4, 10).unwrap().len(), 1);
+        assert!(read_trade_log_page(&data, 5, 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_trade_log_grow_after_wrap() {
+        let mut log = TradeLog {
+            total: 0,
+            first_sequence: 0,
+            capacity: 3,
+            bump: 0,
+            padding: [0; 3],
+        };
+        let mut entries = vec![entry(0); 3];
+        for i in 1..=5 {
+            log.push(&mut entries, entry(i));
+        }
+
+        // 扩容后未重新写入的槽位是旧数据, 不能当作历史返回
+        let kept = (log.oldest()..log.total)
+            .map(|sequence| (sequence, *log.get(&entries, sequence).unwrap()))
+            .collect();
+        entries.resize(5, entry(0));
+        log.resize(&mut entries, kept, 5);
+        assert_eq!(log.first_sequence, 2);
+        assert_eq!(log.oldest(), 2);
+        assert!(log.get(&entries, 0).is_none());
+        assert!(log.get(&entries, 1).is_none());
+        assert_eq!(log.get(&entries, 2).unwrap().input_amount, 3);
+        assert_eq!(log.get(&entries, 4).unwrap().input_amount, 5);
+
+        let mut data = TradeLog::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&log));
+        data.extend_from_slice(bytemuck::cast_slice(&entries));
+        assert_eq!(
+            read_trade_log_page(&data, 0, 10)
+                .unwrap()
+                .iter()
+                .map(|(sequence, entry)| (*sequence, entry.input_amount))
+                .collect::<Vec<_>>(),
+            vec![(2, 3), (3, 4), (4, 5)]
+        );
+
+        // 新容量写满后按 total 推进
+        for i in 6..=8 {
+            log.push(&mut entries, entry(i));
+        }
+        assert_eq!(log.oldest(), 3);
+        assert_eq!(log.get(&entries, 3).unwrap().input_amount, 4);
+        assert_eq!(log.get(&entries, 7).unwrap().input_amount, 8);
+    }
+}
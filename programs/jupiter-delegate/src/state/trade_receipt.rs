@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::TRADE_RECEIPT_RETENTION;
+
+#[account]
+#[derive(InitSpace)]
+pub struct TradeReceipt {
+    pub user: Pubkey,
+    pub client_order_id: u64,
+    pub operator: Pubkey, // paid the rent, reclaims it on close
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_amount: u64,  // spent by the trade, net of transfer fees
+    pub output_amount: u64, // delivered to the user, net of protocol fees
+    pub executed_at: i64,
+    pub bump: u8,
+}
+
+impl TradeReceipt {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub fn is_closable(&self, now: i64) -> bool {
+        now >= self.executed_at.saturating_add(TRADE_RECEIPT_RETENTION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trade_receipt_retention() {
+        let receipt = TradeReceipt {
+            user: Pubkey::new_unique(),
+            client_order_id: 1,
+            operator: Pubkey::new_unique(),
+            input_mint: Pubkey::new_unique(),
+            output_mint: Pubkey::new_unique(),
+            input_amount: 1_000,
+            output_amount: 990,
+            executed_at: 100,
+            bump: 0,
+        };
+        assert!(!receipt.is_closable(100));
+        assert!(!receipt.is_closable(100 + TRADE_RECEIPT_RETENTION - 1));
+        assert!(receipt.is_closable(100 + TRADE_RECEIPT_RETENTION));
+    }
+}
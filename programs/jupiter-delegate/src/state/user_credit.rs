@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Tokens of `mint` held in the shared vault on behalf of `user`.
+#[account]
+#[derive(InitSpace)]
+pub struct UserCredit {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl UserCredit {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub fn credit(&mut self, amount: u64) {
+        self.amount = self.amount.checked_add(amount).expect("overflow");
+    }
+
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        self.amount = self
+            .amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientUserCredit)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_credit_ledger() {
+        let mut credit = UserCredit {
+            user: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 0,
+            bump: 0,
+        };
+        credit.credit(700);
+        credit.credit(300);
+        assert_eq!(credit.amount, 1_000);
+        credit.debit(400).unwrap();
+        assert_eq!(credit.amount, 600);
+        assert_eq!(
+            credit.debit(601).unwrap_err(),
+            ErrorCode::InsufficientUserCredit.into()
+        );
+        assert_eq!(credit.amount, 600);
+        credit.debit(600).unwrap();
+        assert_eq!(credit.amount, 0);
+    }
+}
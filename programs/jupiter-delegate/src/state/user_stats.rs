@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_USER_STATS_MINTS;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct MintStats {
+    pub mint: Pubkey, // default while the slot is free
+    pub input_volume: u64,
+    pub output_volume: u64, // delivered to the user, net of protocol fees
+    pub fee_volume: u64,    // protocol fees taken from the output
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UserStats {
+    pub user: Pubkey,
+    pub trade_count: u64,
+    pub last_trade_at: i64,
+    pub mints: [MintStats; MAX_USER_STATS_MINTS as usize],
+    pub bump: u8,
+}
+
+impl UserStats {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Adds a trade to the totals. Volumes of mints that no longer fit in a
+    /// slot are dropped, the trade count is always kept.
+    pub fn record_trade(
+        &mut self,
+        input_mint: &Pubkey,
+        input_amount: u64,
+        output_mint: &Pubkey,
+        output_amount: u64,
+        fee_amount: u64,
+        now: i64,
+    ) {
+        self.trade_count = self.trade_count.saturating_add(1);
+        self.last_trade_at = now;
+        if let Some(slot) = self.mint_slot(input_mint) {
+            slot.input_volume = slot.input_volume.saturating_add(input_amount);
+        }
+        if let Some(slot) = self.mint_slot(output_mint) {
+            slot.output_volume = slot.output_volume.saturating_add(output_amount);
+            slot.fee_volume = slot.fee_volume.saturating_add(fee_amount);
+        }
+    }
+
+    pub fn mint_stats(&self, mint: &Pubkey) -> Option<&MintStats> {
+        self.mints.iter().find(|slot| slot.mint == *mint)
+    }
+
+    fn mint_slot(&mut self, mint: &Pubkey) -> Option<&mut MintStats> {
+        let index = self
+            .mints
+            .iter()
+            .position(|slot| slot.mint == *mint)
+            .or_else(|| {
+                self.mints
+                    .iter()
+                    .position(|slot| slot.mint == Pubkey::default())
+            })?;
+        let slot = &mut self.mints[index];
+        slot.mint = *mint;
+        Some(slot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_trade() {
+        let mut stats = UserStats {
+            user: Pubkey::new_unique(),
+            trade_count: 0,
+            last_trade_at: 0,
+            mints: Default::default(),
+            bump: 0,
+        };
+        let usdc = Pubkey::new_unique();
+        let sol = Pubkey::new_unique();
+
+        stats.record_trade(&usdc, 1_000, &sol, 990, 10, 100);
+        stats.record_trade(&sol, 500, &usdc, 495, 5, 200);
+        assert_eq!(stats.trade_count, 2);
+        assert_eq!(stats.last_trade_at, 200);
+        let usdc_stats = stats.mint_stats(&usdc).unwrap();
+        assert_eq!(usdc_stats.input_volume, 1_000);
+        assert_eq!(usdc_stats.output_volume, 495);
+        assert_eq!(usdc_stats.fee_volume, 5);
+        let sol_stats = stats.mint_stats(&sol).unwrap();
+        assert_eq!(sol_stats.input_volume, 500);
+        assert_eq!(sol_stats.output_volume, 990);
+
+        // 槽位用尽后新 mint 的成交量不再记录
+        for _ in 0..MAX_USER_STATS_MINTS {
+            stats.record_trade(&Pubkey::new_unique(), 1, &usdc, 1, 0, 300);
+        }
+        assert_eq!(stats.trade_count, 2 + MAX_USER_STATS_MINTS as u64);
+        assert_eq!(
+            stats
+                .mints
+                .iter()
+                .filter(|s| s.mint != Pubkey::default())
+                .count(),
+            MAX_USER_STATS_MINTS as usize
+        );
+        assert_eq!(
+            stats.mint_stats(&usdc).unwrap().output_volume,
+            495 + MAX_USER_STATS_MINTS as u64
+        );
+    }
+}
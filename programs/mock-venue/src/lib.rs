@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+// Deployed at the OKX DEX address on the local test validator, so
+// `okx_aggregator` can route trades to it.
+declare_id!("6m2CDdhRgxpH4WjvdzxAYbGxwdGUz5MziiL5jek2kBma");
+
+pub const POOL_AUTHORITY_SEED: &str = "mock-venue-pool";
+
+/// Stand-in for an aggregator route, for the compute unit benchmark only.
+///
+/// `swap` shares its discriminator with the OKX `swap` instruction and routes
+/// the input through two pools: input -> intermediate -> output. The pools are
+/// pre-funded token accounts owned by the pool authority; no price is computed,
+/// the caller passes the output amount.
+#[program]
+pub mod mock_venue {
+    use super::*;
+
+    pub fn swap(ctx: Context<Swap>, in_amount: u64, out_amount: u64) -> Result<()> {
+        let bump = [ctx.bumps.pool_authority];
+        let signer_seeds: &[&[&[u8]]] = &[&[POOL_AUTHORITY_SEED.as_bytes(), &bump]];
+        let token_program = ctx.accounts.token_program.to_account_info();
+
+        // 1. 输入代币进入第一个池
+        token::transfer(
+            CpiContext::new(
+                token_program.clone(),
+                Transfer {
+                    from: ctx.accounts.source_token_account.to_account_info(),
+                    to: ctx.accounts.first_pool_input.to_account_info(),
+                    authority: ctx.accounts.user_transfer_authority.to_account_info(),
+                },
+            ),
+            in_amount,
+        )?;
+
+        // 2. 中间代币从第一个池转入第二个池
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                Transfer {
+                    from: ctx.accounts.first_pool_output.to_account_info(),
+                    to: ctx.accounts.second_pool_input.to_account_info(),
+                    authority: ctx.accounts.pool_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            in_amount,
+        )?;
+
+        // 3. 输出代币转给调用方
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program,
+                Transfer {
+                    from: ctx.accounts.second_pool_output.to_account_info(),
+                    to: ctx.accounts.destination_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            out_amount,
+        )
+    }
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    pub token_program: Program<'info, Token>,
+
+    pub user_transfer_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub source_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Owner of the pool token accounts
+    #[account(seeds = [POOL_AUTHORITY_SEED.as_bytes()], bump)]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(mut, token::authority = pool_authority)]
+    pub first_pool_input: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = pool_authority)]
+    pub first_pool_output: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = pool_authority)]
+    pub second_pool_input: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = pool_authority)]
+    pub second_pool_output: Account<'info, TokenAccount>,
+}
@@ -0,0 +1,378 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import {
+  approve,
+  createAccount,
+  createMint,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import {
+  ComputeBudgetProgram,
+  Keypair,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { JupiterDelegate } from "../target/types/jupiter_delegate";
+import { MockVenue } from "../target/types/mock_venue";
+
+// Compute units of each instruction, measured on the local test validator.
+// Run with `anchor test`; the table is printed once all cases have run.
+describe("compute units", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.JupiterDelegate as Program<JupiterDelegate>;
+  // Deployed at the OKX address, routes trades through two pools
+  const mockVenue = anchor.workspace.MockVenue as Program<MockVenue>;
+  const admin = provider.wallet.publicKey;
+
+  const namespace = 7;
+  const namespaceSeed = Buffer.from(new Uint16Array([namespace]).buffer);
+  const pda = (...seeds: Buffer[]) =>
+    PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+  const config = pda(Buffer.from("jupiter-delegate-config"), namespaceSeed);
+  const vault = pda(Buffer.from("jupiter-delegate-vault"), namespaceSeed);
+  const user = Keypair.generate();
+  const receiver = Keypair.generate();
+  const access = pda(
+    Buffer.from("jupiter-delegate-access"),
+    namespaceSeed,
+    user.publicKey.toBuffer()
+  );
+  const tradeLog = pda(
+    Buffer.from("jupiter-delegate-trade-log"),
+    namespaceSeed
+  );
+  const destination = pda(
+    Buffer.from("jupiter-delegate-destination"),
+    namespaceSeed,
+    receiver.publicKey.toBuffer()
+  );
+
+  let mint: PublicKey;
+  let userCredit: PublicKey;
+  let userTokenAccount: PublicKey;
+  let receiverTokenAccount: PublicKey;
+  let vaultTokenAccount: PublicKey;
+
+  const poolAuthority = PublicKey.findProgramAddressSync(
+    [Buffer.from("mock-venue-pool")],
+    mockVenue.programId
+  )[0];
+  let intermediateMint: PublicKey;
+  let outputMint: PublicKey;
+  let userOutputTokenAccount: PublicKey;
+  let vaultOutputTokenAccount: PublicKey;
+  let firstPoolInput: PublicKey;
+  let firstPoolOutput: PublicKey;
+  let secondPoolInput: PublicKey;
+  let secondPoolOutput: PublicKey;
+
+  const computeUnits: { instruction: string; computeUnits: number }[] = [];
+
+  async function measure(instruction: string, signature: Promise<string>) {
+    const tx = await provider.connection.getTransaction(await signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    expect(tx?.meta?.err).to.be.null;
+    computeUnits.push({
+      instruction,
+      computeUnits: tx!.meta!.computeUnitsConsumed!,
+    });
+  }
+
+  // transfer_out enforces the trade cooldown, which is strict on the second
+  const nextSecond = () => new Promise((resolve) => setTimeout(resolve, 1100));
+
+  before(async () => {
+    const payer = (provider.wallet as anchor.Wallet).payer;
+    mint = await createMint(provider.connection, payer, admin, null, 6);
+    userTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        mint,
+        user.publicKey
+      )
+    ).address;
+    receiverTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        mint,
+        receiver.publicKey
+      )
+    ).address;
+    vaultTokenAccount = getAssociatedTokenAddressSync(mint, vault, true);
+    userCredit = pda(
+      Buffer.from("jupiter-delegate-user-credit"),
+      namespaceSeed,
+      user.publicKey.toBuffer(),
+      mint.toBuffer()
+    );
+    await mintTo(
+      provider.connection,
+      payer,
+      mint,
+      userTokenAccount,
+      admin,
+      1_000_000
+    );
+
+    // mock venue route: mint -> intermediateMint -> outputMint
+    intermediateMint = await createMint(
+      provider.connection,
+      payer,
+      admin,
+      null,
+      6
+    );
+    outputMint = await createMint(provider.connection, payer, admin, null, 6);
+    const poolAccount = (poolMint: PublicKey) =>
+      createAccount(
+        provider.connection,
+        payer,
+        poolMint,
+        poolAuthority,
+        Keypair.generate()
+      );
+    firstPoolInput = await poolAccount(mint);
+    firstPoolOutput = await poolAccount(intermediateMint);
+    secondPoolInput = await poolAccount(intermediateMint);
+    secondPoolOutput = await poolAccount(outputMint);
+    await mintTo(
+      provider.connection,
+      payer,
+      intermediateMint,
+      firstPoolOutput,
+      admin,
+      1_000_000
+    );
+    await mintTo(
+      provider.connection,
+      payer,
+      outputMint,
+      secondPoolOutput,
+      admin,
+      1_000_000
+    );
+    userOutputTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        outputMint,
+        user.publicKey
+      )
+    ).address;
+    vaultOutputTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        outputMint,
+        vault,
+        true
+      )
+    ).address;
+    await approve(
+      provider.connection,
+      payer,
+      userTokenAccount,
+      vault,
+      user,
+      100_000
+    );
+  });
+
+  after(() => {
+    console.table(computeUnits);
+  });
+
+  it("init_config", async () => {
+    await measure(
+      "init_config",
+      program.methods
+        .initConfig(namespace, admin, new BN(0))
+        .accountsPartial({ admin, config, vault })
+        .rpc({ commitment: "confirmed" })
+    );
+  });
+
+  it("grant_access", async () => {
+    await measure(
+      "grant_access",
+      program.methods
+        .grantAccess(user.publicKey)
+        .accountsPartial({ admin, config, access, partner: null })
+        .rpc({ commitment: "confirmed" })
+    );
+  });
+
+  it("add_destination", async () => {
+    await measure(
+      "add_destination",
+      program.methods
+        .addDestination(receiver.publicKey)
+        .accountsPartial({ admin, config, destination })
+        .rpc({ commitment: "confirmed" })
+    );
+  });
+
+  it("transfer_in", async () => {
+    await measure(
+      "transfer_in",
+      program.methods
+        .transferIn(new BN(500_000))
+        .accountsPartial({
+          operator: admin,
+          authority: user.publicKey,
+          tokenMint: mint,
+          fromTokenAccount: userTokenAccount,
+          config,
+          vault,
+          toTokenAccount: vaultTokenAccount,
+          access,
+          userCredit,
+        })
+        .signers([user])
+        .rpc({ commitment: "confirmed" })
+    );
+  });
+
+  it("transfer_out to a registered destination", async () => {
+    await nextSecond();
+    await measure(
+      "transfer_out (destination)",
+      program.methods
+        .transferOut(new BN(100_000))
+        .accountsPartial({
+          operator: admin,
+          config,
+          vault,
+          tokenMint: mint,
+          fromTokenAccount: vaultTokenAccount,
+          toTokenAccount: receiverTokenAccount,
+          destination,
+          userCredit: null,
+        })
+        .rpc({ commitment: "confirmed" })
+    );
+  });
+
+  it("transfer_out from ledger credit", async () => {
+    await nextSecond();
+    await measure(
+      "transfer_out (credit)",
+      program.methods
+        .transferOut(new BN(100_000))
+        .accountsPartial({
+          operator: admin,
+          config,
+          vault,
+          tokenMint: mint,
+          fromTokenAccount: vaultTokenAccount,
+          toTokenAccount: userTokenAccount,
+          destination: null,
+          userCredit,
+        })
+        .rpc({ commitment: "confirmed" })
+    );
+  });
+
+  it("init_trade_log", async () => {
+    await measure(
+      "init_trade_log",
+      program.methods
+        .initTradeLog(64)
+        .accountsPartial({
+          admin,
+          config,
+          tradeLog,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc({ commitment: "confirmed" })
+    );
+  });
+
+  it("okx_aggregator through a two-hop route", async () => {
+    await nextSecond();
+    const inAmount = new BN(100_000);
+    const clientOrderId = new BN(1);
+    const route = await mockVenue.methods
+      .swap(inAmount, new BN(90_000))
+      .accountsPartial({
+        userTransferAuthority: vault,
+        sourceTokenAccount: vaultTokenAccount,
+        destinationTokenAccount: vaultOutputTokenAccount,
+        poolAuthority,
+        firstPoolInput,
+        firstPoolOutput,
+        secondPoolInput,
+        secondPoolOutput,
+      })
+      .instruction();
+    await measure(
+      "okx_aggregator (2 hops)",
+      program.methods
+        .okxAggregator({
+          data: route.data,
+          inAmount,
+          delegate: user.publicKey,
+          clientOrderId,
+          intent: null,
+        })
+        .accountsPartial({
+          inputMint: mint,
+          inputMintProgram: TOKEN_PROGRAM_ID,
+          outputMint,
+          outputMintProgram: TOKEN_PROGRAM_ID,
+          operator: admin,
+          vault,
+          delegateInputTokenAccount: userTokenAccount,
+          vaultInputTokenAccount: vaultTokenAccount,
+          vaultOutputTokenAccount,
+          config,
+          access,
+          user: user.publicKey,
+          receiverOutputTokenAccount: userOutputTokenAccount,
+          nativeOutputTokenAccount: null,
+          feeTokenAccount: pda(
+            Buffer.from("jupiter-delegate-fee-vault"),
+            namespaceSeed,
+            outputMint.toBuffer()
+          ),
+          feeVaultAuthority: vault,
+          partner: null,
+          partnerFeeTokenAccount: null,
+          okxProgram: mockVenue.programId,
+          instructionsSysvar: null,
+          tradeReceipt: pda(
+            Buffer.from("jupiter-delegate-trade-receipt"),
+            namespaceSeed,
+            user.publicKey.toBuffer(),
+            clientOrderId.toArrayLike(Buffer, "le", 8)
+          ),
+          userStats: pda(
+            Buffer.from("jupiter-delegate-user-stats"),
+            namespaceSeed,
+            user.publicKey.toBuffer()
+          ),
+          tradeLog,
+          userCredit: null,
+          systemProgram: SystemProgram.programId,
+        })
+        // route accounts go to the venue, the vault signs by seeds
+        .remainingAccounts(
+          route.keys.map((key) => ({ ...key, isSigner: false }))
+        )
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }),
+        ])
+        .rpc({ commitment: "confirmed" })
+    );
+  });
+});